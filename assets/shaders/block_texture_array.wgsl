@@ -0,0 +1,65 @@
+// Block material fragment shader: the standard PBR fragment with the base color sampled
+// from the block texture array.
+//
+// Block meshes carry tile-local UVs in UV_0 and the texture layer of each face in the X
+// of UV_1, which the standard vertex shader passes through.
+
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    mesh_view_bindings::view,
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::{
+        alpha_discard, apply_normal_mapping, apply_pbr_lighting, calculate_tbn_mikktspace,
+        main_pass_post_lighting_processing,
+    },
+    pbr_types::{STANDARD_MATERIAL_FLAGS_DOUBLE_SIDED_BIT, STANDARD_MATERIAL_FLAGS_UNLIT_BIT},
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var block_textures: texture_2d_array<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(101) var block_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(102) var block_normals: texture_2d_array<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(103) var block_normal_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(104) var<uniform> normal_mapping: u32;
+
+@fragment
+fn fragment(in: VertexOutput, @builtin(front_facing) is_front: bool) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+#ifdef VERTEX_UVS_A
+#ifdef VERTEX_UVS_B
+    let layer = i32(in.uv_b.x + 0.5);
+#else
+    let layer = 0;
+#endif
+    pbr_input.material.base_color *=
+        textureSampleBias(block_textures, block_sampler, in.uv, layer, view.mip_bias);
+
+#ifdef VERTEX_TANGENTS
+    if normal_mapping != 0u {
+        let Nt = textureSampleBias(
+            block_normals,
+            block_normal_sampler,
+            in.uv,
+            layer,
+            view.mip_bias,
+        ).rgb;
+        let TBN = calculate_tbn_mikktspace(pbr_input.world_normal, in.world_tangent);
+        let flags = pbr_input.material.flags;
+        let double_sided = (flags & STANDARD_MATERIAL_FLAGS_DOUBLE_SIDED_BIT) != 0u;
+        pbr_input.N = apply_normal_mapping(flags, TBN, double_sided, is_front, Nt);
+    }
+#endif
+#endif
+
+    pbr_input.material.base_color =
+        alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+    var out: FragmentOutput;
+    if (pbr_input.material.flags & STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
+        out.color = apply_pbr_lighting(pbr_input);
+    } else {
+        out.color = pbr_input.material.base_color;
+    }
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+    return out;
+}
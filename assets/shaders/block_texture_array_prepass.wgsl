@@ -0,0 +1,73 @@
+// Block material prepass fragment shader: discards the texels the block texture array
+// makes transparent, so e.g. leaves and tall grass cast cut-out shadows.
+
+#import bevy_pbr::{
+    mesh_view_bindings::view,
+    pbr_bindings,
+    pbr_types::{
+        STANDARD_MATERIAL_FLAGS_ALPHA_MODE_MASK, STANDARD_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE,
+        STANDARD_MATERIAL_FLAGS_ALPHA_MODE_RESERVED_BITS,
+    },
+    prepass_io::VertexOutput,
+}
+
+#ifdef PREPASS_FRAGMENT
+#import bevy_pbr::prepass_io::FragmentOutput
+#endif
+
+#ifdef MOTION_VECTOR_PREPASS
+#import bevy_pbr::pbr_prepass_functions::calculate_motion_vector
+#endif
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var block_textures: texture_2d_array<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(101) var block_sampler: sampler;
+
+// Alpha below which blended texels leave no depth, as in the standard prepass.
+const BLEND_ALPHA_CUTOFF: f32 = 0.05;
+
+// Discard the fragment when its texel is transparent under the material's alpha mode.
+fn block_alpha_discard(in: VertexOutput) {
+#ifdef MAY_DISCARD
+#ifdef VERTEX_UVS_A
+#ifdef VERTEX_UVS_B
+    let layer = i32(in.uv_b.x + 0.5);
+#else
+    let layer = 0;
+#endif
+    let alpha = pbr_bindings::material.base_color.a
+        * textureSampleBias(block_textures, block_sampler, in.uv, layer, view.mip_bias).a;
+    let alpha_mode = pbr_bindings::material.flags & STANDARD_MATERIAL_FLAGS_ALPHA_MODE_RESERVED_BITS;
+    if alpha_mode == STANDARD_MATERIAL_FLAGS_ALPHA_MODE_MASK {
+        if alpha < pbr_bindings::material.alpha_cutoff {
+            discard;
+        }
+    } else if alpha_mode != STANDARD_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE && alpha < BLEND_ALPHA_CUTOFF {
+        discard;
+    }
+#endif
+#endif
+}
+
+#ifdef PREPASS_FRAGMENT
+@fragment
+fn fragment(in: VertexOutput) -> FragmentOutput {
+    block_alpha_discard(in);
+
+    var out: FragmentOutput;
+#ifdef UNCLIPPED_DEPTH_ORTHO_EMULATION
+    out.frag_depth = in.unclipped_depth;
+#endif
+#ifdef NORMAL_PREPASS
+    out.normal = vec4(in.world_normal * 0.5 + vec3(0.5), 1.0);
+#endif
+#ifdef MOTION_VECTOR_PREPASS
+    out.motion_vector = calculate_motion_vector(in.world_position, in.previous_world_position);
+#endif
+    return out;
+}
+#else
+@fragment
+fn fragment(in: VertexOutput) {
+    block_alpha_discard(in);
+}
+#endif
@@ -0,0 +1,53 @@
+// Fluid material fragment shader for the strip atlas: the standard PBR fragment with the
+// base color sampled from the face's atlas tile at UVs that drift with time, wrapped
+// inside the tile.
+//
+// Strip atlas UVs span every tile side by side and stay inset from tile edges, so the
+// tile a fragment belongs to is the whole part of its scaled U.
+
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::{alpha_discard, apply_pbr_lighting, main_pass_post_lighting_processing},
+    pbr_types::STANDARD_MATERIAL_FLAGS_UNLIT_BIT,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var block_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(101) var block_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(102) var<uniform> time: f32;
+@group(#{MATERIAL_BIND_GROUP}) @binding(103) var<uniform> tiles: u32;
+
+// Tiles per second the texture drifts; whole tiles per 48 s flow cycle so it loops cleanly.
+const FLOW: vec2<f32> = vec2<f32>(1.0 / 16.0, 1.0 / 24.0);
+
+@fragment
+fn fragment(in: VertexOutput, @builtin(front_facing) is_front: bool) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+#ifdef VERTEX_UVS_A
+    let count = f32(tiles);
+    let tile = floor(in.uv.x * count);
+    let flowing = vec2<f32>(in.uv.x * count - tile, in.uv.y) + FLOW * time;
+    let wrapped = fract(flowing);
+    // Gradients of the unwrapped UVs keep the mip level steady across the wrap seam.
+    pbr_input.material.base_color *= textureSampleGrad(
+        block_texture,
+        block_sampler,
+        vec2<f32>((tile + wrapped.x) / count, wrapped.y),
+        dpdx(flowing) * vec2<f32>(1.0 / count, 1.0),
+        dpdy(flowing) * vec2<f32>(1.0 / count, 1.0),
+    );
+#endif
+
+    pbr_input.material.base_color =
+        alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+    var out: FragmentOutput;
+    if (pbr_input.material.flags & STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
+        out.color = apply_pbr_lighting(pbr_input);
+    } else {
+        out.color = pbr_input.material.base_color;
+    }
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+    return out;
+}
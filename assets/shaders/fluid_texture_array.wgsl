@@ -0,0 +1,54 @@
+// Fluid material fragment shader: the standard PBR fragment with the base color sampled
+// from the block texture array at UVs that drift with time, wrapped inside the tile.
+//
+// Fluid meshes carry tile-local UVs in UV_0 and the texture layer of each face in the X
+// of UV_1, like block meshes.
+
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::{alpha_discard, apply_pbr_lighting, main_pass_post_lighting_processing},
+    pbr_types::STANDARD_MATERIAL_FLAGS_UNLIT_BIT,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var block_textures: texture_2d_array<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(101) var block_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(102) var<uniform> time: f32;
+
+// Tiles per second the texture drifts; whole tiles per 48 s flow cycle so it loops cleanly.
+const FLOW: vec2<f32> = vec2<f32>(1.0 / 16.0, 1.0 / 24.0);
+
+@fragment
+fn fragment(in: VertexOutput, @builtin(front_facing) is_front: bool) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+#ifdef VERTEX_UVS_A
+#ifdef VERTEX_UVS_B
+    let layer = i32(in.uv_b.x + 0.5);
+#else
+    let layer = 0;
+#endif
+    let flowing = in.uv + FLOW * time;
+    // Gradients of the unwrapped UVs keep the mip level steady across the wrap seam.
+    pbr_input.material.base_color *= textureSampleGrad(
+        block_textures,
+        block_sampler,
+        fract(flowing),
+        layer,
+        dpdx(flowing),
+        dpdy(flowing),
+    );
+#endif
+
+    pbr_input.material.base_color =
+        alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+    var out: FragmentOutput;
+    if (pbr_input.material.flags & STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
+        out.color = apply_pbr_lighting(pbr_input);
+    } else {
+        out.color = pbr_input.material.base_color;
+    }
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+    return out;
+}
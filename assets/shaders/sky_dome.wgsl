@@ -0,0 +1,49 @@
+// Sky dome shader: a vertical gradient from the horizon to the zenith color, brightened
+// towards the sun, drawn at the far plane behind everything else.
+//
+// The dome is centered on the camera, so a vertex's local position is its view direction.
+
+#import bevy_pbr::mesh_functions::{get_world_from_local, mesh_position_local_to_clip}
+
+struct SkyDome {
+    zenith: vec4<f32>,
+    horizon: vec4<f32>,
+    sun_glow: vec4<f32>,
+    sun_direction: vec3<f32>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> sky: SkyDome;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) direction: vec3<f32>,
+}
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = mesh_position_local_to_clip(
+        get_world_from_local(vertex.instance_index),
+        vec4(vertex.position, 1.0),
+    );
+    // Reverse-Z puts the far plane at zero depth, so every other mesh draws in front.
+    out.clip_position.z = 0.0;
+    out.direction = vertex.position;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let direction = normalize(in.direction);
+    let height = clamp(direction.y, 0.0, 1.0);
+    var color = mix(sky.horizon.rgb, sky.zenith.rgb, sqrt(height));
+    // A wide halo plus a tighter one right around the sun.
+    let towards_sun = max(dot(direction, sky.sun_direction), 0.0);
+    color += sky.sun_glow.rgb * (0.6 * pow(towards_sun, 4.0) + 0.4 * pow(towards_sun, 32.0));
+    return vec4(color, 1.0);
+}
@@ -0,0 +1,36 @@
+// Sun billboard shader: the sun disc texture, drawn at the far plane like the sky dome so
+// terrain always covers it, whatever the billboard's distance from the camera.
+
+#import bevy_pbr::mesh_functions::{get_world_from_local, mesh_position_local_to_clip}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var sun_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var sun_sampler: sampler;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = mesh_position_local_to_clip(
+        get_world_from_local(vertex.instance_index),
+        vec4(vertex.position, 1.0),
+    );
+    // Reverse-Z far plane: the sky dome's depth ties and passes, any nearer surface fails.
+    out.clip_position.z = 0.0;
+    out.uv = vertex.uv;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(sun_texture, sun_sampler, in.uv);
+}
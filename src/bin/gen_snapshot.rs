@@ -0,0 +1,364 @@
+#[path = "../terrain.rs"]
+#[allow(
+    dead_code,
+    reason = "runtime caches and chunk-generation helpers are unused by the snapshot tool"
+)]
+mod terrain;
+
+#[path = "../dimensions.rs"]
+#[allow(
+    dead_code,
+    reason = "the snapshot tool only needs the dimensions terrain generation reads"
+)]
+mod dimensions;
+
+use dimensions::{CHUNK_SIZE, MIN_CHUNK_LAYER};
+use png::{BitDepth, ColorType, Decoder, Encoder};
+use std::env;
+use std::fs;
+use std::io::{BufWriter, Cursor};
+use std::path::{Path, PathBuf};
+use terrain::{Biome, NoiseBackend, Surface, TerrainConfig, TerrainNoise};
+
+/// Snapshot edge length in blocks (one pixel per column).
+const SNAPSHOT_SIZE: u32 = 256;
+/// Directory of the checked-in golden snapshots compared against by default.
+const GOLDEN_DIR: &str = "tests/golden";
+/// Snapshot maps, each compared against `GOLDEN_DIR/<name>_256.png`.
+const MAP_NAMES: [&str; 3] = ["terrain", "biomes", "surface"];
+/// World seed the golden snapshots are rendered with.
+const SNAPSHOT_SEED: u64 = 0;
+/// Column height at or above which a column counts as mountain.
+const MOUNTAIN_HEIGHT: i32 = 12;
+
+/// Print CLI usage.
+fn print_usage(program: &str) {
+    eprintln!(
+        "Usage: {program} [--regenerate] [--output <dir>] [--noise-backends <dir>]\n\
+         Renders {SNAPSHOT_SIZE}x{SNAPSHOT_SIZE} top-down height, biome, and surface-block\n\
+         maps centered on the origin and compares them against the goldens in {GOLDEN_DIR}.\n\
+         Use --regenerate when terrain changes are intentional; --output writes the maps\n\
+         into <dir> without comparing; --noise-backends writes one heightmap per noise\n\
+         backend into <dir> for comparison."
+    );
+}
+
+/// Snapshot action selected on the command line.
+enum Mode {
+    /// Compare against the golden images.
+    Check,
+    /// Overwrite the golden images.
+    Regenerate,
+    /// Write the maps into a custom directory.
+    Output(PathBuf),
+    /// Write one heightmap per noise backend into a directory.
+    NoiseBackends(PathBuf),
+}
+
+/// Parse CLI args into the snapshot mode.
+fn parse_args() -> Result<Mode, String> {
+    let mut mode = Mode::Check;
+    let mut it = env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--regenerate" => mode = Mode::Regenerate,
+            "--output" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| "--output requires a value".to_string())?;
+                mode = Mode::Output(PathBuf::from(value));
+            }
+            "--noise-backends" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| "--noise-backends requires a value".to_string())?;
+                mode = Mode::NoiseBackends(PathBuf::from(value));
+            }
+            "--help" | "-h" => {
+                let program = env::args()
+                    .next()
+                    .unwrap_or_else(|| "gen_snapshot".to_string());
+                print_usage(&program);
+                std::process::exit(0);
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        }
+    }
+    Ok(mode)
+}
+
+/// Columns of the snapshot window in row-major order (rows along +Z).
+struct Snapshot {
+    /// Surface height of each column, rivers carved in.
+    heights: Vec<i32>,
+    /// Biome of each column.
+    biomes: Vec<Biome>,
+    /// Surface block of each column, or `None` where water covers it.
+    surfaces: Vec<Option<Surface>>,
+    /// Whether each column lies in a river.
+    rivers: Vec<bool>,
+}
+
+impl Snapshot {
+    /// Sample every column of the snapshot window.
+    ///
+    /// Callers pass the built-in terrain shaping, not `assets/terrain.ron`, so local tuning
+    /// does not break the golden comparison. Trees, ruins, caves, and decorations are not
+    /// captured, so changes to those alone do not fail it.
+    fn sample(config: &TerrainConfig) -> Self {
+        let half = SNAPSHOT_SIZE as i32 / 2;
+        let count = (SNAPSHOT_SIZE * SNAPSHOT_SIZE) as usize;
+        let mut snapshot = Self {
+            heights: Vec::with_capacity(count),
+            biomes: Vec::with_capacity(count),
+            surfaces: Vec::with_capacity(count),
+            rivers: Vec::with_capacity(count),
+        };
+        for z in -half..half {
+            for x in -half..half {
+                let height = TerrainNoise::height_at(x, z, SNAPSHOT_SEED, config);
+                let biome = TerrainNoise::biome_at(x, z, SNAPSHOT_SEED, config);
+                let flooded = TerrainNoise::water_level_at(x, z, SNAPSHOT_SEED, config) > height;
+                snapshot.heights.push(height);
+                snapshot.biomes.push(biome);
+                snapshot.surfaces.push((!flooded).then(|| {
+                    TerrainNoise::surface_for(
+                        biome,
+                        height,
+                        TerrainNoise::snow_line_at(x, z, SNAPSHOT_SEED),
+                        TerrainNoise::is_sand_patch(x, z, height, SNAPSHOT_SEED),
+                    )
+                }));
+                snapshot
+                    .rivers
+                    .push(TerrainNoise::river_mask(x, z, SNAPSHOT_SEED) > 0.0);
+            }
+        }
+        snapshot
+    }
+
+    /// Return the grayscale pixels of every map, in `MAP_NAMES` order.
+    fn maps(&self) -> [Vec<u8>; 3] {
+        [
+            heights_to_pixels(&self.heights),
+            self.biomes
+                .iter()
+                .map(|biome| biome_shade(*biome))
+                .collect(),
+            self.surfaces
+                .iter()
+                .map(|surface| surface_shade(*surface))
+                .collect(),
+        ]
+    }
+
+    /// Return `(mean_height, mountain_fraction, river_fraction)` over the window.
+    fn stats(&self) -> (f32, f32, f32) {
+        let count = self.heights.len().max(1) as f32;
+        let mean = self
+            .heights
+            .iter()
+            .map(|height| *height as f32)
+            .sum::<f32>()
+            / count;
+        let mountains = self
+            .heights
+            .iter()
+            .filter(|height| **height >= MOUNTAIN_HEIGHT)
+            .count() as f32;
+        let rivers = self.rivers.iter().filter(|river| **river).count() as f32;
+        (mean, mountains / count, rivers / count)
+    }
+}
+
+/// Map heights to grayscale pixels, scaled over the terrain's clamped height range.
+fn heights_to_pixels(heights: &[i32]) -> Vec<u8> {
+    let max = (CHUNK_SIZE * 2 - 1) as f32;
+    heights
+        .iter()
+        .map(|height| ((*height as f32 / max).clamp(0.0, 1.0) * 255.0).round() as u8)
+        .collect()
+}
+
+/// Return the gray a biome is drawn with in the biome map.
+fn biome_shade(biome: Biome) -> u8 {
+    match biome {
+        Biome::Plains => 80,
+        Biome::Desert => 170,
+        Biome::Mountains => 255,
+    }
+}
+
+/// Return the gray a surface block is drawn with in the surface map; water is black.
+fn surface_shade(surface: Option<Surface>) -> u8 {
+    match surface {
+        None => 0,
+        Some(Surface::Grass) => 60,
+        Some(Surface::Dirt) => 100,
+        Some(Surface::Stone) => 140,
+        Some(Surface::Sand) => 190,
+        Some(Surface::SnowyGrass) => 255,
+    }
+}
+
+/// Return the golden image path of the map named `name`.
+fn golden_path(name: &str) -> PathBuf {
+    Path::new(GOLDEN_DIR).join(format!("{name}_{SNAPSHOT_SIZE}.png"))
+}
+
+/// Encode grayscale pixels to PNG bytes.
+fn encode_png(pixels: &[u8]) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = Encoder::new(BufWriter::new(&mut bytes), SNAPSHOT_SIZE, SNAPSHOT_SIZE);
+        encoder.set_color(ColorType::Grayscale);
+        encoder.set_depth(BitDepth::Eight);
+        let mut png_writer = encoder
+            .write_header()
+            .map_err(|e| format!("Failed to write PNG header: {e}"))?;
+        png_writer
+            .write_image_data(pixels)
+            .map_err(|e| format!("Failed to write PNG data: {e}"))?;
+    }
+    Ok(bytes)
+}
+
+/// Decode grayscale PNG bytes back into pixels.
+fn decode_png(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let mut reader = Decoder::new(Cursor::new(bytes))
+        .read_info()
+        .map_err(|e| format!("Failed to read PNG header: {e}"))?;
+    let size = reader
+        .output_buffer_size()
+        .ok_or_else(|| "PNG output size overflows".to_string())?;
+    let mut pixels = vec![0; size];
+    let info = reader
+        .next_frame(&mut pixels)
+        .map_err(|e| format!("Failed to decode PNG data: {e}"))?;
+    if info.color_type != ColorType::Grayscale
+        || info.width != SNAPSHOT_SIZE
+        || info.height != SNAPSHOT_SIZE
+    {
+        return Err(format!(
+            "Expected {SNAPSHOT_SIZE}x{SNAPSHOT_SIZE} grayscale PNG, got {}x{} {:?}",
+            info.width, info.height, info.color_type
+        ));
+    }
+    pixels.truncate(info.buffer_size());
+    Ok(pixels)
+}
+
+/// Write bytes to `path`, creating parent directories.
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {e}", parent.display()))?;
+    }
+    fs::write(path, bytes).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+fn main() -> Result<(), String> {
+    let mode = parse_args()?;
+    let snapshot = Snapshot::sample(&TerrainConfig::default());
+    let (mean, mountain_fraction, river_fraction) = snapshot.stats();
+    println!("mean height: {mean:.3}");
+    println!("mountain fraction: {mountain_fraction:.4}");
+    println!("river fraction: {river_fraction:.4}");
+    let maps = snapshot.maps();
+
+    match mode {
+        Mode::Regenerate => {
+            for (name, pixels) in MAP_NAMES.iter().zip(&maps) {
+                let path = golden_path(name);
+                write_file(&path, &encode_png(pixels)?)?;
+                println!("Wrote {}", path.display());
+            }
+        }
+        Mode::Output(dir) => {
+            for (name, pixels) in MAP_NAMES.iter().zip(&maps) {
+                let path = dir.join(format!("{name}_{SNAPSHOT_SIZE}.png"));
+                write_file(&path, &encode_png(pixels)?)?;
+                println!("Wrote {}", path.display());
+            }
+        }
+        Mode::NoiseBackends(dir) => {
+            for noise in [NoiseBackend::Value, NoiseBackend::Gradient] {
+                let config = TerrainConfig {
+                    noise,
+                    ..TerrainConfig::default()
+                };
+                let pixels = heights_to_pixels(&Snapshot::sample(&config).heights);
+                let path = dir.join(format!("heights_{noise:?}.png").to_lowercase());
+                write_file(&path, &encode_png(&pixels)?)?;
+                println!("Wrote {}", path.display());
+            }
+        }
+        Mode::Check => {
+            let mut drifted = Vec::new();
+            for (name, pixels) in MAP_NAMES.iter().zip(&maps) {
+                let path = golden_path(name);
+                let golden = fs::read(&path)
+                    .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+                let expected = decode_png(&golden)?;
+                let differing = expected.iter().zip(pixels).filter(|(a, b)| a != b).count();
+                if differing > 0 {
+                    drifted.push(format!("{differing} pixels differ from {}", path.display()));
+                }
+            }
+            if !drifted.is_empty() {
+                return Err(format!(
+                    "{}; rerun with --regenerate if intentional",
+                    drifted.join(", ")
+                ));
+            }
+            println!("Snapshots match {GOLDEN_DIR}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mean column height of the golden snapshot.
+    const EXPECTED_MEAN_HEIGHT: f32 = 7.18;
+    /// Fraction of mountain columns in the golden snapshot.
+    const EXPECTED_MOUNTAIN_FRACTION: f32 = 0.281;
+    /// Fraction of river columns in the golden snapshot.
+    const EXPECTED_RIVER_FRACTION: f32 = 0.0467;
+
+    /// Verify generated terrain still matches the checked-in golden maps pixel for pixel.
+    #[test]
+    fn snapshot_matches_golden() {
+        let goldens: [&[u8]; 3] = [
+            include_bytes!("../../tests/golden/terrain_256.png"),
+            include_bytes!("../../tests/golden/biomes_256.png"),
+            include_bytes!("../../tests/golden/surface_256.png"),
+        ];
+        let maps = Snapshot::sample(&TerrainConfig::default()).maps();
+        for ((name, golden), actual) in MAP_NAMES.iter().zip(goldens).zip(maps) {
+            let expected = decode_png(golden).unwrap();
+            assert!(expected == actual, "{name} snapshot drifted from golden");
+        }
+    }
+
+    /// Verify coarse terrain statistics stay within tolerance of the golden world.
+    #[test]
+    fn terrain_stats_stay_within_tolerance() {
+        let (mean, mountain_fraction, river_fraction) =
+            Snapshot::sample(&TerrainConfig::default()).stats();
+        assert!(
+            (mean - EXPECTED_MEAN_HEIGHT).abs() < 0.25,
+            "mean height {mean}"
+        );
+        assert!(
+            (mountain_fraction - EXPECTED_MOUNTAIN_FRACTION).abs() < 0.01,
+            "mountain fraction {mountain_fraction}"
+        );
+        assert!(
+            (river_fraction - EXPECTED_RIVER_FRACTION).abs() < 0.005,
+            "river fraction {river_fraction}"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Number of hottest chunk coordinates printed in the summary.
+const DEFAULT_TOP_CHUNKS: usize = 10;
+
+/// Print CLI usage.
+fn print_usage(program: &str) {
+    eprintln!(
+        "Usage: {program} <session.jsonl>... [--top <n>]\n\
+         Summarizes session journal event counts per type and the hottest chunk coordinates."
+    );
+}
+
+/// Parse CLI args into journal paths and hot-chunk count.
+fn parse_args() -> Result<(Vec<PathBuf>, usize), String> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut top = DEFAULT_TOP_CHUNKS;
+
+    let mut it = env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--top" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| "--top requires a value".to_string())?;
+                top = value
+                    .parse()
+                    .map_err(|e| format!("Invalid --top value {value}: {e}"))?;
+            }
+            "--help" | "-h" => {
+                let program = env::args()
+                    .next()
+                    .unwrap_or_else(|| "journal_stats".to_string());
+                print_usage(&program);
+                std::process::exit(0);
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    if paths.is_empty() {
+        return Err("at least one journal file is required".to_string());
+    }
+    Ok((paths, top))
+}
+
+/// Extract the string value of a top-level `"key":"value"` field from one JSON line.
+fn string_field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let pattern = format!("\"{key}\":\"");
+    let start = line.find(&pattern)? + pattern.len();
+    let len = line[start..].find('"')?;
+    Some(&line[start..start + len])
+}
+
+/// Extract the `"chunk":[x,y,z]` coordinate from one JSON line.
+fn chunk_field(line: &str) -> Option<[i32; 3]> {
+    let pattern = "\"chunk\":[";
+    let start = line.find(pattern)? + pattern.len();
+    let len = line[start..].find(']')?;
+    let mut parts = line[start..start + len]
+        .split(',')
+        .map(|part| part.trim().parse::<i32>());
+    let x = parts.next()?.ok()?;
+    let y = parts.next()?.ok()?;
+    let z = parts.next()?.ok()?;
+    Some([x, y, z])
+}
+
+fn main() -> Result<(), String> {
+    let (paths, top) = parse_args()?;
+
+    let mut per_type: HashMap<String, u64> = HashMap::new();
+    let mut per_chunk: HashMap<[i32; 3], u64> = HashMap::new();
+    let mut malformed = 0_u64;
+    for path in &paths {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let Some(event_type) = string_field(line, "type") else {
+                malformed += 1;
+                continue;
+            };
+            *per_type.entry(event_type.to_string()).or_default() += 1;
+            if let Some(chunk) = chunk_field(line) {
+                *per_chunk.entry(chunk).or_default() += 1;
+            }
+        }
+    }
+
+    let mut types: Vec<(String, u64)> = per_type.into_iter().collect();
+    types.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    println!("Events per type:");
+    for (event_type, count) in &types {
+        println!("  {event_type:<16} {count}");
+    }
+
+    let mut chunks: Vec<([i32; 3], u64)> = per_chunk.into_iter().collect();
+    chunks.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    println!("Hottest chunks:");
+    for ([x, y, z], count) in chunks.iter().take(top) {
+        println!("  ({x}, {y}, {z}) {count}");
+    }
+
+    if malformed > 0 {
+        println!("Skipped {malformed} malformed line(s)");
+    }
+    Ok(())
+}
@@ -4,7 +4,8 @@ use bevy::image::{CompressedImageFormats, Image, ImageSampler, ImageType};
 mod material_catalog;
 
 use material_catalog::{
-    TextureId, atlas_texture_order, source_base_filename, source_overlay_filename,
+    ATLAS_TILE_PIXELS, TextureId, atlas_texture_order, is_tinted_base, source_base_filename,
+    source_overlay_filename,
 };
 use png::{BitDepth, ColorType, Encoder};
 use std::env;
@@ -14,20 +15,27 @@ use std::path::{Path, PathBuf};
 
 /// RGBA pixel stride in bytes.
 const RGBA_STRIDE: usize = 4;
+/// Scale applied to luminance gradients when deriving normal maps (higher = bumpier).
+const NORMAL_STRENGTH: f32 = 2.0;
 
 /// Print CLI usage.
 fn print_usage(program: &str) {
     eprintln!(
-        "Usage: {program} --source-dir <dir> [--output <path>]\n\
+        "Usage: {program} --source-dir <dir> [--output <path>] [--normals] [--array]\n\
          Default output: assets/textures/atlas.png\n\
+         --normals also writes a tangent-space normal atlas next to it (atlas_n.png).\n\
+         --array also writes the tiles stacked top to bottom, one texture array layer\n\
+         each (atlas_array.png, and atlas_array_n.png with --normals).\n\
          Required files in <dir> are defined by shared material_catalog."
     );
 }
 
-/// Parse simple CLI args for source dir and output path.
-fn parse_args() -> Result<(PathBuf, PathBuf), String> {
+/// Parse simple CLI args for source dir, output path, and normal-map and array flags.
+fn parse_args() -> Result<(PathBuf, PathBuf, bool, bool), String> {
     let mut source_dir: Option<PathBuf> = None;
     let mut output = PathBuf::from("assets/textures/atlas.png");
+    let mut normals = false;
+    let mut array = false;
 
     let mut it = env::args().skip(1);
     while let Some(arg) = it.next() {
@@ -44,6 +52,8 @@ fn parse_args() -> Result<(PathBuf, PathBuf), String> {
                     .ok_or_else(|| "--output requires a value".to_string())?;
                 output = PathBuf::from(value);
             }
+            "--normals" => normals = true,
+            "--array" => array = true,
             "--help" | "-h" => {
                 let program = env::args()
                     .next()
@@ -56,7 +66,7 @@ fn parse_args() -> Result<(PathBuf, PathBuf), String> {
     }
 
     let source = source_dir.ok_or_else(|| "--source-dir is required".to_string())?;
-    Ok((source, output))
+    Ok((source, output, normals, array))
 }
 
 /// Decoded RGBA8 texture payload.
@@ -165,6 +175,18 @@ fn alpha_blend(base: &RgbaTexture, top: &RgbaTexture) -> Result<RgbaTexture, Str
     })
 }
 
+/// Turn a green texture into the gray base the mesh builder tints per biome.
+///
+/// Keeps the green channel as the gray level, so the plains grass tint restores the
+/// source colors.
+fn grayscale_base(mut texture: RgbaTexture) -> RgbaTexture {
+    for px in texture.data.chunks_exact_mut(RGBA_STRIDE) {
+        px[0] = px[1];
+        px[2] = px[1];
+    }
+    texture
+}
+
 /// Build atlas RGBA bytes in horizontal order from provided tile list.
 fn build_atlas_data(tiles: &[RgbaTexture]) -> Vec<u8> {
     let tile_w = tiles[0].width as usize;
@@ -187,6 +209,76 @@ fn build_atlas_data(tiles: &[RgbaTexture]) -> Vec<u8> {
     out
 }
 
+/// Build texture array RGBA bytes: the tiles stacked top to bottom, one layer each.
+fn build_array_data(tiles: &[RgbaTexture]) -> Vec<u8> {
+    tiles
+        .iter()
+        .flat_map(|tile| tile.data.iter().copied())
+        .collect()
+}
+
+/// Derive a tangent-space normal tile from albedo luminance with a wrapping Sobel filter.
+///
+/// Output follows Bevy's default (OpenGL, +Y up) convention with full alpha.
+fn build_normal_tile(tile: &RgbaTexture) -> RgbaTexture {
+    let width = tile.width as usize;
+    let height = tile.height as usize;
+    let luminance: Vec<f32> = tile
+        .data
+        .chunks_exact(RGBA_STRIDE)
+        .map(|px| (0.2126 * px[0] as f32 + 0.7152 * px[1] as f32 + 0.0722 * px[2] as f32) / 255.0)
+        .collect();
+    // Tiles repeat across faces, so sample neighbors with wrap-around.
+    let sample = |x: usize, y: usize, dx: isize, dy: isize| {
+        let sx = (x as isize + dx).rem_euclid(width as isize) as usize;
+        let sy = (y as isize + dy).rem_euclid(height as isize) as usize;
+        luminance[sy * width + sx]
+    };
+
+    let mut data = vec![0_u8; tile.data.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let gx = (sample(x, y, 1, -1) + 2.0 * sample(x, y, 1, 0) + sample(x, y, 1, 1))
+                - (sample(x, y, -1, -1) + 2.0 * sample(x, y, -1, 0) + sample(x, y, -1, 1));
+            let gy = (sample(x, y, -1, 1) + 2.0 * sample(x, y, 0, 1) + sample(x, y, 1, 1))
+                - (sample(x, y, -1, -1) + 2.0 * sample(x, y, 0, -1) + sample(x, y, 1, -1));
+            // Image rows grow downward while tangent-space +Y points up.
+            let nx = -gx * NORMAL_STRENGTH;
+            let ny = gy * NORMAL_STRENGTH;
+            let len = (nx * nx + ny * ny + 1.0).sqrt();
+            let idx = (y * width + x) * RGBA_STRIDE;
+            for (channel, value) in [nx / len, ny / len, 1.0 / len].into_iter().enumerate() {
+                data[idx + channel] = ((value * 0.5 + 0.5) * 255.0).round() as u8;
+            }
+            data[idx + 3] = 255;
+        }
+    }
+
+    RgbaTexture {
+        width: tile.width,
+        height: tile.height,
+        data,
+    }
+}
+
+/// Return the texture array path next to `output` (`atlas.png` -> `atlas_array.png`).
+fn array_atlas_path(output: &Path) -> PathBuf {
+    let stem = output
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "atlas".to_string());
+    output.with_file_name(format!("{stem}_array.png"))
+}
+
+/// Return the normal-atlas path next to `output` (`atlas.png` -> `atlas_n.png`).
+fn normal_atlas_path(output: &Path) -> PathBuf {
+    let stem = output
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "atlas".to_string());
+    output.with_file_name(format!("{stem}_n.png"))
+}
+
 /// Ensure output parent directory exists.
 fn ensure_parent_dir(path: &Path) -> Result<(), String> {
     let Some(parent) = path.parent() else {
@@ -217,7 +309,7 @@ fn save_png_rgba8(path: &Path, width: u32, height: u32, data: &[u8]) -> Result<(
 }
 
 fn main() -> Result<(), String> {
-    let (source_dir, output) = parse_args()?;
+    let (source_dir, output, normals, array) = parse_args()?;
 
     let mut tiles: Vec<(TextureId, String, RgbaTexture)> = Vec::new();
     for texture in atlas_texture_order() {
@@ -232,6 +324,11 @@ fn main() -> Result<(), String> {
         } else {
             base
         };
+        let final_tile = if is_tinted_base(*texture) {
+            grayscale_base(final_tile)
+        } else {
+            final_tile
+        };
 
         tiles.push((*texture, base_filename.to_string(), final_tile));
     }
@@ -240,16 +337,55 @@ fn main() -> Result<(), String> {
         .map(|(_, filename, texture)| (filename.as_str(), texture))
         .collect();
     let (tile_w, tile_h) = ensure_same_size(&refs)?;
+    // Runtime UVs are inset by half a texel of this size, so the tiles must match it.
+    if tile_w != ATLAS_TILE_PIXELS || tile_h != ATLAS_TILE_PIXELS {
+        return Err(format!(
+            "Tiles are {tile_w}x{tile_h}, but material_catalog expects {ATLAS_TILE_PIXELS}x{ATLAS_TILE_PIXELS}"
+        ));
+    }
     let ordered_tiles: Vec<RgbaTexture> = tiles.into_iter().map(|(_, _, t)| t).collect();
     let atlas_data = build_atlas_data(&ordered_tiles);
+    let atlas_w = tile_w * atlas_texture_order().len() as u32;
     ensure_parent_dir(&output)?;
-    save_png_rgba8(
-        &output,
-        tile_w * atlas_texture_order().len() as u32,
-        tile_h,
-        &atlas_data,
-    )?;
-
+    save_png_rgba8(&output, atlas_w, tile_h, &atlas_data)?;
     println!("Atlas generated: {}", output.display());
+
+    let normal_tiles: Option<Vec<RgbaTexture>> =
+        normals.then(|| ordered_tiles.iter().map(build_normal_tile).collect());
+    if let Some(normal_tiles) = &normal_tiles {
+        let normal_output = normal_atlas_path(&output);
+        save_png_rgba8(
+            &normal_output,
+            atlas_w,
+            tile_h,
+            &build_atlas_data(normal_tiles),
+        )?;
+        println!("Normal atlas generated: {}", normal_output.display());
+    }
+
+    if array {
+        let array_h = tile_h * ordered_tiles.len() as u32;
+        let array_output = array_atlas_path(&output);
+        save_png_rgba8(
+            &array_output,
+            tile_w,
+            array_h,
+            &build_array_data(&ordered_tiles),
+        )?;
+        println!("Texture array generated: {}", array_output.display());
+        if let Some(normal_tiles) = &normal_tiles {
+            let normal_output = normal_atlas_path(&array_output);
+            save_png_rgba8(
+                &normal_output,
+                tile_w,
+                array_h,
+                &build_array_data(normal_tiles),
+            )?;
+            println!(
+                "Normal texture array generated: {}",
+                normal_output.display()
+            );
+        }
+    }
     Ok(())
 }
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Player action throttled by [`ActionCooldowns`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ActionId {
+    /// Breaking the targeted block.
+    Break,
+    /// Placing the selected block.
+    Place,
+    /// Using the targeted block, such as opening a door or a chest.
+    Use,
+    /// Throwing one held item of the selected block kind.
+    Throw,
+}
+
+/// Per-action cooldown timestamps with individually configured intervals.
+///
+/// An action that has never fired is ready immediately, so the first press always acts.
+#[derive(Resource, Default)]
+pub struct ActionCooldowns {
+    /// Minimum seconds between two firings of each action (missing means no cooldown).
+    intervals: HashMap<ActionId, f32>,
+    /// Simulation time (seconds) each action last fired.
+    last_fired: HashMap<ActionId, f32>,
+}
+
+impl ActionCooldowns {
+    /// Set the cooldown interval for one action.
+    pub fn with_interval(mut self, action: ActionId, secs: f32) -> Self {
+        self.intervals.insert(action, secs);
+        self
+    }
+
+    /// Return whether `action` may fire at time `now`.
+    pub fn ready(&self, action: ActionId, now: f32) -> bool {
+        let interval = self.intervals.get(&action).copied().unwrap_or(0.0);
+        self.last_fired
+            .get(&action)
+            .is_none_or(|last| now - last >= interval)
+    }
+
+    /// Record that `action` fired at time `now`.
+    ///
+    /// Callers check `ready` first and mark only once the action took effect, so a failed
+    /// attempt never burns the cooldown.
+    pub fn mark(&mut self, action: ActionId, now: f32) {
+        self.last_fired.insert(action, now);
+    }
+
+    /// Fire `action` if ready, recording it, and return whether it fired.
+    #[cfg(test)]
+    pub fn try_fire(&mut self, action: ActionId, now: f32) -> bool {
+        if !self.ready(action, now) {
+            return false;
+        }
+        self.mark(action, now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify first firing is immediate and each action keeps its own interval.
+    #[test]
+    fn cooldowns_fire_first_immediately_and_track_actions_independently() {
+        let mut cooldowns = ActionCooldowns::default()
+            .with_interval(ActionId::Break, 0.2)
+            .with_interval(ActionId::Place, 0.5);
+
+        assert!(cooldowns.try_fire(ActionId::Break, 0.0));
+        assert!(!cooldowns.try_fire(ActionId::Break, 0.1));
+        assert!(cooldowns.try_fire(ActionId::Place, 0.1));
+        assert!(cooldowns.try_fire(ActionId::Break, 0.2));
+        assert!(!cooldowns.ready(ActionId::Place, 0.5));
+        assert!(cooldowns.ready(ActionId::Place, 0.7));
+    }
+
+    /// Verify an action checked but never marked stays ready for the next attempt.
+    #[test]
+    fn unmarked_attempts_keep_the_action_ready() {
+        let mut cooldowns = ActionCooldowns::default().with_interval(ActionId::Use, 1.0);
+        assert!(cooldowns.ready(ActionId::Use, 0.0));
+        assert!(cooldowns.ready(ActionId::Use, 0.1));
+        cooldowns.mark(ActionId::Use, 0.1);
+        assert!(!cooldowns.ready(ActionId::Use, 0.5));
+        assert!(cooldowns.ready(ActionId::Throw, 0.5));
+    }
+}
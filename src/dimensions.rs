@@ -0,0 +1,8 @@
+//! World dimensions shared by the game and the offline terrain tools.
+
+/// Chunk width/height/depth in blocks.
+pub const CHUNK_SIZE: i32 = 16;
+/// Lowest vertical chunk layer streamed; the bedrock floor fills its bottom slice.
+pub const MIN_CHUNK_LAYER: i32 = -2;
+/// One past the highest vertical chunk layer streamed (`MIN_CHUNK_LAYER..MAX_CHUNK_LAYER`).
+pub const MAX_CHUNK_LAYER: i32 = 6;
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::cooldown::{ActionCooldowns, ActionId};
+
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+/// Gameplay tuning read by the player action systems.
+pub struct GameConfig {
+    /// Minimum seconds between repeated block breaks while holding the button.
+    pub break_cooldown_secs: f32,
+    /// Minimum seconds between repeated block placements while holding the button.
+    pub place_cooldown_secs: f32,
+    /// Minimum seconds between two uses of blocks such as doors and crafting tables.
+    pub use_cooldown_secs: f32,
+    /// Minimum seconds between two thrown items.
+    pub throw_cooldown_secs: f32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            break_cooldown_secs: 0.2,
+            place_cooldown_secs: 0.2,
+            use_cooldown_secs: 0.25,
+            throw_cooldown_secs: 0.15,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Build the action cooldowns with this config's per-action intervals.
+    pub fn action_cooldowns(&self) -> ActionCooldowns {
+        ActionCooldowns::default()
+            .with_interval(ActionId::Break, self.break_cooldown_secs)
+            .with_interval(ActionId::Place, self.place_cooldown_secs)
+            .with_interval(ActionId::Use, self.use_cooldown_secs)
+            .with_interval(ActionId::Throw, self.throw_cooldown_secs)
+    }
+}
@@ -0,0 +1,266 @@
+use bevy::prelude::*;
+use bevy::tasks::{IoTaskPool, Task};
+use futures_lite::future;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::voxel::{BlockKind, WorldState};
+
+/// One world event recorded into the session journal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JournalEvent {
+    /// A block at `pos` changed from `from` to `to`.
+    BlockChange {
+        /// World block coordinate.
+        pos: IVec3,
+        /// Block kind before the change.
+        from: BlockKind,
+        /// Block kind after the change.
+        to: BlockKind,
+    },
+    /// A chunk finished streaming in.
+    ChunkLoad {
+        /// Chunk coordinate.
+        coord: IVec3,
+    },
+    /// A chunk was unloaded.
+    ChunkUnload {
+        /// Chunk coordinate.
+        coord: IVec3,
+    },
+    /// A falling block settled into the voxel world.
+    FallingSettle {
+        /// World block coordinate where it landed.
+        pos: IVec3,
+        /// Kind of the settled block.
+        kind: BlockKind,
+    },
+    /// TNT exploded at `pos`, carving out `removed` blocks.
+    Explosion {
+        /// World block coordinate of the TNT.
+        pos: IVec3,
+        /// Number of blocks the blast removed.
+        removed: usize,
+    },
+}
+
+impl JournalEvent {
+    /// Stable event type tag written into the `type` field.
+    fn type_tag(self) -> &'static str {
+        match self {
+            Self::BlockChange { .. } => "block_change",
+            Self::ChunkLoad { .. } => "chunk_load",
+            Self::ChunkUnload { .. } => "chunk_unload",
+            Self::FallingSettle { .. } => "falling_settle",
+            Self::Explosion { .. } => "explosion",
+        }
+    }
+
+    /// Chunk coordinate the event belongs to.
+    fn chunk(self) -> IVec3 {
+        match self {
+            Self::BlockChange { pos, .. }
+            | Self::FallingSettle { pos, .. }
+            | Self::Explosion { pos, .. } => WorldState::world_to_chunk_local(pos).0,
+            Self::ChunkLoad { coord } | Self::ChunkUnload { coord } => coord,
+        }
+    }
+
+    /// Format this event as one JSON line (without trailing newline).
+    fn to_json_line(self, time_secs: f32) -> String {
+        let chunk = self.chunk();
+        let mut line = format!(
+            "{{\"t\":{time_secs:.3},\"type\":\"{}\",\"chunk\":[{},{},{}]",
+            self.type_tag(),
+            chunk.x,
+            chunk.y,
+            chunk.z
+        );
+        match self {
+            Self::BlockChange { pos, from, to } => line.push_str(&format!(
+                ",\"pos\":[{},{},{}],\"from\":\"{from:?}\",\"to\":\"{to:?}\"",
+                pos.x, pos.y, pos.z
+            )),
+            Self::FallingSettle { pos, kind } => line.push_str(&format!(
+                ",\"pos\":[{},{},{}],\"kind\":\"{kind:?}\"",
+                pos.x, pos.y, pos.z
+            )),
+            Self::Explosion { pos, removed } => line.push_str(&format!(
+                ",\"pos\":[{},{},{}],\"removed\":{removed}",
+                pos.x, pos.y, pos.z
+            )),
+            Self::ChunkLoad { .. } | Self::ChunkUnload { .. } => {}
+        }
+        line.push('}');
+        line
+    }
+}
+
+/// Opt-in JSON-lines journal of world events for debugging emergent issues.
+#[derive(Resource)]
+pub struct SessionJournal {
+    /// Whether events are currently recorded.
+    enabled: bool,
+    /// Output file for this session.
+    path: PathBuf,
+    /// Formatted lines waiting to be written.
+    buffer: Vec<String>,
+    /// Lines dropped because the buffer was full while a write was in flight.
+    dropped: u64,
+    /// Current background append task, if any.
+    write_task: Option<Task<io::Result<()>>>,
+}
+
+impl SessionJournal {
+    /// Hotkey that toggles journaling on and off.
+    pub(crate) const TOGGLE_KEY: KeyCode = KeyCode::F7;
+    /// Max buffered lines before new events are dropped.
+    const MAX_BUFFERED_LINES: usize = 4096;
+    /// Directory holding session journals.
+    const LOG_DIR: &'static str = "logs";
+
+    /// Build a disabled journal targeting `logs/session-<unix-seconds>.jsonl`.
+    pub fn new() -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        Self {
+            enabled: false,
+            path: PathBuf::from(Self::LOG_DIR).join(format!("session-{timestamp}.jsonl")),
+            buffer: Vec::new(),
+            dropped: 0,
+            write_task: None,
+        }
+    }
+
+    /// Enable or disable recording.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Append one event when enabled, dropping it (counted) if the buffer is full.
+    pub fn record(&mut self, event: JournalEvent, time: &Time) {
+        if !self.enabled {
+            return;
+        }
+        if self.buffer.len() >= Self::MAX_BUFFERED_LINES {
+            self.dropped += 1;
+            return;
+        }
+        self.buffer.push(event.to_json_line(time.elapsed_secs()));
+    }
+
+    /// Return whether the previous background write has finished (logging its error, if any).
+    fn poll_write_task(&mut self) -> bool {
+        let Some(task) = self.write_task.as_mut() else {
+            return true;
+        };
+        let Some(result) = future::block_on(future::poll_once(task)) else {
+            return false;
+        };
+        if let Err(error) = result {
+            warn!(
+                "Failed to write session journal {}: {error}",
+                self.path.display()
+            );
+        }
+        self.write_task = None;
+        true
+    }
+
+    /// Hand buffered lines to a background append task.
+    fn flush(&mut self) {
+        if self.buffer.is_empty() && self.dropped == 0 {
+            return;
+        }
+        let mut lines = std::mem::take(&mut self.buffer);
+        if self.dropped > 0 {
+            lines.push(format!(
+                "{{\"type\":\"dropped\",\"count\":{}}}",
+                self.dropped
+            ));
+            self.dropped = 0;
+        }
+        let path = self.path.clone();
+        self.write_task = Some(IoTaskPool::get().spawn(async move {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            let mut payload = lines.join("\n");
+            payload.push('\n');
+            file.write_all(payload.as_bytes())
+        }));
+    }
+}
+
+/// Toggle journaling from the hotkey and flush buffered events via the IO task pool.
+pub fn session_journal_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut journal: ResMut<SessionJournal>,
+) {
+    if keys.just_pressed(SessionJournal::TOGGLE_KEY) {
+        let enabled = !journal.enabled;
+        journal.set_enabled(enabled);
+        info!(
+            "Session journal {} ({})",
+            if enabled { "on" } else { "off" },
+            journal.path.display()
+        );
+    }
+    if journal.poll_write_task() {
+        journal.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::{JournalEvent, SessionJournal};
+    use crate::voxel::BlockKind;
+
+    /// Verify event lines carry the type tag, owning chunk, and payload fields.
+    #[test]
+    fn journal_event_formats_as_json_line() {
+        let event = JournalEvent::BlockChange {
+            pos: IVec3::new(-1, 17, 3),
+            from: BlockKind::Dirt,
+            to: BlockKind::Air,
+        };
+        assert_eq!(
+            event.to_json_line(1.5),
+            "{\"t\":1.500,\"type\":\"block_change\",\"chunk\":[-1,1,0],\
+             \"pos\":[-1,17,3],\"from\":\"Dirt\",\"to\":\"Air\"}"
+        );
+        let explosion = JournalEvent::Explosion {
+            pos: IVec3::new(20, 3, -4),
+            removed: 57,
+        };
+        assert_eq!(
+            explosion.to_json_line(2.0),
+            "{\"t\":2.000,\"type\":\"explosion\",\"chunk\":[1,0,-1],\
+             \"pos\":[20,3,-4],\"removed\":57}"
+        );
+    }
+
+    /// Verify the bounded buffer drops (and counts) events instead of growing.
+    #[test]
+    fn journal_drops_events_when_buffer_is_full() {
+        let time = Time::<()>::default();
+        let mut journal = SessionJournal::new();
+        let event = JournalEvent::ChunkLoad { coord: IVec3::ZERO };
+        journal.record(event, &time);
+        assert!(journal.buffer.is_empty());
+
+        journal.set_enabled(true);
+        for _ in 0..SessionJournal::MAX_BUFFERED_LINES + 3 {
+            journal.record(event, &time);
+        }
+        assert_eq!(journal.buffer.len(), SessionJournal::MAX_BUFFERED_LINES);
+        assert_eq!(journal.dropped, 3);
+    }
+}
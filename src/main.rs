@@ -1,34 +1,71 @@
 use bevy::image::ImagePlugin;
+use bevy::pbr::wireframe::WireframePlugin;
 use bevy::prelude::*;
 
+mod cooldown;
+mod dimensions;
+mod game_config;
+mod journal;
 mod material_catalog;
 mod player;
 mod scene;
 mod terrain;
 mod voxel;
 
+use dimensions::{CHUNK_SIZE, MAX_CHUNK_LAYER, MIN_CHUNK_LAYER};
+use game_config::GameConfig;
+use journal::{SessionJournal, session_journal_system};
 use player::{
-    camera_follow_system, camera_look_system, camera_move_system, crouch_system,
-    crouch_transition_system, physics_system, preview_follow_system, toggle_fly_system,
+    CameraOrbit, PlayerRecovered, camera_follow_system, camera_look_system, camera_move_system,
+    camera_orbit_inactive, camera_orbit_system, crouch_system, crouch_transition_system,
+    physics_system, player_control_enabled, preview_follow_system, recovery_toast_system,
+    spawn_protection_border_system, spawn_protection_steady_border_system, spawn_protection_system,
+    toggle_fly_system,
 };
-use scene::{setup_cursor, setup_scene, sun_billboard_system};
+use scene::{
+    AccessibilitySettings, CameraShake, DisplaySettings, ExplosionEffects, LanguageSettings,
+    Locale, LocaleChanged, SettingsFile, SkyPlugin, TimeOfDay, Toast, accessibility_input_system,
+    apply_display_settings_system, apply_language_system, apply_normal_map_setting_system,
+    apply_shadow_preset_system, apply_ui_scale_system, apply_view_distance_system,
+    biome_hud_system, camera_shake_apply_system, camera_shake_clear_system, container_input_system,
+    container_ui_system, crack_overlay_system, crafting_input_system, crafting_ui_system,
+    day_night_system, display_settings_input_system, dust_cloud_system, explosion_effects_system,
+    flashing_effects_enabled, frame_limiter_system, held_tool_hud_system, language_input_system,
+    light_flicker_enabled, localized_text_system, motion_effects_enabled,
+    placement_distance_hud_system, setup_cursor, setup_scene, sky_dome_system,
+    sun_billboard_system, target_highlight_system, toast_system, view_distance_fog_system,
+};
+use terrain::TerrainConfig;
 use voxel::{
-    FallingPropagationQueue, block_interaction_system, chunk_loading_system,
-    spawn_falling_blocks_system, update_falling_blocks_system,
+    BlockMaterialPlugin, BlockRegistry, BreakProgress, ContainerMenu, CraftingMenu, Explosion,
+    FallingPropagationQueue, HeldTool, Inventory, LightingConfig, MeshBudget, RandomTicks,
+    TntConfig, TorchLightPool, TreeRegistry, WaterTickTimer, block_interaction_system,
+    block_light_system, block_update_system, block_use_system, chunk_loading_system,
+    chunk_remesh_system, column_cache_report_system, current_target_system,
+    falling_collider_system, held_tool_system, item_pickup_system, light_flicker_system,
+    mesh_budget_system, primed_tnt_system, random_tick_system, sapling_growth_system,
+    settled_block_system, spawn_falling_blocks_system, spill_block_entities_system, sponge_system,
+    steady_light_system, terrain_config_reload_system, throw_item_system, tnt_ignite_system,
+    torch_light_system, update_falling_blocks_system, update_item_drops_system, water_flow_system,
+    wireframe_toggle_system, world_controls_enabled, world_material_system,
 };
 
-/// Chunk width/height/depth in blocks.
-const CHUNK_SIZE: i32 = 16;
 /// Size of one block in world units.
 const BLOCK_SIZE: f32 = 1.0;
 /// Horizontal chunk radius around the player to keep loaded.
 const VIEW_DISTANCE: i32 = 10;
-/// Number of vertical chunk layers to generate (y=0..layers-1).
-const VERTICAL_CHUNK_LAYERS: i32 = 6;
 /// Max chunk builds started per frame.
 const LOADS_PER_FRAME: usize = 16;
 /// Max async chunk build tasks in flight.
 const MAX_IN_FLIGHT: usize = 16;
+/// Max chunks unloaded per frame.
+const UNLOADS_PER_FRAME: usize = 64;
+/// Max chunk meshes rebuilt per frame after a shading preset change.
+const REMESHES_PER_FRAME: usize = 8;
+/// Max chunk meshes re-tinted per frame after the sun moves.
+const SUN_RETINTS_PER_FRAME: usize = 32;
+/// Seconds between two rings added to the streamed radius while the view distance grows.
+const RING_GROW_SECS: f32 = 0.5;
 /// Gravity acceleration for the player.
 const GRAVITY: f32 = 40.0;
 /// Duration of jump boost when holding jump.
@@ -45,31 +82,217 @@ const CROUCH_HALF_SIZE: Vec3 = Vec3::new(0.3 * BLOCK_SIZE, 0.45 * BLOCK_SIZE, 0.
 const STAND_EYE_HEIGHT: f32 = 1.8 * BLOCK_SIZE;
 /// Eye height when crouching (in world units).
 const CROUCH_EYE_HEIGHT: f32 = 0.8 * BLOCK_SIZE;
-/// Shadow map resolution for directional light (lower = faster).
-const SHADOW_MAP_SIZE: usize = 1024;
+/// Maximum player hit points.
+const PLAYER_MAX_HEALTH: f32 = 20.0;
 
 /// App entry point and system registration.
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
+        .add_plugins(BlockMaterialPlugin)
+        .add_plugins(WireframePlugin::default())
+        .add_plugins(SkyPlugin)
+        .insert_resource(BlockRegistry::global())
+        .insert_resource(TreeRegistry::global())
         .insert_resource(FallingPropagationQueue::default())
+        .insert_resource(BreakProgress::from_args())
+        .insert_resource(CameraOrbit::default())
+        .insert_resource(SessionJournal::new())
+        .insert_resource(DisplaySettings::load())
+        .insert_resource(AccessibilitySettings::load())
+        .insert_resource(LanguageSettings::load())
+        .insert_resource(TerrainConfig::load())
+        .insert_resource(TimeOfDay::from_args())
+        .init_resource::<MeshBudget>()
+        .init_resource::<TorchLightPool>()
+        .init_resource::<LightingConfig>()
+        .init_resource::<GameConfig>()
+        .init_resource::<Inventory>()
+        .init_resource::<HeldTool>()
+        .init_resource::<WaterTickTimer>()
+        .init_resource::<RandomTicks>()
+        .init_resource::<TntConfig>()
+        .init_resource::<CraftingMenu>()
+        .init_resource::<ContainerMenu>()
+        .init_resource::<CameraShake>()
+        .init_resource::<ExplosionEffects>()
+        .init_resource::<Locale>()
+        .add_message::<Explosion>()
+        .add_message::<LocaleChanged>()
+        .add_message::<Toast>()
+        .add_message::<PlayerRecovered>()
+        .add_systems(PreUpdate, camera_shake_clear_system)
         .add_systems(Startup, (setup_scene, setup_cursor))
         .add_systems(
             Update,
             (
-                chunk_loading_system,
-                camera_look_system,
-                camera_move_system,
-                toggle_fly_system,
-                crouch_system,
-                crouch_transition_system,
-                physics_system,
-                camera_follow_system,
-                block_interaction_system,
-                spawn_falling_blocks_system,
-                update_falling_blocks_system,
+                (terrain_config_reload_system, chunk_loading_system).chain(),
+                camera_look_system
+                    .run_if(camera_orbit_inactive)
+                    .run_if(world_controls_enabled),
+                camera_move_system.run_if(player_control_enabled),
+                toggle_fly_system.run_if(player_control_enabled),
+                crouch_system.run_if(player_control_enabled),
+                crouch_transition_system.run_if(player_control_enabled),
+                physics_system.run_if(player_control_enabled),
+                camera_follow_system.run_if(camera_orbit_inactive),
+                camera_orbit_system,
+                (
+                    held_tool_system,
+                    block_interaction_system
+                        .run_if(camera_orbit_inactive)
+                        .run_if(world_controls_enabled),
+                    crack_overlay_system,
+                )
+                    .chain(),
+                (
+                    current_target_system,
+                    (
+                        placement_distance_hud_system,
+                        held_tool_hud_system,
+                        biome_hud_system,
+                        target_highlight_system,
+                    ),
+                )
+                    .chain()
+                    .before(block_interaction_system),
+                (
+                    block_use_system
+                        .after(current_target_system)
+                        .run_if(camera_orbit_inactive)
+                        .run_if(world_controls_enabled),
+                    crafting_input_system,
+                    crafting_ui_system,
+                    container_input_system,
+                    container_ui_system,
+                )
+                    .chain(),
+                (
+                    (
+                        torch_light_system,
+                        (
+                            light_flicker_system.run_if(light_flicker_enabled),
+                            steady_light_system.run_if(not(light_flicker_enabled)),
+                        ),
+                    )
+                        .chain(),
+                    block_light_system,
+                ),
+                (
+                    spawn_falling_blocks_system,
+                    (update_falling_blocks_system, falling_collider_system)
+                        .chain()
+                        .before(physics_system),
+                    (
+                        spill_block_entities_system,
+                        throw_item_system.run_if(world_controls_enabled),
+                        update_item_drops_system,
+                        item_pickup_system,
+                    )
+                        .chain()
+                        .after(physics_system),
+                    (sponge_system, water_flow_system).chain(),
+                    random_tick_system,
+                    block_update_system,
+                    sapling_growth_system,
+                    (
+                        tnt_ignite_system.after(current_target_system),
+                        primed_tnt_system,
+                        explosion_effects_system,
+                    )
+                        .chain(),
+                    dust_cloud_system,
+                ),
+                spawn_protection_system,
+                spawn_protection_border_system.run_if(flashing_effects_enabled),
+                spawn_protection_steady_border_system.run_if(not(flashing_effects_enabled)),
+                (session_journal_system, wireframe_toggle_system),
+                (
+                    display_settings_input_system,
+                    apply_display_settings_system,
+                    apply_normal_map_setting_system,
+                    apply_shadow_preset_system,
+                    apply_view_distance_system,
+                ),
+                (
+                    accessibility_input_system,
+                    apply_ui_scale_system,
+                    (
+                        language_input_system,
+                        apply_language_system,
+                        localized_text_system,
+                        recovery_toast_system,
+                        toast_system,
+                    )
+                        .chain(),
+                ),
+            ),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                (
+                    chunk_remesh_system,
+                    settled_block_system,
+                    world_material_system,
+                )
+                    .chain(),
+                preview_follow_system,
+                (
+                    day_night_system,
+                    (
+                        sun_billboard_system,
+                        (sky_dome_system, view_distance_fog_system).chain(),
+                    ),
+                    camera_shake_apply_system.run_if(motion_effects_enabled),
+                )
+                    .chain()
+                    .before(TransformSystems::Propagate),
+                (mesh_budget_system, column_cache_report_system),
             ),
         )
-        .add_systems(PostUpdate, (preview_follow_system, sun_billboard_system))
+        .add_systems(Last, frame_limiter_system)
         .run();
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::voxel::{
+        IGNITE_KEY, MESH_BUDGET_REPORT_KEY, SelectedBlock, THROW_KEY, WIREFRAME_KEY,
+    };
+
+    /// Verify no two global hotkeys share a key, so one press never triggers two actions.
+    #[test]
+    fn global_hotkeys_are_distinct() {
+        let keys = [
+            SessionJournal::TOGGLE_KEY,
+            CameraOrbit::TOGGLE_KEY,
+            AccessibilitySettings::REDUCED_MOTION_KEY,
+            AccessibilitySettings::PHOTOSENSITIVE_KEY,
+            AccessibilitySettings::UI_SCALE_UP_KEY,
+            AccessibilitySettings::UI_SCALE_DOWN_KEY,
+            LanguageSettings::LANGUAGE_KEY,
+            DisplaySettings::FULLSCREEN_KEY,
+            DisplaySettings::VSYNC_KEY,
+            DisplaySettings::NORMAL_MAPS_KEY,
+            DisplaySettings::CHEAP_SHADOWS_KEY,
+            DisplaySettings::SHADOW_QUALITY_KEY,
+            DisplaySettings::VIEW_DISTANCE_UP_KEY,
+            DisplaySettings::VIEW_DISTANCE_DOWN_KEY,
+            MESH_BUDGET_REPORT_KEY,
+            WIREFRAME_KEY,
+            IGNITE_KEY,
+            HeldTool::CYCLE_KEY,
+            THROW_KEY,
+        ]
+        .into_iter()
+        .chain(SelectedBlock::HOTKEYS.map(|(key, _)| key));
+        let mut bound = HashSet::new();
+        for key in keys {
+            assert!(bound.insert(key), "{key:?} is bound twice");
+        }
+    }
+}
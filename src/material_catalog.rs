@@ -9,14 +9,116 @@ pub enum TextureId {
     Dirt,
     /// Sand texture.
     Sand,
+    /// Stone texture.
+    Stone,
+    /// Bark texture on the sides of logs.
+    LogSide,
+    /// Growth-ring texture on the ends of logs.
+    LogTop,
+    /// Leaf texture.
+    Leaves,
+    /// Water surface texture.
+    Water,
+    /// Stone with coal specks.
+    CoalOre,
+    /// Stone with iron specks.
+    IronOre,
+    /// Bedrock texture.
+    Bedrock,
+    /// Left/right texture for snow-over-dirt blocks.
+    SnowSide,
+    /// Top texture for snow-over-dirt blocks.
+    SnowTop,
+    /// Cobblestone texture.
+    Cobblestone,
+    /// Moss-grown cobblestone texture.
+    MossyCobblestone,
+    /// Tall grass blades with a transparent background.
+    TallGrass,
+    /// Yellow flower with a transparent background.
+    Flower,
+    /// Mostly clear glass pane with a light frame.
+    Glass,
+    /// Torch stick with a flame on top, on a transparent background.
+    Torch,
+    /// Red TNT wrapping with a white label band.
+    TntSide,
+    /// TNT top with the fuse in the middle.
+    TntTop,
+    /// Young tree sprout on a transparent background.
+    Sapling,
+    /// Staggered wooden boards.
+    Planks,
+    /// Crafting table top with its work grid.
+    CraftingTableTop,
+    /// Crafting table side with tools hanging on it.
+    CraftingTableSide,
+    /// Chest front with its latch.
+    ChestFront,
+    /// Chest side with the lid seam.
+    ChestSide,
+    /// Chest lid seen from above.
+    ChestTop,
+    /// Lower half of a wooden door with its handle.
+    DoorLower,
+    /// Upper half of a wooden door with its window.
+    DoorUpper,
+    /// Bright yellow crystals of a glowstone block.
+    Glowstone,
+    /// Loose grey pebbles.
+    Gravel,
+    /// Pale blue, slightly see-through ice.
+    Ice,
+    /// Ribbed, spiny cactus side.
+    CactusSide,
+    /// Cactus cut face seen from above or below.
+    CactusTop,
+    /// Dry, porous yellow sponge.
+    Sponge,
+    /// Darker sponge soaked with water.
+    SpongeWet,
 }
 
 /// Stable atlas tile order used by runtime UV lookup and atlas generation.
-pub const ATLAS_TEXTURE_ORDER: [TextureId; 4] = [
+pub const ATLAS_TEXTURE_ORDER: [TextureId; 38] = [
     TextureId::GrassSide,
     TextureId::GrassTop,
     TextureId::Dirt,
     TextureId::Sand,
+    TextureId::Stone,
+    TextureId::LogSide,
+    TextureId::LogTop,
+    TextureId::Leaves,
+    TextureId::Water,
+    TextureId::CoalOre,
+    TextureId::IronOre,
+    TextureId::Bedrock,
+    TextureId::SnowSide,
+    TextureId::SnowTop,
+    TextureId::Cobblestone,
+    TextureId::MossyCobblestone,
+    TextureId::TallGrass,
+    TextureId::Flower,
+    TextureId::Glass,
+    TextureId::Torch,
+    TextureId::TntSide,
+    TextureId::TntTop,
+    TextureId::Sapling,
+    TextureId::Planks,
+    TextureId::CraftingTableTop,
+    TextureId::CraftingTableSide,
+    TextureId::ChestFront,
+    TextureId::ChestSide,
+    TextureId::ChestTop,
+    TextureId::DoorLower,
+    TextureId::DoorUpper,
+    TextureId::Glowstone,
+    TextureId::Gravel,
+    TextureId::Ice,
+    TextureId::CactusSide,
+    TextureId::CactusTop,
+    TextureId::Sponge,
+    TextureId::SpongeWet,
 ];
 
 /// Return atlas tile order as a slice.
@@ -25,6 +127,59 @@ pub const fn atlas_texture_order() -> &'static [TextureId] {
     &ATLAS_TEXTURE_ORDER
 }
 
+/// Return the name block definitions refer to one texture id by.
+#[allow(dead_code, reason = "used by runtime block registry")]
+pub const fn texture_name(texture: TextureId) -> &'static str {
+    match texture {
+        TextureId::GrassSide => "grass_side",
+        TextureId::GrassTop => "grass_top",
+        TextureId::Dirt => "dirt",
+        TextureId::Sand => "sand",
+        TextureId::Stone => "stone",
+        TextureId::LogSide => "log_side",
+        TextureId::LogTop => "log_top",
+        TextureId::Leaves => "leaves",
+        TextureId::Water => "water",
+        TextureId::CoalOre => "coal_ore",
+        TextureId::IronOre => "iron_ore",
+        TextureId::Bedrock => "bedrock",
+        TextureId::SnowSide => "snow_side",
+        TextureId::SnowTop => "snow_top",
+        TextureId::Cobblestone => "cobblestone",
+        TextureId::MossyCobblestone => "mossy_cobblestone",
+        TextureId::TallGrass => "tall_grass",
+        TextureId::Flower => "flower",
+        TextureId::Glass => "glass",
+        TextureId::Torch => "torch",
+        TextureId::TntSide => "tnt_side",
+        TextureId::TntTop => "tnt_top",
+        TextureId::Sapling => "sapling",
+        TextureId::Planks => "planks",
+        TextureId::CraftingTableTop => "crafting_table_top",
+        TextureId::CraftingTableSide => "crafting_table_side",
+        TextureId::ChestFront => "chest_front",
+        TextureId::ChestSide => "chest_side",
+        TextureId::ChestTop => "chest_top",
+        TextureId::DoorLower => "door_lower",
+        TextureId::DoorUpper => "door_upper",
+        TextureId::Glowstone => "glowstone",
+        TextureId::Gravel => "gravel",
+        TextureId::Ice => "ice",
+        TextureId::CactusSide => "cactus_side",
+        TextureId::CactusTop => "cactus_top",
+        TextureId::Sponge => "sponge",
+        TextureId::SpongeWet => "sponge_wet",
+    }
+}
+
+/// Resolve a texture id from its `texture_name`, or `None` for an unknown name.
+#[allow(dead_code, reason = "used by runtime block registry")]
+pub fn texture_by_name(name: &str) -> Option<TextureId> {
+    ATLAS_TEXTURE_ORDER
+        .into_iter()
+        .find(|texture| texture_name(*texture) == name)
+}
+
 /// Return the source texture file name for one texture id.
 #[allow(dead_code, reason = "used by atlas tool binary")]
 pub const fn source_base_filename(texture: TextureId) -> &'static str {
@@ -33,6 +188,40 @@ pub const fn source_base_filename(texture: TextureId) -> &'static str {
         TextureId::GrassTop => "default_grass.png",
         TextureId::Dirt => "default_dirt.png",
         TextureId::Sand => "default_sand.png",
+        TextureId::Stone => "default_stone.png",
+        TextureId::LogSide => "default_tree.png",
+        TextureId::LogTop => "default_tree_top.png",
+        TextureId::Leaves => "default_leaves.png",
+        TextureId::Water => "default_water.png",
+        TextureId::CoalOre => "default_stone.png",
+        TextureId::IronOre => "default_stone.png",
+        TextureId::Bedrock => "default_bedrock.png",
+        TextureId::SnowSide => "default_dirt.png",
+        TextureId::SnowTop => "default_snow.png",
+        TextureId::Cobblestone => "default_cobble.png",
+        TextureId::MossyCobblestone => "default_mossycobble.png",
+        TextureId::TallGrass => "default_grass_3.png",
+        TextureId::Flower => "flowers_dandelion_yellow.png",
+        TextureId::Glass => "default_glass.png",
+        TextureId::Torch => "default_torch.png",
+        TextureId::TntSide => "tnt_side.png",
+        TextureId::TntTop => "tnt_top.png",
+        TextureId::Sapling => "default_sapling.png",
+        TextureId::Planks => "default_wood.png",
+        TextureId::CraftingTableTop => "crafting_workbench_top.png",
+        TextureId::CraftingTableSide => "crafting_workbench_side.png",
+        TextureId::ChestFront => "default_chest_front.png",
+        TextureId::ChestSide => "default_chest_side.png",
+        TextureId::ChestTop => "default_chest_top.png",
+        TextureId::DoorLower => "doors_door_wood_lower.png",
+        TextureId::DoorUpper => "doors_door_wood_upper.png",
+        TextureId::Glowstone => "default_glowstone.png",
+        TextureId::Gravel => "default_gravel.png",
+        TextureId::Ice => "default_ice.png",
+        TextureId::CactusSide => "default_cactus_side.png",
+        TextureId::CactusTop => "default_cactus_top.png",
+        TextureId::Sponge => "default_sponge.png",
+        TextureId::SpongeWet => "default_sponge_wet.png",
     }
 }
 
@@ -44,9 +233,46 @@ pub const fn source_overlay_filename(texture: TextureId) -> Option<&'static str>
         TextureId::GrassTop => None,
         TextureId::Dirt => None,
         TextureId::Sand => None,
+        TextureId::Stone => None,
+        TextureId::LogSide => None,
+        TextureId::LogTop => None,
+        TextureId::Leaves => None,
+        TextureId::Water => None,
+        TextureId::CoalOre => Some("default_mineral_coal.png"),
+        TextureId::IronOre => Some("default_mineral_iron.png"),
+        TextureId::Bedrock => None,
+        TextureId::SnowSide => Some("default_snow_side.png"),
+        TextureId::SnowTop => None,
+        TextureId::Cobblestone => None,
+        TextureId::MossyCobblestone => None,
+        TextureId::TallGrass => None,
+        TextureId::Flower => None,
+        TextureId::Glass => None,
+        TextureId::Torch => None,
+        TextureId::TntSide => None,
+        TextureId::TntTop => None,
+        TextureId::Sapling => None,
+        TextureId::Planks => None,
+        TextureId::CraftingTableTop => None,
+        TextureId::CraftingTableSide => None,
+        TextureId::ChestFront => None,
+        TextureId::ChestSide => None,
+        TextureId::ChestTop => None,
+        TextureId::DoorLower => None,
+        TextureId::DoorUpper => None,
+        TextureId::Glowstone => None,
+        TextureId::Gravel => None,
+        TextureId::Ice => None,
+        TextureId::CactusSide => None,
+        TextureId::CactusTop => None,
+        TextureId::Sponge => None,
+        TextureId::SpongeWet => None,
     }
 }
 
+/// Width and height in pixels of every atlas tile.
+pub const ATLAS_TILE_PIXELS: u32 = 16;
+
 /// Return horizontal tile count of the current atlas.
 #[allow(dead_code, reason = "used by runtime mesh atlas")]
 pub fn atlas_tiles_x() -> f32 {
@@ -61,11 +287,51 @@ pub const fn atlas_tile_index(texture: TextureId) -> u32 {
         TextureId::GrassTop => 1,
         TextureId::Dirt => 2,
         TextureId::Sand => 3,
+        TextureId::Stone => 4,
+        TextureId::LogSide => 5,
+        TextureId::LogTop => 6,
+        TextureId::Leaves => 7,
+        TextureId::Water => 8,
+        TextureId::CoalOre => 9,
+        TextureId::IronOre => 10,
+        TextureId::Bedrock => 11,
+        TextureId::SnowSide => 12,
+        TextureId::SnowTop => 13,
+        TextureId::Cobblestone => 14,
+        TextureId::MossyCobblestone => 15,
+        TextureId::TallGrass => 16,
+        TextureId::Flower => 17,
+        TextureId::Glass => 18,
+        TextureId::Torch => 19,
+        TextureId::TntSide => 20,
+        TextureId::TntTop => 21,
+        TextureId::Sapling => 22,
+        TextureId::Planks => 23,
+        TextureId::CraftingTableTop => 24,
+        TextureId::CraftingTableSide => 25,
+        TextureId::ChestFront => 26,
+        TextureId::ChestSide => 27,
+        TextureId::ChestTop => 28,
+        TextureId::DoorLower => 29,
+        TextureId::DoorUpper => 30,
+        TextureId::Glowstone => 31,
+        TextureId::Gravel => 32,
+        TextureId::Ice => 33,
+        TextureId::CactusSide => 34,
+        TextureId::CactusTop => 35,
+        TextureId::Sponge => 36,
+        TextureId::SpongeWet => 37,
     }
 }
 
+/// Return whether this texture is stored as a grayscale base that meshes tint per biome.
+#[allow(dead_code, reason = "used by atlas tool binary")]
+pub const fn is_tinted_base(texture: TextureId) -> bool {
+    matches!(texture, TextureId::GrassTop)
+}
+
 /// Return whether this texture should use V-flipped UVs.
 #[allow(dead_code, reason = "used by runtime mesh atlas")]
 pub const fn needs_v_flip(texture: TextureId) -> bool {
-    matches!(texture, TextureId::GrassSide)
+    matches!(texture, TextureId::GrassSide | TextureId::SnowSide)
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::collections::VecDeque;
 
 use crate::voxel::Block;
 use crate::voxel::WorldState;
@@ -62,6 +63,7 @@ pub struct PlayerBody;
 
 /// Runtime state for player locomotion and stance.
 #[derive(Component)]
+#[require(TransformWatchdog)]
 pub struct Player {
     /// Whether the player is currently grounded.
     pub on_ground: bool,
@@ -81,11 +83,21 @@ pub struct Player {
     pub crouching: bool,
     /// Whether the player is currently in fly mode.
     pub flying: bool,
+    /// Block under the player's feet as of the last physics tick, `None` while airborne.
+    pub ground_block: Option<Block>,
 }
 
 impl Player {
     /// Air-control interpolation factor used while in the air.
     const AIR_CONTROL: f32 = 0.08;
+    /// Minimum downward look component (`-forward.y`) for pillar-jump placement.
+    const PILLAR_MIN_LOOK_DOWN: f32 = 0.7;
+    /// Max upward nudge applied to make room for a block placed under the feet.
+    const PILLAR_MAX_LIFT: f32 = 0.6 * BLOCK_SIZE;
+    /// Depth below the feet probed when looking for supporting cells.
+    const SUPPORT_PROBE: f32 = 0.01 * BLOCK_SIZE;
+    /// Tallest obstacle a grounded player walks up onto without jumping (a slab).
+    const STEP_HEIGHT: f32 = 0.5 * BLOCK_SIZE;
 
     /// Build default standing player state for initial spawn.
     pub fn new_standing(jump_speed: f32, half_size: Vec3, eye_height: f32) -> Self {
@@ -99,6 +111,7 @@ impl Player {
             target_eye_height: eye_height,
             crouching: false,
             flying: false,
+            ground_block: None,
         }
     }
 
@@ -168,6 +181,35 @@ impl Player {
             && player_max.z > block_min.z
     }
 
+    /// Return whether `block_world` is one of the cells directly under the feet at `player_pos`.
+    pub fn support_footprint_contains(&self, player_pos: Vec3, block_world: IVec3) -> bool {
+        let min = player_pos - self.half_size;
+        let max = player_pos + self.half_size;
+        let support_y = ((min.y - Self::SUPPORT_PROBE) / BLOCK_SIZE).floor() as i32;
+        let cell = |value: f32| (value / BLOCK_SIZE).floor() as i32;
+        block_world.y == support_y
+            && (cell(min.x)..=cell(max.x)).contains(&block_world.x)
+            && (cell(min.z)..=cell(max.z)).contains(&block_world.z)
+    }
+
+    /// Return the upward nudge that lets an airborne player place a block under their feet.
+    ///
+    /// Pillar-jump support: while airborne and looking steeply down, the cell directly below
+    /// the feet may be filled even though it still overlaps the collider by a small amount.
+    /// Returns `None` when the placement is not a pillar placement.
+    pub fn pillar_lift(&self, player_pos: Vec3, block_world: IVec3, look: Vec3) -> Option<f32> {
+        if self.on_ground || self.flying || -look.y < Self::PILLAR_MIN_LOOK_DOWN {
+            return None;
+        }
+        let feet = player_pos - Vec3::Y * self.half_size.y;
+        if Block::world_coord_from_position(feet) != block_world {
+            return None;
+        }
+        let block_top = (block_world.y + 1) as f32 * BLOCK_SIZE;
+        let lift = block_top - feet.y;
+        (lift > 0.0 && lift <= Self::PILLAR_MAX_LIFT).then_some(lift)
+    }
+
     /// Update jump-boost timer and apply extra vertical acceleration when active.
     pub fn apply_jump_boost(
         &mut self,
@@ -217,10 +259,13 @@ impl Player {
     }
 
     /// Apply horizontal velocity from desired wish vector on ground or in air.
-    pub fn apply_horizontal_movement(&self, velocity: &mut Vec3, wish: Vec3) {
+    ///
+    /// On the ground the velocity closes `friction` of its gap to the wish vector, so a
+    /// friction of `1.0` snaps to it and slippery ground slides.
+    pub fn apply_horizontal_movement(&self, velocity: &mut Vec3, wish: Vec3, friction: f32) {
         if self.on_ground {
-            velocity.x = wish.x;
-            velocity.z = wish.z;
+            velocity.x += (wish.x - velocity.x) * friction;
+            velocity.z += (wish.z - velocity.z) * friction;
             return;
         }
         if wish == Vec3::ZERO {
@@ -230,7 +275,31 @@ impl Player {
         velocity.z += (wish.z - velocity.z) * Self::AIR_CONTROL;
     }
 
+    /// Return the friction of the ground the player last stood on, `1.0` when airborne.
+    pub fn ground_friction(&self) -> f32 {
+        self.ground_block.map_or(1.0, |block| block.friction())
+    }
+
+    /// Return the solid block under the feet of a grounded body at `position`.
+    ///
+    /// Looks one cell lower when the cell right under the feet is open, as when standing
+    /// on a fence.
+    pub(crate) fn block_under_feet(&self, position: Vec3, world: &WorldState) -> Option<Block> {
+        if !self.on_ground {
+            return None;
+        }
+        let foot = position - Vec3::Y * (self.half_size.y + Self::SUPPORT_PROBE);
+        let cell = Block::world_coord_from_position(foot);
+        [cell, cell - IVec3::Y]
+            .into_iter()
+            .filter_map(|cell| world.get_block_world(cell))
+            .find(|block| block.is_solid())
+    }
+
     /// Resolve movement against voxel collisions in X/Z then Y order.
+    ///
+    /// With `step_up` set, horizontal moves climb obstacles up to `STEP_HEIGHT` tall.
+    /// Returns whether the world border stopped a horizontal move.
     pub(crate) fn resolve_motion_axes(
         &self,
         pos: &mut Vec3,
@@ -238,10 +307,12 @@ impl Player {
         world: &WorldState,
         dt: f32,
         crouch_edge_guard: bool,
-    ) {
-        self.move_axis(Vec3::X, pos, vel, world, dt, crouch_edge_guard);
-        self.move_axis(Vec3::Z, pos, vel, world, dt, crouch_edge_guard);
-        self.move_axis(Vec3::Y, pos, vel, world, dt, false);
+        step_up: bool,
+    ) -> bool {
+        let blocked_x = self.move_axis(Vec3::X, pos, vel, world, dt, crouch_edge_guard, step_up);
+        let blocked_z = self.move_axis(Vec3::Z, pos, vel, world, dt, crouch_edge_guard, step_up);
+        self.move_axis(Vec3::Y, pos, vel, world, dt, false, false);
+        blocked_x || blocked_z
     }
 
     /// Apply one crouch-transition step to collider and eye height.
@@ -266,7 +337,35 @@ impl Player {
         self.eye_height += (self.target_eye_height - self.eye_height) * t;
     }
 
+    /// Return `candidate` raised onto the obstacle it runs into, when that is at most
+    /// `STEP_HEIGHT` above the feet and the raised collider is clear.
+    fn step_up_position(&self, candidate: Vec3, world: &WorldState) -> Option<Vec3> {
+        let top = world.highest_solid_top(candidate, self.half_size)?;
+        let lift = top - (candidate.y - self.half_size.y);
+        let stepped = candidate + Vec3::Y * lift;
+        (lift > 0.0
+            && lift <= Self::STEP_HEIGHT
+            && !world.intersects_solid(stepped, self.half_size))
+        .then_some(stepped)
+    }
+
+    /// Drop `pos` onto the top of the ground the fall to `candidate` ran into.
+    fn land_flush(&self, pos: &mut Vec3, candidate: Vec3, world: &WorldState) {
+        let Some(top) = world.highest_solid_top(candidate, self.half_size) else {
+            return;
+        };
+        let landed = Vec3::new(pos.x, top + self.half_size.y, pos.z);
+        if landed.y <= pos.y && !world.intersects_solid(landed, self.half_size) {
+            *pos = landed;
+        }
+    }
+
     /// Move along one axis and clamp velocity on collision.
+    ///
+    /// A falling body lands flush on top of what it hits, and with `step_up` a horizontal
+    /// move climbs onto an obstacle up to `STEP_HEIGHT` tall. Returns whether the move was
+    /// refused for crossing out of the world border; a body already outside it is not trapped.
+    #[allow(clippy::too_many_arguments)]
     fn move_axis(
         &self,
         axis: Vec3,
@@ -275,7 +374,8 @@ impl Player {
         world: &WorldState,
         dt: f32,
         prevent_fall: bool,
-    ) {
+        step_up: bool,
+    ) -> bool {
         let delta = if axis == Vec3::X {
             vel.x * dt
         } else if axis == Vec3::Y {
@@ -284,7 +384,7 @@ impl Player {
             vel.z * dt
         };
         if delta == 0.0 {
-            return;
+            return false;
         }
 
         let mut candidate = *pos;
@@ -297,26 +397,43 @@ impl Player {
         }
 
         if world.intersects_solid(candidate, self.half_size) {
-            if axis == Vec3::X {
-                vel.x = 0.0;
-            } else if axis == Vec3::Y {
-                vel.y = 0.0;
+            let stepped = if step_up && axis != Vec3::Y {
+                self.step_up_position(candidate, world)
             } else {
-                vel.z = 0.0;
+                None
+            };
+            match stepped {
+                Some(stepped) => candidate = stepped,
+                None => {
+                    if axis == Vec3::X {
+                        vel.x = 0.0;
+                    } else if axis == Vec3::Y {
+                        if delta < 0.0 {
+                            self.land_flush(pos, candidate, world);
+                        }
+                        vel.y = 0.0;
+                    } else {
+                        vel.z = 0.0;
+                    }
+                    return false;
+                }
             }
-            return;
         }
 
-        if prevent_fall && axis != Vec3::Y && !world.has_ground_support(candidate, self.half_size) {
+        let crosses_border = !world.border.contains_aabb(candidate, self.half_size)
+            && world.border.contains_aabb(*pos, self.half_size);
+        let unsupported = prevent_fall && !world.has_ground_support(candidate, self.half_size);
+        if axis != Vec3::Y && (crosses_border || unsupported) {
             if axis == Vec3::X {
                 vel.x = 0.0;
             } else {
                 vel.z = 0.0;
             }
-            return;
+            return crosses_border;
         }
 
         *pos = candidate;
+        false
     }
 }
 
@@ -403,6 +520,52 @@ impl PlayerController {
     }
 }
 
+/// Player hit points plus the post-spawn damage-immunity window.
+#[derive(Component)]
+pub struct Health {
+    /// Current hit points.
+    pub current: f32,
+    /// Remaining spawn-protection time in seconds (`0.0` when unprotected).
+    pub spawn_protection: f32,
+}
+
+impl Health {
+    /// Damage-immunity duration granted after (re)spawning.
+    pub const SPAWN_PROTECTION_SECS: f32 = 5.0;
+
+    /// Build full health with a fresh spawn-protection window.
+    pub fn new_spawned(max: f32) -> Self {
+        Self {
+            current: max,
+            spawn_protection: Self::SPAWN_PROTECTION_SECS,
+        }
+    }
+
+    /// Return whether spawn protection currently blocks damage and mob aggro.
+    pub fn is_spawn_protected(&self) -> bool {
+        self.spawn_protection > 0.0
+    }
+
+    /// End spawn protection early (the player attacked or broke a block).
+    pub fn cancel_spawn_protection(&mut self) {
+        self.spawn_protection = 0.0;
+    }
+
+    /// Count down the spawn-protection window.
+    pub fn tick_spawn_protection(&mut self, dt: f32) {
+        self.spawn_protection = (self.spawn_protection - dt).max(0.0);
+    }
+
+    /// Apply damage unless spawn-protected and return whether it was applied.
+    pub fn apply_damage(&mut self, amount: f32) -> bool {
+        if self.is_spawn_protected() {
+            return false;
+        }
+        self.current = (self.current - amount).max(0.0);
+        true
+    }
+}
+
 /// Linear velocity component for player movement integration.
 #[derive(Component, Default)]
 pub struct Velocity(
@@ -410,11 +573,81 @@ pub struct Velocity(
     pub Vec3,
 );
 
+/// One physics tick of player input, kept by `TransformWatchdog` to log on recovery.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputSample {
+    /// Frame time of the tick, in seconds.
+    pub dt: f32,
+    /// Keys held during the tick.
+    pub keys: Vec<KeyCode>,
+}
+
+/// Sent when the physics watchdog reset a player out of non-finite state.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct PlayerRecovered {
+    /// Position the player was moved back to.
+    pub position: Vec3,
+}
+
+/// Last known-good player position used to recover from non-finite physics state.
+#[derive(Component, Default)]
+pub struct TransformWatchdog {
+    /// Most recent finite position snapshot (`None` until the first physics tick).
+    last_good: Option<Vec3>,
+    /// Seconds since `last_good` was refreshed.
+    since_snapshot: f32,
+    /// Input of the latest physics ticks, oldest first.
+    recent_inputs: VecDeque<InputSample>,
+}
+
+impl TransformWatchdog {
+    /// Seconds between known-good position snapshots.
+    const SNAPSHOT_INTERVAL: f32 = 1.0;
+    /// Number of physics ticks whose input is kept for the recovery log.
+    pub(crate) const RECENT_INPUTS: usize = 8;
+
+    /// Return whether a transform/velocity pair is unusable for simulation.
+    pub fn is_invalid(translation: Vec3, velocity: Vec3) -> bool {
+        !translation.is_finite() || !velocity.is_finite()
+    }
+
+    /// Position to restore after invalid state, falling back to `spawn` before any snapshot.
+    pub fn recovery_position(&self, spawn: Vec3) -> Vec3 {
+        self.last_good.unwrap_or(spawn)
+    }
+
+    /// Remember the input of one physics tick, dropping the oldest beyond `RECENT_INPUTS`.
+    pub fn record_input(&mut self, dt: f32, keys: impl IntoIterator<Item = KeyCode>) {
+        if self.recent_inputs.len() == Self::RECENT_INPUTS {
+            self.recent_inputs.pop_front();
+        }
+        self.recent_inputs.push_back(InputSample {
+            dt,
+            keys: keys.into_iter().collect(),
+        });
+    }
+
+    /// Return the input of the latest physics ticks, oldest first.
+    pub fn recent_inputs(&self) -> &VecDeque<InputSample> {
+        &self.recent_inputs
+    }
+
+    /// Advance the snapshot timer and record `translation` when a snapshot is due.
+    pub fn track(&mut self, translation: Vec3, dt: f32) {
+        self.since_snapshot += dt;
+        if self.last_good.is_none() || self.since_snapshot >= Self::SNAPSHOT_INTERVAL {
+            self.last_good = Some(translation);
+            self.since_snapshot = 0.0;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use bevy::prelude::{IVec3, Vec3};
+    use bevy::prelude::*;
 
-    use super::Player;
+    use super::{Health, Player};
+    use crate::voxel::{Block, Chunk, ChunkData, WorldState};
 
     /// Ensure placement-collision guard blocks overlapping placement and allows clear placement.
     #[test]
@@ -428,4 +661,54 @@ mod tests {
         // Far away block should not overlap.
         assert!(!player.intersects_block(player_pos, IVec3::new(4, 1, 4)));
     }
+
+    /// Verify standing on ice slides towards the wish velocity while dirt snaps to it.
+    #[test]
+    fn ice_underfoot_makes_movement_slide() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(1, 0, 1), Block::ice());
+        chunk.set_block(IVec3::new(3, 0, 1), Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut player = Player::new_standing(10.0, Vec3::new(0.3, 0.95, 0.3), 1.8);
+        assert_eq!(
+            player.block_under_feet(Vec3::new(1.5, 1.95, 1.5), &world),
+            None
+        );
+        player.on_ground = true;
+
+        player.ground_block = player.block_under_feet(Vec3::new(1.5, 1.95, 1.5), &world);
+        assert_eq!(player.ground_block, Some(Block::ice()));
+        let mut velocity = Vec3::new(4.0, 0.0, 0.0);
+        player.apply_horizontal_movement(&mut velocity, Vec3::ZERO, player.ground_friction());
+        assert!(velocity.x > 3.0 && velocity.x < 4.0);
+
+        player.ground_block = player.block_under_feet(Vec3::new(3.5, 1.95, 1.5), &world);
+        assert_eq!(player.ground_block, Some(Block::dirt()));
+        player.apply_horizontal_movement(&mut velocity, Vec3::ZERO, player.ground_friction());
+        assert_eq!(velocity, Vec3::ZERO);
+    }
+
+    /// Verify spawn protection blocks damage until it expires or is canceled.
+    #[test]
+    fn spawn_protection_expires_or_cancels_early() {
+        let mut health = Health::new_spawned(20.0);
+        assert!(!health.apply_damage(5.0));
+        assert_eq!(health.current, 20.0);
+
+        health.tick_spawn_protection(Health::SPAWN_PROTECTION_SECS - 0.1);
+        assert!(health.is_spawn_protected());
+        health.tick_spawn_protection(0.2);
+        assert!(!health.is_spawn_protected());
+        assert!(health.apply_damage(5.0));
+        assert_eq!(health.current, 15.0);
+
+        // Breaking a block (or attacking) ends protection immediately.
+        let mut health = Health::new_spawned(20.0);
+        health.cancel_spawn_protection();
+        assert!(health.apply_damage(5.0));
+    }
 }
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use crate::player::components::{Health, PlayerBody};
+
+/// Marker component for the screen-border overlay shown during spawn protection.
+#[derive(Component)]
+pub struct SpawnProtectionBorder;
+
+/// Border flash frequency while spawn protection is active (flashes per second).
+const SPAWN_PROTECTION_FLASH_HZ: f32 = 1.5;
+/// Peak border alpha while spawn protection is active.
+const SPAWN_PROTECTION_FLASH_ALPHA: f32 = 0.35;
+/// Constant border alpha used instead of the flash in photosensitivity mode.
+const SPAWN_PROTECTION_STEADY_ALPHA: f32 = 0.2;
+
+/// Count down spawn protection on the player.
+pub fn spawn_protection_system(time: Res<Time>, mut query: Query<&mut Health, With<PlayerBody>>) {
+    let dt = time.delta_secs();
+    for mut health in &mut query {
+        if health.is_spawn_protected() {
+            health.tick_spawn_protection(dt);
+        }
+    }
+}
+
+/// Pulse the screen-border overlay while the player is spawn-protected.
+pub fn spawn_protection_border_system(
+    time: Res<Time>,
+    health_query: Query<&Health, With<PlayerBody>>,
+    mut border_query: Query<&mut BorderColor, With<SpawnProtectionBorder>>,
+) {
+    let protected = health_query
+        .single()
+        .is_ok_and(|health| health.is_spawn_protected());
+    let alpha = if protected {
+        let phase = time.elapsed_secs() * SPAWN_PROTECTION_FLASH_HZ * std::f32::consts::TAU;
+        SPAWN_PROTECTION_FLASH_ALPHA * (0.5 + 0.5 * phase.sin())
+    } else {
+        0.0
+    };
+    for mut border in &mut border_query {
+        *border = BorderColor::all(Color::srgba(1.0, 1.0, 1.0, alpha));
+    }
+}
+
+/// Tint the screen border steadily while spawn-protected (non-flashing alternative).
+pub fn spawn_protection_steady_border_system(
+    health_query: Query<&Health, With<PlayerBody>>,
+    mut border_query: Query<&mut BorderColor, With<SpawnProtectionBorder>>,
+) {
+    let protected = health_query
+        .single()
+        .is_ok_and(|health| health.is_spawn_protected());
+    let alpha = if protected {
+        SPAWN_PROTECTION_STEADY_ALPHA
+    } else {
+        0.0
+    };
+    for mut border in &mut border_query {
+        *border = BorderColor::all(Color::srgba(1.0, 1.0, 1.0, alpha));
+    }
+}
@@ -1,11 +1,20 @@
 mod camera;
 mod components;
+mod health;
 mod held_item;
 mod movement;
+mod orbit;
 mod physics;
 
 pub use camera::{camera_follow_system, camera_look_system};
-pub use components::{FlyCamera, Player, PlayerBody, PlayerController, Velocity};
+pub use components::{
+    FlyCamera, Health, Player, PlayerBody, PlayerController, PlayerRecovered, Velocity,
+};
+pub use health::{
+    SpawnProtectionBorder, spawn_protection_border_system, spawn_protection_steady_border_system,
+    spawn_protection_system,
+};
 pub use held_item::{PreviewBlock, preview_follow_system};
 pub use movement::{camera_move_system, toggle_fly_system};
-pub use physics::{crouch_system, crouch_transition_system, physics_system};
+pub use orbit::{CameraOrbit, camera_orbit_inactive, camera_orbit_system, player_control_enabled};
+pub use physics::{crouch_system, crouch_transition_system, physics_system, recovery_toast_system};
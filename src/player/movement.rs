@@ -27,7 +27,8 @@ pub fn camera_move_system(
                 input.pressed(KeyCode::ShiftLeft),
                 player.crouching,
             );
-            player.apply_horizontal_movement(&mut velocity.0, wish);
+            let friction = player.ground_friction();
+            player.apply_horizontal_movement(&mut velocity.0, wish, friction);
 
             if input.just_pressed(KeyCode::Space) && player.on_ground {
                 player.try_start_jump(&mut velocity.0);
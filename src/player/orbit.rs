@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+
+use crate::player::components::{FlyCamera, PlayerBody};
+use crate::player::held_item::PreviewBlock;
+use crate::scene::HudRoot;
+use crate::voxel::WorldState;
+use crate::{BLOCK_SIZE, STAND_EYE_HEIGHT};
+
+/// Cinematic orbit-camera state for trailers and AFK shots.
+#[derive(Resource)]
+pub struct CameraOrbit {
+    /// Whether the camera is currently detached and orbiting.
+    pub active: bool,
+    /// World-space point the camera orbits around.
+    pub center: Vec3,
+    /// Horizontal orbit radius in world units.
+    pub radius: f32,
+    /// Camera height above `center` in world units.
+    pub height: f32,
+    /// Angular speed in radians per second once fully eased in.
+    pub angular_speed: f32,
+    /// Whether player physics stays frozen while orbiting.
+    pub freeze_player: bool,
+    /// Current orbit angle in radians around `center`.
+    angle: f32,
+    /// Ease-in progress in `[0, 1]`.
+    blend: f32,
+    /// Camera translation when the orbit started (ease-in origin).
+    start_translation: Vec3,
+}
+
+impl Default for CameraOrbit {
+    fn default() -> Self {
+        Self {
+            active: false,
+            center: Vec3::ZERO,
+            radius: 12.0 * BLOCK_SIZE,
+            height: 6.0 * BLOCK_SIZE,
+            angular_speed: 0.15,
+            freeze_player: true,
+            angle: 0.0,
+            blend: 0.0,
+            start_translation: Vec3::ZERO,
+        }
+    }
+}
+
+impl CameraOrbit {
+    /// Hotkey that toggles orbit mode.
+    pub(crate) const TOGGLE_KEY: KeyCode = KeyCode::F6;
+    /// Movement keys that exit orbit mode back to first person.
+    const EXIT_KEYS: [KeyCode; 6] = [
+        KeyCode::KeyW,
+        KeyCode::KeyA,
+        KeyCode::KeyS,
+        KeyCode::KeyD,
+        KeyCode::Space,
+        KeyCode::ControlLeft,
+    ];
+    /// Seconds spent easing from the first-person view into the orbit path.
+    const EASE_IN_SECS: f32 = 2.0;
+
+    /// Start orbiting `center`, easing in from the current camera translation.
+    pub fn start(&mut self, center: Vec3, camera_translation: Vec3) {
+        let offset = camera_translation - center;
+        self.active = true;
+        self.center = center;
+        self.angle = offset.z.atan2(offset.x);
+        self.blend = 0.0;
+        self.start_translation = camera_translation;
+    }
+
+    /// Stop orbiting.
+    pub fn stop(&mut self) {
+        self.active = false;
+    }
+
+    /// Advance the orbit by `dt` and return the new camera translation.
+    pub fn advance(&mut self, dt: f32) -> Vec3 {
+        self.blend = (self.blend + dt / Self::EASE_IN_SECS).min(1.0);
+        let ease = self.blend * self.blend * (3.0 - 2.0 * self.blend);
+        self.angle += self.angular_speed * ease * dt;
+        self.start_translation.lerp(self.orbit_translation(), ease)
+    }
+
+    /// Return the camera translation on the orbit path at the current angle.
+    fn orbit_translation(&self) -> Vec3 {
+        self.center
+            + Vec3::new(
+                self.angle.cos() * self.radius,
+                self.height,
+                self.angle.sin() * self.radius,
+            )
+    }
+}
+
+/// Return `true` when the first-person camera owns the view (orbit mode is off).
+pub fn camera_orbit_inactive(orbit: Res<CameraOrbit>) -> bool {
+    !orbit.active
+}
+
+/// Return `true` when player simulation and input should run (not frozen by orbit mode).
+pub fn player_control_enabled(orbit: Res<CameraOrbit>) -> bool {
+    !orbit.active || !orbit.freeze_player
+}
+
+/// Toggle orbit mode, drive the orbiting camera, and restore first person on exit.
+#[allow(clippy::type_complexity)]
+pub fn camera_orbit_system(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    world: Res<WorldState>,
+    mut orbit: ResMut<CameraOrbit>,
+    mut camera_query: Query<(&mut Transform, &GlobalTransform, &FlyCamera), Without<PlayerBody>>,
+    body_query: Query<&Transform, (With<PlayerBody>, Without<FlyCamera>)>,
+    mut hud_query: Query<&mut Visibility, Or<(With<HudRoot>, With<PreviewBlock>)>>,
+) {
+    let Ok((mut cam_transform, cam_global, camera)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let exit_pressed = keys.any_just_pressed(CameraOrbit::EXIT_KEYS);
+    if orbit.active && (exit_pressed || keys.just_pressed(CameraOrbit::TOGGLE_KEY)) {
+        orbit.stop();
+        // Mouse look is ignored while orbiting, so the stored yaw/pitch are the pre-orbit view.
+        cam_transform.rotation = camera.camera_rotation();
+        set_hud_visibility(&mut hud_query, Visibility::Inherited);
+        return;
+    }
+
+    if !orbit.active {
+        if !keys.just_pressed(CameraOrbit::TOGGLE_KEY) {
+            return;
+        }
+        let target_block = world
+            .raymarch_from_camera(cam_global)
+            .and_then(|(hit, _)| hit)
+            .map(|hit| (hit.as_vec3() + Vec3::splat(0.5)) * BLOCK_SIZE);
+        let player_center = body_query
+            .get(camera.target)
+            .map(|body| body.translation + Vec3::Y * STAND_EYE_HEIGHT * 0.5)
+            .unwrap_or(cam_transform.translation);
+        orbit.start(
+            target_block.unwrap_or(player_center),
+            cam_transform.translation,
+        );
+        set_hud_visibility(&mut hud_query, Visibility::Hidden);
+    }
+
+    let translation = orbit.advance(time.delta_secs());
+    cam_transform.translation = translation;
+    cam_transform.look_at(orbit.center, Vec3::Y);
+}
+
+/// Show or hide HUD and held-item entities.
+#[allow(clippy::type_complexity)]
+fn set_hud_visibility(
+    hud_query: &mut Query<&mut Visibility, Or<(With<HudRoot>, With<PreviewBlock>)>>,
+    visibility: Visibility,
+) {
+    for mut hud_visibility in hud_query.iter_mut() {
+        *hud_visibility = visibility;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::CameraOrbit;
+
+    /// Verify orbit eases in from the start position and settles onto the configured radius.
+    #[test]
+    fn orbit_eases_in_then_follows_radius() {
+        let mut orbit = CameraOrbit::default();
+        let start = Vec3::new(3.0, 5.0, 0.0);
+        orbit.start(Vec3::ZERO, start);
+
+        let first = orbit.advance(0.0);
+        assert!(first.distance(start) < 1e-4);
+
+        for _ in 0..240 {
+            orbit.advance(1.0 / 60.0);
+        }
+        let settled = orbit.advance(1.0 / 60.0);
+        let horizontal = Vec2::new(settled.x, settled.z).length();
+        assert!((horizontal - orbit.radius).abs() < 1e-3);
+        assert!((settled.y - orbit.height).abs() < 1e-3);
+    }
+}
@@ -1,12 +1,22 @@
 use bevy::prelude::*;
 
-use crate::voxel::WorldState;
+use crate::scene::{Locale, SpawnPoint, Toast, t};
+use crate::voxel::{BlockKind, WorldState};
 use crate::{
-    CROUCH_EYE_HEIGHT, CROUCH_HALF_SIZE, CROUCH_TRANSITION_SPEED, GRAVITY, JUMP_BOOST_ACCEL,
-    STAND_EYE_HEIGHT, STAND_HALF_SIZE,
+    BLOCK_SIZE, CROUCH_EYE_HEIGHT, CROUCH_HALF_SIZE, CROUCH_TRANSITION_SPEED, GRAVITY,
+    JUMP_BOOST_ACCEL, STAND_EYE_HEIGHT, STAND_HALF_SIZE,
 };
 
-use crate::player::components::{Player, PlayerBody, Velocity};
+use crate::player::components::{
+    Health, Player, PlayerBody, PlayerRecovered, TransformWatchdog, Velocity,
+};
+
+/// Gap within which the player counts as touching a cactus.
+const CACTUS_CONTACT_MARGIN: f32 = 0.05 * BLOCK_SIZE;
+/// Hit points a cactus takes per hit.
+const CACTUS_DAMAGE: f32 = 1.0;
+/// Seconds between two cactus hits while the player keeps touching one.
+const CACTUS_DAMAGE_INTERVAL_SECS: f32 = 0.5;
 
 /// Start or stop crouch intent and update target collider/eye height.
 pub fn crouch_system(
@@ -46,14 +56,53 @@ pub fn crouch_transition_system(
 }
 
 /// Apply gravity and movement, then resolve collisions.
+///
+/// Non-finite position or velocity is logged with the input of the latest ticks and reset to
+/// the last known-good position, or to the spawn point before one was recorded, announced
+/// as a `PlayerRecovered` message.
+/// Bumping into the world border is logged once per contact. Falling blocks do not block
+/// the move but push the player out of them afterwards. Touching a cactus after the move
+/// hurts the player every `CACTUS_DAMAGE_INTERVAL_SECS`.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn physics_system(
     time: Res<Time>,
     input: Res<ButtonInput<KeyCode>>,
-    mut query: Query<(&mut Transform, &mut Velocity, &mut Player), With<PlayerBody>>,
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &mut Player,
+            &mut TransformWatchdog,
+            Option<&mut Health>,
+        ),
+        With<PlayerBody>,
+    >,
     world: Res<WorldState>,
+    spawn_point: Res<SpawnPoint>,
+    mut recovered: MessageWriter<PlayerRecovered>,
+    mut at_border: Local<bool>,
+    mut cactus_cooldown: Local<f32>,
 ) {
     let dt = time.delta_secs();
-    for (mut transform, mut velocity, mut player) in &mut query {
+    *cactus_cooldown = (*cactus_cooldown - dt).max(0.0);
+    for (mut transform, mut velocity, mut player, mut watchdog, health) in &mut query {
+        watchdog.record_input(dt, input.get_pressed().copied());
+        if TransformWatchdog::is_invalid(transform.translation, velocity.0) {
+            let recovery = watchdog.recovery_position(spawn_point.player_position());
+            warn!(
+                "Invalid player state: translation {:?}, velocity {:?}, dt {dt}, recent inputs {:?}; resetting to {recovery:?}",
+                transform.translation,
+                velocity.0,
+                watchdog.recent_inputs()
+            );
+            transform.translation = recovery;
+            velocity.0 = Vec3::ZERO;
+            player.on_ground = false;
+            player.ground_block = None;
+            recovered.write(PlayerRecovered { position: recovery });
+            continue;
+        }
+
         let was_on_ground = player.on_ground;
         // Only apply gravity/jump boost when not flying.
         if !player.flying {
@@ -71,15 +120,76 @@ pub fn physics_system(
         player.on_ground = false;
         let crouch_edge_guard = player.crouch_edge_guard_enabled(was_on_ground);
 
+        // Ride falling blocks by following their top instead of free-falling a frame behind.
+        if !player.flying
+            && was_on_ground
+            && vel.y <= 0.0
+            && let Some(collider) = world.riding_collider(pos, player.half_size, dt)
+        {
+            let riding = Vec3::new(pos.x, collider.max.y + player.half_size.y, pos.z);
+            if !world.intersects_solid(riding, player.half_size) {
+                pos = riding;
+                vel.y = 0.0;
+            }
+        }
+
         // Resolve collisions per axis to keep movement stable.
-        player.resolve_motion_axes(&mut pos, &mut vel, &world, dt, crouch_edge_guard);
+        let step_up = was_on_ground && !player.flying;
+        let bumped =
+            player.resolve_motion_axes(&mut pos, &mut vel, &world, dt, crouch_edge_guard, step_up);
+        if bumped && !*at_border {
+            info!(
+                "Reached the world border at ±{} blocks",
+                world.border.half_extent
+            );
+        }
+        *at_border = bumped;
+
+        // Falling blocks never stop a move; a player inside one is shoved out of it, and
+        // one shoved upward lands on its top.
+        if let Some(pushed) = world.push_out_of_transient_colliders(pos, player.half_size) {
+            let push = pushed - pos;
+            for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                if push.dot(axis) * vel.dot(axis) < 0.0 {
+                    vel -= axis * vel.dot(axis);
+                }
+            }
+            pos = pushed;
+        }
 
         let was_flying = player.flying;
         let old_vertical_velocity = velocity.0.y;
         player.update_grounded_after_move(was_flying, old_vertical_velocity, vel.y);
+        player.ground_block = player.block_under_feet(pos, &world);
+        if let Some(mut health) = health
+            && *cactus_cooldown == 0.0
+            && world.touches_kind(
+                pos,
+                player.half_size,
+                CACTUS_CONTACT_MARGIN,
+                BlockKind::Cactus,
+            )
+        {
+            health.apply_damage(CACTUS_DAMAGE);
+            *cactus_cooldown = CACTUS_DAMAGE_INTERVAL_SECS;
+        }
 
         transform.translation = pos;
         velocity.0 = vel;
+        if !TransformWatchdog::is_invalid(pos, vel) {
+            watchdog.track(pos, dt);
+        }
+    }
+}
+
+/// Tell the player with a toast that the watchdog moved them back to a safe position.
+pub fn recovery_toast_system(
+    locale: Res<Locale>,
+    mut recovered: MessageReader<PlayerRecovered>,
+    mut toasts: MessageWriter<Toast>,
+) {
+    if recovered.read().count() > 0 {
+        toasts.write(Toast(t!(locale, "toast.recovered")));
     }
 }
 
@@ -88,22 +198,278 @@ mod tests {
     use bevy::prelude::*;
 
     use super::*;
+    use crate::voxel::WorldBorder;
 
     /// Verify crouch edge guard prevents horizontal movement without ground support.
     #[test]
     fn crouch_edge_guard_blocks_horizontal_movement_when_unsupported() {
-        let world = WorldState::new(Handle::<StandardMaterial>::default());
+        let world = WorldState::new();
         let player = Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
 
         let mut guarded_pos = Vec3::new(1.5, 2.0, 1.5);
         let mut guarded_vel = Vec3::new(4.0, 0.0, 0.0);
-        player.resolve_motion_axes(&mut guarded_pos, &mut guarded_vel, &world, 0.1, true);
+        player.resolve_motion_axes(&mut guarded_pos, &mut guarded_vel, &world, 0.1, true, false);
         assert_eq!(guarded_pos, Vec3::new(1.5, 2.0, 1.5));
         assert_eq!(guarded_vel.x, 0.0);
 
         let mut free_pos = Vec3::new(1.5, 2.0, 1.5);
         let mut free_vel = Vec3::new(4.0, 0.0, 0.0);
-        player.resolve_motion_axes(&mut free_pos, &mut free_vel, &world, 0.1, false);
+        player.resolve_motion_axes(&mut free_pos, &mut free_vel, &world, 0.1, false, false);
         assert!(free_pos.x > 1.5);
     }
+
+    /// Verify the world border stops horizontal motion at its plane and reports the bump.
+    #[test]
+    fn world_border_blocks_horizontal_motion() {
+        let mut world = WorldState::new();
+        world.border = WorldBorder { half_extent: 8 };
+        let player = Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+
+        let start = Vec3::new(7.5, 2.0, -7.5);
+        let mut pos = start;
+        let mut vel = Vec3::new(4.0, 0.0, -4.0);
+        assert!(player.resolve_motion_axes(&mut pos, &mut vel, &world, 0.1, false, false));
+        assert_eq!((pos.x, pos.z), (start.x, start.z));
+        assert_eq!((vel.x, vel.z), (0.0, 0.0));
+
+        let mut pos = start;
+        let mut vel = Vec3::new(-4.0, 0.0, 4.0);
+        assert!(!player.resolve_motion_axes(&mut pos, &mut vel, &world, 0.1, false, false));
+        assert!(pos.x < start.x && pos.z > start.z);
+    }
+
+    /// Verify a falling player lands flush on a slab and a walking one steps up onto it,
+    /// but not onto a full block.
+    #[test]
+    fn slab_is_stood_on_at_half_height_and_stepped_onto() {
+        use crate::voxel::{Block, Chunk, ChunkData};
+        use crate::{BLOCK_SIZE, CHUNK_SIZE};
+
+        let world_with_step = |step: Block| {
+            let mut world = WorldState::new();
+            let mut chunk = Chunk::new_empty();
+            for x in 0..CHUNK_SIZE {
+                for z in 0..CHUNK_SIZE {
+                    chunk.set_block(IVec3::new(x, 0, z), Block::stone());
+                }
+            }
+            chunk.set_block(IVec3::new(3, 1, 2), step);
+            world.chunks.insert(
+                IVec3::ZERO,
+                ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+            );
+            world
+        };
+        let player = Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+        let feet = |pos: Vec3| pos.y - STAND_HALF_SIZE.y;
+
+        let world = world_with_step(Block::slab());
+        let mut pos = Vec3::new(3.5, 4.0, 2.5);
+        let mut vel = Vec3::new(0.0, -10.0, 0.0);
+        for _ in 0..10 {
+            player.resolve_motion_axes(&mut pos, &mut vel, &world, 0.05, false, false);
+            vel.y = -10.0;
+        }
+        assert!((feet(pos) - (1.0 + 0.5) * BLOCK_SIZE).abs() < 1e-4, "{pos}");
+
+        for (step, climbed) in [(Block::slab(), true), (Block::stone(), false)] {
+            let world = world_with_step(step);
+            let mut pos = Vec3::new(2.0, 1.0 + STAND_HALF_SIZE.y, 2.5);
+            for _ in 0..10 {
+                let mut vel = Vec3::new(4.0, -1.0, 0.0);
+                player.resolve_motion_axes(&mut pos, &mut vel, &world, 0.05, false, true);
+            }
+            assert_eq!(pos.x > 3.0, climbed, "{step:?} at {pos}");
+            let expected = if climbed { 1.5 } else { 1.0 };
+            assert!(
+                (feet(pos) - expected * BLOCK_SIZE).abs() < 1e-4,
+                "{step:?} at {pos}"
+            );
+        }
+    }
+
+    /// Verify a player walking into a staircase climbs it one half step at a time.
+    #[test]
+    fn stairs_are_climbed_without_jumping() {
+        use crate::voxel::{Block, Chunk, ChunkData};
+        use crate::{BLOCK_SIZE, CHUNK_SIZE};
+
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                chunk.set_block(IVec3::new(x, 0, z), Block::stone());
+            }
+        }
+        // Stairs face back toward the player walking +X.
+        let stairs = Block::stairs().with_front_from_direction(Vec3::NEG_X);
+        chunk.set_block(IVec3::new(3, 1, 2), stairs);
+        chunk.set_block(IVec3::new(4, 1, 2), Block::stone());
+        chunk.set_block(IVec3::new(4, 2, 2), stairs);
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let player = Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+
+        let mut pos = Vec3::new(2.0, 1.0 + STAND_HALF_SIZE.y, 2.5);
+        for _ in 0..14 {
+            let mut vel = Vec3::new(4.0, -1.0, 0.0);
+            player.resolve_motion_axes(&mut pos, &mut vel, &world, 0.05, false, true);
+        }
+        assert!(pos.x > 4.5, "{pos}");
+        assert!(
+            (pos.y - STAND_HALF_SIZE.y - 3.0 * BLOCK_SIZE).abs() < 1e-4,
+            "{pos}"
+        );
+    }
+
+    /// Verify touching a cactus's inset side hurts the player, but standing a little off
+    /// does not.
+    #[test]
+    fn cactus_contact_deals_damage() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        use crate::voxel::{Block, Chunk, ChunkData};
+
+        let health_after_standing_at = |x: f32| {
+            let mut world = World::new();
+            let mut state = WorldState::new();
+            let mut chunk = Chunk::new_empty();
+            chunk.set_block(IVec3::new(3, 1, 2), Block::cactus());
+            state.chunks.insert(
+                IVec3::ZERO,
+                ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+            );
+            world.insert_resource(state);
+            world.insert_resource(SpawnPoint {
+                ground: IVec3::ZERO,
+            });
+            world.init_resource::<ButtonInput<KeyCode>>();
+            world.init_resource::<Messages<PlayerRecovered>>();
+            let mut time = Time::<()>::default();
+            time.advance_by(Duration::from_millis(16));
+            world.insert_resource(time);
+            let mut player = Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+            player.flying = true;
+            let mut health = Health::new_spawned(20.0);
+            health.cancel_spawn_protection();
+            let entity = world
+                .spawn((
+                    PlayerBody,
+                    Transform::from_translation(Vec3::new(x, 1.0 + STAND_HALF_SIZE.y, 2.5)),
+                    Velocity::default(),
+                    player,
+                    health,
+                ))
+                .id();
+            world.run_system_once(physics_system).unwrap();
+            world.get::<Health>(entity).unwrap().current
+        };
+
+        let touching = (3.0 + 0.0625) * BLOCK_SIZE - STAND_HALF_SIZE.x;
+        assert_eq!(health_after_standing_at(touching), 20.0 - CACTUS_DAMAGE);
+        assert_eq!(health_after_standing_at(touching - 0.2 * BLOCK_SIZE), 20.0);
+    }
+
+    /// Verify a NaN velocity resets the player to the last known-good position at rest,
+    /// keeps the input that led up to it, and toasts the recovery.
+    #[test]
+    fn nan_velocity_recovers_last_good_position() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(SpawnPoint {
+            ground: IVec3::ZERO,
+        });
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Messages<PlayerRecovered>>();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(16));
+        world.insert_resource(time);
+        let mut player = Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+        player.flying = true;
+        let start = Vec3::new(1.5, 20.0, 1.5);
+        let entity = world
+            .spawn((
+                PlayerBody,
+                Transform::from_translation(start),
+                Velocity::default(),
+                player,
+            ))
+            .id();
+
+        world.run_system_once(physics_system).unwrap();
+        world.get_mut::<Velocity>(entity).unwrap().0 = Vec3::new(f32::NAN, 0.0, 0.0);
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyW);
+        world.run_system_once(physics_system).unwrap();
+
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, start);
+        assert_eq!(world.get::<Velocity>(entity).unwrap().0, Vec3::ZERO);
+        let inputs = world
+            .get::<TransformWatchdog>(entity)
+            .unwrap()
+            .recent_inputs();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs.back().unwrap().keys, [KeyCode::KeyW]);
+
+        world.insert_resource(Locale::load(Locale::FALLBACK));
+        world.init_resource::<Messages<Toast>>();
+        world.run_system_once(recovery_toast_system).unwrap();
+        let toasts: Vec<Toast> = world.resource_mut::<Messages<Toast>>().drain().collect();
+        assert_eq!(
+            toasts,
+            [Toast(
+                "Lost track of your position; moved you back to safety".to_string()
+            )]
+        );
+    }
+
+    /// Verify the watchdog keeps only the input of the latest ticks, oldest first.
+    #[test]
+    fn watchdog_keeps_latest_inputs() {
+        let mut watchdog = TransformWatchdog::default();
+        for tick in 0..TransformWatchdog::RECENT_INPUTS + 3 {
+            watchdog.record_input(tick as f32, []);
+        }
+        let inputs = watchdog.recent_inputs();
+        assert_eq!(inputs.len(), TransformWatchdog::RECENT_INPUTS);
+        assert_eq!(inputs.front().unwrap().dt, 3.0);
+    }
+
+    /// Verify invalid state before the first snapshot resets the player onto the spawn point.
+    #[test]
+    fn nan_before_first_snapshot_recovers_spawn_point() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let spawn_point = SpawnPoint {
+            ground: IVec3::new(4, 7, -3),
+        };
+        let mut world = World::new();
+        world.insert_resource(WorldState::new());
+        world.insert_resource(spawn_point);
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<Messages<PlayerRecovered>>();
+        world.insert_resource(Time::<()>::default());
+        let entity = world
+            .spawn((
+                PlayerBody,
+                Transform::from_translation(Vec3::new(f32::NAN, 0.0, 0.0)),
+                Velocity::default(),
+                Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT),
+            ))
+            .id();
+
+        world.run_system_once(physics_system).unwrap();
+
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            spawn_point.player_position()
+        );
+    }
 }
@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use crate::scene::HudRoot;
+use crate::scene::settings_file::SettingsFile;
+
+/// Accessibility options checked by effect systems through run conditions.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct AccessibilitySettings {
+    /// Disable camera and held-item motion effects.
+    pub reduced_motion: bool,
+    /// Replace flashing effects with steady or slow alternatives.
+    pub photosensitive: bool,
+    /// Scale multiplier applied to the HUD root node.
+    pub ui_scale: f32,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            reduced_motion: false,
+            photosensitive: false,
+            ui_scale: 1.0,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Hotkey that toggles reduced motion.
+    pub(crate) const REDUCED_MOTION_KEY: KeyCode = KeyCode::F5;
+    /// Hotkey that toggles photosensitivity mode.
+    pub(crate) const PHOTOSENSITIVE_KEY: KeyCode = KeyCode::F1;
+    /// Hotkey that grows the HUD by one step.
+    pub(crate) const UI_SCALE_UP_KEY: KeyCode = KeyCode::BracketRight;
+    /// Hotkey that shrinks the HUD by one step.
+    pub(crate) const UI_SCALE_DOWN_KEY: KeyCode = KeyCode::BracketLeft;
+    /// HUD scale change per hotkey press.
+    const UI_SCALE_STEP: f32 = 0.25;
+    /// Smallest HUD scale.
+    const MIN_UI_SCALE: f32 = 0.5;
+    /// Largest HUD scale.
+    const MAX_UI_SCALE: f32 = 2.0;
+}
+
+impl SettingsFile for AccessibilitySettings {
+    const PATH: &'static str = "settings/accessibility.cfg";
+    const NAME: &'static str = "accessibility settings";
+
+    fn apply_entry(&mut self, key: &str, value: &str) {
+        match key {
+            "reduced_motion" => self.reduced_motion = value.parse().unwrap_or(self.reduced_motion),
+            "photosensitive" => self.photosensitive = value.parse().unwrap_or(self.photosensitive),
+            "ui_scale" => {
+                self.ui_scale = value
+                    .parse()
+                    .ok()
+                    .filter(|scale| (Self::MIN_UI_SCALE..=Self::MAX_UI_SCALE).contains(scale))
+                    .unwrap_or(self.ui_scale);
+            }
+            _ => {}
+        }
+    }
+
+    fn entries(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("reduced_motion", self.reduced_motion.to_string()),
+            ("photosensitive", self.photosensitive.to_string()),
+            ("ui_scale", self.ui_scale.to_string()),
+        ]
+    }
+}
+
+/// Run condition: camera/held-item motion effects may play.
+pub fn motion_effects_enabled(settings: Res<AccessibilitySettings>) -> bool {
+    !settings.reduced_motion
+}
+
+/// Run condition: flashing effects may play.
+pub fn flashing_effects_enabled(settings: Res<AccessibilitySettings>) -> bool {
+    !settings.photosensitive
+}
+
+/// Apply accessibility hotkeys and persist the result.
+pub fn accessibility_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    let mut next = *settings;
+    if keys.just_pressed(AccessibilitySettings::REDUCED_MOTION_KEY) {
+        next.reduced_motion = !next.reduced_motion;
+    }
+    if keys.just_pressed(AccessibilitySettings::PHOTOSENSITIVE_KEY) {
+        next.photosensitive = !next.photosensitive;
+    }
+    if keys.just_pressed(AccessibilitySettings::UI_SCALE_UP_KEY) {
+        next.ui_scale = (next.ui_scale + AccessibilitySettings::UI_SCALE_STEP)
+            .min(AccessibilitySettings::MAX_UI_SCALE);
+    }
+    if keys.just_pressed(AccessibilitySettings::UI_SCALE_DOWN_KEY) {
+        next.ui_scale = (next.ui_scale - AccessibilitySettings::UI_SCALE_STEP)
+            .max(AccessibilitySettings::MIN_UI_SCALE);
+    }
+    if next != *settings {
+        *settings = next;
+        next.save();
+    }
+}
+
+/// Scale the HUD root node by the configured UI scale.
+pub fn apply_ui_scale_system(
+    settings: Res<AccessibilitySettings>,
+    mut hud_query: Query<&mut bevy::ui::UiTransform, With<HudRoot>>,
+    added: Query<(), Added<HudRoot>>,
+) {
+    if !settings.is_changed() && added.is_empty() {
+        return;
+    }
+    for mut transform in &mut hud_query {
+        transform.scale = Vec2::splat(settings.ui_scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::player::{
+        Health, PlayerBody, SpawnProtectionBorder, spawn_protection_border_system,
+        spawn_protection_steady_border_system,
+    };
+
+    /// Verify settings round-trip and photosensitivity swaps the flashing border for a steady one.
+    #[test]
+    fn photosensitive_flag_gates_flashing_border() {
+        let settings = AccessibilitySettings {
+            reduced_motion: true,
+            photosensitive: true,
+            ui_scale: 1.5,
+        };
+        assert_eq!(AccessibilitySettings::parse(&settings.to_text()), settings);
+        assert_eq!(
+            AccessibilitySettings::parse("ui_scale = 9\nphotosensitive = sure\n"),
+            AccessibilitySettings::default()
+        );
+
+        // Sample the border at two times a quarter flash period apart.
+        let border_alphas = |photosensitive: bool| {
+            let mut app = App::new();
+            app.insert_resource(AccessibilitySettings {
+                photosensitive,
+                ..default()
+            })
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (
+                    spawn_protection_border_system.run_if(flashing_effects_enabled),
+                    spawn_protection_steady_border_system.run_if(not(flashing_effects_enabled)),
+                ),
+            );
+            app.world_mut()
+                .spawn((PlayerBody, Health::new_spawned(20.0)));
+            let border = app
+                .world_mut()
+                .spawn((BorderColor::all(Color::NONE), SpawnProtectionBorder))
+                .id();
+            let mut alphas = [0.0; 2];
+            for alpha in &mut alphas {
+                app.update();
+                *alpha = app.world().get::<BorderColor>(border).unwrap().top.alpha();
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(170));
+            }
+            alphas
+        };
+        let flashing = border_alphas(false);
+        let steady = border_alphas(true);
+        assert_ne!(flashing[0], flashing[1]);
+        assert!(steady[0] > 0.0);
+        assert_eq!(steady[0], steady[1]);
+    }
+}
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use bevy::window::{CursorOptions, PrimaryWindow};
+
+use crate::scene::locale::{Locale, LocaleChanged};
+use crate::scene::menu::{
+    CLOSE_KEYS, InventoryButton, SLOT_GAP, SLOT_SIZE, fill_inventory_row, inventory_row_node,
+    label, localized_label, set_slot_label, show_menu, slot_bundle, slot_color, spawn_menu_root,
+    stack_label,
+};
+use crate::voxel::{CHEST_SLOTS, ContainerMenu, Inventory, ItemKind, WorldState};
+
+/// Container slots per row on screen.
+const CONTAINER_COLUMNS: u16 = 9;
+
+/// Marker for the full-screen container root, hidden while the screen is closed.
+#[derive(Component)]
+pub struct ContainerUiRoot;
+
+/// Button showing one container slot; clicking it moves the stack to the inventory.
+#[derive(Component)]
+pub struct ContainerSlotButton(pub usize);
+
+/// Row listing held items under the container slots; clicking a block stores that kind.
+#[derive(Component)]
+pub struct ContainerInventoryRow;
+
+/// Build the hidden container screen: the chest slots over the held items.
+pub(crate) fn spawn_container_ui(commands: &mut Commands) {
+    spawn_menu_root(commands, ContainerUiRoot, |panel| {
+        panel.spawn(localized_label("container.chest"));
+        panel
+            .spawn(Node {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::px(CONTAINER_COLUMNS, SLOT_SIZE),
+                row_gap: Val::Px(SLOT_GAP),
+                column_gap: Val::Px(SLOT_GAP),
+                ..default()
+            })
+            .with_children(|grid| {
+                for index in 0..CHEST_SLOTS {
+                    grid.spawn((slot_bundle(), ContainerSlotButton(index)))
+                        .with_child(label(""));
+                }
+            });
+        panel.spawn(localized_label("container.inventory"));
+        panel.spawn((inventory_row_node(), ContainerInventoryRow));
+    });
+}
+
+/// Close the container screen on its close keys or once its block is gone, and move items
+/// for clicks on its slots.
+#[allow(clippy::type_complexity)]
+pub fn container_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<ContainerMenu>,
+    mut world: ResMut<WorldState>,
+    mut inventory: ResMut<Inventory>,
+    mut buttons: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Option<&ContainerSlotButton>,
+            Option<&InventoryButton>,
+        ),
+        Changed<Interaction>,
+    >,
+) {
+    let Some(pos) = menu.open_at() else {
+        return;
+    };
+    if keys.any_just_pressed(CLOSE_KEYS) || world.block_entities.container(pos).is_none() {
+        menu.close();
+        return;
+    }
+    for (interaction, mut background, slot, held) in &mut buttons {
+        background.0 = slot_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(ContainerSlotButton(index)) = slot {
+            menu.withdraw(&mut world, &mut inventory, *index);
+        } else if let Some(InventoryButton(ItemKind::Block(item))) = held {
+            menu.deposit(&mut world, &mut inventory, *item);
+        }
+    }
+}
+
+/// Mirror the open container into its screen and hand the cursor over while it is open.
+///
+/// Slot labels are redrawn in the new language when it changes.
+#[allow(clippy::too_many_arguments)]
+pub fn container_ui_system(
+    mut commands: Commands,
+    menu: Res<ContainerMenu>,
+    world: Res<WorldState>,
+    inventory: Res<Inventory>,
+    locale: Res<Locale>,
+    mut locale_changed: MessageReader<LocaleChanged>,
+    mut windows: Query<&mut CursorOptions, With<PrimaryWindow>>,
+    mut root_query: Query<&mut Node, With<ContainerUiRoot>>,
+    slot_query: Query<(&Children, &ContainerSlotButton)>,
+    mut text_query: Query<&mut Text>,
+    row_query: Query<Entity, With<ContainerInventoryRow>>,
+) {
+    let relabel = locale_changed.read().count() > 0;
+    if !menu.is_changed() && !inventory.is_changed() && !relabel {
+        return;
+    }
+    if menu.is_changed()
+        && let Ok(mut root) = root_query.single_mut()
+    {
+        show_menu(
+            &mut root,
+            windows.single_mut().ok(),
+            menu.open_at().is_some(),
+        );
+    }
+    let Some(container) = menu
+        .open_at()
+        .and_then(|pos| world.block_entities.container(pos))
+    else {
+        return;
+    };
+    for (children, ContainerSlotButton(index)) in &slot_query {
+        let stack = container.slots().get(*index).copied().flatten();
+        set_slot_label(
+            children,
+            &mut text_query,
+            stack.map_or(String::new(), |(kind, count)| {
+                stack_label(&locale, kind, count)
+            }),
+        );
+    }
+    if let Ok(row) = row_query.single() {
+        fill_inventory_row(&mut commands, row, &inventory, &locale);
+    }
+}
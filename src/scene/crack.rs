@@ -0,0 +1,260 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use std::f32::consts::TAU;
+
+use crate::BLOCK_SIZE;
+use crate::terrain::ChunkRng;
+use crate::voxel::{Block, BreakProgress};
+
+/// Number of crack stages shown while a block breaks.
+const CRACK_STAGES: usize = 5;
+/// Width and height of each crack stage texture in texels.
+const CRACK_TEXTURE_SIZE: u32 = 16;
+/// Cracks running out from the middle of each face.
+const CRACK_ARMS: u32 = 5;
+/// Texel steps each crack arm takes until the last stage.
+const CRACK_ARM_LENGTH: u32 = 9;
+/// Seed of the fixed crack pattern.
+const CRACK_SEED: u64 = 0x6372_6163_6b73;
+/// Color of crack texels.
+const CRACK_COLOR: [u8; 4] = [24, 20, 18, 210];
+/// How far the overlay stands off the block faces; below the outline's margin so the
+/// outline stays on top.
+const CRACK_MARGIN: f32 = 0.002 * BLOCK_SIZE;
+
+/// Marker for the crack overlay drawn over the block being broken.
+#[derive(Component)]
+pub(crate) struct CrackOverlay {
+    /// Block the overlay covers.
+    target: IVec3,
+}
+
+/// Shared mesh and per-stage materials of the crack overlay.
+#[derive(Resource)]
+pub(crate) struct CrackOverlayAssets {
+    /// Cube slightly larger than a block cell, centered on the origin.
+    mesh: Handle<Mesh>,
+    /// Material of each crack stage, from the first hairline to nearly broken.
+    stages: Vec<Handle<StandardMaterial>>,
+}
+
+impl CrackOverlayAssets {
+    /// Build the overlay mesh and crack stage materials.
+    pub(crate) fn new(
+        meshes: &mut ResMut<Assets<Mesh>>,
+        materials: &mut ResMut<Assets<StandardMaterial>>,
+        images: &mut ResMut<Assets<Image>>,
+    ) -> Self {
+        let stages = crack_stage_textures()
+            .into_iter()
+            .map(|image| {
+                materials.add(StandardMaterial {
+                    base_color_texture: Some(images.add(image)),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                })
+            })
+            .collect();
+        Self {
+            mesh: meshes.add(Cuboid::from_length(BLOCK_SIZE + 2.0 * CRACK_MARGIN)),
+            stages,
+        }
+    }
+
+    /// Return the material of the stage showing break `fraction`.
+    fn stage_material(&self, fraction: f32) -> &Handle<StandardMaterial> {
+        let stage = (fraction * CRACK_STAGES as f32) as usize;
+        &self.stages[stage.min(CRACK_STAGES - 1)]
+    }
+}
+
+/// Return the step along its crack arm at which each texel cracks, or `None` if it never does.
+///
+/// Arms wander out from the middle in a fixed pseudo-random pattern; a texel crossed by
+/// several arms cracks with the earliest.
+fn crack_steps() -> Vec<Option<u32>> {
+    let size = CRACK_TEXTURE_SIZE as i32;
+    let mut steps = vec![None; (size * size) as usize];
+    let mut rng = ChunkRng::new(IVec3::ZERO, CRACK_SEED, 0);
+    for arm in 0..CRACK_ARMS {
+        let mut angle = (arm as f32 + rng.below(60) as f32 / 100.0) * TAU / CRACK_ARMS as f32;
+        let mut pos = Vec2::splat(size as f32 * 0.5);
+        for step in 0..CRACK_ARM_LENGTH {
+            let texel = pos.floor().as_ivec2();
+            if texel.cmpge(IVec2::ZERO).all() && texel.cmplt(IVec2::splat(size)).all() {
+                let cell = &mut steps[(texel.y * size + texel.x) as usize];
+                *cell = Some(cell.map_or(step, |earlier: u32| earlier.min(step)));
+            }
+            angle += (rng.below(61) - 30) as f32 * TAU / 360.0;
+            pos += Vec2::from_angle(angle);
+        }
+    }
+    steps
+}
+
+/// Build the texture of every crack stage; each stage extends the cracks of the one before.
+fn crack_stage_textures() -> Vec<Image> {
+    let steps = crack_steps();
+    (0..CRACK_STAGES)
+        .map(|stage| {
+            // The first stage already shows short cracks, the last one the full arms.
+            let reach = (stage as u32 + 2) * CRACK_ARM_LENGTH / (CRACK_STAGES as u32 + 1);
+            let data = steps
+                .iter()
+                .flat_map(|step| match step {
+                    Some(step) if *step < reach => CRACK_COLOR,
+                    _ => [0; 4],
+                })
+                .collect();
+            Image::new(
+                Extent3d {
+                    width: CRACK_TEXTURE_SIZE,
+                    height: CRACK_TEXTURE_SIZE,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                data,
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            )
+        })
+        .collect()
+}
+
+/// Show the crack stage of the block being broken over it.
+///
+/// The overlay is spawned when progress starts, advances a stage as `BreakProgress` grows,
+/// and is despawned once the block breaks, the button is released, or the target changes.
+/// It is a plain mesh entity, so the voxel raymarch of interactions never sees it.
+pub fn crack_overlay_system(
+    mut commands: Commands,
+    progress: Res<BreakProgress>,
+    assets: Option<Res<CrackOverlayAssets>>,
+    mut overlay_query: Query<(
+        Entity,
+        &CrackOverlay,
+        &mut bevy::pbr::MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    let held = progress.target.zip(progress.fraction());
+    let mut shown = false;
+    for (entity, overlay, mut material) in &mut overlay_query {
+        match held {
+            Some((target, fraction)) if target == overlay.target && !shown => {
+                let stage = assets.stage_material(fraction);
+                if material.0 != *stage {
+                    material.0 = stage.clone();
+                }
+                shown = true;
+            }
+            _ => commands.entity(entity).despawn(),
+        }
+    }
+    if let Some((target, fraction)) = held.filter(|_| !shown) {
+        commands.spawn((
+            bevy::mesh::Mesh3d(assets.mesh.clone()),
+            bevy::pbr::MeshMaterial3d(assets.stage_material(fraction).clone()),
+            Transform::from_translation(
+                Block::world_translation(target) + Vec3::splat(0.5 * BLOCK_SIZE),
+            ),
+            bevy::light::NotShadowCaster,
+            CrackOverlay { target },
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// Return the cracked texels of a stage texture.
+    fn cracked(image: &Image) -> Vec<bool> {
+        image
+            .data
+            .as_ref()
+            .unwrap()
+            .chunks_exact(4)
+            .map(|texel| texel[3] != 0)
+            .collect()
+    }
+
+    /// Verify every stage keeps the cracks of the one before and adds more.
+    #[test]
+    fn crack_stages_grow() {
+        let stages: Vec<Vec<bool>> = crack_stage_textures().iter().map(cracked).collect();
+        assert_eq!(stages.len(), CRACK_STAGES);
+        assert!(stages[0].contains(&true));
+        for pair in stages.windows(2) {
+            assert!(
+                pair[0]
+                    .iter()
+                    .zip(&pair[1])
+                    .all(|(before, after)| !before || *after)
+            );
+            assert!(pair[0] != pair[1]);
+        }
+    }
+
+    /// Verify the overlay follows the held block's stage and goes away with its progress.
+    #[test]
+    fn overlay_tracks_break_progress() {
+        let mut app = App::new();
+        app.init_resource::<BreakProgress>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<Assets<Image>>()
+            .add_systems(Update, crack_overlay_system);
+        app.world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 mut meshes: ResMut<Assets<Mesh>>,
+                 mut materials: ResMut<Assets<StandardMaterial>>,
+                 mut images: ResMut<Assets<Image>>| {
+                    commands.insert_resource(CrackOverlayAssets::new(
+                        &mut meshes,
+                        &mut materials,
+                        &mut images,
+                    ));
+                },
+            )
+            .unwrap();
+        let overlays = |app: &mut App| {
+            app.world_mut()
+                .query::<(&CrackOverlay, &bevy::pbr::MeshMaterial3d<StandardMaterial>)>()
+                .iter(app.world())
+                .map(|(overlay, material)| (overlay.target, material.0.clone()))
+                .collect::<Vec<_>>()
+        };
+        let stage = |app: &App, stage: usize| {
+            app.world().resource::<CrackOverlayAssets>().stages[stage].clone()
+        };
+
+        let first = IVec3::new(1, 2, 3);
+        let mut progress = app.world_mut().resource_mut::<BreakProgress>();
+        assert!(!progress.advance(first, 1.0, 0.1));
+        app.update();
+        assert_eq!(overlays(&mut app), vec![(first, stage(&app, 0))]);
+
+        let mut progress = app.world_mut().resource_mut::<BreakProgress>();
+        assert!(!progress.advance(first, 1.0, 0.85));
+        app.update();
+        assert_eq!(overlays(&mut app), vec![(first, stage(&app, 4))]);
+
+        let second = first + IVec3::X;
+        let mut progress = app.world_mut().resource_mut::<BreakProgress>();
+        assert!(!progress.advance(second, 1.0, 0.3));
+        app.update();
+        assert_eq!(overlays(&mut app), vec![(second, stage(&app, 1))]);
+
+        app.world_mut().resource_mut::<BreakProgress>().reset();
+        app.update();
+        assert!(overlays(&mut app).is_empty());
+    }
+}
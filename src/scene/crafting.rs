@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy::window::{CursorOptions, PrimaryWindow};
+
+use crate::scene::locale::{Locale, LocaleChanged};
+use crate::scene::menu::{
+    CLOSE_KEYS, InventoryButton, SLOT_GAP, SLOT_SIZE, fill_inventory_row, inventory_row_node,
+    label, localized_label, set_slot_label, show_menu, slot_bundle, slot_color, spawn_menu_root,
+    stack_label,
+};
+use crate::voxel::{CRAFTING_GRID_SLOTS, CraftingMenu, Inventory, ItemKind};
+
+/// Marker for the full-screen crafting root, hidden while the screen is closed.
+#[derive(Component)]
+pub struct CraftingUiRoot;
+
+/// Button showing one crafting grid slot; clicking it returns the item to the inventory.
+#[derive(Component)]
+pub struct CraftingSlotButton(pub usize);
+
+/// Button showing the recipe output; clicking it crafts.
+#[derive(Component)]
+pub struct CraftingOutputButton;
+
+/// Row listing held items; rebuilt whenever the inventory changes.
+#[derive(Component)]
+pub struct CraftingInventoryRow;
+
+/// Build the hidden crafting screen: a 2×2 grid, an output slot, and the held items.
+pub(crate) fn spawn_crafting_ui(commands: &mut Commands) {
+    spawn_menu_root(commands, CraftingUiRoot, |panel| {
+        panel.spawn(localized_label("crafting.title"));
+        panel
+            .spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(2.0 * SLOT_GAP),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn(Node {
+                    display: Display::Grid,
+                    grid_template_columns: RepeatedGridTrack::px(2, SLOT_SIZE),
+                    row_gap: Val::Px(SLOT_GAP),
+                    column_gap: Val::Px(SLOT_GAP),
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for index in 0..CRAFTING_GRID_SLOTS {
+                        grid.spawn((slot_bundle(), CraftingSlotButton(index)))
+                            .with_child(label(""));
+                    }
+                });
+                row.spawn(label("->"));
+                row.spawn((slot_bundle(), CraftingOutputButton))
+                    .with_child(label(""));
+            });
+        panel.spawn((inventory_row_node(), CraftingInventoryRow));
+    });
+}
+
+/// Close the crafting screen on its close keys and apply clicks on its slots.
+#[allow(clippy::type_complexity)]
+pub fn crafting_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut menu: ResMut<CraftingMenu>,
+    mut inventory: ResMut<Inventory>,
+    mut buttons: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Option<&CraftingSlotButton>,
+            Option<&CraftingOutputButton>,
+            Option<&InventoryButton>,
+        ),
+        Changed<Interaction>,
+    >,
+) {
+    if !menu.is_open() {
+        return;
+    }
+    if keys.any_just_pressed(CLOSE_KEYS) {
+        menu.close(&mut inventory);
+        return;
+    }
+    for (interaction, mut background, slot, output, held) in &mut buttons {
+        background.0 = slot_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(CraftingSlotButton(index)) = slot {
+            menu.take_back(&mut inventory, *index);
+        } else if output.is_some() {
+            menu.craft(&mut inventory);
+        } else if let Some(InventoryButton(ItemKind::Block(item))) = held {
+            menu.put(&mut inventory, *item);
+        }
+    }
+}
+
+/// Mirror the crafting state into its screen and hand the cursor over while it is open.
+///
+/// Opening releases the cursor grab from `setup_cursor` so slots can be clicked; closing
+/// locks it again for mouse look. Slot labels are redrawn in the new language when it
+/// changes.
+#[allow(clippy::too_many_arguments)]
+pub fn crafting_ui_system(
+    mut commands: Commands,
+    menu: Res<CraftingMenu>,
+    inventory: Res<Inventory>,
+    locale: Res<Locale>,
+    mut locale_changed: MessageReader<LocaleChanged>,
+    mut windows: Query<&mut CursorOptions, With<PrimaryWindow>>,
+    mut root_query: Query<&mut Node, With<CraftingUiRoot>>,
+    slot_query: Query<(&Children, &CraftingSlotButton)>,
+    output_query: Query<&Children, With<CraftingOutputButton>>,
+    mut text_query: Query<&mut Text>,
+    row_query: Query<Entity, With<CraftingInventoryRow>>,
+) {
+    let relabel = locale_changed.read().count() > 0;
+    if !menu.is_changed() && !inventory.is_changed() && !relabel {
+        return;
+    }
+    if menu.is_changed()
+        && let Ok(mut root) = root_query.single_mut()
+    {
+        show_menu(&mut root, windows.single_mut().ok(), menu.is_open());
+    }
+    if !menu.is_open() {
+        return;
+    }
+    for (children, CraftingSlotButton(index)) in &slot_query {
+        let item = menu.grid[*index];
+        set_slot_label(
+            children,
+            &mut text_query,
+            item.map_or(String::new(), |item| locale.item_name(item)),
+        );
+    }
+    if let Ok(children) = output_query.single() {
+        set_slot_label(
+            children,
+            &mut text_query,
+            menu.output().map_or(String::new(), |(kind, count)| {
+                stack_label(&locale, kind, count)
+            }),
+        );
+    }
+    if let Ok(row) = row_query.single() {
+        fill_inventory_row(&mut commands, row, &inventory, &locale);
+    }
+}
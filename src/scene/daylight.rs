@@ -0,0 +1,302 @@
+use bevy::light::{DirectionalLight, GlobalAmbientLight};
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_1_SQRT_2, TAU};
+
+use crate::scene::SunBillboard;
+use crate::voxel::{NIGHT_SKY_DARKENING, WorldState};
+
+/// Real seconds one full day lasts by default.
+const DEFAULT_DAY_LENGTH_SECS: f32 = 1200.0;
+/// Time of day a session starts at by default: mid-morning.
+const DEFAULT_START_TIME: f32 = 0.33;
+/// Horizontal direction the sun rises towards; it sets on the opposite side.
+const SUNRISE_DIRECTION: Vec3 = Vec3::new(FRAC_1_SQRT_2, 0.0, FRAC_1_SQRT_2);
+/// Directional-light illuminance of the sun at full daylight.
+pub(crate) const SUN_ILLUMINANCE: f32 = 14_000.0;
+/// Directional-light color of the sun high in the sky.
+pub(crate) const SUN_COLOR: Color = Color::srgb(1.0, 0.97, 0.90);
+/// Directional-light color of the sun at the horizon.
+const SUNSET_SUN_COLOR: Color = Color::srgb(1.0, 0.62, 0.38);
+/// Horizon color of the daytime sky, also the clear color behind the sky dome.
+pub(crate) const DAY_SKY_COLOR: Color = Color::srgb(0.52, 0.74, 0.88);
+/// Horizon color of the sky glow around sunrise and sunset.
+const SUNSET_SKY_COLOR: Color = Color::srgb(0.93, 0.55, 0.36);
+/// Horizon color of the night sky.
+const NIGHT_SKY_COLOR: Color = Color::srgb(0.02, 0.03, 0.07);
+/// Zenith color of the daytime sky.
+const DAY_ZENITH_COLOR: Color = Color::srgb(0.26, 0.48, 0.82);
+/// Zenith color of the night sky.
+const NIGHT_ZENITH_COLOR: Color = Color::srgb(0.005, 0.008, 0.03);
+/// Color the sky brightens with around the sun during the day.
+const DAY_SUN_GLOW_COLOR: Color = Color::srgb(0.45, 0.42, 0.35);
+/// Color the sky brightens with around the sun at sunrise and sunset.
+const SUNSET_SUN_GLOW_COLOR: Color = Color::srgb(0.7, 0.35, 0.15);
+/// Ambient-light color during the day.
+pub(crate) const DAY_AMBIENT_COLOR: Color = Color::srgb(0.72, 0.78, 0.90);
+/// Ambient-light color at night, a cool moonlit blue.
+const NIGHT_AMBIENT_COLOR: Color = Color::srgb(0.45, 0.55, 0.90);
+/// Ambient-light brightness during the day.
+pub(crate) const DAY_AMBIENT_BRIGHTNESS: f32 = 3_600.0;
+/// Ambient-light brightness at night: dark, but blocks stay readable.
+const NIGHT_AMBIENT_BRIGHTNESS: f32 = 350.0;
+/// Sun height (sine of its elevation) below which the sky is fully night.
+const NIGHT_SUN_HEIGHT: f32 = -0.1;
+/// Sun height above which the sky is fully day.
+const DAY_SUN_HEIGHT: f32 = 0.25;
+/// Sun height band around the horizon that glows in sunset colors.
+const SUNSET_BAND: f32 = 0.3;
+/// Share of sunset color mixed into the sky at the horizon.
+const SUNSET_SKY_MIX: f32 = 0.6;
+/// Sun height below which the sun billboard is hidden.
+const BILLBOARD_HIDE_HEIGHT: f32 = -0.15;
+/// Radians the sun may move past the direction baked into cheap-shadow shading before every
+/// chunk is remeshed with the new direction.
+const SUN_SHADING_REBAKE_ANGLE: f32 = 0.1;
+
+/// Game clock of the day/night cycle.
+///
+/// `fraction` runs from 0 at midnight through 0.25 at sunrise, 0.5 at noon, and 0.75 at
+/// sunset. Debug tooling can freeze the clock with `paused` and jump with `set`.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct TimeOfDay {
+    /// Elapsed share of the current day, in `0..1`.
+    pub fraction: f32,
+    /// Real seconds one full day lasts.
+    pub day_length_secs: f32,
+    /// Stop the clock, keeping the sun where it is.
+    pub paused: bool,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self {
+            fraction: DEFAULT_START_TIME,
+            day_length_secs: DEFAULT_DAY_LENGTH_SECS,
+            paused: false,
+        }
+    }
+}
+
+impl TimeOfDay {
+    /// Read `--day-length <secs>`, `--time-of-day <fraction>`, and `--freeze-time` from the
+    /// command line.
+    pub fn from_args() -> Self {
+        let mut clock = Self::default();
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day-length" => match args.next().map(|value| value.parse::<f32>()) {
+                    Some(Ok(secs)) if secs > 0.0 => clock.day_length_secs = secs,
+                    _ => warn!(
+                        "Ignoring invalid --day-length value, using {DEFAULT_DAY_LENGTH_SECS}s"
+                    ),
+                },
+                "--time-of-day" => match args.next().map(|value| value.parse::<f32>()) {
+                    Some(Ok(fraction)) if fraction.is_finite() => clock.set(fraction),
+                    _ => warn!("Ignoring invalid --time-of-day value"),
+                },
+                "--freeze-time" => clock.paused = true,
+                _ => {}
+            }
+        }
+        clock
+    }
+
+    /// Jump to `fraction` of the day, wrapping values outside `0..1`.
+    pub fn set(&mut self, fraction: f32) {
+        self.fraction = fraction.rem_euclid(1.0);
+    }
+
+    /// Move the clock `delta_secs` real seconds forward unless it is paused.
+    pub fn advance(&mut self, delta_secs: f32) {
+        if !self.paused {
+            self.set(self.fraction + delta_secs / self.day_length_secs);
+        }
+    }
+
+    /// Return the unit direction from the world towards the sun.
+    ///
+    /// The sun rises along `SUNRISE_DIRECTION`, passes overhead at noon, and is straight
+    /// below the world at midnight.
+    pub fn sun_direction(&self) -> Vec3 {
+        let angle = (self.fraction - 0.25) * TAU;
+        SUNRISE_DIRECTION * angle.cos() + Vec3::Y * angle.sin()
+    }
+}
+
+/// Lighting and sky colors for one sun position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Daylight {
+    /// Sun directional-light illuminance.
+    sun_illuminance: f32,
+    /// Sun directional-light color.
+    sun_color: Color,
+    /// Global ambient-light color.
+    ambient_color: Color,
+    /// Global ambient-light brightness.
+    ambient_brightness: f32,
+    /// Sky color at the horizon, also used as the clear color.
+    pub(crate) sky_color: Color,
+    /// Sky color straight up.
+    pub(crate) zenith_color: Color,
+    /// Color added to the sky towards the sun; black while the sun is down.
+    pub(crate) sun_glow: Color,
+    /// Sky light levels gameplay light loses to the time of day.
+    pub(crate) sky_darkening: u8,
+}
+
+impl Daylight {
+    /// Return the lighting for a sun at `sun_height`, the sine of its elevation.
+    ///
+    /// Light and sky fade from day to night while the sun crosses the horizon band, and the
+    /// sky and sunlight turn warm inside it.
+    pub(crate) fn at(sun_height: f32) -> Self {
+        let day = smoothstep((sun_height - NIGHT_SUN_HEIGHT) / (DAY_SUN_HEIGHT - NIGHT_SUN_HEIGHT));
+        let sun = smoothstep(sun_height / DAY_SUN_HEIGHT);
+        let sunset = (1.0 - sun_height.abs() / SUNSET_BAND).clamp(0.0, 1.0);
+        let sky = mix(NIGHT_SKY_COLOR, DAY_SKY_COLOR, day);
+        let glow = mix(DAY_SUN_GLOW_COLOR, SUNSET_SUN_GLOW_COLOR, sunset);
+        let glow_strength = smoothstep((sun_height - BILLBOARD_HIDE_HEIGHT) / SUNSET_BAND);
+        Self {
+            sun_illuminance: SUN_ILLUMINANCE * sun,
+            sun_color: mix(SUN_COLOR, SUNSET_SUN_COLOR, sunset),
+            ambient_color: mix(NIGHT_AMBIENT_COLOR, DAY_AMBIENT_COLOR, day),
+            ambient_brightness: NIGHT_AMBIENT_BRIGHTNESS
+                + (DAY_AMBIENT_BRIGHTNESS - NIGHT_AMBIENT_BRIGHTNESS) * day,
+            sky_color: mix(sky, SUNSET_SKY_COLOR, sunset * SUNSET_SKY_MIX),
+            zenith_color: mix(NIGHT_ZENITH_COLOR, DAY_ZENITH_COLOR, day),
+            sun_glow: mix(Color::BLACK, glow, glow_strength),
+            sky_darkening: ((1.0 - day) * f32::from(NIGHT_SKY_DARKENING)).round() as u8,
+        }
+    }
+}
+
+/// Return the smooth Hermite ramp of `t` clamped to `0..1`.
+fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Blend colors `from` and `to` in linear space.
+fn mix(from: Color, to: Color, t: f32) -> Color {
+    LinearRgba::from(from).mix(&to.into(), t).into()
+}
+
+/// Return the sun directional-light transform shining from `to_sun` towards the world.
+pub(crate) fn sun_light_transform(to_sun: Vec3) -> Transform {
+    // The orbit axis is perpendicular to every sun direction, so it never degenerates as up.
+    Transform::default().looking_to(-to_sun, SUNRISE_DIRECTION.cross(Vec3::Y))
+}
+
+/// Return whether shading baked for the sun at `baked` is stale with the sun now at `to_sun`.
+fn sun_shading_is_stale(baked: Vec3, to_sun: Vec3) -> bool {
+    baked.angle_between(to_sun) > SUN_SHADING_REBAKE_ANGLE
+}
+
+/// Advance the day/night cycle and move the sun and its billboard with it.
+///
+/// The sky dome and clear color follow the clock in `sky_dome_system`. Gameplay sky light
+/// dims with the sun, and cheap-shadow sun shading baked into chunk meshes is re-baked once
+/// the sun has moved far enough.
+pub fn day_night_system(
+    time: Res<Time>,
+    mut clock: ResMut<TimeOfDay>,
+    mut ambient: ResMut<GlobalAmbientLight>,
+    mut suns: Query<(&mut DirectionalLight, &mut Transform)>,
+    mut billboards: Query<(&mut SunBillboard, &mut Visibility)>,
+    world: Option<ResMut<WorldState>>,
+) {
+    clock.advance(time.delta_secs());
+    let to_sun = clock.sun_direction();
+    let daylight = Daylight::at(to_sun.y);
+    for (mut light, mut transform) in &mut suns {
+        *transform = sun_light_transform(to_sun);
+        light.illuminance = daylight.sun_illuminance;
+        light.color = daylight.sun_color;
+    }
+    for (mut billboard, mut visibility) in &mut billboards {
+        billboard.direction = to_sun;
+        visibility.set_if_neq(if to_sun.y < BILLBOARD_HIDE_HEIGHT {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+    }
+    ambient.color = daylight.ambient_color;
+    ambient.brightness = daylight.ambient_brightness;
+    let Some(mut world) = world else {
+        return;
+    };
+    if world.sky_darkening != daylight.sky_darkening {
+        world.sky_darkening = daylight.sky_darkening;
+    }
+    if world
+        .sun_shading
+        .is_some_and(|baked| sun_shading_is_stale(baked, to_sun))
+    {
+        world.set_sun_shading(Some(to_sun));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify the clock wraps, pauses, and puts the sun overhead at noon and below at midnight.
+    #[test]
+    fn clock_wraps_pauses_and_orbits_the_sun() {
+        let mut clock = TimeOfDay {
+            day_length_secs: 100.0,
+            ..default()
+        };
+        clock.set(0.9);
+        clock.advance(20.0);
+        assert!((clock.fraction - 0.1).abs() < 1e-5);
+        clock.paused = true;
+        clock.advance(20.0);
+        assert!((clock.fraction - 0.1).abs() < 1e-5);
+
+        clock.set(0.5);
+        assert!(clock.sun_direction().abs_diff_eq(Vec3::Y, 1e-5));
+        clock.set(0.0);
+        assert!(clock.sun_direction().abs_diff_eq(Vec3::NEG_Y, 1e-5));
+        clock.set(0.25);
+        assert!(clock.sun_direction().abs_diff_eq(SUNRISE_DIRECTION, 1e-5));
+        let light = sun_light_transform(clock.sun_direction());
+        assert!(light.forward().abs_diff_eq(-SUNRISE_DIRECTION, 1e-5));
+    }
+
+    /// Verify baked sun shading only goes stale once the sun has moved past the threshold.
+    #[test]
+    fn sun_shading_rebakes_past_threshold() {
+        let mut clock = TimeOfDay::default();
+        let baked = clock.sun_direction();
+        let nudge = SUN_SHADING_REBAKE_ANGLE / TAU;
+        clock.set(clock.fraction + nudge * 0.5);
+        assert!(!sun_shading_is_stale(baked, clock.sun_direction()));
+        clock.set(clock.fraction + nudge);
+        assert!(sun_shading_is_stale(baked, clock.sun_direction()));
+    }
+
+    /// Verify noon uses the day palette and midnight is dark but not black.
+    #[test]
+    fn midnight_is_dark_but_not_black() {
+        let noon = Daylight::at(1.0);
+        assert_eq!(noon.sun_illuminance, SUN_ILLUMINANCE);
+        assert_eq!(noon.ambient_brightness, DAY_AMBIENT_BRIGHTNESS);
+        assert_eq!(noon.sky_color, mix(DAY_SKY_COLOR, SUNSET_SKY_COLOR, 0.0));
+
+        let midnight = Daylight::at(-1.0);
+        assert_eq!(midnight.sun_illuminance, 0.0);
+        assert!(midnight.ambient_brightness > 0.0);
+        assert!(midnight.ambient_brightness < noon.ambient_brightness / 5.0);
+        assert_ne!(LinearRgba::from(midnight.sky_color), LinearRgba::BLACK);
+        assert_eq!(LinearRgba::from(midnight.sun_glow), LinearRgba::BLACK);
+        assert_eq!(noon.sky_darkening, 0);
+        assert_eq!(midnight.sky_darkening, NIGHT_SKY_DARKENING);
+
+        let dusk = Daylight::at(0.0);
+        assert!(dusk.sun_illuminance < noon.sun_illuminance);
+        assert!(dusk.ambient_brightness > midnight.ambient_brightness);
+    }
+}
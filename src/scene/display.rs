@@ -0,0 +1,434 @@
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, WindowMode};
+use std::time::{Duration, Instant};
+
+use crate::scene::settings_file::SettingsFile;
+use crate::scene::shadows::{ShadowQuality, ShadowSettings};
+use crate::voxel::{BlockMaterial, WorldMaterials, WorldState, set_block_normal_map};
+use crate::{BLOCK_SIZE, CHUNK_SIZE, VIEW_DISTANCE};
+
+/// Share of the fog end distance at which terrain starts to fade into the sky.
+const FOG_START_SHARE: f32 = 0.6;
+/// Rate the fog distance eases toward the streamed radius, in share of the gap per second.
+const FOG_EASE_RATE: f32 = 2.0;
+
+/// Presentation settings: window mode, vsync, the no-vsync frame cap, normal mapping,
+/// the shadow presets, view distance, and torch light flicker.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplaySettings {
+    /// Borderless fullscreen on the current monitor instead of a window.
+    pub fullscreen: bool,
+    /// Present with `AutoVsync` instead of `AutoNoVsync`.
+    pub vsync: bool,
+    /// Frame-rate cap applied only while vsync is off.
+    pub fps_cap: Option<u32>,
+    /// Sample the block normal atlas in the world material when it is available.
+    pub normal_maps: bool,
+    /// Low-end shadow preset: sun shadow maps off, sun-facing face darkening baked into meshes.
+    pub cheap_shadows: bool,
+    /// Sun shadow map quality preset; cheap shadows override it with no shadow maps at all.
+    pub shadow_quality: ShadowQuality,
+    /// Horizontal chunk streaming radius.
+    pub view_distance: i32,
+    /// Let torch and glowstone lights near the camera flicker instead of burning steadily.
+    pub light_flicker: bool,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            fullscreen: false,
+            vsync: true,
+            fps_cap: None,
+            normal_maps: true,
+            cheap_shadows: false,
+            shadow_quality: ShadowQuality::default(),
+            view_distance: VIEW_DISTANCE,
+            light_flicker: true,
+        }
+    }
+}
+
+impl DisplaySettings {
+    /// Hotkey that toggles borderless fullscreen.
+    pub(crate) const FULLSCREEN_KEY: KeyCode = KeyCode::F11;
+    /// Hotkey that toggles vsync.
+    pub(crate) const VSYNC_KEY: KeyCode = KeyCode::F10;
+    /// Hotkey that toggles normal mapping.
+    pub(crate) const NORMAL_MAPS_KEY: KeyCode = KeyCode::F9;
+    /// Hotkey that toggles the cheap-shadow preset.
+    pub(crate) const CHEAP_SHADOWS_KEY: KeyCode = KeyCode::F8;
+    /// Hotkey that cycles the shadow quality preset.
+    pub(crate) const SHADOW_QUALITY_KEY: KeyCode = KeyCode::F12;
+    /// Hotkey that increases view distance by one chunk.
+    pub(crate) const VIEW_DISTANCE_UP_KEY: KeyCode = KeyCode::PageUp;
+    /// Hotkey that decreases view distance by one chunk.
+    pub(crate) const VIEW_DISTANCE_DOWN_KEY: KeyCode = KeyCode::PageDown;
+    /// Smallest selectable view distance in chunks.
+    const MIN_VIEW_DISTANCE: i32 = 2;
+    /// Largest selectable view distance in chunks.
+    const MAX_VIEW_DISTANCE: i32 = 32;
+    /// Window mode requested by these settings.
+    fn window_mode(self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        }
+    }
+
+    /// Present mode requested by these settings.
+    fn present_mode(self) -> PresentMode {
+        if self.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+
+    /// Target frame duration when the cap is active.
+    fn frame_budget(self) -> Option<Duration> {
+        if self.vsync {
+            return None;
+        }
+        self.fps_cap
+            .map(|fps| Duration::from_secs_f64(1.0 / f64::from(fps)))
+    }
+}
+
+impl SettingsFile for DisplaySettings {
+    const PATH: &'static str = "settings/display.cfg";
+    const NAME: &'static str = "display settings";
+
+    fn apply_entry(&mut self, key: &str, value: &str) {
+        match key {
+            "fullscreen" => self.fullscreen = value.parse().unwrap_or(self.fullscreen),
+            "vsync" => self.vsync = value.parse().unwrap_or(self.vsync),
+            "fps_cap" => self.fps_cap = value.parse().ok().filter(|fps| *fps > 0),
+            "normal_maps" => self.normal_maps = value.parse().unwrap_or(self.normal_maps),
+            "cheap_shadows" => self.cheap_shadows = value.parse().unwrap_or(self.cheap_shadows),
+            "shadow_quality" => {
+                self.shadow_quality =
+                    ShadowQuality::from_name(value).unwrap_or(self.shadow_quality);
+            }
+            "view_distance" => {
+                self.view_distance = value
+                    .parse()
+                    .ok()
+                    .filter(|distance| {
+                        (Self::MIN_VIEW_DISTANCE..=Self::MAX_VIEW_DISTANCE).contains(distance)
+                    })
+                    .unwrap_or(self.view_distance);
+            }
+            "light_flicker" => self.light_flicker = value.parse().unwrap_or(self.light_flicker),
+            _ => {}
+        }
+    }
+
+    /// `fps_cap = 0` means uncapped.
+    fn entries(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("fullscreen", self.fullscreen.to_string()),
+            ("vsync", self.vsync.to_string()),
+            ("fps_cap", self.fps_cap.unwrap_or(0).to_string()),
+            ("normal_maps", self.normal_maps.to_string()),
+            ("cheap_shadows", self.cheap_shadows.to_string()),
+            ("shadow_quality", self.shadow_quality.name().to_string()),
+            ("view_distance", self.view_distance.to_string()),
+            ("light_flicker", self.light_flicker.to_string()),
+        ]
+    }
+}
+
+/// Normal textures for world blocks, `None` when they are not shipped.
+#[derive(Resource, Default)]
+pub struct BlockNormalMap(pub Option<Handle<Image>>);
+
+/// Apply fullscreen/vsync/normal-map/shadow-preset/view-distance hotkeys and persist the result.
+pub fn display_settings_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<DisplaySettings>,
+) {
+    let mut next = *settings;
+    if keys.just_pressed(DisplaySettings::FULLSCREEN_KEY) {
+        next.fullscreen = !next.fullscreen;
+    }
+    if keys.just_pressed(DisplaySettings::VSYNC_KEY) {
+        next.vsync = !next.vsync;
+    }
+    if keys.just_pressed(DisplaySettings::NORMAL_MAPS_KEY) {
+        next.normal_maps = !next.normal_maps;
+    }
+    if keys.just_pressed(DisplaySettings::CHEAP_SHADOWS_KEY) {
+        next.cheap_shadows = !next.cheap_shadows;
+    }
+    if keys.just_pressed(DisplaySettings::SHADOW_QUALITY_KEY) {
+        next.shadow_quality = next.shadow_quality.next();
+    }
+    if keys.just_pressed(DisplaySettings::VIEW_DISTANCE_UP_KEY) {
+        next.view_distance = (next.view_distance + 1).min(DisplaySettings::MAX_VIEW_DISTANCE);
+    }
+    if keys.just_pressed(DisplaySettings::VIEW_DISTANCE_DOWN_KEY) {
+        next.view_distance = (next.view_distance - 1).max(DisplaySettings::MIN_VIEW_DISTANCE);
+    }
+    if next != *settings {
+        *settings = next;
+        next.save();
+    }
+}
+
+/// Push changed settings to the primary window and report modes the platform replaced.
+///
+/// Winit/bevy write back the mode actually in effect, so a mismatch one frame after
+/// applying is reported as a fallback.
+pub fn apply_display_settings_system(
+    settings: Res<DisplaySettings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut awaiting_check: Local<bool>,
+) {
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    if settings.is_changed() {
+        window.mode = settings.window_mode();
+        window.present_mode = settings.present_mode();
+        *awaiting_check = true;
+        return;
+    }
+    if !std::mem::take(&mut *awaiting_check) {
+        return;
+    }
+    if window.mode != settings.window_mode() {
+        warn!(
+            "Window mode {:?} unsupported, platform selected {:?}",
+            settings.window_mode(),
+            window.mode
+        );
+    }
+    if window.present_mode != settings.present_mode() {
+        warn!(
+            "Present mode {:?} unsupported, platform selected {:?}",
+            settings.present_mode(),
+            window.present_mode
+        );
+    }
+}
+
+/// Attach or detach the block normal atlas on the shared opaque world material.
+pub fn apply_normal_map_setting_system(
+    settings: Res<DisplaySettings>,
+    normal_map: Res<BlockNormalMap>,
+    world_materials: Option<Res<WorldMaterials>>,
+    mut materials: ResMut<Assets<BlockMaterial>>,
+) {
+    let Some(world_materials) = world_materials else {
+        return;
+    };
+    if !settings.is_changed() && !world_materials.is_added() {
+        return;
+    }
+    let Some(material) = materials.get_mut(&world_materials.opaque) else {
+        return;
+    };
+    set_block_normal_map(
+        material,
+        normal_map.0.clone().filter(|_| settings.normal_maps),
+    );
+}
+
+/// Switch between sun shadow maps and baked sun-facing face darkening, and apply the
+/// shadow quality preset to the sun's cascades.
+///
+/// Cheap shadows drop cast shadows entirely: faces are only darkened by how far they turn
+/// away from the sun, so overhangs and caves stay lit. Loaded chunks are remeshed over
+/// the following frames by the streaming system. With shadows off either way, the sun
+/// has no shadow views, so no shadow passes are rendered.
+pub fn apply_shadow_preset_system(
+    settings: Res<DisplaySettings>,
+    world: Option<ResMut<WorldState>>,
+    mut shadows: ResMut<ShadowSettings>,
+    mut shadow_map: ResMut<bevy::light::DirectionalLightShadowMap>,
+    mut suns: Query<(
+        &mut bevy::light::DirectionalLight,
+        &mut bevy::light::CascadeShadowConfig,
+        &Transform,
+    )>,
+) {
+    let Some(mut world) = world else {
+        return;
+    };
+    if !settings.is_changed() && !world.is_added() {
+        return;
+    }
+    let preset = ShadowSettings::for_quality(settings.shadow_quality);
+    if *shadows != preset {
+        info!("Shadow quality: {}", settings.shadow_quality.name());
+        *shadows = preset;
+    }
+    if shadow_map.size != preset.map_size {
+        *shadow_map = preset.shadow_map();
+    }
+    let mut to_sun = None;
+    for (mut light, mut cascades, transform) in &mut suns {
+        light.shadows_enabled = preset.enabled && !settings.cheap_shadows;
+        *cascades = preset.cascade_config();
+        to_sun = Some(transform.back().as_vec3());
+    }
+    // Only bake on a toggle; `day_night_system` re-bakes as the sun moves.
+    if world.sun_shading.is_some() != settings.cheap_shadows {
+        info!(
+            "Shadow preset: {}",
+            if settings.cheap_shadows {
+                "cheap (no cast shadows, baked sun-facing shading)"
+            } else {
+                "shadow maps"
+            }
+        );
+        world.set_sun_shading(to_sun.filter(|_| settings.cheap_shadows));
+    }
+}
+
+/// Hand the configured view distance to chunk streaming, which converges on it gradually.
+pub fn apply_view_distance_system(
+    settings: Res<DisplaySettings>,
+    world: Option<ResMut<WorldState>>,
+) {
+    let Some(mut world) = world else {
+        return;
+    };
+    if settings.is_changed() || world.is_added() {
+        world.view_distance = settings.view_distance;
+    }
+}
+
+/// Return the distance at which fog fully hides terrain streamed `radius` chunks out.
+///
+/// The camera may stand anywhere in its center chunk, so only `radius - 1` chunks are sure
+/// to be loaded in every direction.
+pub(crate) fn fog_end_for_radius(radius: i32) -> f32 {
+    ((radius - 1).max(1) * CHUNK_SIZE) as f32 * BLOCK_SIZE
+}
+
+/// Return linear distance fog in `color` ending at `end`.
+pub(crate) fn view_distance_fog(end: f32, color: Color) -> DistanceFog {
+    DistanceFog {
+        color,
+        falloff: FogFalloff::Linear {
+            start: end * FOG_START_SHARE,
+            end,
+        },
+        ..default()
+    }
+}
+
+/// Ease the fog toward the edge of the effective view distance and tint it with the sky.
+///
+/// The fog follows the radius actually streamed rather than the setting, so growing rings
+/// fade in from the fog instead of popping in past it.
+pub fn view_distance_fog_system(
+    time: Res<Time>,
+    world: Option<Res<WorldState>>,
+    clear_color: Res<ClearColor>,
+    mut fogs: Query<&mut DistanceFog>,
+) {
+    let Some(world) = world else {
+        return;
+    };
+    let target = fog_end_for_radius(world.effective_view_distance);
+    let ease = (FOG_EASE_RATE * time.delta_secs()).min(1.0);
+    for mut fog in &mut fogs {
+        let end = match fog.falloff {
+            FogFalloff::Linear { end, .. } => end + (target - end) * ease,
+            _ => target,
+        };
+        *fog = view_distance_fog(end, clear_color.0);
+    }
+}
+
+/// Run condition: pooled torch and glowstone lights may flicker.
+pub fn light_flicker_enabled(settings: Res<DisplaySettings>) -> bool {
+    settings.light_flicker
+}
+
+/// Pace frames to the configured cap by sleeping at the end of the frame.
+pub fn frame_limiter_system(settings: Res<DisplaySettings>, mut last: Local<Option<Instant>>) {
+    let Some(budget) = settings.frame_budget() else {
+        *last = None;
+        return;
+    };
+    if let Some(previous) = *last {
+        let elapsed = previous.elapsed();
+        if elapsed < budget {
+            std::thread::sleep(budget - elapsed);
+        }
+    }
+    *last = Some(Instant::now());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify settings round-trip through the file format and corrupt values fall back.
+    #[test]
+    fn display_settings_round_trip_and_fallback() {
+        let settings = DisplaySettings {
+            fullscreen: true,
+            vsync: false,
+            fps_cap: Some(90),
+            normal_maps: false,
+            cheap_shadows: true,
+            shadow_quality: ShadowQuality::High,
+            view_distance: 6,
+            light_flicker: false,
+        };
+        assert_eq!(DisplaySettings::parse(&settings.to_text()), settings);
+        assert_eq!(
+            settings.frame_budget(),
+            Some(Duration::from_secs_f64(1.0 / 90.0))
+        );
+
+        let corrupt = DisplaySettings::parse(
+            "fullscreen = maybe\nvsync\nfps_cap = -5\nshadow_quality = ultra\n\
+                 view_distance = 0\nlight_flicker = sometimes\n",
+        );
+        assert_eq!(corrupt, DisplaySettings::default());
+        assert_eq!(corrupt.frame_budget(), None);
+    }
+
+    /// Verify the fog eases toward the effective view distance, not the setting, in the sky
+    /// color.
+    #[test]
+    fn fog_follows_effective_view_distance() {
+        let mut world = WorldState::new();
+        world.view_distance = 8;
+        world.effective_view_distance = 4;
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(ClearColor(Color::WHITE))
+            .insert_resource(world)
+            .add_systems(Update, view_distance_fog_system);
+        let camera = app
+            .world_mut()
+            .spawn(view_distance_fog(fog_end_for_radius(2), Color::BLACK))
+            .id();
+        let fog_end = |app: &App| match app.world().get::<DistanceFog>(camera).unwrap().falloff {
+            FogFalloff::Linear { end, .. } => end,
+            _ => panic!("fog is not linear"),
+        };
+
+        let mut previous = fog_end(&app);
+        for _ in 0..20 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.1));
+            app.update();
+            let end = fog_end(&app);
+            assert!(end > previous && end <= fog_end_for_radius(4));
+            previous = end;
+        }
+        assert!((previous - fog_end_for_radius(4)).abs() < 1.0);
+        let fog = app.world().get::<DistanceFog>(camera).unwrap();
+        assert_eq!(fog.color, Color::WHITE);
+    }
+}
@@ -0,0 +1,306 @@
+use bevy::audio::{AudioPlayer, AudioSource, PlaybackSettings};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::BLOCK_SIZE;
+use crate::player::FlyCamera;
+use crate::voxel::Explosion;
+
+/// Seconds a camera shake takes to decay to nothing.
+const SHAKE_DURATION_SECS: f32 = 0.4;
+/// Largest shake angle in radians, reached close to the blast.
+const SHAKE_MAX_ANGLE: f32 = 0.05;
+/// Distance, in blast radii, beyond which an explosion no longer shakes the camera.
+const SHAKE_REACH_FACTOR: f32 = 6.0;
+/// Oscillations per second of the shake.
+const SHAKE_FREQUENCY_HZ: f32 = 17.0;
+/// Rumble played at each blast.
+const RUMBLE_SOUND_PATH: &str = "sounds/explosion_rumble.wav";
+/// Dust puffs raised by one explosion.
+const DUST_PUFFS: usize = 24;
+/// Seconds a dust puff lives before it has thinned out.
+const DUST_LIFETIME_SECS: f32 = 2.0;
+/// Launch speed of dust puffs, in world units per second.
+const DUST_SPEED: f32 = 3.0 * BLOCK_SIZE;
+/// Fraction of dust velocity lost per second to air drag.
+const DUST_DRAG: f32 = 1.6;
+/// Steady upward drift of dust puffs, in world units per second.
+const DUST_RISE: f32 = 0.5 * BLOCK_SIZE;
+/// Diameter of a fresh dust puff, in world units.
+const DUST_SIZE: f32 = 0.8 * BLOCK_SIZE;
+/// Color of the dust cloud.
+const DUST_COLOR: Color = Color::srgba(0.46, 0.43, 0.39, 0.55);
+
+/// Return the shake angle for a camera `distance` blocks from a blast of `radius` blocks.
+///
+/// Falls off linearly to zero at `SHAKE_REACH_FACTOR` radii and is capped at
+/// `SHAKE_MAX_ANGLE` over the inner half of that reach.
+pub(crate) fn shake_amplitude(distance: f32, radius: f32) -> f32 {
+    let reach = radius * SHAKE_REACH_FACTOR;
+    if reach <= 0.0 || distance >= reach {
+        return 0.0;
+    }
+    (SHAKE_MAX_ANGLE * 2.0 * (1.0 - distance / reach)).min(SHAKE_MAX_ANGLE)
+}
+
+/// Cosmetic camera shake layered over the camera rotation.
+///
+/// The shake is applied after gameplay has run and removed again before the next frame's
+/// gameplay, so aiming, targeting, and `FlyCamera` look angles never see it.
+#[derive(Resource, Default)]
+pub struct CameraShake {
+    /// Elapsed seconds when the current shake began.
+    started_at: f32,
+    /// Peak shake angle of the current shake, in radians.
+    amplitude: f32,
+    /// Oscillation phases of the yaw and pitch wobble.
+    phases: Vec2,
+    /// Rotation offset currently applied to the camera transform.
+    applied: Quat,
+}
+
+impl CameraShake {
+    /// Start a shake of `amplitude` at `now`, unless a stronger one is still playing.
+    fn start(&mut self, now: f32, amplitude: f32, seed: Vec3) {
+        if amplitude <= self.amplitude_at(now) {
+            return;
+        }
+        let hash = |salt: f32| (seed.dot(Vec3::new(12.99, 78.23, 37.72)) + salt).sin() * 43_758.55;
+        self.started_at = now;
+        self.amplitude = amplitude;
+        self.phases = Vec2::new(hash(0.0).fract(), hash(1.0).fract()) * TAU;
+    }
+
+    /// Return the decayed shake angle at `now`.
+    fn amplitude_at(&self, now: f32) -> f32 {
+        let t = now - self.started_at;
+        if !(0.0..SHAKE_DURATION_SECS).contains(&t) {
+            return 0.0;
+        }
+        let remaining = 1.0 - t / SHAKE_DURATION_SECS;
+        self.amplitude * remaining * remaining
+    }
+
+    /// Return the yaw/pitch rotation offset of the shake at `now`.
+    fn offset_at(&self, now: f32) -> Quat {
+        let amplitude = self.amplitude_at(now);
+        if amplitude == 0.0 {
+            return Quat::IDENTITY;
+        }
+        let angle = (now - self.started_at) * SHAKE_FREQUENCY_HZ * TAU;
+        let yaw = amplitude * (angle + self.phases.x).sin();
+        let pitch = amplitude * (angle * 1.3 + self.phases.y).sin();
+        Quat::from_euler(EulerRot::YXZ, yaw, pitch, 0.0)
+    }
+}
+
+/// Shared assets of explosion effects.
+#[derive(Resource)]
+pub struct ExplosionEffects {
+    /// Low-frequency rumble played at the blast.
+    rumble: Handle<AudioSource>,
+    /// Mesh of one dust puff.
+    dust_mesh: Handle<Mesh>,
+    /// Translucent unlit material shared by every dust puff.
+    dust_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for ExplosionEffects {
+    fn from_world(world: &mut World) -> Self {
+        let rumble = world.resource::<AssetServer>().load(RUMBLE_SOUND_PATH);
+        let dust_mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Sphere::new(0.5).mesh().ico(1).unwrap());
+        let dust_material =
+            world
+                .resource_mut::<Assets<StandardMaterial>>()
+                .add(StandardMaterial {
+                    base_color: DUST_COLOR,
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                });
+        Self {
+            rumble,
+            dust_mesh,
+            dust_material,
+        }
+    }
+}
+
+/// One puff of an explosion's dust cloud.
+#[derive(Component)]
+pub(crate) struct DustPuff {
+    /// Current velocity in world units per second.
+    velocity: Vec3,
+    /// Seconds since the puff was raised.
+    age: f32,
+}
+
+/// Return the launch direction of dust puff `index` of `count`, spread evenly over the
+/// upper hemisphere along a golden-angle spiral.
+fn dust_direction(index: usize, count: usize) -> Vec3 {
+    let height = (index as f32 + 0.5) / count as f32;
+    let ring = (1.0 - height * height).sqrt();
+    let angle = index as f32 * 2.399_963;
+    Vec3::new(ring * angle.cos(), height, ring * angle.sin())
+}
+
+/// React to explosions: shake the camera by its distance from the blast, play a rumble at
+/// the blast, and raise a dust cloud around it.
+pub fn explosion_effects_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    effects: Res<ExplosionEffects>,
+    mut explosions: MessageReader<Explosion>,
+    mut shake: ResMut<CameraShake>,
+    camera_query: Query<&GlobalTransform, With<FlyCamera>>,
+) {
+    let eye = camera_query.single().ok().map(GlobalTransform::translation);
+    for explosion in explosions.read() {
+        if let Some(eye) = eye {
+            let distance = eye.distance(explosion.center) / BLOCK_SIZE;
+            shake.start(
+                time.elapsed_secs(),
+                shake_amplitude(distance, explosion.radius),
+                explosion.center,
+            );
+        }
+        commands.spawn((
+            AudioPlayer::new(effects.rumble.clone()),
+            PlaybackSettings::DESPAWN.with_spatial(true),
+            Transform::from_translation(explosion.center),
+            Name::new("ExplosionRumble"),
+        ));
+        for index in 0..DUST_PUFFS {
+            let direction = dust_direction(index, DUST_PUFFS);
+            commands.spawn((
+                Mesh3d(effects.dust_mesh.clone()),
+                MeshMaterial3d(effects.dust_material.clone()),
+                Transform::from_translation(explosion.center).with_scale(Vec3::splat(DUST_SIZE)),
+                DustPuff {
+                    velocity: direction * DUST_SPEED * explosion.radius.sqrt(),
+                    age: 0.0,
+                },
+                Name::new("DustPuff"),
+            ));
+        }
+    }
+}
+
+/// Drift dust puffs outwards and up, swelling and then thinning them out until they vanish.
+pub fn dust_cloud_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut puffs: Query<(Entity, &mut DustPuff, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut puff, mut transform) in &mut puffs {
+        puff.age += dt;
+        if puff.age >= DUST_LIFETIME_SECS {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let velocity = puff.velocity * (1.0 - DUST_DRAG * dt).max(0.0);
+        puff.velocity = velocity;
+        transform.translation += (velocity + Vec3::Y * DUST_RISE) * dt;
+        let life = puff.age / DUST_LIFETIME_SECS;
+        transform.scale = Vec3::splat(DUST_SIZE * (1.0 + 1.5 * life) * (1.0 - life * life * life));
+    }
+}
+
+/// Take the shake offset applied last frame back off the camera before gameplay runs.
+pub fn camera_shake_clear_system(
+    mut shake: ResMut<CameraShake>,
+    mut camera_query: Query<&mut Transform, With<FlyCamera>>,
+) {
+    if shake.applied == Quat::IDENTITY {
+        return;
+    }
+    for mut transform in &mut camera_query {
+        transform.rotation *= shake.applied.inverse();
+    }
+    shake.applied = Quat::IDENTITY;
+}
+
+/// Layer this frame's shake offset over the camera rotation gameplay settled on.
+///
+/// Runs only while motion effects are enabled, so reduced motion keeps the camera still.
+pub fn camera_shake_apply_system(
+    time: Res<Time>,
+    mut shake: ResMut<CameraShake>,
+    mut camera_query: Query<&mut Transform, With<FlyCamera>>,
+) {
+    let offset = shake.offset_at(time.elapsed_secs());
+    if offset == Quat::IDENTITY {
+        return;
+    }
+    for mut transform in &mut camera_query {
+        transform.rotation *= offset;
+    }
+    shake.applied = offset;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify the shake is capped near the blast, weakens with distance, reaches further
+    /// for bigger blasts, and is gone past the reach.
+    #[test]
+    fn shake_amplitude_falls_off_with_distance() {
+        let radius = 4.0;
+        let reach = radius * SHAKE_REACH_FACTOR;
+        assert_eq!(shake_amplitude(0.0, radius), SHAKE_MAX_ANGLE);
+        assert_eq!(shake_amplitude(reach * 0.5, radius), SHAKE_MAX_ANGLE);
+        let samples: Vec<f32> = (0..=40)
+            .map(|step| shake_amplitude(reach * step as f32 / 40.0, radius))
+            .collect();
+        assert!(samples.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(samples.iter().all(|angle| *angle <= SHAKE_MAX_ANGLE));
+        assert!(shake_amplitude(reach * 0.75, radius) > 0.0);
+        assert_eq!(shake_amplitude(reach, radius), 0.0);
+        assert_eq!(shake_amplitude(reach * 2.0, radius), 0.0);
+        assert!(shake_amplitude(reach, radius * 2.0) > 0.0);
+    }
+
+    /// Verify the shake decays to nothing and the clear step restores the exact camera
+    /// rotation it was layered over.
+    #[test]
+    fn shake_decays_and_clears_without_drift() {
+        let mut shake = CameraShake::default();
+        shake.start(1.0, SHAKE_MAX_ANGLE, Vec3::new(3.0, 4.0, 5.0));
+        assert!(shake.amplitude_at(1.1) > shake.amplitude_at(1.3));
+        assert_eq!(shake.offset_at(1.5), Quat::IDENTITY);
+        // A weaker blast does not cut a stronger shake short.
+        shake.start(1.05, SHAKE_MAX_ANGLE * 0.1, Vec3::ZERO);
+        assert_eq!(shake.started_at, 1.0);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(shake)
+            .add_systems(
+                Update,
+                (camera_shake_clear_system, camera_shake_apply_system).chain(),
+            );
+        let base = Quat::from_rotation_y(0.7);
+        let camera = app
+            .world_mut()
+            .spawn((
+                FlyCamera::new(0.0, 0.0, 0.0, Entity::PLACEHOLDER),
+                Transform::from_rotation(base),
+            ))
+            .id();
+        let rotation = |app: &App| app.world().get::<Transform>(camera).unwrap().rotation;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(1.1));
+        app.update();
+        assert!(rotation(&app).angle_between(base) > 0.0);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(1.0));
+        app.update();
+        assert!(rotation(&app).angle_between(base) < 1e-5);
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+
+use crate::BLOCK_SIZE;
+use crate::voxel::{Block, CurrentTarget};
+
+/// How far the outline stands off the block faces, so its edges do not z-fight with them.
+const HIGHLIGHT_MARGIN: f32 = 0.004 * BLOCK_SIZE;
+/// Color of the aimed-block outline.
+const HIGHLIGHT_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+/// Corner pairs of the twelve cube edges, indexing corners by their x, y, z bits.
+const CUBE_EDGES: [[u32; 2]; 12] = [
+    [0, 1],
+    [2, 3],
+    [4, 5],
+    [6, 7],
+    [0, 2],
+    [1, 3],
+    [4, 6],
+    [5, 7],
+    [0, 4],
+    [1, 5],
+    [2, 6],
+    [3, 7],
+];
+
+/// Marker for the outline drawn around the block the camera is aiming at.
+#[derive(Component)]
+pub(crate) struct TargetHighlight;
+
+/// Build a line mesh of the edges of a block cell inflated by `HIGHLIGHT_MARGIN` on every side.
+fn highlight_mesh() -> Mesh {
+    let positions: Vec<[f32; 3]> = (0..8)
+        .map(|corner| {
+            let bits = Vec3::new(
+                (corner & 1) as f32,
+                ((corner >> 1) & 1) as f32,
+                ((corner >> 2) & 1) as f32,
+            );
+            (bits * (BLOCK_SIZE + 2.0 * HIGHLIGHT_MARGIN) - HIGHLIGHT_MARGIN).to_array()
+        })
+        .collect();
+    Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_indices(Indices::U32(CUBE_EDGES.concat()))
+}
+
+/// Spawn the hidden outline `target_highlight_system` moves onto the aimed block.
+pub(crate) fn spawn_target_highlight(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        bevy::mesh::Mesh3d(meshes.add(highlight_mesh())),
+        bevy::pbr::MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: HIGHLIGHT_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::default(),
+        Visibility::Hidden,
+        bevy::light::NotShadowCaster,
+        TargetHighlight,
+    ));
+}
+
+/// Outline the block the camera is aiming at, hiding the outline when nothing is in reach.
+///
+/// Reads the hit `current_target_system` found, which is the block a break would remove.
+pub fn target_highlight_system(
+    target: Res<CurrentTarget>,
+    mut highlight_query: Query<(&mut Transform, &mut Visibility), With<TargetHighlight>>,
+) {
+    let Ok((mut transform, mut visibility)) = highlight_query.single_mut() else {
+        return;
+    };
+    let Some(hit) = target.hit else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    visibility.set_if_neq(Visibility::Inherited);
+    let translation = Block::world_translation(hit);
+    if transform.translation != translation {
+        transform.translation = translation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify the outline follows the hit cell and hides once nothing is aimed at.
+    #[test]
+    fn highlight_follows_hit_and_hides_without_one() {
+        let mut app = App::new();
+        app.init_resource::<CurrentTarget>()
+            .add_systems(Update, target_highlight_system);
+        let highlight = app
+            .world_mut()
+            .spawn((Transform::default(), Visibility::Hidden, TargetHighlight))
+            .id();
+        let hit = IVec3::new(3, -2, 7);
+        app.world_mut().resource_mut::<CurrentTarget>().hit = Some(hit);
+        app.update();
+        let entity = app.world().entity(highlight);
+        assert_eq!(entity.get::<Visibility>(), Some(&Visibility::Inherited));
+        assert_eq!(
+            entity.get::<Transform>().unwrap().translation,
+            Block::world_translation(hit)
+        );
+        app.world_mut().resource_mut::<CurrentTarget>().hit = None;
+        app.update();
+        assert_eq!(
+            app.world().entity(highlight).get::<Visibility>(),
+            Some(&Visibility::Hidden)
+        );
+    }
+}
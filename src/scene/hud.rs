@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+
+use crate::BLOCK_SIZE;
+use crate::player::PlayerBody;
+use crate::scene::locale::{Locale, LocaleChanged, t};
+use crate::terrain::{Biome, ColumnCache};
+use crate::voxel::{CurrentTarget, HeldTool, RAY_MAX_DISTANCE_BLOCKS};
+
+/// Marker for the placement-distance readout under the crosshair.
+#[derive(Component)]
+pub struct PlacementDistanceText;
+
+/// Marker for the held-tool readout under the placement distance.
+#[derive(Component)]
+pub struct HeldToolText;
+
+/// Marker for the readout naming the biome the player stands in.
+#[derive(Component)]
+pub struct BiomeText;
+
+/// Distance (in blocks) before max reach at which the readout turns amber.
+const REACH_WARNING_MARGIN: f32 = 0.5;
+/// Readout color within comfortable reach.
+const DISTANCE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.8);
+/// Readout color in the last half block before reach runs out.
+const DISTANCE_WARNING_COLOR: Color = Color::srgb(1.0, 0.7, 0.1);
+/// Font size of the biome readout.
+const BIOME_FONT_SIZE: f32 = 14.0;
+/// Gap between the top-left screen corner and the biome readout, in pixels.
+const BIOME_MARGIN: f32 = 8.0;
+
+/// Build the biome readout in the top-left screen corner.
+pub(crate) fn spawn_biome_ui(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(BIOME_MARGIN),
+            top: Val::Px(BIOME_MARGIN),
+            ..default()
+        },
+        Text::new(""),
+        TextFont {
+            font_size: BIOME_FONT_SIZE,
+            ..default()
+        },
+        TextColor(DISTANCE_COLOR),
+        BiomeText,
+    ));
+}
+
+/// Show the eye-to-placement-cell distance, refreshing only on 0.1-block steps.
+pub fn placement_distance_hud_system(
+    target: Res<CurrentTarget>,
+    mut text_query: Query<
+        (&mut Text, &mut TextColor, &mut Visibility),
+        With<PlacementDistanceText>,
+    >,
+    mut shown_tenths: Local<Option<i32>>,
+) {
+    let Ok((mut text, mut color, mut visibility)) = text_query.single_mut() else {
+        return;
+    };
+    if target.place.is_none() {
+        visibility.set_if_neq(Visibility::Hidden);
+        *shown_tenths = None;
+        return;
+    }
+    visibility.set_if_neq(Visibility::Inherited);
+    let tenths = (target.place_distance * 10.0).round() as i32;
+    if *shown_tenths == Some(tenths) {
+        return;
+    }
+    *shown_tenths = Some(tenths);
+    text.0 = format!("{:.1}", tenths as f32 / 10.0);
+    color.0 = if target.place_distance > RAY_MAX_DISTANCE_BLOCKS - REACH_WARNING_MARGIN {
+        DISTANCE_WARNING_COLOR
+    } else {
+        DISTANCE_COLOR
+    };
+}
+
+/// Name the tool in the hand in the active language, hiding the readout while bare-handed.
+pub fn held_tool_hud_system(
+    held: Res<HeldTool>,
+    locale: Res<Locale>,
+    mut locale_changed: MessageReader<LocaleChanged>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<HeldToolText>>,
+) {
+    let relabel = locale_changed.read().count() > 0;
+    if !held.is_changed() && !relabel {
+        return;
+    }
+    let Ok((mut text, mut visibility)) = text_query.single_mut() else {
+        return;
+    };
+    match held.tool() {
+        Some(tool) => {
+            text.0 = locale.item_name(tool);
+            visibility.set_if_neq(Visibility::Inherited);
+        }
+        None => {
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+    }
+}
+
+/// Return the string key naming `biome`.
+fn biome_key(biome: Biome) -> &'static str {
+    match biome {
+        Biome::Plains => "biome.plains",
+        Biome::Desert => "biome.desert",
+        Biome::Mountains => "biome.mountains",
+    }
+}
+
+/// Name the biome of the column the player stands in, relabeling only when it changes.
+///
+/// The column is looked up through the `ColumnCache`, so standing or walking around costs
+/// no noise evaluations once the nearby columns are cached.
+pub fn biome_hud_system(
+    mut column_cache: ResMut<ColumnCache>,
+    locale: Res<Locale>,
+    mut locale_changed: MessageReader<LocaleChanged>,
+    player_query: Query<&Transform, With<PlayerBody>>,
+    mut text_query: Query<&mut Text, With<BiomeText>>,
+    mut shown: Local<Option<Biome>>,
+) {
+    let relabel = locale_changed.read().count() > 0;
+    let (Ok(transform), Ok(mut text)) = (player_query.single(), text_query.single_mut()) else {
+        return;
+    };
+    let column = (transform.translation / BLOCK_SIZE).floor();
+    let biome = column_cache.biome_at(column.x as i32, column.z as i32);
+    if *shown == Some(biome) && !relabel {
+        return;
+    }
+    *shown = Some(biome);
+    text.0 = t!(locale, biome_key(biome));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::{TerrainConfig, TerrainNoise};
+
+    /// Verify the readout names the player's biome and standing still evaluates no noise.
+    #[test]
+    fn biome_readout_reuses_cached_columns() {
+        let config = TerrainConfig::default();
+        let mut app = App::new();
+        app.insert_resource(ColumnCache::new(16, 0, config))
+            .insert_resource(Locale::load(Locale::FALLBACK))
+            .add_message::<LocaleChanged>()
+            .add_systems(Update, biome_hud_system);
+        app.world_mut()
+            .spawn((Transform::from_xyz(0.5, 40.0, 0.5), PlayerBody));
+        let text = app.world_mut().spawn((Text::new(""), BiomeText)).id();
+
+        app.update();
+        let biome = TerrainNoise::biome_at(0, 0, 0, &config);
+        let expected = Locale::load(Locale::FALLBACK).text(biome_key(biome), &[]);
+        assert_eq!(app.world().get::<Text>(text).unwrap().0, expected);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<ColumnCache>().evaluations(), 1);
+    }
+}
@@ -0,0 +1,318 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+
+use crate::scene::settings_file::SettingsFile;
+use crate::scene::toast::Toast;
+use crate::voxel::ItemKind;
+
+/// Translate `key` through a `Locale`, filling `{name}` placeholders from `name = value`
+/// arguments, such as `t!(locale, "menu.stack", item = name, count = 3)`.
+macro_rules! t {
+    ($locale:expr, $key:expr) => {
+        $locale.text($key, &[])
+    };
+    ($locale:expr, $key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $locale.text($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+pub(crate) use t;
+
+/// Sent after the active language changed, so screens re-query their labels.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocaleChanged;
+
+/// Chosen UI language, persisted with the other settings.
+#[derive(Resource, Clone, Debug, PartialEq, Eq)]
+pub struct LanguageSettings {
+    /// Code of the language file in `Locale::DIR`, such as `en`.
+    pub language: String,
+}
+
+impl Default for LanguageSettings {
+    fn default() -> Self {
+        Self {
+            language: Locale::FALLBACK.to_string(),
+        }
+    }
+}
+
+impl LanguageSettings {
+    /// Hotkey that switches to the next shipped language.
+    pub(crate) const LANGUAGE_KEY: KeyCode = KeyCode::KeyL;
+}
+
+impl SettingsFile for LanguageSettings {
+    const PATH: &'static str = "settings/language.cfg";
+    const NAME: &'static str = "language settings";
+
+    fn apply_entry(&mut self, key: &str, value: &str) {
+        if key == "language" && Locale::LANGUAGES.contains(&value) {
+            self.language = value.to_string();
+        }
+    }
+
+    fn entries(&self) -> Vec<(&'static str, String)> {
+        vec![("language", self.language.clone())]
+    }
+}
+
+/// Translated UI strings of the active language, with English behind every missing key.
+#[derive(Resource, Debug)]
+pub struct Locale {
+    /// Code of the active language.
+    code: String,
+    /// Strings of the active language, keyed by string key.
+    strings: HashMap<String, String>,
+    /// English strings looked up for keys the active language lacks.
+    fallback: HashMap<String, String>,
+}
+
+impl FromWorld for Locale {
+    fn from_world(world: &mut World) -> Self {
+        let code = world
+            .get_resource::<LanguageSettings>()
+            .map_or(Self::FALLBACK, |settings| settings.language.as_str())
+            .to_string();
+        Self::load(&code)
+    }
+}
+
+impl Locale {
+    /// Directory holding one RON string map per language, named `<code>.ron`.
+    pub const DIR: &'static str = "assets/lang";
+    /// Language whose strings stand in for missing keys.
+    pub const FALLBACK: &'static str = "en";
+    /// Shipped languages, in the order the language hotkey cycles through.
+    pub const LANGUAGES: [&'static str; 2] = ["en", "de"];
+
+    /// Load the strings of `code` over the English fallback.
+    ///
+    /// A missing or corrupt language file leaves every string to the fallback.
+    pub fn load(code: &str) -> Self {
+        let fallback = Self::read_strings(Self::FALLBACK);
+        let strings = if code == Self::FALLBACK {
+            HashMap::new()
+        } else {
+            Self::read_strings(code)
+        };
+        Self::from_strings(code, strings, fallback)
+    }
+
+    /// Build a locale from already parsed string maps.
+    pub(crate) fn from_strings(
+        code: &str,
+        strings: HashMap<String, String>,
+        fallback: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            code: code.to_string(),
+            strings,
+            fallback,
+        }
+    }
+
+    /// Read the string map of `code`, or an empty one when the file is missing or invalid.
+    fn read_strings(code: &str) -> HashMap<String, String> {
+        let path = format!("{}/{code}.ron", Self::DIR);
+        let Ok(text) = fs::read_to_string(&path) else {
+            warn!("Missing language file {path}");
+            return HashMap::new();
+        };
+        ron::from_str(&text).unwrap_or_else(|err| {
+            warn!("Ignoring invalid {path}: {err}");
+            HashMap::new()
+        })
+    }
+
+    /// Return the code of the active language.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Return the raw string of `key`, from the active language or else the fallback.
+    fn lookup(&self, key: &str) -> Option<&str> {
+        self.strings
+            .get(key)
+            .or_else(|| self.fallback.get(key))
+            .map(String::as_str)
+    }
+
+    /// Return the string of `key` with its `{name}` placeholders filled from `args`.
+    ///
+    /// Keys missing from both languages show as the key itself, so gaps are visible in
+    /// play instead of blank.
+    pub fn text(&self, key: &str, args: &[(&str, String)]) -> String {
+        interpolate(self.lookup(key).unwrap_or(key), args)
+    }
+
+    /// Return the display name of `kind`: its `item.<Name>` string, or its built-in name.
+    pub fn item_name(&self, kind: impl Into<ItemKind>) -> String {
+        let name = kind.into().name();
+        self.lookup(&format!("item.{name}"))
+            .map_or(name, str::to_string)
+    }
+}
+
+/// Replace every `{name}` in `template` with the value of the matching argument.
+///
+/// Placeholders without an argument are kept as written.
+fn interpolate(template: &str, args: &[(&str, String)]) -> String {
+    let mut text = template.to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), value);
+    }
+    text
+}
+
+/// Text node whose string is the translation of a fixed key.
+#[derive(Component)]
+pub(crate) struct LocalizedText(pub(crate) &'static str);
+
+/// Switch to the next shipped language on the language hotkey and persist the choice.
+pub fn language_input_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<LanguageSettings>,
+) {
+    if !keys.just_pressed(LanguageSettings::LANGUAGE_KEY) {
+        return;
+    }
+    let current = Locale::LANGUAGES
+        .iter()
+        .position(|code| *code == settings.language)
+        .unwrap_or(0);
+    settings.language = Locale::LANGUAGES[(current + 1) % Locale::LANGUAGES.len()].to_string();
+    settings.save();
+}
+
+/// Load the chosen language when it changes, announce it to screens, and toast it.
+pub fn apply_language_system(
+    settings: Res<LanguageSettings>,
+    mut locale: ResMut<Locale>,
+    mut changed: MessageWriter<LocaleChanged>,
+    mut toasts: MessageWriter<Toast>,
+) {
+    if !settings.is_changed() || locale.code() == settings.language {
+        return;
+    }
+    *locale = Locale::load(&settings.language);
+    changed.write(LocaleChanged);
+    toasts.write(Toast(t!(
+        locale,
+        "toast.language",
+        name = t!(locale, "language.name")
+    )));
+}
+
+/// Fill new localized text nodes, and refill every one after the language changed.
+pub fn localized_text_system(
+    locale: Res<Locale>,
+    mut changed: MessageReader<LocaleChanged>,
+    mut query: Query<(Ref<LocalizedText>, &mut Text)>,
+) {
+    let relabel = changed.read().count() > 0;
+    for (localized, mut text) in &mut query {
+        if relabel || localized.is_added() {
+            text.0 = t!(locale, localized.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::BlockKind;
+
+    /// Return a string map from key/value pairs.
+    fn strings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Verify missing keys fall back to English, then to the key, and item names to their
+    /// built-in names.
+    #[test]
+    fn missing_keys_fall_back_to_english() {
+        let locale = Locale::from_strings(
+            "de",
+            strings(&[("crafting.title", "Handwerk"), ("item.Dirt", "Erde")]),
+            strings(&[("crafting.title", "Crafting"), ("container.chest", "Chest")]),
+        );
+        assert_eq!(t!(locale, "crafting.title"), "Handwerk");
+        assert_eq!(t!(locale, "container.chest"), "Chest");
+        assert_eq!(t!(locale, "no.such.key"), "no.such.key");
+        assert_eq!(locale.item_name(BlockKind::Dirt), "Erde");
+        assert_eq!(locale.item_name(BlockKind::Stone), "Stone");
+    }
+
+    /// Verify arguments fill their placeholders in any order and unknown ones stay put.
+    #[test]
+    fn arguments_fill_placeholders() {
+        let locale = Locale::from_strings(
+            "de",
+            strings(&[("menu.stack", "{count}× {item}")]),
+            strings(&[("menu.stack", "{item} x{count}"), ("toast", "{who} {what}")]),
+        );
+        assert_eq!(
+            t!(locale, "menu.stack", item = "Erde", count = 3),
+            "3× Erde"
+        );
+        assert_eq!(t!(locale, "toast", who = "Steve"), "Steve {what}");
+    }
+
+    /// Verify switching the language relabels localized text and toasts the new language.
+    #[test]
+    fn switching_language_relabels_and_toasts() {
+        let mut app = App::new();
+        app.insert_resource(LanguageSettings::default())
+            .init_resource::<Locale>()
+            .add_message::<LocaleChanged>()
+            .add_message::<Toast>()
+            .add_systems(
+                Update,
+                (apply_language_system, localized_text_system).chain(),
+            );
+        let title = app
+            .world_mut()
+            .spawn((Text::new(""), LocalizedText("container.chest")))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Text>(title).unwrap().0, "Chest");
+
+        app.world_mut().resource_mut::<LanguageSettings>().language = "de".to_string();
+        app.update();
+        assert_eq!(app.world().resource::<Locale>().code(), "de");
+        assert_eq!(app.world().get::<Text>(title).unwrap().0, "Truhe");
+        let toasts: Vec<Toast> = app
+            .world_mut()
+            .resource_mut::<Messages<Toast>>()
+            .drain()
+            .collect();
+        assert_eq!(toasts, [Toast("Sprache: Deutsch".to_string())]);
+    }
+
+    /// Verify the shipped language files parse, English has every key the others have, and
+    /// the language setting only accepts shipped languages.
+    #[test]
+    fn shipped_languages_are_complete() {
+        let english = Locale::read_strings(Locale::FALLBACK);
+        assert!(english.contains_key("language.name"));
+        for code in Locale::LANGUAGES {
+            let strings = Locale::read_strings(code);
+            assert!(strings.contains_key("language.name"), "{code} has no name");
+            for key in strings.keys().filter(|key| !key.starts_with("item.")) {
+                assert!(
+                    english.contains_key(key),
+                    "{code} key {key} missing in English"
+                );
+            }
+        }
+        assert_eq!(LanguageSettings::parse("language = de\n").language, "de");
+        assert_eq!(
+            LanguageSettings::parse("language = xx\n"),
+            LanguageSettings::default()
+        );
+    }
+}
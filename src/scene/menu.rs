@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use bevy::window::CursorOptions;
+
+use crate::scene::locale::{Locale, LocalizedText, t};
+use crate::scene::setup::set_cursor_grab;
+use crate::voxel::{Inventory, ItemKind};
+
+/// Keys that close an open block screen.
+pub(crate) const CLOSE_KEYS: [KeyCode; 2] = [KeyCode::Escape, KeyCode::KeyE];
+/// Side length of one slot in pixels.
+pub(crate) const SLOT_SIZE: f32 = 72.0;
+/// Gap between slots and rows in pixels.
+pub(crate) const SLOT_GAP: f32 = 8.0;
+/// Font size of slot and inventory labels.
+const LABEL_FONT_SIZE: f32 = 13.0;
+/// Dimmed backdrop behind a screen's panel.
+const BACKDROP_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5);
+/// Screen panel background.
+const PANEL_COLOR: Color = Color::srgb(0.55, 0.55, 0.55);
+/// Slot background at rest.
+const SLOT_COLOR: Color = Color::srgb(0.35, 0.35, 0.35);
+/// Slot background under the cursor.
+const SLOT_HOVER_COLOR: Color = Color::srgb(0.45, 0.45, 0.45);
+/// Held-item buttons per inventory row line before wrapping.
+const INVENTORY_ROW_SLOTS: f32 = 9.0;
+
+/// Button for one held kind in a screen's inventory row.
+#[derive(Component)]
+pub struct InventoryButton(pub ItemKind);
+
+/// Return the node and background of one square slot button.
+pub(crate) fn slot_bundle() -> (Button, Node, BackgroundColor) {
+    (
+        Button,
+        Node {
+            width: Val::Px(SLOT_SIZE),
+            height: Val::Px(SLOT_SIZE),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(SLOT_COLOR),
+    )
+}
+
+/// Return a slot label text bundle.
+pub(crate) fn label(text: impl Into<String>) -> (Text, TextFont, TextColor) {
+    (
+        Text::new(text),
+        TextFont {
+            font_size: LABEL_FONT_SIZE,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    )
+}
+
+/// Return a label bundle showing the translation of `key`.
+pub(crate) fn localized_label(key: &'static str) -> impl Bundle {
+    (label(""), LocalizedText(key))
+}
+
+/// Return the label of `count` items of `kind`.
+pub(crate) fn stack_label(locale: &Locale, kind: impl Into<ItemKind>, count: u32) -> String {
+    t!(
+        locale,
+        "menu.stack",
+        item = locale.item_name(kind),
+        count = count
+    )
+}
+
+/// Spawn a hidden full-screen backdrop tagged `marker` around a centered column panel
+/// built by `panel`.
+pub(crate) fn spawn_menu_root(
+    commands: &mut Commands,
+    marker: impl Bundle,
+    panel: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(BACKDROP_COLOR),
+            marker,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(SLOT_GAP),
+                    padding: UiRect::all(Val::Px(2.0 * SLOT_GAP)),
+                    ..default()
+                },
+                BackgroundColor(PANEL_COLOR),
+            ))
+            .with_children(panel);
+        });
+}
+
+/// Return the node of a wrapping row of held-item buttons.
+pub(crate) fn inventory_row_node() -> Node {
+    Node {
+        flex_wrap: FlexWrap::Wrap,
+        column_gap: Val::Px(SLOT_GAP),
+        row_gap: Val::Px(SLOT_GAP),
+        max_width: Val::Px(INVENTORY_ROW_SLOTS * (SLOT_SIZE + SLOT_GAP)),
+        ..default()
+    }
+}
+
+/// Replace the buttons of inventory row `row` with one per held kind.
+pub(crate) fn fill_inventory_row(
+    commands: &mut Commands,
+    row: Entity,
+    inventory: &Inventory,
+    locale: &Locale,
+) {
+    commands
+        .entity(row)
+        .despawn_children()
+        .with_children(|row| {
+            for (kind, count) in inventory.held() {
+                row.spawn((slot_bundle(), InventoryButton(kind)))
+                    .with_child(label(stack_label(locale, kind, count)));
+            }
+        });
+}
+
+/// Show or hide a screen root, freeing the cursor from the `setup_cursor` grab while shown.
+pub(crate) fn show_menu(root: &mut Node, cursor: Option<Mut<CursorOptions>>, open: bool) {
+    let display = if open { Display::Flex } else { Display::None };
+    if root.display == display {
+        return;
+    }
+    root.display = display;
+    if let Some(mut cursor) = cursor {
+        set_cursor_grab(&mut cursor, !open);
+    }
+}
+
+/// Write `value` into the label that is the first child of a slot button.
+pub(crate) fn set_slot_label(children: &Children, texts: &mut Query<&mut Text>, value: String) {
+    if let Some(mut text) = children
+        .first()
+        .and_then(|child| texts.get_mut(*child).ok())
+    {
+        text.0 = value;
+    }
+}
+
+/// Return the background a slot button shows for `interaction`.
+pub(crate) fn slot_color(interaction: Interaction) -> Color {
+    match interaction {
+        Interaction::Hovered => SLOT_HOVER_COLOR,
+        _ => SLOT_COLOR,
+    }
+}
@@ -1,25 +1,78 @@
 use bevy::prelude::*;
 
+mod accessibility;
+mod container;
+mod crack;
+mod crafting;
+mod daylight;
+mod display;
 mod effects;
+mod explosion;
+mod highlight;
+mod hud;
+mod locale;
+mod menu;
+mod settings_file;
 mod setup;
+mod shadows;
+mod sky;
+mod spawn;
+mod toast;
 
+pub use accessibility::{
+    AccessibilitySettings, accessibility_input_system, apply_ui_scale_system,
+    flashing_effects_enabled, motion_effects_enabled,
+};
+pub use container::{container_input_system, container_ui_system};
+pub use crack::crack_overlay_system;
+pub use crafting::{crafting_input_system, crafting_ui_system};
+pub use daylight::{TimeOfDay, day_night_system};
+pub use display::{
+    BlockNormalMap, DisplaySettings, apply_display_settings_system,
+    apply_normal_map_setting_system, apply_shadow_preset_system, apply_view_distance_system,
+    display_settings_input_system, frame_limiter_system, light_flicker_enabled,
+    view_distance_fog_system,
+};
 pub use effects::sun_billboard_system;
+pub use explosion::{
+    CameraShake, ExplosionEffects, camera_shake_apply_system, camera_shake_clear_system,
+    dust_cloud_system, explosion_effects_system,
+};
+pub use highlight::target_highlight_system;
+pub use hud::{
+    HeldToolText, PlacementDistanceText, biome_hud_system, held_tool_hud_system,
+    placement_distance_hud_system,
+};
+pub(crate) use locale::t;
+pub use locale::{
+    LanguageSettings, Locale, LocaleChanged, apply_language_system, language_input_system,
+    localized_text_system,
+};
+pub(crate) use settings_file::SettingsFile;
 pub use setup::{setup_cursor, setup_scene};
+pub use shadows::ShadowSettings;
+pub use sky::{SkyPlugin, sky_dome_system};
+pub use spawn::SpawnPoint;
+pub use toast::{Toast, toast_system};
+
+/// Marker for the root node of HUD overlays hidden during cinematic camera modes.
+#[derive(Component)]
+pub(crate) struct HudRoot;
 
 /// Billboard marker and parameters for the rendered sun quad.
 #[derive(Component)]
 pub(crate) struct SunBillboard {
-    /// Normalized direction from camera toward the sun billboard.
+    /// Normalized direction from camera toward the sun billboard, moved by the day/night cycle.
     pub(crate) direction: Vec3,
     /// Distance from camera at which the billboard is rendered.
     pub(crate) distance: f32,
 }
 
 impl SunBillboard {
-    /// Build billboard parameters from a world-space sun position and display distance.
-    pub(crate) fn from_world_position(sun_position: Vec3, distance: f32) -> Self {
+    /// Build billboard parameters from a direction towards the sun and display distance.
+    pub(crate) fn from_direction(to_sun: Vec3, distance: f32) -> Self {
         Self {
-            direction: sun_position.normalize_or_zero(),
+            direction: to_sun.normalize_or_zero(),
             distance,
         }
     }
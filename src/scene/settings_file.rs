@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use std::fs;
+use std::path::Path;
+
+/// Settings persisted as a `key = value` file, one entry per line.
+///
+/// Implementors only map their fields to and from entries; loading, parsing, and saving
+/// are shared.
+pub(crate) trait SettingsFile: Default {
+    /// Settings file path.
+    const PATH: &'static str;
+    /// What the settings are, for save failure messages.
+    const NAME: &'static str;
+
+    /// Apply one entry; unknown keys and bad values keep the current setting.
+    fn apply_entry(&mut self, key: &str, value: &str);
+
+    /// Return every entry in file order.
+    fn entries(&self) -> Vec<(&'static str, String)>;
+
+    /// Load persisted settings, falling back to defaults for a missing or corrupt file.
+    fn load() -> Self {
+        fs::read_to_string(Self::PATH)
+            .map(|text| Self::parse(&text))
+            .unwrap_or_default()
+    }
+
+    /// Parse `key = value` lines over the defaults.
+    fn parse(text: &str) -> Self {
+        let mut settings = Self::default();
+        for line in text.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                settings.apply_entry(key.trim(), value.trim());
+            }
+        }
+        settings
+    }
+
+    /// Serialize to the settings file format.
+    fn to_text(&self) -> String {
+        self.entries()
+            .into_iter()
+            .map(|(key, value)| format!("{key} = {value}\n"))
+            .collect()
+    }
+
+    /// Write settings to disk, logging failures instead of interrupting play.
+    fn save(&self) {
+        let path = Path::new(Self::PATH);
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(path, self.to_text()));
+        if let Err(err) = result {
+            warn!("Failed to save {} to {}: {err}", Self::NAME, Self::PATH);
+        }
+    }
+}
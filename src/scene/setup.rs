@@ -1,32 +1,45 @@
+use bevy::image::ImageLoaderSettings;
 use bevy::prelude::*;
 use bevy::ui::{AlignItems, BackgroundColor, JustifyContent, Node, PositionType, Val};
 
-use crate::player::{FlyCamera, Player, PlayerBody, PlayerController, PreviewBlock, Velocity};
-use crate::terrain::TerrainNoise;
+use crate::game_config::GameConfig;
+use crate::player::{
+    FlyCamera, Health, Player, PlayerBody, PlayerController, PreviewBlock, SpawnProtectionBorder,
+    Velocity,
+};
+use crate::terrain::{ColumnCache, TerrainConfig, WorldGenMode, WorldSeed};
 use crate::voxel::{
-    Block, InteractionCooldown, SelectedBlock, WorldState, build_single_block_mesh,
+    BLOCK_NORMAL_TEXTURE_PATH, BLOCK_TEXTURE_PATH, Block, BlockMaterial, CurrentTarget,
+    FluidMaterial, SelectedBlock, WorldBorder, WorldMaterial, WorldMaterials, WorldState,
+    block_material, configure_block_texture, fluid_material, generator_for_mode,
 };
-use crate::{BLOCK_SIZE, SHADOW_MAP_SIZE, STAND_EYE_HEIGHT, STAND_HALF_SIZE};
+use crate::{PLAYER_MAX_HEALTH, STAND_EYE_HEIGHT, STAND_HALF_SIZE, VIEW_DISTANCE};
 
-use crate::scene::SunBillboard;
+use crate::scene::container::spawn_container_ui;
+use crate::scene::crack::CrackOverlayAssets;
+use crate::scene::crafting::spawn_crafting_ui;
+use crate::scene::daylight::{
+    DAY_AMBIENT_BRIGHTNESS, DAY_AMBIENT_COLOR, DAY_SKY_COLOR, SUN_COLOR, SUN_ILLUMINANCE,
+    sun_light_transform,
+};
+use crate::scene::display::{fog_end_for_radius, view_distance_fog};
 use crate::scene::effects::SunVisualFactory;
+use crate::scene::highlight::spawn_target_highlight;
+use crate::scene::hud::spawn_biome_ui;
+use crate::scene::sky::{SkyMaterial, SunMaterial, spawn_sky_dome};
+use crate::scene::toast::spawn_toast_ui;
+use crate::scene::{
+    BlockNormalMap, DisplaySettings, HeldToolText, HudRoot, PlacementDistanceText, ShadowSettings,
+    SpawnPoint, SunBillboard, TimeOfDay,
+};
 
-/// Spawn block X coordinate used for initial player placement.
-const PLAYER_SPAWN_X_BLOCK: i32 = 4;
-/// Spawn block Z coordinate used for initial player placement.
-const PLAYER_SPAWN_Z_BLOCK: i32 = 4;
 /// Initial world position of the in-hand preview block.
 const PREVIEW_SPAWN_POS: Vec3 = Vec3::new(2.0, 2.0, 1.2);
 /// Uniform scale of the in-hand preview block.
 const PREVIEW_SPAWN_SCALE: f32 = 0.15;
-/// World-space sun position used for light direction.
-const SUN_POSITION: Vec3 = Vec3::new(60.0, 50.0, 60.0);
-/// Distance of sun billboard from camera.
+/// Distance of sun billboard from camera; it draws at the far plane, so this only sets its
+/// apparent size together with the quad size.
 const SUN_BILLBOARD_DISTANCE: f32 = 200.0;
-/// Directional-light illuminance used for the sun.
-const SUN_ILLUMINANCE: f32 = 14_000.0;
-/// Directional-light color used for the sun.
-const SUN_COLOR: Color = Color::srgb(1.0, 0.97, 0.90);
 /// Initial player jump speed.
 const PLAYER_JUMP_SPEED: f32 = 10.4;
 /// Base player move speed.
@@ -37,12 +50,10 @@ const CAMERA_SENSITIVITY: f32 = 0.002;
 const CAMERA_INITIAL_PITCH: f32 = -0.35;
 /// Initial first-person camera yaw angle.
 const CAMERA_INITIAL_YAW: f32 = -2.3;
-/// Clear-color used for the sky background.
-const SKY_COLOR: Color = Color::srgb(0.52, 0.74, 0.88);
-/// Global ambient-light color.
-const AMBIENT_COLOR: Color = Color::srgb(0.72, 0.78, 0.90);
-/// Global ambient-light brightness.
-const AMBIENT_BRIGHTNESS: f32 = 3_600.0;
+/// Distance between the listener's ears for positioned sounds, in world units.
+const EAR_GAP: f32 = 0.2 * crate::BLOCK_SIZE;
+/// Tint and opacity multiplied into the water atlas tile.
+const WATER_TINT: Color = Color::srgba(1.0, 1.0, 1.0, 0.65);
 /// Crosshair outer horizontal/vertical line length in pixels.
 const CROSSHAIR_OUTER_LEN: f32 = 16.0;
 /// Crosshair outer line thickness in pixels.
@@ -51,98 +62,270 @@ const CROSSHAIR_OUTER_THICK: f32 = 3.0;
 const CROSSHAIR_INNER_LEN: f32 = 10.0;
 /// Crosshair inner line thickness in pixels.
 const CROSSHAIR_INNER_THICK: f32 = 2.0;
+/// Spawn-protection screen-border thickness in pixels.
+const SPAWN_PROTECTION_BORDER_THICK: f32 = 6.0;
+/// Vertical offset of the placement distance readout below the crosshair (pixels).
+const PLACEMENT_DISTANCE_OFFSET: f32 = 40.0;
+/// Font size of the placement distance readout.
+const PLACEMENT_DISTANCE_FONT_SIZE: f32 = 14.0;
+/// Vertical offset of the held-tool readout below the crosshair (pixels).
+const HELD_TOOL_OFFSET: f32 = 72.0;
 
 /// Build initial world, lighting, player, camera, preview, and UI.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_scene(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
+    mut block_materials: ResMut<Assets<BlockMaterial>>,
+    mut fluid_materials: ResMut<Assets<FluidMaterial>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
     mut images: ResMut<Assets<Image>>,
+    terrain_config: Res<TerrainConfig>,
+    mut sky_materials: ResMut<Assets<SkyMaterial>>,
+    mut sun_materials: ResMut<Assets<SunMaterial>>,
+    time_of_day: Res<TimeOfDay>,
+    display_settings: Res<DisplaySettings>,
+    game_config: Res<GameConfig>,
 ) {
-    setup_environment(&mut commands);
-    let material = build_world_material(&asset_server, &mut materials);
+    let shadows = ShadowSettings::for_quality(display_settings.shadow_quality);
+    setup_environment(&mut commands, &shadows);
+    commands.insert_resource(load_block_normal_map(&asset_server));
     commands.insert_resource(SelectedBlock::new(Block::dirt_with_grass()));
-    commands.insert_resource(InteractionCooldown::new());
-    spawn_initial_chunk_world(&mut commands, &mut meshes, material.clone());
-    spawn_sun(&mut commands, &mut meshes, &mut materials, &mut images);
-    spawn_player_and_camera(&mut commands);
-    spawn_preview_block(&mut commands, &mut meshes, material);
+    commands.insert_resource(CurrentTarget::default());
+    commands.insert_resource(game_config.action_cooldowns());
+    let seed = WorldSeed::from_args();
+    let gen_mode = WorldGenMode::from_args();
+    let shaped_config = gen_mode.shape(*terrain_config);
+    commands.insert_resource(seed);
+    commands.insert_resource(gen_mode);
+    commands.insert_resource(build_world_materials(
+        &asset_server,
+        &mut block_materials,
+        &mut fluid_materials,
+    ));
+    let mut world_state =
+        build_initial_chunk_world(&mut commands, &mut meshes, seed, gen_mode, shaped_config);
+    spawn_sun(
+        &mut commands,
+        &mut meshes,
+        &mut sun_materials,
+        &mut images,
+        &shadows,
+        time_of_day.sun_direction(),
+    );
+    spawn_sky_dome(&mut commands, &mut meshes, &mut sky_materials, &time_of_day);
+    let mut column_cache = ColumnCache::new(ColumnCache::DEFAULT_CAPACITY, seed.0, shaped_config);
+    let spawn_point = SpawnPoint::find(&mut column_cache, world_state.generator.as_ref());
+    spawn_player_and_camera(&mut commands, spawn_point);
+    commands.insert_resource(column_cache);
+    commands.insert_resource(spawn_point);
+    spawn_preview_block(&mut commands, &mut meshes, &mut world_state);
+    spawn_target_highlight(&mut commands, &mut meshes, &mut materials);
+    commands.insert_resource(CrackOverlayAssets::new(
+        &mut meshes,
+        &mut materials,
+        &mut images,
+    ));
+    commands.insert_resource(world_state);
 
     spawn_crosshair_ui(&mut commands);
+    spawn_protection_border_ui(&mut commands);
+    spawn_crafting_ui(&mut commands);
+    spawn_container_ui(&mut commands);
+    spawn_toast_ui(&mut commands);
+    spawn_biome_ui(&mut commands);
 }
 
-/// Insert global background, ambient-light, and shadow-map resources.
-fn setup_environment(commands: &mut Commands) {
+/// Insert global background, ambient-light, and shadow resources.
+fn setup_environment(commands: &mut Commands, shadows: &ShadowSettings) {
     // Sky-like background color.
-    commands.insert_resource(ClearColor(SKY_COLOR));
+    commands.insert_resource(ClearColor(DAY_SKY_COLOR));
     // Global ambient light to avoid fully black backfaces.
     commands.insert_resource(bevy::light::GlobalAmbientLight {
-        color: AMBIENT_COLOR,
-        brightness: AMBIENT_BRIGHTNESS,
+        color: DAY_AMBIENT_COLOR,
+        brightness: DAY_AMBIENT_BRIGHTNESS,
         affects_lightmapped_meshes: true,
     });
-    // Reduce shadow map resolution to improve performance.
-    commands.insert_resource(bevy::light::DirectionalLightShadowMap {
-        size: SHADOW_MAP_SIZE,
-    });
+    // Shadow map resolution and cascades follow the shadow quality preset.
+    commands.insert_resource(shadows.shadow_map());
+    commands.insert_resource(*shadows);
+}
+
+/// Load the block textures every block material samples.
+fn load_block_textures(asset_server: &Res<AssetServer>) -> Handle<Image> {
+    asset_server.load_with_settings(BLOCK_TEXTURE_PATH, configure_block_texture)
+}
+
+/// Build the shared materials of chunk mesh classes and block meshes.
+fn build_world_materials(
+    asset_server: &Res<AssetServer>,
+    materials: &mut ResMut<Assets<BlockMaterial>>,
+    fluid_materials: &mut ResMut<Assets<FluidMaterial>>,
+) -> WorldMaterials {
+    WorldMaterials {
+        opaque: build_opaque_material(asset_server, materials),
+        transparent: build_glass_material(asset_server, materials),
+        emissive: build_emissive_material(asset_server, materials),
+        fluid: build_water_material(asset_server, fluid_materials),
+        flash: build_flash_material(asset_server, materials),
+    }
+}
+
+/// Build the shared textured material for opaque chunk meshes and block meshes.
+fn build_opaque_material(
+    asset_server: &Res<AssetServer>,
+    materials: &mut ResMut<Assets<BlockMaterial>>,
+) -> Handle<BlockMaterial> {
+    materials.add(block_material(
+        StandardMaterial {
+            base_color: Color::WHITE,
+            // Cut out the gaps between leaves and the background of decoration tiles.
+            alpha_mode: AlphaMode::Mask(0.5),
+            perceptual_roughness: 0.85,
+            metallic: 0.0,
+            reflectance: 0.04,
+            ..default()
+        },
+        load_block_textures(asset_server),
+    ))
 }
 
-/// Build the shared textured material for chunks and preview mesh.
-fn build_world_material(
+/// Build the translucent, flowing material for chunk water meshes.
+fn build_water_material(
     asset_server: &Res<AssetServer>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Handle<StandardMaterial> {
-    // Shared material for world blocks.
-    let atlas_handle: Handle<Image> = asset_server.load("textures/atlas.png");
-    materials.add(bevy::pbr::StandardMaterial {
-        base_color: Color::WHITE,
-        base_color_texture: Some(atlas_handle),
-        perceptual_roughness: 0.85,
-        metallic: 0.0,
-        reflectance: 0.04,
-        ..default()
-    })
+    materials: &mut ResMut<Assets<FluidMaterial>>,
+) -> Handle<FluidMaterial> {
+    materials.add(fluid_material(
+        StandardMaterial {
+            base_color: WATER_TINT,
+            alpha_mode: AlphaMode::Blend,
+            perceptual_roughness: 0.3,
+            metallic: 0.0,
+            reflectance: 0.2,
+            ..default()
+        },
+        load_block_textures(asset_server),
+    ))
+}
+
+/// Build the translucent block material for chunk glass meshes.
+///
+/// Glass keeps the texture alpha as is, so the frame stays visible and the pane stays clear.
+fn build_glass_material(
+    asset_server: &Res<AssetServer>,
+    materials: &mut ResMut<Assets<BlockMaterial>>,
+) -> Handle<BlockMaterial> {
+    materials.add(block_material(
+        StandardMaterial {
+            base_color: Color::WHITE,
+            alpha_mode: AlphaMode::Blend,
+            perceptual_roughness: 0.1,
+            metallic: 0.0,
+            reflectance: 0.5,
+            ..default()
+        },
+        load_block_textures(asset_server),
+    ))
+}
+
+/// Build the full-bright block material for chunk meshes of glowing blocks.
+///
+/// Unlit, so glowstone keeps its texture's own colors in the dark instead of taking the
+/// light around it.
+fn build_emissive_material(
+    asset_server: &Res<AssetServer>,
+    materials: &mut ResMut<Assets<BlockMaterial>>,
+) -> Handle<BlockMaterial> {
+    materials.add(block_material(
+        StandardMaterial {
+            base_color: Color::WHITE,
+            alpha_mode: AlphaMode::Mask(0.5),
+            unlit: true,
+            ..default()
+        },
+        load_block_textures(asset_server),
+    ))
+}
+
+/// Build the brightened block material primed TNT flashes with.
+fn build_flash_material(
+    asset_server: &Res<AssetServer>,
+    materials: &mut ResMut<Assets<BlockMaterial>>,
+) -> Handle<BlockMaterial> {
+    materials.add(block_material(
+        StandardMaterial {
+            base_color: Color::WHITE,
+            emissive: LinearRgba::rgb(0.8, 0.8, 0.8),
+            perceptual_roughness: 0.85,
+            metallic: 0.0,
+            reflectance: 0.04,
+            ..default()
+        },
+        load_block_textures(asset_server),
+    ))
 }
 
-/// Spawn the initial origin chunk and insert `WorldState`.
-fn spawn_initial_chunk_world(
+/// Load the block normal textures as linear data if they were generated.
+///
+/// `make_atlas --normals` produces them; without the file, the world material stays flat.
+fn load_block_normal_map(asset_server: &Res<AssetServer>) -> BlockNormalMap {
+    if !std::path::Path::new("assets")
+        .join(BLOCK_NORMAL_TEXTURE_PATH)
+        .exists()
+    {
+        return BlockNormalMap(None);
+    }
+    BlockNormalMap(Some(asset_server.load_with_settings(
+        BLOCK_NORMAL_TEXTURE_PATH,
+        |settings: &mut ImageLoaderSettings| {
+            configure_block_texture(settings);
+            settings.is_srgb = false;
+        },
+    )))
+}
+
+/// Build `WorldState` with the initial origin chunk spawned.
+fn build_initial_chunk_world(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
-    material: Handle<StandardMaterial>,
-) {
-    let mut world_state = WorldState::new(material);
+    seed: WorldSeed,
+    gen_mode: WorldGenMode,
+    terrain_config: TerrainConfig,
+) -> WorldState {
+    let mut world_state = WorldState::new();
+    world_state.generator = generator_for_mode(seed.0, gen_mode, terrain_config);
+    world_state.border = WorldBorder::from_args();
     let spawn_coord = IVec3::new(0, 0, 0);
     world_state.ensure_chunk(commands, meshes, spawn_coord);
     world_state.center = spawn_coord;
-    commands.insert_resource(world_state);
+    world_state
 }
 
-/// Spawn directional sun light and its billboard mesh.
+/// Spawn directional sun light with `shadows` cascades and its billboard mesh with the sun
+/// towards `to_sun`.
 fn spawn_sun(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    sun_materials: &mut ResMut<Assets<SunMaterial>>,
     images: &mut ResMut<Assets<Image>>,
+    shadows: &ShadowSettings,
+    to_sun: Vec3,
 ) {
     // Sun-like directional light.
     commands.spawn((
         bevy::light::DirectionalLight {
             illuminance: SUN_ILLUMINANCE,
             color: SUN_COLOR,
-            shadows_enabled: true,
+            shadows_enabled: shadows.enabled,
             ..default()
         },
-        Transform::from_translation(SUN_POSITION).looking_at(Vec3::ZERO, Vec3::Y),
+        shadows.cascade_config(),
+        sun_light_transform(to_sun),
     ));
     let sun_texture = images.add(SunVisualFactory::build_texture(256));
-    let sun_material = materials.add(bevy::pbr::StandardMaterial {
-        base_color: Color::WHITE,
-        base_color_texture: Some(sun_texture),
-        unlit: true,
-        alpha_mode: AlphaMode::Blend,
-        cull_mode: None,
-        ..default()
+    let sun_material = sun_materials.add(SunMaterial {
+        texture: sun_texture,
     });
     let sun_mesh = meshes.add(SunVisualFactory::build_quad(20.0));
     commands.spawn((
@@ -150,13 +333,13 @@ fn spawn_sun(
         bevy::pbr::MeshMaterial3d(sun_material),
         Transform::from_translation(Vec3::ZERO),
         bevy::light::NotShadowCaster,
-        SunBillboard::from_world_position(SUN_POSITION, SUN_BILLBOARD_DISTANCE),
+        SunBillboard::from_direction(to_sun, SUN_BILLBOARD_DISTANCE),
     ));
 }
 
 /// Spawn the player body and first-person camera.
-fn spawn_player_and_camera(commands: &mut Commands) {
-    let spawn_pos = SpawnLayout::player_position();
+fn spawn_player_and_camera(commands: &mut Commands, spawn_point: SpawnPoint) {
+    let spawn_pos = spawn_point.player_position();
     let player_entity = commands
         .spawn((
             PlayerBody,
@@ -164,12 +347,14 @@ fn spawn_player_and_camera(commands: &mut Commands) {
             Velocity::default(),
             Player::new_standing(PLAYER_JUMP_SPEED, STAND_HALF_SIZE, STAND_EYE_HEIGHT),
             PlayerController::new(PLAYER_MOVE_SPEED),
+            Health::new_spawned(PLAYER_MAX_HEALTH),
         ))
         .id();
 
-    // First-person camera.
+    // First-person camera, also the listener for positioned sounds.
     commands.spawn((
         bevy::camera::Camera3d::default(),
+        view_distance_fog(fog_end_for_radius(VIEW_DISTANCE), DAY_SKY_COLOR),
         Transform::from_translation(SpawnLayout::camera_position(spawn_pos)),
         FlyCamera::new(
             CAMERA_SENSITIVITY,
@@ -177,39 +362,33 @@ fn spawn_player_and_camera(commands: &mut Commands) {
             CAMERA_INITIAL_YAW,
             player_entity,
         ),
+        SpatialListener::new(EAR_GAP),
     ));
 }
 
-/// Spawn-layout calculator for player and camera initial placement.
+/// Spawn-layout calculator for the camera's initial placement.
 struct SpawnLayout;
 
 impl SpawnLayout {
-    /// Compute the player world-space spawn position from terrain height.
-    fn player_position() -> Vec3 {
-        let ground_height = TerrainNoise::height_at(PLAYER_SPAWN_X_BLOCK, PLAYER_SPAWN_Z_BLOCK);
-        let spawn_y = (ground_height as f32 + 2.0) * BLOCK_SIZE + STAND_HALF_SIZE.y;
-        let spawn_x = (PLAYER_SPAWN_X_BLOCK as f32 + 0.5) * BLOCK_SIZE;
-        let spawn_z = (PLAYER_SPAWN_Z_BLOCK as f32 + 0.5) * BLOCK_SIZE;
-        Vec3::new(spawn_x, spawn_y, spawn_z)
-    }
-
     /// Convert player spawn position to camera spawn using eye-height offset.
     fn camera_position(player_spawn: Vec3) -> Vec3 {
         player_spawn + Vec3::Y * (STAND_EYE_HEIGHT - STAND_HALF_SIZE.y)
     }
 }
 
-/// Spawn the in-hand preview block mesh.
+/// Spawn the in-hand preview block using the world's shared block mesh.
 fn spawn_preview_block(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
-    material: Handle<StandardMaterial>,
+    world_state: &mut WorldState,
 ) {
     // Preview block shown near the camera.
-    let preview_mesh = meshes.add(build_single_block_mesh(Block::dirt_with_grass()));
+    let preview_mesh = world_state
+        .block_meshes
+        .get_or_build(meshes, Block::dirt_with_grass());
     commands.spawn((
         bevy::mesh::Mesh3d(preview_mesh),
-        bevy::pbr::MeshMaterial3d(material),
+        WorldMaterial::Opaque,
         Transform::from_translation(PREVIEW_SPAWN_POS).with_scale(Vec3::splat(PREVIEW_SPAWN_SCALE)),
         PreviewBlock,
     ));
@@ -232,6 +411,7 @@ fn spawn_crosshair_ui(commands: &mut Commands) {
                 ..default()
             },
             BackgroundColor(Color::NONE),
+            HudRoot,
         ))
         .with_children(|parent| {
             // White outline lines.
@@ -273,9 +453,58 @@ fn spawn_crosshair_ui(commands: &mut Commands) {
                 },
                 BackgroundColor(Color::BLACK),
             ));
+
+            // Placement distance readout below the crosshair.
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    margin: UiRect::top(Val::Px(PLACEMENT_DISTANCE_OFFSET)),
+                    ..default()
+                },
+                Text::new(""),
+                TextFont {
+                    font_size: PLACEMENT_DISTANCE_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Visibility::Hidden,
+                PlacementDistanceText,
+            ));
+
+            // Held-tool readout below the placement distance.
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    margin: UiRect::top(Val::Px(HELD_TOOL_OFFSET)),
+                    ..default()
+                },
+                Text::new(""),
+                TextFont {
+                    font_size: PLACEMENT_DISTANCE_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Visibility::Hidden,
+                HeldToolText,
+            ));
         });
 }
 
+/// Build the full-screen border overlay that flashes during spawn protection.
+fn spawn_protection_border_ui(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            border: UiRect::all(Val::Px(SPAWN_PROTECTION_BORDER_THICK)),
+            ..default()
+        },
+        BorderColor::all(Color::NONE),
+        SpawnProtectionBorder,
+    ));
+}
+
 /// Lock and hide cursor for mouse-look controls.
 pub fn setup_cursor(
     mut windows: Query<&mut bevy::window::CursorOptions, With<bevy::window::PrimaryWindow>>,
@@ -283,6 +512,15 @@ pub fn setup_cursor(
     let Ok(mut cursor_options) = windows.single_mut() else {
         return;
     };
-    cursor_options.grab_mode = bevy::window::CursorGrabMode::Locked;
-    cursor_options.visible = false;
+    set_cursor_grab(&mut cursor_options, true);
+}
+
+/// Lock and hide the cursor for mouse look, or free and show it for clicking UI.
+pub(crate) fn set_cursor_grab(cursor_options: &mut bevy::window::CursorOptions, grabbed: bool) {
+    cursor_options.grab_mode = if grabbed {
+        bevy::window::CursorGrabMode::Locked
+    } else {
+        bevy::window::CursorGrabMode::None
+    };
+    cursor_options.visible = !grabbed;
 }
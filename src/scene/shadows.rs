@@ -0,0 +1,141 @@
+use bevy::light::{CascadeShadowConfig, CascadeShadowConfigBuilder, DirectionalLightShadowMap};
+use bevy::prelude::*;
+
+use crate::BLOCK_SIZE;
+
+/// Sun shadow map quality preset, cycled with a hotkey and saved with the display settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShadowQuality {
+    /// No sun shadow maps are rendered at all.
+    Off,
+    /// One short-range cascade at a small resolution.
+    Low,
+    /// A few cascades covering the nearby terrain.
+    #[default]
+    Medium,
+    /// Four higher-resolution cascades reaching further out.
+    High,
+}
+
+impl ShadowQuality {
+    /// Presets in hotkey cycling order.
+    const ALL: [Self; 4] = [Self::Off, Self::Low, Self::Medium, Self::High];
+
+    /// Return the preset after this one, wrapping from `High` back to `Off`.
+    pub(crate) fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|quality| *quality == self);
+        Self::ALL[index.map_or(0, |index| (index + 1) % Self::ALL.len())]
+    }
+
+    /// Return the name used in the settings file and log.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+
+    /// Parse a settings file name, `None` for anything unknown.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|quality| quality.name() == name)
+    }
+}
+
+/// Sun shadow coverage and resolution of the active quality preset.
+///
+/// Applied to the sun's `CascadeShadowConfig` when it spawns and again whenever the preset
+/// changes.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct ShadowSettings {
+    /// Render sun shadow maps at all; off skips the shadow passes entirely.
+    pub enabled: bool,
+    /// Distance from the camera past which nothing receives sun shadows.
+    pub max_distance: f32,
+    /// Number of shadow cascades splitting that distance.
+    pub num_cascades: usize,
+    /// Far bound of the sharpest, nearest cascade; ignored with a single cascade.
+    pub first_cascade_far_bound: f32,
+    /// Width and height of each cascade's shadow map in texels.
+    pub map_size: usize,
+}
+
+impl ShadowSettings {
+    /// Return the settings of a quality preset.
+    pub fn for_quality(quality: ShadowQuality) -> Self {
+        match quality {
+            ShadowQuality::Off => Self {
+                enabled: false,
+                ..Self::for_quality(ShadowQuality::Low)
+            },
+            ShadowQuality::Low => Self {
+                enabled: true,
+                max_distance: 40.0 * BLOCK_SIZE,
+                num_cascades: 1,
+                first_cascade_far_bound: 40.0 * BLOCK_SIZE,
+                map_size: 1024,
+            },
+            ShadowQuality::Medium => Self {
+                enabled: true,
+                max_distance: 96.0 * BLOCK_SIZE,
+                num_cascades: 3,
+                first_cascade_far_bound: 10.0 * BLOCK_SIZE,
+                map_size: 1024,
+            },
+            ShadowQuality::High => Self {
+                enabled: true,
+                max_distance: 160.0 * BLOCK_SIZE,
+                num_cascades: 4,
+                first_cascade_far_bound: 8.0 * BLOCK_SIZE,
+                map_size: 2048,
+            },
+        }
+    }
+
+    /// Return the sun's cascade split for these settings.
+    pub(crate) fn cascade_config(&self) -> CascadeShadowConfig {
+        CascadeShadowConfigBuilder {
+            num_cascades: self.num_cascades,
+            maximum_distance: self.max_distance,
+            first_cascade_far_bound: self.first_cascade_far_bound,
+            ..default()
+        }
+        .build()
+    }
+
+    /// Return the shadow map resolution resource for these settings.
+    pub(crate) fn shadow_map(&self) -> DirectionalLightShadowMap {
+        DirectionalLightShadowMap {
+            size: self.map_size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify the hotkey cycle visits every preset, names round-trip, and cascades build.
+    #[test]
+    fn presets_cycle_and_build_cascades() {
+        let mut quality = ShadowQuality::Off;
+        for expected in [
+            ShadowQuality::Low,
+            ShadowQuality::Medium,
+            ShadowQuality::High,
+            ShadowQuality::Off,
+        ] {
+            quality = quality.next();
+            assert_eq!(quality, expected);
+            assert_eq!(ShadowQuality::from_name(quality.name()), Some(quality));
+            let settings = ShadowSettings::for_quality(quality);
+            assert_eq!(settings.enabled, quality != ShadowQuality::Off);
+            let cascades = settings.cascade_config();
+            assert_eq!(cascades.bounds.len(), settings.num_cascades);
+            let far = cascades.bounds.last().unwrap();
+            assert!((far - settings.max_distance).abs() < 1e-3);
+        }
+        assert_eq!(ShadowQuality::from_name("ultra"), None);
+    }
+}
@@ -0,0 +1,193 @@
+use bevy::mesh::MeshVertexBufferLayoutRef;
+use bevy::pbr::{MaterialPipeline, MaterialPipelineKey};
+use bevy::prelude::*;
+use bevy::render::render_resource::{
+    AsBindGroup, RenderPipelineDescriptor, ShaderType, SpecializedMeshPipelineError,
+};
+use bevy::shader::ShaderRef;
+
+use crate::player::FlyCamera;
+use crate::scene::TimeOfDay;
+use crate::scene::daylight::Daylight;
+
+/// Shader drawing the sky dome gradient.
+const SKY_DOME_SHADER_PATH: &str = "shaders/sky_dome.wgsl";
+/// Shader drawing the sun billboard at the far plane.
+const SUN_BILLBOARD_SHADER_PATH: &str = "shaders/sun_billboard.wgsl";
+/// Radius of the dome mesh; it draws at the far plane whatever its size.
+const SKY_DOME_RADIUS: f32 = 10.0;
+/// Icosphere subdivisions of the dome mesh.
+const SKY_DOME_SUBDIVISIONS: u32 = 4;
+
+/// Marker for the sky dome centered on the camera.
+#[derive(Component)]
+pub(crate) struct SkyDome;
+
+/// Sky colors and sun direction handed to the dome shader.
+#[derive(ShaderType, Clone, Copy, Debug, Default)]
+pub(crate) struct SkyDomeUniform {
+    /// Linear color straight up.
+    zenith: Vec4,
+    /// Linear color at and below the horizon.
+    horizon: Vec4,
+    /// Linear color added towards the sun.
+    sun_glow: Vec4,
+    /// Unit direction towards the sun.
+    sun_direction: Vec3,
+}
+
+/// Material of the sky dome: a horizon-to-zenith gradient brightened around the sun.
+///
+/// The dome is drawn from inside at the far plane, casts no shadows, and skips the prepass,
+/// so it only fills pixels no other mesh covers; `ClearColor` stays behind it as a fallback.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub(crate) struct SkyMaterial {
+    /// Current sky colors.
+    #[uniform(0)]
+    sky: SkyDomeUniform,
+}
+
+impl SkyMaterial {
+    /// Color the sky for `daylight` with the sun towards `to_sun`.
+    fn set(&mut self, daylight: &Daylight, to_sun: Vec3) {
+        self.sky = SkyDomeUniform {
+            zenith: LinearRgba::from(daylight.zenith_color).to_vec4(),
+            horizon: LinearRgba::from(daylight.sky_color).to_vec4(),
+            sun_glow: LinearRgba::from(daylight.sun_glow).to_vec4(),
+            sun_direction: to_sun,
+        };
+    }
+}
+
+impl Material for SkyMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SKY_DOME_SHADER_PATH.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        SKY_DOME_SHADER_PATH.into()
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // The camera sits inside the dome and looks at its back faces.
+        descriptor.primitive.cull_mode = None;
+        Ok(())
+    }
+}
+
+/// Material of the sun billboard: its disc texture blended over the sky dome.
+///
+/// Like the dome, the quad is drawn at the far plane, so it sits in the background however
+/// far from the camera it is placed: terrain in front of it always wins the depth test, and
+/// blending keeps it from writing depth of its own.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub(crate) struct SunMaterial {
+    /// Sun disc with a soft alpha edge.
+    #[texture(0)]
+    #[sampler(1)]
+    pub(crate) texture: Handle<Image>,
+}
+
+impl Material for SunMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SUN_BILLBOARD_SHADER_PATH.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        SUN_BILLBOARD_SHADER_PATH.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Blend
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        descriptor.primitive.cull_mode = None;
+        Ok(())
+    }
+}
+
+/// Registers the sky dome and sun billboard materials.
+pub struct SkyPlugin;
+
+impl Plugin for SkyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            MaterialPlugin::<SkyMaterial>::default(),
+            MaterialPlugin::<SunMaterial>::default(),
+        ));
+    }
+}
+
+/// Spawn the sky dome colored for `time_of_day`.
+pub(crate) fn spawn_sky_dome(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<SkyMaterial>>,
+    time_of_day: &TimeOfDay,
+) {
+    let to_sun = time_of_day.sun_direction();
+    let mut material = SkyMaterial::default();
+    material.set(&Daylight::at(to_sun.y), to_sun);
+    let mesh = Sphere::new(SKY_DOME_RADIUS)
+        .mesh()
+        .ico(SKY_DOME_SUBDIVISIONS)
+        .expect("sky dome subdivisions are within the icosphere limit");
+    commands.spawn((
+        bevy::mesh::Mesh3d(meshes.add(mesh)),
+        bevy::pbr::MeshMaterial3d(materials.add(material)),
+        Transform::default(),
+        bevy::light::NotShadowCaster,
+        SkyDome,
+    ));
+}
+
+/// Keep the sky dome centered on the camera and colored for the time of day.
+///
+/// The clear color follows the dome's horizon color so any gap shows a matching sky.
+pub fn sky_dome_system(
+    time_of_day: Res<TimeOfDay>,
+    camera_query: Query<&Transform, (With<FlyCamera>, Without<SkyDome>)>,
+    mut dome_query: Query<(&mut Transform, &bevy::pbr::MeshMaterial3d<SkyMaterial>), With<SkyDome>>,
+    mut materials: ResMut<Assets<SkyMaterial>>,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    let to_sun = time_of_day.sun_direction();
+    let daylight = Daylight::at(to_sun.y);
+    clear_color.0 = daylight.sky_color;
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    for (mut transform, material) in &mut dome_query {
+        transform.translation = camera_transform.translation;
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.set(&daylight, to_sun);
+        }
+    }
+}
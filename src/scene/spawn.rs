@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::terrain::ColumnCache;
+use crate::voxel::{Block, BlockKind, Chunk, ChunkGenerator};
+use crate::{BLOCK_SIZE, CHUNK_SIZE, MAX_CHUNK_LAYER, MIN_CHUNK_LAYER, STAND_HALF_SIZE};
+
+/// Column the spawn search starts from.
+const SEARCH_ORIGIN: IVec2 = IVec2::new(4, 4);
+/// Farthest ring, in blocks, searched around `SEARCH_ORIGIN`.
+const SEARCH_RADIUS: i32 = 64;
+/// Blocks above the raw heightmap a generated column can reach (tree crowns, ruin walls).
+const SURFACE_HEADROOM: i32 = 12;
+/// Largest ground height difference to a face-neighbor column that still counts as flat.
+const MAX_NEIGHBOR_STEP: i32 = 1;
+
+/// Ground cell the player spawns on, kept for respawning.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpawnPoint {
+    /// World block coordinate of the solid ground block under the spawn.
+    pub ground: IVec3,
+}
+
+impl SpawnPoint {
+    /// Search a spiral of columns around the origin for a safe spawn on the generated world.
+    ///
+    /// Columns are read from real generated chunks, so trees, ruins, and water count. A
+    /// spawn stands on solid natural ground with only air or decorations above it, and
+    /// differs from each face-neighbor column by at most `MAX_NEIGHBOR_STEP`. Falls back
+    /// to the search origin's ground when no column within `SEARCH_RADIUS` qualifies.
+    ///
+    /// `column_cache` must hold the generator's seed and config; it bounds how high each
+    /// column is scanned so only the chunks near the surface get generated.
+    pub fn find(column_cache: &mut ColumnCache, generator: &dyn ChunkGenerator) -> Self {
+        let mut columns = GeneratedColumns {
+            column_cache,
+            generator,
+            chunks: HashMap::new(),
+        };
+        for reach in 0..=SEARCH_RADIUS {
+            for dz in -reach..=reach {
+                for dx in -reach..=reach {
+                    if dx.abs() != reach && dz.abs() != reach {
+                        continue;
+                    }
+                    let column = SEARCH_ORIGIN + IVec2::new(dx, dz);
+                    if let Some(ground) = columns.safe_ground(column) {
+                        return Self {
+                            ground: IVec3::new(column.x, ground, column.y),
+                        };
+                    }
+                }
+            }
+        }
+        let fallback = columns.top(SEARCH_ORIGIN).map_or(0, |(ground, _)| ground);
+        Self {
+            ground: IVec3::new(SEARCH_ORIGIN.x, fallback, SEARCH_ORIGIN.y),
+        }
+    }
+
+    /// Compute the player world-space spawn position above this spawn's ground.
+    pub fn player_position(self) -> Vec3 {
+        let spawn_y = (self.ground.y as f32 + 2.0) * BLOCK_SIZE + STAND_HALF_SIZE.y;
+        let spawn_x = (self.ground.x as f32 + 0.5) * BLOCK_SIZE;
+        let spawn_z = (self.ground.z as f32 + 0.5) * BLOCK_SIZE;
+        Vec3::new(spawn_x, spawn_y, spawn_z)
+    }
+}
+
+/// Lazily generated chunks the spawn search reads columns from.
+struct GeneratedColumns<'a> {
+    /// Raw heightmap bounding each column's scan.
+    column_cache: &'a mut ColumnCache,
+    /// Generator the chunks come from.
+    generator: &'a dyn ChunkGenerator,
+    /// Chunks generated so far, by chunk coordinate.
+    chunks: HashMap<IVec3, Chunk>,
+}
+
+impl GeneratedColumns<'_> {
+    /// Return the generated block at world coordinate `pos`.
+    fn block(&mut self, pos: IVec3) -> Block {
+        let coord = pos.div_euclid(IVec3::splat(CHUNK_SIZE));
+        let generator = self.generator;
+        self.chunks
+            .entry(coord)
+            .or_insert_with(|| generator.generate(coord))
+            .get_block(pos.rem_euclid(IVec3::splat(CHUNK_SIZE)))
+    }
+
+    /// Return the world y and block of the highest block in `column` that is not air or a
+    /// decoration, or `None` for an empty column.
+    fn top(&mut self, column: IVec2) -> Option<(i32, Block)> {
+        let ceiling = (self.column_cache.height_at(column.x, column.y) + SURFACE_HEADROOM)
+            .min(MAX_CHUNK_LAYER * CHUNK_SIZE - 1);
+        (MIN_CHUNK_LAYER * CHUNK_SIZE..=ceiling)
+            .rev()
+            .map(|y| (y, self.block(IVec3::new(column.x, y, column.y))))
+            .find(|(_, block)| !block.is_air() && !block.is_decoration())
+    }
+
+    /// Return the ground height of `column` when it is a safe, flat spawn.
+    fn safe_ground(&mut self, column: IVec2) -> Option<i32> {
+        let (ground, block) = self.top(column)?;
+        if !block.is_solid() || matches!(block.kind, BlockKind::Log | BlockKind::Leaves) {
+            return None;
+        }
+        for offset in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+            let (neighbor, _) = self.top(column + offset)?;
+            if (neighbor - ground).abs() > MAX_NEIGHBOR_STEP {
+                return None;
+            }
+        }
+        Some(ground)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::{TerrainConfig, WorldGenMode};
+    use crate::voxel::generator_for_mode;
+
+    /// Verify spawns stand on flat dry ground with two free cells above, for every preset.
+    #[test]
+    fn spawn_is_flat_dry_and_clear() {
+        let base = TerrainConfig::default();
+        for (seed, gen_mode) in [
+            (0, WorldGenMode::Normal),
+            (7, WorldGenMode::Normal),
+            (3, WorldGenMode::Islands),
+            (5, WorldGenMode::Amplified),
+            (0, WorldGenMode::Superflat),
+        ] {
+            let config = gen_mode.shape(base);
+            let generator = generator_for_mode(seed, gen_mode, config);
+            let mut column_cache = ColumnCache::new(64, seed, config);
+            let spawn = SpawnPoint::find(&mut column_cache, generator.as_ref());
+            assert_eq!(
+                spawn,
+                SpawnPoint::find(&mut column_cache, generator.as_ref())
+            );
+
+            let mut columns = GeneratedColumns {
+                column_cache: &mut column_cache,
+                generator: generator.as_ref(),
+                chunks: HashMap::new(),
+            };
+            let ground = columns.block(spawn.ground);
+            assert_eq!(columns.top(spawn.ground.xz()).unwrap().0, spawn.ground.y);
+            assert!(
+                ground.is_solid(),
+                "{gen_mode:?} seed {seed} spawns on {ground:?}"
+            );
+            assert!(!matches!(ground.kind, BlockKind::Log | BlockKind::Leaves));
+            for dy in 1..=2 {
+                let above = columns.block(spawn.ground + IVec3::Y * dy);
+                assert!(
+                    above.is_air() || above.is_decoration(),
+                    "{gen_mode:?} seed {seed} spawn is covered by {above:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+/// Seconds a toast stays on screen.
+const TOAST_SECS: f32 = 3.0;
+/// Font size of the toast line.
+const TOAST_FONT_SIZE: f32 = 18.0;
+/// Gap between the top of the screen and the toast, in pixels.
+const TOAST_TOP: f32 = 48.0;
+
+/// Short message shown to the player at the top of the screen for a few seconds.
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct Toast(pub String);
+
+/// Marker for the text node showing the current toast.
+#[derive(Component)]
+pub struct ToastText;
+
+/// Build the hidden toast line, centered at the top of the screen.
+pub(crate) fn spawn_toast_ui(commands: &mut Commands) {
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            top: Val::Px(TOAST_TOP),
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_child((
+            Text::new(""),
+            TextFont {
+                font_size: TOAST_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Visibility::Hidden,
+            ToastText,
+        ));
+}
+
+/// Show the latest toast and hide it once it has been up for `TOAST_SECS`.
+///
+/// A newer toast replaces the one showing and restarts the timer.
+pub fn toast_system(
+    time: Res<Time>,
+    mut toasts: MessageReader<Toast>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<ToastText>>,
+    mut shown_for: Local<Option<f32>>,
+) {
+    let Ok((mut text, mut visibility)) = text_query.single_mut() else {
+        return;
+    };
+    if let Some(Toast(latest)) = toasts.read().last() {
+        text.0.clone_from(latest);
+        visibility.set_if_neq(Visibility::Inherited);
+        *shown_for = Some(0.0);
+        return;
+    }
+    let Some(elapsed) = shown_for.as_mut() else {
+        return;
+    };
+    *elapsed += time.delta_secs();
+    if *elapsed >= TOAST_SECS {
+        visibility.set_if_neq(Visibility::Hidden);
+        *shown_for = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Verify the latest toast shows, replaces older ones, and hides after its time is up.
+    #[test]
+    fn latest_toast_shows_then_hides() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<Toast>()
+            .add_systems(Update, toast_system);
+        let toast = app
+            .world_mut()
+            .spawn((Text::new(""), Visibility::Hidden, ToastText))
+            .id();
+        let shown = |app: &App| {
+            let text = app.world().get::<Text>(toast).unwrap().0.clone();
+            let visible = app.world().get::<Visibility>(toast) != Some(&Visibility::Hidden);
+            visible.then_some(text)
+        };
+
+        app.world_mut().write_message(Toast("first".into()));
+        app.world_mut().write_message(Toast("second".into()));
+        app.update();
+        assert_eq!(shown(&app).as_deref(), Some("second"));
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(TOAST_SECS * 0.5));
+        app.update();
+        assert_eq!(shown(&app).as_deref(), Some("second"));
+        app.update();
+        assert_eq!(shown(&app), None);
+    }
+}
@@ -1,56 +1,505 @@
-use crate::CHUNK_SIZE;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
 
-/// Terrain noise generator with mountain/plains shaping constants.
-pub struct TerrainNoise;
+use crate::{CHUNK_SIZE, MIN_CHUNK_LAYER};
 
-impl TerrainNoise {
+/// World generation seed mixed into every terrain noise lookup.
+///
+/// Seed `0` reproduces the original fixed-constant world.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorldSeed(
+    /// Raw 64-bit seed value.
+    pub u64,
+);
+
+impl WorldSeed {
+    /// Read `--seed <u64>` from the command line, defaulting to seed `0`.
+    pub fn from_args() -> Self {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg != "--seed" {
+                continue;
+            }
+            match args.next().map(|value| value.parse()) {
+                Some(Ok(seed)) => return Self(seed),
+                _ => warn!("Ignoring invalid --seed value, using seed 0"),
+            }
+        }
+        Self::default()
+    }
+}
+
+/// Terrain generator used for newly streamed chunks.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WorldGenMode {
+    /// Noise heightmap with biomes, caves, ores, trees, and water.
+    #[default]
+    Normal,
+    /// Flat slab of bedrock, two dirt layers, and grass, for building and physics tests.
+    Superflat,
+    /// Normal generation with taller, steeper terrain and a raised height cap.
+    Amplified,
+    /// Normal generation sunk below sea level except for scattered landmasses.
+    Islands,
+}
+
+impl WorldGenMode {
+    /// Surface (grass) height of every superflat column.
+    pub const SUPERFLAT_HEIGHT: i32 = 3;
+
+    /// Highest column the amplified preset may reach.
+    const AMPLIFIED_MAX_HEIGHT: i32 = CHUNK_SIZE * 4 - 1;
+    /// Blocks the islands preset sinks terrain outside its landmasses.
+    const ISLANDS_SINK: f32 = 14.0;
+
+    /// Pick the mode from the command line: `--superflat`, `--amplified`, or `--islands`.
+    pub fn from_args() -> Self {
+        std::env::args()
+            .skip(1)
+            .find_map(|arg| match arg.as_str() {
+                "--superflat" => Some(Self::Superflat),
+                "--amplified" => Some(Self::Amplified),
+                "--islands" => Some(Self::Islands),
+                _ => None,
+            })
+            .unwrap_or(Self::Normal)
+    }
+
+    /// Apply this preset's shaping on top of the loaded terrain `config`.
+    pub fn shape(self, config: TerrainConfig) -> TerrainConfig {
+        match self {
+            Self::Normal | Self::Superflat => config,
+            Self::Amplified => TerrainConfig {
+                plain_amplitude: config.plain_amplitude * 3.0,
+                mountain_amplitude: config.mountain_amplitude * 2.0,
+                slope_steepness: config.slope_steepness * 1.5,
+                max_height: Self::AMPLIFIED_MAX_HEIGHT,
+                ..config
+            },
+            Self::Islands => TerrainConfig {
+                island_sink: Self::ISLANDS_SINK,
+                ..config
+            },
+        }
+    }
+}
+
+/// Lattice noise implementation sampled by the heightmap and biome octaves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum NoiseBackend {
+    /// Interpolated random values per lattice point (blocky, axis-aligned features).
+    #[default]
+    Value,
+    /// Interpolated dot products with random lattice gradients (Perlin-style, rounder).
+    Gradient,
+}
+
+/// Heightmap and biome shaping parameters, loaded from `assets/terrain.ron`.
+///
+/// The defaults reproduce the built-in world; fields missing from the file keep them.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct TerrainConfig {
     /// Base ground level for the heightmap.
-    const BASE_HEIGHT: f32 = 4.0;
+    pub base_height: f32,
     /// Small amplitude for plains to keep them flat.
-    const PLAIN_AMPLITUDE: f32 = 0.9;
+    pub plain_amplitude: f32,
     /// Large amplitude for mountains to make them tall.
-    const MOUNTAIN_AMPLITUDE: f32 = 100.0;
+    pub mountain_amplitude: f32,
     /// Weight of mountain regions (higher means denser mountains).
-    const MOUNTAIN_WEIGHT: f32 = 0.4;
+    pub mountain_weight: f32,
     /// How flat mountain tops become (0.0 none, 1.0 strong flattening).
-    const MOUNTAIN_PLATEAU_WEIGHT: f32 = 0.55;
+    pub mountain_plateau_weight: f32,
     /// Threshold for starting plateau flattening in mask space.
-    const MOUNTAIN_PLATEAU_START: f32 = 0.7;
+    pub mountain_plateau_start: f32,
     /// Controls slope shaping (`>1` steeper, `<1` smoother).
-    const SLOPE_STEEPNESS: f32 = 0.20;
+    pub slope_steepness: f32,
     /// Noise scale for general terrain undulation.
-    const TERRAIN_SCALE: f32 = 0.06;
+    pub terrain_scale: f32,
     /// Noise scale for mountain mask distribution.
-    const MOUNTAIN_SCALE: f32 = 0.18;
+    pub mountain_scale: f32,
+    /// Mountain mask above which a column belongs to the mountains biome.
+    pub mountain_biome_mask: f32,
+    /// Noise scale for the desert/plains climate split (lower means larger biomes).
+    pub desert_scale: f32,
+    /// Climate noise above which a non-mountain column is desert.
+    pub desert_threshold: f32,
+    /// Noise implementation for the heightmap and biome octaves.
+    pub noise: NoiseBackend,
+    /// Highest column height the heightmap is clamped to.
+    pub max_height: i32,
+    /// Blocks subtracted from columns outside the low-frequency island mask (`0` disables).
+    pub island_sink: f32,
+}
+
+impl Default for TerrainConfig {
+    fn default() -> Self {
+        Self {
+            base_height: 4.0,
+            plain_amplitude: 0.9,
+            mountain_amplitude: 100.0,
+            mountain_weight: 0.4,
+            mountain_plateau_weight: 0.55,
+            mountain_plateau_start: 0.7,
+            slope_steepness: 0.20,
+            terrain_scale: 0.06,
+            mountain_scale: 0.18,
+            mountain_biome_mask: 0.34,
+            desert_scale: 0.0072,
+            desert_threshold: 0.25,
+            noise: NoiseBackend::Value,
+            max_height: CHUNK_SIZE * 2 - 1,
+            island_sink: 0.0,
+        }
+    }
+}
+
+impl TerrainConfig {
+    /// Terrain config file, one RON `TerrainConfig(...)` struct.
+    pub const PATH: &'static str = "assets/terrain.ron";
+
+    /// Load the terrain config, falling back to defaults for a missing or corrupt file.
+    pub fn load() -> Self {
+        match fs::read_to_string(Self::PATH) {
+            Ok(text) => Self::parse(&text).unwrap_or_else(|err| {
+                warn!("Ignoring invalid {}: {err}", Self::PATH);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Parse a RON `TerrainConfig(...)` struct.
+    fn parse(text: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(text)
+    }
+}
+
+/// Poll state for reloading `assets/terrain.ron` while the game runs.
+#[derive(Default)]
+pub struct TerrainConfigWatch {
+    /// When the file was last checked.
+    last_poll: Option<Instant>,
+    /// File modification time seen at the last check.
+    modified: Option<SystemTime>,
+}
+
+impl TerrainConfigWatch {
+    /// Minimum time between two file checks.
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Return the freshly parsed config if the file changed since the last check.
+    ///
+    /// Checks at most once per `POLL_INTERVAL`; an unparsable file is reported and skipped.
+    pub fn poll(&mut self) -> Option<TerrainConfig> {
+        let now = Instant::now();
+        if self
+            .last_poll
+            .is_some_and(|last| now.duration_since(last) < Self::POLL_INTERVAL)
+        {
+            return None;
+        }
+        self.last_poll = Some(now);
+        let modified = fs::metadata(Path::new(TerrainConfig::PATH))
+            .and_then(|meta| meta.modified())
+            .ok();
+        if modified.is_none() || modified == self.modified {
+            return None;
+        }
+        self.modified = modified;
+        let text = fs::read_to_string(TerrainConfig::PATH).ok()?;
+        TerrainConfig::parse(&text)
+            .inspect_err(|err| warn!("Ignoring invalid {}: {err}", TerrainConfig::PATH))
+            .ok()
+    }
+}
+
+/// Climate region of a world column, deciding its surface blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Biome {
+    /// Grass over dirt, with sparse oaks.
+    Plains,
+    /// Sand over sand, with cacti.
+    Desert,
+    /// Bare dirt on the slopes and bare stone on the peaks, with conifers below the stone.
+    Mountains,
+}
+
+/// Top block of a generated column, before trees, ruins, water, and decorations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Surface {
+    /// Grass over dirt.
+    Grass,
+    /// Snow-capped grass over dirt, above the snow line.
+    SnowyGrass,
+    /// Sand: desert columns, beaches, and inland sand spots.
+    Sand,
+    /// Bare dirt on mountain slopes.
+    Dirt,
+    /// Bare stone on mountain peaks.
+    Stone,
+}
+
+/// Terrain noise generator; heightmap shaping comes from a `TerrainConfig`.
+pub struct TerrainNoise;
+
+impl TerrainNoise {
+    /// Noise scale for 3D cave density (lower means larger caves).
+    const CAVE_SCALE: f32 = 0.11;
+    /// Density above which underground blocks are carved into air.
+    const CAVE_THRESHOLD: f32 = 0.4;
+    /// Salt mixed into the cave hash so caves do not mirror the heightmap.
+    const CAVE_SALT: u32 = 0x27d4_eb2f;
+    /// Salt mixed into the per-column tree hash.
+    const TREE_SALT: u32 = 0x1656_67b1;
+    /// Chance in `[0, 1]` that a plains column grows an oak; sparse, so plains stay open.
+    const PLAINS_TREE_CHANCE: f32 = 0.005;
+    /// Chance in `[0, 1]` that a mountain column grows a conifer.
+    const MOUNTAIN_TREE_CHANCE: f32 = 0.02;
+    /// Chance in `[0, 1]` that a desert column grows a cactus.
+    const DESERT_TREE_CHANCE: f32 = 0.006;
+    /// World y of the unbreakable bedrock floor, the bottom slice of the lowest layer.
+    pub const BEDROCK_Y: i32 = MIN_CHUNK_LAYER * CHUNK_SIZE;
+    /// Highest world y filled with water where the terrain lies below it.
+    pub const SEA_LEVEL: i32 = 3;
+    /// Column height above which surfaces turn to snow, before per-column dithering.
+    pub const SNOW_LINE: i32 = 22;
+    /// Surface height above which mountain columns are bare stone.
+    const MOUNTAIN_STONE_LINE: i32 = 14;
+    /// Blocks the snow line is jittered up or down per column, so the cap edge is ragged.
+    const SNOW_BAND: i32 = 2;
+    /// Salt mixed into the per-column snow line hash.
+    const SNOW_SALT: u32 = 0x3c6e_f372;
+    /// Noise scale for surface sand patches.
+    const SAND_SCALE: f32 = 0.045;
+    /// Highest column that counts as shoreline or seabed for beach sand.
+    const BEACH_MAX_HEIGHT: i32 = Self::SEA_LEVEL + 2;
+    /// Patch noise above which a shoreline column is beach sand.
+    const BEACH_THRESHOLD: f32 = -0.3;
+    /// Patch noise above which an inland column is a scattered sand spot.
+    const SAND_SPOT_THRESHOLD: f32 = 0.4;
+    /// Seed salt so the sand patch noise does not mirror the heightmap.
+    const SAND_SALT: u64 = 0x1b87_3593;
+    /// Noise scale for the river ridge channel (lower means longer, straighter rivers).
+    const RIVER_SCALE: f32 = 0.006;
+    /// Ridge distance from zero under which a column lies in a river.
+    const RIVER_WIDTH: f32 = 0.02;
+    /// Blocks a river lowers the column at its center line.
+    const RIVER_DEPTH: i32 = 3;
+    /// Seed salt so rivers do not follow the heightmap noise.
+    const RIVER_SALT: u64 = 0x68e3_1da4;
+    /// Noise scale for the island landmass mask (lower means larger islands).
+    const ISLAND_SCALE: f32 = 0.012;
+    /// Island mask range over which terrain rises from sunk to full height.
+    const ISLAND_SHORE: std::ops::Range<f32> = 0.55..0.7;
+    /// Seed salt so islands do not follow the heightmap noise.
+    const ISLAND_SALT: u64 = 0x4f1b_bcdc;
+    /// Seed salt so the climate noise does not mirror the heightmap.
+    const DESERT_SALT: u64 = 0x5bd1_e995;
 
-    /// Compute terrain height at `(x, z)` using layered value-noise.
-    pub fn height_at(x: i32, z: i32) -> i32 {
-        let fx = x as f32 * Self::TERRAIN_SCALE;
-        let fz = z as f32 * Self::TERRAIN_SCALE;
+    /// Compute terrain height at `(x, z)` for `seed`, with river trenches carved in.
+    pub fn height_at(x: i32, z: i32, seed: u64, config: &TerrainConfig) -> i32 {
+        let carve = (Self::river_mask(x, z, seed) * Self::RIVER_DEPTH as f32).round() as i32;
+        (Self::land_height_at(x, z, seed, config) - carve).max(1)
+    }
+
+    /// Return the highest world y filled with water in column `(x, z)`.
+    ///
+    /// River trenches fill to one block under their banks; everything else to sea level.
+    pub fn water_level_at(x: i32, z: i32, seed: u64, config: &TerrainConfig) -> i32 {
+        if Self::river_mask(x, z, seed) > 0.0 {
+            (Self::land_height_at(x, z, seed, config) - 1).max(Self::SEA_LEVEL)
+        } else {
+            Self::SEA_LEVEL
+        }
+    }
+
+    /// Return how close column `(x, z)` is to a river center line, in `[0, 1]` (`0` is dry land).
+    pub fn river_mask(x: i32, z: i32, seed: u64) -> f32 {
+        let ridge = Self::fbm_2d(
+            x as f32 * Self::RIVER_SCALE,
+            z as f32 * Self::RIVER_SCALE,
+            seed ^ Self::RIVER_SALT,
+            NoiseBackend::Value,
+        )
+        .abs();
+        (1.0 - ridge / Self::RIVER_WIDTH).max(0.0)
+    }
 
-        let noise = Self::fbm_2d(fx, fz);
-        let mask = (Self::fbm_2d(fx * Self::MOUNTAIN_SCALE, fz * Self::MOUNTAIN_SCALE) + 1.0) * 0.5;
-        let mountain_mask = mask.powf(2.0);
+    /// Compute the uncarved terrain height at `(x, z)` for `seed` using layered value-noise.
+    fn land_height_at(x: i32, z: i32, seed: u64, config: &TerrainConfig) -> i32 {
+        let fx = x as f32 * config.terrain_scale;
+        let fz = z as f32 * config.terrain_scale;
+
+        let noise = Self::fbm_2d(fx, fz, seed, config.noise);
+        let mountain_mask = Self::mountain_mask(x, z, seed, config);
         let mut amp = Self::lerp(
-            Self::PLAIN_AMPLITUDE,
-            Self::MOUNTAIN_AMPLITUDE,
-            mountain_mask * Self::MOUNTAIN_WEIGHT,
+            config.plain_amplitude,
+            config.mountain_amplitude,
+            mountain_mask * config.mountain_weight,
+        );
+        let plateau = Self::smoothstep(config.mountain_plateau_start, 1.0, mountain_mask);
+        amp *= Self::lerp(1.0, 1.0 - config.mountain_plateau_weight, plateau);
+        let shaped = noise.signum() * noise.abs().powf(config.slope_steepness);
+        let sink = if config.island_sink > 0.0 {
+            config.island_sink * (1.0 - Self::island_mask(x, z, seed))
+        } else {
+            0.0
+        };
+        let height = (config.base_height + shaped * amp - sink).round() as i32;
+        height.clamp(1, config.max_height)
+    }
+
+    /// Return how far inside a landmass column `(x, z)` is for the islands preset, in `[0, 1]`.
+    fn island_mask(x: i32, z: i32, seed: u64) -> f32 {
+        let noise = Self::fbm_2d(
+            x as f32 * Self::ISLAND_SCALE,
+            z as f32 * Self::ISLAND_SCALE,
+            seed ^ Self::ISLAND_SALT,
+            NoiseBackend::Value,
+        );
+        Self::smoothstep(
+            Self::ISLAND_SHORE.start,
+            Self::ISLAND_SHORE.end,
+            (noise + 1.0) * 0.5,
+        )
+    }
+
+    /// Resolve the biome of column `(x, z)` for `seed` from low-frequency noise.
+    pub fn biome_at(x: i32, z: i32, seed: u64, config: &TerrainConfig) -> Biome {
+        if Self::mountain_mask(x, z, seed, config) > config.mountain_biome_mask {
+            return Biome::Mountains;
+        }
+        let climate = Self::fbm_2d(
+            x as f32 * config.desert_scale,
+            z as f32 * config.desert_scale,
+            seed ^ Self::DESERT_SALT,
+            config.noise,
+        );
+        if climate > config.desert_threshold {
+            Biome::Desert
+        } else {
+            Biome::Plains
+        }
+    }
+
+    /// Return whether the column `(x, z)` of `height` is surfaced with a sand patch.
+    ///
+    /// Shoreline and seabed columns form wide beaches; inland only the noise peaks do.
+    pub fn is_sand_patch(x: i32, z: i32, height: i32, seed: u64) -> bool {
+        let patch = Self::fbm_2d(
+            x as f32 * Self::SAND_SCALE,
+            z as f32 * Self::SAND_SCALE,
+            seed ^ Self::SAND_SALT,
+            NoiseBackend::Value,
         );
-        let plateau = Self::smoothstep(Self::MOUNTAIN_PLATEAU_START, 1.0, mountain_mask);
-        amp *= Self::lerp(1.0, 1.0 - Self::MOUNTAIN_PLATEAU_WEIGHT, plateau);
-        let shaped = noise.signum() * noise.abs().powf(Self::SLOPE_STEEPNESS);
-        let height = (Self::BASE_HEIGHT + shaped * amp).round() as i32;
-        height.clamp(1, CHUNK_SIZE * 2 - 1)
+        if height <= Self::BEACH_MAX_HEIGHT {
+            patch > Self::BEACH_THRESHOLD
+        } else {
+            patch > Self::SAND_SPOT_THRESHOLD
+        }
+    }
+
+    /// Return the surface of a `biome` column of `height`.
+    ///
+    /// Columns above their `snow_line` are snow-capped whatever their biome; `sand_patch`
+    /// turns a plains surface to sand.
+    pub fn surface_for(biome: Biome, height: i32, snow_line: i32, sand_patch: bool) -> Surface {
+        match biome {
+            _ if height > snow_line => Surface::SnowyGrass,
+            Biome::Plains if sand_patch => Surface::Sand,
+            Biome::Plains => Surface::Grass,
+            Biome::Desert => Surface::Sand,
+            Biome::Mountains if height > Self::MOUNTAIN_STONE_LINE => Surface::Stone,
+            Biome::Mountains => Surface::Dirt,
+        }
+    }
+
+    /// Return how mountainous column `(x, z)` is, in `[0, 1]`.
+    fn mountain_mask(x: i32, z: i32, seed: u64, config: &TerrainConfig) -> f32 {
+        let scale = config.terrain_scale * config.mountain_scale;
+        let noise = Self::fbm_2d(x as f32 * scale, z as f32 * scale, seed, config.noise);
+        let mask = (noise + 1.0) * 0.5;
+        mask.powf(2.0)
     }
 
-    /// Compute 2D fractal Brownian motion from value-noise octaves.
-    fn fbm_2d(x: f32, z: f32) -> f32 {
+    /// Compute 3D cave density at world block `(x, y, z)` for `seed`, in `[-1, 1]`.
+    ///
+    /// Pure function of world coordinates so caves line up across chunk borders.
+    pub fn density_at(x: i32, y: i32, z: i32, seed: u64) -> f32 {
+        let fx = x as f32 * Self::CAVE_SCALE;
+        let fy = y as f32 * Self::CAVE_SCALE;
+        let fz = z as f32 * Self::CAVE_SCALE;
+        let mut value = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut norm = 0.0;
+        for _ in 0..2 {
+            value += Self::value_noise_3d(fx * frequency, fy * frequency, fz * frequency, seed)
+                * amplitude;
+            norm += amplitude;
+            amplitude *= 0.5;
+            frequency *= 2.0;
+        }
+        value / norm
+    }
+
+    /// Return whether the block at `(x, y, z)` under a column of height `surface` is cave air.
+    ///
+    /// The surface block and the bottom world layer are never carved.
+    pub fn is_cave(x: i32, y: i32, z: i32, surface: i32, seed: u64) -> bool {
+        y > Self::BEDROCK_Y && y < surface && Self::density_at(x, y, z, seed) > Self::CAVE_THRESHOLD
+    }
+
+    /// Return the chance in `[0, 1]` that a column of `biome` grows a tree.
+    pub fn tree_chance(biome: Biome) -> f32 {
+        match biome {
+            Biome::Plains => Self::PLAINS_TREE_CHANCE,
+            Biome::Mountains => Self::MOUNTAIN_TREE_CHANCE,
+            Biome::Desert => Self::DESERT_TREE_CHANCE,
+        }
+    }
+
+    /// Return a pick in `[0, 1)` sizing the tree rooted at column `(x, z)` of `biome`, if
+    /// one grows there.
+    pub fn tree_at(x: i32, z: i32, seed: u64, biome: Biome) -> Option<f32> {
+        let roll = (Self::hash_2d(x, z, seed.wrapping_add(Self::TREE_SALT as u64)) + 1.0) * 0.5;
+        let chance = Self::tree_chance(biome);
+        // Reuse the roll's low range so the size is as deterministic as the tree itself.
+        (roll < chance).then(|| roll / chance)
+    }
+
+    /// Return a uniform per-column roll in `[0, 1]`, decorrelated from other rolls by `salt`.
+    pub fn column_roll(x: i32, z: i32, seed: u64, salt: u64) -> f32 {
+        (Self::hash_2d(x, z, seed.wrapping_add(salt)) + 1.0) * 0.5
+    }
+
+    /// Return the height above which column `(x, z)` is snow-capped.
+    pub fn snow_line_at(x: i32, z: i32, seed: u64) -> i32 {
+        let roll = (Self::hash_2d(x, z, seed.wrapping_add(Self::SNOW_SALT as u64)) + 1.0) * 0.5;
+        let span = 2 * Self::SNOW_BAND + 1;
+        let offset = ((roll * span as f32) as i32).min(span - 1);
+        Self::SNOW_LINE - Self::SNOW_BAND + offset
+    }
+
+    /// Compute 2D fractal Brownian motion from `backend` noise octaves.
+    fn fbm_2d(x: f32, z: f32, seed: u64, backend: NoiseBackend) -> f32 {
         let mut value = 0.0;
         let mut amplitude = 1.0;
         let mut frequency = 1.0;
         let mut norm = 0.0;
         for _ in 0..3 {
-            value += Self::value_noise_2d(x * frequency, z * frequency) * amplitude;
+            let octave = match backend {
+                NoiseBackend::Value => Self::value_noise_2d(x * frequency, z * frequency, seed),
+                NoiseBackend::Gradient => {
+                    Self::gradient_noise_2d(x * frequency, z * frequency, seed)
+                }
+            };
+            value += octave * amplitude;
             norm += amplitude;
             amplitude *= 0.5;
             frequency *= 2.0;
@@ -59,7 +508,7 @@ impl TerrainNoise {
     }
 
     /// Sample smooth 2D value noise with bilinear interpolation.
-    fn value_noise_2d(x: f32, z: f32) -> f32 {
+    fn value_noise_2d(x: f32, z: f32, seed: u64) -> f32 {
         let x0 = x.floor() as i32;
         let z0 = z.floor() as i32;
         let x1 = x0 + 1;
@@ -67,28 +516,104 @@ impl TerrainNoise {
         let tx = Self::fade(x - x0 as f32);
         let tz = Self::fade(z - z0 as f32);
 
-        let v00 = Self::hash_2d(x0, z0);
-        let v10 = Self::hash_2d(x1, z0);
-        let v01 = Self::hash_2d(x0, z1);
-        let v11 = Self::hash_2d(x1, z1);
+        let v00 = Self::hash_2d(x0, z0, seed);
+        let v10 = Self::hash_2d(x1, z0, seed);
+        let v01 = Self::hash_2d(x0, z1, seed);
+        let v11 = Self::hash_2d(x1, z1, seed);
 
         let a = Self::lerp(v00, v10, tx);
         let b = Self::lerp(v01, v11, tx);
         Self::lerp(a, b, tz)
     }
 
-    /// Hash integer grid coordinates into deterministic noise in `[-1, 1]`.
-    fn hash_2d(x: i32, z: i32) -> f32 {
-        let mut n = x as u32;
-        n = n
-            .wrapping_mul(374761393)
-            .wrapping_add((z as u32).wrapping_mul(668265263));
+    /// Sample 2D gradient (Perlin-style) noise, roughly in `[-1, 1]`.
+    fn gradient_noise_2d(x: f32, z: f32, seed: u64) -> f32 {
+        let x0 = x.floor() as i32;
+        let z0 = z.floor() as i32;
+        let fx = x - x0 as f32;
+        let fz = z - z0 as f32;
+        let corner = |cx: i32, cz: i32, dx: f32, dz: f32| {
+            let angle = Self::hash_2d(cx, cz, seed) * std::f32::consts::PI;
+            angle.cos() * dx + angle.sin() * dz
+        };
+
+        let a = Self::lerp(
+            corner(x0, z0, fx, fz),
+            corner(x0 + 1, z0, fx - 1.0, fz),
+            Self::fade(fx),
+        );
+        let b = Self::lerp(
+            corner(x0, z0 + 1, fx, fz - 1.0),
+            corner(x0 + 1, z0 + 1, fx - 1.0, fz - 1.0),
+            Self::fade(fx),
+        );
+        // Unit gradients peak at about `sqrt(0.5)`; rescale toward value-noise range.
+        (Self::lerp(a, b, Self::fade(fz)) * std::f32::consts::SQRT_2).clamp(-1.0, 1.0)
+    }
+
+    /// Sample smooth 3D value noise with trilinear interpolation.
+    fn value_noise_3d(x: f32, y: f32, z: f32, seed: u64) -> f32 {
+        let x0 = x.floor() as i32;
+        let y0 = y.floor() as i32;
+        let z0 = z.floor() as i32;
+        let tx = Self::fade(x - x0 as f32);
+        let ty = Self::fade(y - y0 as f32);
+        let tz = Self::fade(z - z0 as f32);
+
+        let plane = |yy: i32| {
+            let a = Self::lerp(
+                Self::hash_3d(x0, yy, z0, seed),
+                Self::hash_3d(x0 + 1, yy, z0, seed),
+                tx,
+            );
+            let b = Self::lerp(
+                Self::hash_3d(x0, yy, z0 + 1, seed),
+                Self::hash_3d(x0 + 1, yy, z0 + 1, seed),
+                tx,
+            );
+            Self::lerp(a, b, tz)
+        };
+        Self::lerp(plane(y0), plane(y0 + 1), ty)
+    }
+
+    /// Fold a 64-bit seed into a 32-bit hash offset (`0` for seed `0`).
+    fn seed_offset(seed: u64) -> u32 {
+        (seed as u32).wrapping_mul(0x9e37_79b9) ^ ((seed >> 32) as u32).wrapping_mul(0x85eb_ca6b)
+    }
+
+    /// Run the avalanche steps on a mixed hash and map it into `[-1, 1]`.
+    fn finish_hash(mut n: u32) -> f32 {
         n ^= n >> 13;
         n = n.wrapping_mul(1274126177);
         let v = (n & 0x00ff_ffff) as f32 / 0x00ff_ffff as f32;
         v * 2.0 - 1.0
     }
 
+    /// Hash integer grid coordinates and `seed` into deterministic noise in `[-1, 1]`.
+    ///
+    /// The seed is folded in as an offset before the avalanche steps, so seed `0` leaves
+    /// the original hash unchanged.
+    fn hash_2d(x: i32, z: i32, seed: u64) -> f32 {
+        Self::finish_hash(
+            (x as u32)
+                .wrapping_mul(374761393)
+                .wrapping_add((z as u32).wrapping_mul(668265263))
+                .wrapping_add(Self::seed_offset(seed)),
+        )
+    }
+
+    /// Hash integer 3D grid coordinates and `seed` into deterministic noise in `[-1, 1]`.
+    fn hash_3d(x: i32, y: i32, z: i32, seed: u64) -> f32 {
+        Self::finish_hash(
+            (x as u32)
+                .wrapping_mul(374761393)
+                .wrapping_add((y as u32).wrapping_mul(1440662683))
+                .wrapping_add((z as u32).wrapping_mul(668265263))
+                .wrapping_add(Self::seed_offset(seed))
+                .wrapping_add(Self::CAVE_SALT),
+        )
+    }
+
     /// Smooth interpolation curve used by value-noise blending.
     fn fade(t: f32) -> f32 {
         t * t * (3.0 - 2.0 * t)
@@ -105,3 +630,394 @@ impl TerrainNoise {
         t * t * (3.0 - 2.0 * t)
     }
 }
+
+/// Deterministic splitmix64 stream for per-chunk generation features.
+///
+/// Seeded from the chunk coordinate, world seed, and a feature salt, so each chunk
+/// always rolls the same features no matter when or where it is generated.
+pub struct ChunkRng {
+    /// Current splitmix64 state.
+    state: u64,
+}
+
+impl ChunkRng {
+    /// Start the stream of chunk `coord` in the `seed` world for feature `salt`.
+    pub fn new(coord: IVec3, seed: u64, salt: u64) -> Self {
+        let coord_hash = (coord.x as u32 as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+            ^ (coord.y as u32 as u64).wrapping_mul(0xc2b2_ae3d_27d4_eb4f)
+            ^ (coord.z as u32 as u64).wrapping_mul(0x1656_67b1_9e37_79f9);
+        Self {
+            state: coord_hash ^ seed.rotate_left(17) ^ salt,
+        }
+    }
+
+    /// Return the next 64-bit value of the stream.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a value in `0..bound` (`bound` must be positive).
+    pub fn below(&mut self, bound: i32) -> i32 {
+        (self.next_u64() % bound as u64) as i32
+    }
+}
+
+/// Cached result of terrain queries for one world column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnSample {
+    /// Terrain surface height of the column.
+    pub height: i32,
+    /// Biome of the column.
+    pub biome: Biome,
+}
+
+/// One memoized column entry with its last-use stamp.
+struct CachedColumn {
+    /// Cached column query result.
+    sample: ColumnSample,
+    /// Access stamp used to detect stale LRU order records.
+    last_used: u64,
+}
+
+/// LRU-bounded memo of `(x, z) -> ColumnSample` for runtime gameplay queries.
+///
+/// Chunk generation tasks keep calling `TerrainNoise` directly since they touch
+/// each column only once; this cache serves systems that query the same columns
+/// every frame.
+#[derive(Resource)]
+pub struct ColumnCache {
+    /// World seed the cached columns were generated with.
+    seed: u64,
+    /// Terrain shaping the cached columns were generated with.
+    config: TerrainConfig,
+    /// Maximum number of columns kept before evicting the least recently used.
+    capacity: usize,
+    /// Cached columns keyed by world `(x, z)`.
+    entries: HashMap<(i32, i32), CachedColumn>,
+    /// Access order records; entries whose stamp no longer matches are stale.
+    order: VecDeque<((i32, i32), u64)>,
+    /// Monotonic access counter.
+    clock: u64,
+    /// Number of noise evaluations performed on cache misses.
+    evaluations: u64,
+}
+
+impl ColumnCache {
+    /// Default number of cached columns (a 64x64 block area).
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Build an empty cache bounded to `capacity` columns of the `seed` world shaped by `config`.
+    pub fn new(capacity: usize, seed: u64, config: TerrainConfig) -> Self {
+        Self {
+            seed,
+            config,
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            clock: 0,
+            evaluations: 0,
+        }
+    }
+
+    /// Return cached terrain height at `(x, z)`, evaluating noise on a miss.
+    pub fn height_at(&mut self, x: i32, z: i32) -> i32 {
+        self.sample(x, z).height
+    }
+
+    /// Return cached biome at `(x, z)`, evaluating noise on a miss.
+    pub fn biome_at(&mut self, x: i32, z: i32) -> Biome {
+        self.sample(x, z).biome
+    }
+
+    /// Return the cached column sample at `(x, z)`, evaluating noise on a miss.
+    pub fn sample(&mut self, x: i32, z: i32) -> ColumnSample {
+        self.clock += 1;
+        let key = (x, z);
+        let stamp = self.clock;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = stamp;
+            let sample = entry.sample;
+            self.touch(key, stamp);
+            return sample;
+        }
+
+        self.evaluations += 1;
+        let sample = ColumnSample {
+            height: TerrainNoise::height_at(x, z, self.seed, &self.config),
+            biome: TerrainNoise::biome_at(x, z, self.seed, &self.config),
+        };
+        self.entries.insert(
+            key,
+            CachedColumn {
+                sample,
+                last_used: stamp,
+            },
+        );
+        self.touch(key, stamp);
+        self.evict_over_capacity();
+        sample
+    }
+
+    /// Switch to new terrain shaping and forget every column cached under the old one.
+    pub fn set_config(&mut self, config: TerrainConfig) {
+        self.config = config;
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Return how many columns are cached.
+    pub fn cached_columns(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return how many noise evaluations this cache has performed so far.
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations
+    }
+
+    /// Record one access and compact stale order records when they pile up.
+    fn touch(&mut self, key: (i32, i32), stamp: u64) {
+        self.order.push_back((key, stamp));
+        if self.order.len() > self.capacity * 4 {
+            let entries = &self.entries;
+            self.order
+                .retain(|(key, stamp)| entries.get(key).is_some_and(|e| e.last_used == *stamp));
+        }
+    }
+
+    /// Evict least recently used columns until the cache fits its capacity.
+    fn evict_over_capacity(&mut self) {
+        while self.entries.len() > self.capacity {
+            let Some((key, stamp)) = self.order.pop_front() else {
+                break;
+            };
+            if self
+                .entries
+                .get(&key)
+                .is_some_and(|entry| entry.last_used == stamp)
+            {
+                self.entries.remove(&key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{
+        Biome, CHUNK_SIZE, ColumnCache, NoiseBackend, TerrainConfig, TerrainNoise, WorldGenMode,
+    };
+
+    /// Verify the shipped config reproduces the built-in defaults and partial files keep them.
+    #[test]
+    fn terrain_config_parses_from_ron() {
+        let shipped = std::fs::read_to_string(TerrainConfig::PATH).unwrap();
+        assert_eq!(
+            TerrainConfig::parse(&shipped).unwrap(),
+            TerrainConfig::default()
+        );
+
+        let partial = TerrainConfig::parse("(base_height: 12.0)").unwrap();
+        assert_eq!(partial.base_height, 12.0);
+        assert_eq!(
+            partial.terrain_scale,
+            TerrainConfig::default().terrain_scale
+        );
+        assert!(TerrainConfig::parse("(base_height: \"tall\")").is_err());
+    }
+
+    /// Verify biomes are a deterministic function of seed and column, and all of them occur.
+    #[test]
+    fn biomes_are_deterministic_per_seed() {
+        let columns = (-256..256)
+            .step_by(8)
+            .flat_map(|x| (-256..256).step_by(8).map(move |z| (x, z)));
+        let config = TerrainConfig::default();
+        let seen: HashSet<Biome> = columns
+            .clone()
+            .map(|(x, z)| TerrainNoise::biome_at(x, z, 0, &config))
+            .collect();
+        assert_eq!(seen.len(), 3, "missing biomes: {seen:?}");
+        assert!(columns.clone().all(|(x, z)| {
+            TerrainNoise::biome_at(x, z, 0, &config) == TerrainNoise::biome_at(x, z, 0, &config)
+        }));
+        assert!(columns.into_iter().any(|(x, z)| {
+            TerrainNoise::biome_at(x, z, 0, &config) != TerrainNoise::biome_at(x, z, 1, &config)
+        }));
+    }
+
+    /// Verify the heightmap has no seam or mirror image across the x = 0 and z = 0 axes.
+    ///
+    /// Negative coordinates reach the hash through a wrapping `i32 -> u32` cast, which must
+    /// stay as smooth as the positive side.
+    #[test]
+    fn heightmap_has_no_seam_at_origin_axes() {
+        let config = TerrainConfig::default();
+        let height = |x: i32, z: i32| TerrainNoise::height_at(x, z, 0, &config);
+        let mean_step = |x: i32| {
+            let total: i32 = (-512..512)
+                .map(|z| (height(x + 1, z) - height(x, z)).abs())
+                .sum();
+            total as f32 / 1024.0
+        };
+        let typical = [-97, -41, 23, 88].map(mean_step).iter().sum::<f32>() / 4.0;
+        assert!(
+            mean_step(-1) < typical * 2.0,
+            "seam across x = 0: {} vs typical {typical}",
+            mean_step(-1)
+        );
+        let mirrored = (1..512).filter(|&x| height(-x, 7) == height(x, 7)).count();
+        assert!(mirrored < 400, "heightmap mirrors across x = 0");
+        let mirrored = (1..512).filter(|&z| height(7, -z) == height(7, z)).count();
+        assert!(mirrored < 400, "heightmap mirrors across z = 0");
+    }
+
+    /// Verify amplified terrain climbs past the normal cap and islands leave most columns sunk.
+    #[test]
+    fn presets_reshape_heightmap() {
+        let base = TerrainConfig::default();
+        assert_eq!(WorldGenMode::Normal.shape(base), base);
+        assert_eq!(WorldGenMode::Superflat.shape(base), base);
+        let heights = |mode: WorldGenMode| {
+            let config = mode.shape(base);
+            (-64..64)
+                .flat_map(|z| (-64..64).map(move |x| (x * 4, z * 4)))
+                .map(|(x, z)| TerrainNoise::height_at(x, z, 21, &config))
+                .collect::<Vec<_>>()
+        };
+        let normal = heights(WorldGenMode::Normal);
+        let amplified = heights(WorldGenMode::Amplified);
+        let islands = heights(WorldGenMode::Islands);
+        let dry = |heights: &[i32]| {
+            heights
+                .iter()
+                .filter(|&&h| h > TerrainNoise::SEA_LEVEL)
+                .count() as f32
+                / heights.len() as f32
+        };
+        assert_eq!(amplified, heights(WorldGenMode::Amplified));
+        assert_eq!(islands, heights(WorldGenMode::Islands));
+        assert!(amplified.iter().any(|&h| h >= CHUNK_SIZE * 2));
+        assert!(amplified.iter().all(|&h| h < CHUNK_SIZE * 4));
+        assert!(
+            islands
+                .iter()
+                .zip(&normal)
+                .all(|(island, normal)| island <= normal)
+        );
+        assert!(
+            dry(&islands) < dry(&normal) * 0.6,
+            "islands preset left too much dry land"
+        );
+        assert!(dry(&islands) > 0.02, "islands preset has no landmasses");
+    }
+
+    /// Verify both noise backends are deterministic, clamped, and differ.
+    ///
+    /// `gen_snapshot --noise-backends <dir>` renders them side by side for inspection.
+    #[test]
+    fn noise_backends_render_comparable_heightmaps() {
+        const SIZE: i32 = 64;
+        let mut maps = Vec::new();
+        for backend in [NoiseBackend::Value, NoiseBackend::Gradient] {
+            let config = TerrainConfig {
+                noise: backend,
+                ..TerrainConfig::default()
+            };
+            let heights: Vec<i32> = (0..SIZE * SIZE)
+                .map(|i| TerrainNoise::height_at(i % SIZE - 200, i / SIZE, 3, &config))
+                .collect();
+            assert!(heights.iter().all(|h| (1..CHUNK_SIZE * 2).contains(h)));
+            assert_eq!(
+                heights[417],
+                TerrainNoise::height_at(417 % SIZE - 200, 417 / SIZE, 3, &config)
+            );
+            maps.push(heights);
+        }
+        assert_ne!(maps[0], maps[1]);
+    }
+
+    /// Verify repeated column queries hit the cache and match direct evaluation.
+    #[test]
+    fn column_cache_memoizes_repeated_queries() {
+        let config = TerrainConfig::default();
+        let mut cache = ColumnCache::new(16, 7, config);
+        for _ in 0..10 {
+            assert_eq!(
+                cache.height_at(3, -7),
+                TerrainNoise::height_at(3, -7, 7, &config)
+            );
+            assert_eq!(
+                cache.biome_at(3, -7),
+                TerrainNoise::biome_at(3, -7, 7, &config)
+            );
+        }
+        assert_eq!(cache.evaluations(), 1);
+    }
+
+    /// Verify the least recently used column is evicted once capacity is exceeded.
+    #[test]
+    fn column_cache_evicts_least_recently_used() {
+        let mut cache = ColumnCache::new(2, 0, TerrainConfig::default());
+        cache.height_at(0, 0);
+        cache.height_at(1, 0);
+        // Refresh (0, 0) so (1, 0) becomes the eviction candidate.
+        cache.height_at(0, 0);
+        cache.height_at(2, 0);
+        assert_eq!(cache.evaluations(), 3);
+
+        cache.height_at(0, 0);
+        assert_eq!(cache.evaluations(), 3);
+        cache.height_at(1, 0);
+        assert_eq!(cache.evaluations(), 4);
+    }
+
+    /// Time 1,000 frames of a player walking one block every 10 frames while a 17x17
+    /// column area around them is queried each frame, with and without the cache.
+    ///
+    /// Run with `cargo test --release column_cache_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn column_cache_benchmark() {
+        const FRAMES: i32 = 1_000;
+        const REACH: i32 = 8;
+        let config = TerrainConfig::default();
+        let area = |frame: i32| {
+            let center = frame / 10;
+            (-REACH..=REACH).flat_map(move |dz| (-REACH..=REACH).map(move |dx| (center + dx, dz)))
+        };
+
+        let start = std::time::Instant::now();
+        for frame in 0..FRAMES {
+            for (x, z) in area(frame) {
+                std::hint::black_box(TerrainNoise::height_at(x, z, 0, &config));
+                std::hint::black_box(TerrainNoise::biome_at(x, z, 0, &config));
+            }
+        }
+        println!("{FRAMES} frames direct: {:?}", start.elapsed());
+
+        let mut cache = ColumnCache::new(ColumnCache::DEFAULT_CAPACITY, 0, config);
+        let start = std::time::Instant::now();
+        let mut steady = 0;
+        for frame in 0..FRAMES {
+            let before = cache.evaluations();
+            for (x, z) in area(frame) {
+                std::hint::black_box(cache.sample(x, z));
+            }
+            if frame >= FRAMES / 2 {
+                steady += cache.evaluations() - before;
+            }
+        }
+        println!(
+            "{FRAMES} frames cached: {:?}, {:.2} evaluations per steady frame",
+            start.elapsed(),
+            steady as f64 / f64::from(FRAMES / 2)
+        );
+    }
+}
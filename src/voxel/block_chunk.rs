@@ -1,10 +1,14 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
 use crate::material_catalog::TextureId;
-use crate::terrain::TerrainNoise;
-use crate::voxel::block_defs::def_for_block_kind;
+use crate::terrain::{Biome, ChunkRng, Surface, TerrainConfig, TerrainNoise};
 use crate::voxel::block_defs::texture_for_face;
-use crate::{BLOCK_SIZE, CHUNK_SIZE, VERTICAL_CHUNK_LAYERS};
+use crate::voxel::block_defs::{
+    BlockBox, BlockShape, BlockUse, MeshLayer, Opacity, Tool, def_for_block_kind,
+};
+use crate::voxel::tree_defs::{MAX_TREE_REACH, TreeKind, TreeRegistry, TreeTemplate};
+use crate::{BLOCK_SIZE, CHUNK_SIZE, MAX_CHUNK_LAYER, MIN_CHUNK_LAYER};
 
 /// 3D front orientation stored on direction-sensitive blocks.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -24,6 +28,9 @@ pub enum Facing {
 }
 
 impl Facing {
+    /// The four horizontal facings, in the order fence arms are stored in a fence's state.
+    pub const HORIZONTAL: [Self; 4] = [Self::PosX, Self::NegX, Self::PosZ, Self::NegZ];
+
     /// Resolve nearest cardinal 3D facing from a world-space direction vector.
     pub fn from_direction(direction: Vec3) -> Self {
         if direction.length_squared() == 0.0 {
@@ -73,10 +80,22 @@ impl Facing {
             Self::NegZ => IVec3::NEG_Z,
         }
     }
+
+    /// Return this facing turned a quarter turn about +Y (+Z to +X); vertical facings are
+    /// returned unchanged.
+    pub const fn quarter_turn(self) -> Self {
+        match self {
+            Self::PosZ => Self::PosX,
+            Self::PosX => Self::NegZ,
+            Self::NegZ => Self::NegX,
+            Self::NegX => Self::PosZ,
+            Self::PosY | Self::NegY => self,
+        }
+    }
 }
 
 /// Semantic block kind used for behavior/material lookup.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
 pub enum BlockKind {
     /// Empty cell with no geometry or collision.
     Air,
@@ -84,8 +103,106 @@ pub enum BlockKind {
     Dirt,
     /// Dirt block with grass textures on top/sides.
     DirtWithGrass,
+    /// Dirt block with snow on top, capping columns above the snow line.
+    SnowyGrass,
     /// Sand block affected by gravity when unsupported.
     Sand,
+    /// Stone block filling the terrain below the dirt layer.
+    Stone,
+    /// Tree trunk block.
+    Log,
+    /// Tree canopy block.
+    Leaves,
+    /// Static water block below sea level.
+    Water,
+    /// Coal ore generated in stone.
+    CoalOre,
+    /// Iron ore generated deeper in stone.
+    IronOre,
+    /// Unbreakable floor layer at the bottom of the streamed world.
+    Bedrock,
+    /// Cobblestone making up generated ruins.
+    Cobblestone,
+    /// Moss-grown cobblestone mixed into generated ruins.
+    MossyCobblestone,
+    /// Walk-through grass tuft growing on grass blocks.
+    TallGrass,
+    /// Walk-through flower growing on grass blocks.
+    Flower,
+    /// Clear, solid building block.
+    Glass,
+    /// Walk-through light source attached to a solid block below or beside it.
+    Torch,
+    /// Half-height stone block; its front points at the open half of the cell.
+    Slab,
+    /// Stone stairs rising away from their horizontal front.
+    Stairs,
+    /// Explosive block, primed by the ignite key and carving a crater when its fuse runs out.
+    Tnt,
+    /// Wooden post with arms reaching to neighboring fences and solid blocks.
+    Fence,
+    /// Young tree planted on soil, growing into a full tree after a while.
+    Sapling,
+    /// Wooden boards crafted from logs.
+    Planks,
+    /// Workbench that opens the crafting grid when used.
+    CraftingTable,
+    /// Storage block facing the player, holding items in its block entity.
+    Chest,
+    /// Lower half of a door; its front faces the player who placed it and state bit 0 is
+    /// set while it is open.
+    Door,
+    /// Upper half of a door, mirroring the front and open bit of the lower half below it.
+    DoorTop,
+    /// Glowing block giving off full-strength block light.
+    Glowstone,
+    /// Loose gravel falling like sand when unsupported.
+    Gravel,
+    /// Slippery, slightly see-through frozen water.
+    Ice,
+    /// Spiny desert plant growing on sand; hurts the player on contact.
+    Cactus,
+    /// Soaks up the water around it when placed, then stays wet.
+    Sponge,
+}
+
+impl BlockKind {
+    /// Every kind, in declaration order so `ALL[kind as usize] == kind`.
+    pub const ALL: [Self; 33] = [
+        Self::Air,
+        Self::Dirt,
+        Self::DirtWithGrass,
+        Self::SnowyGrass,
+        Self::Sand,
+        Self::Stone,
+        Self::Log,
+        Self::Leaves,
+        Self::Water,
+        Self::CoalOre,
+        Self::IronOre,
+        Self::Bedrock,
+        Self::Cobblestone,
+        Self::MossyCobblestone,
+        Self::TallGrass,
+        Self::Flower,
+        Self::Glass,
+        Self::Torch,
+        Self::Slab,
+        Self::Stairs,
+        Self::Tnt,
+        Self::Fence,
+        Self::Sapling,
+        Self::Planks,
+        Self::CraftingTable,
+        Self::Chest,
+        Self::Door,
+        Self::DoorTop,
+        Self::Glowstone,
+        Self::Gravel,
+        Self::Ice,
+        Self::Cactus,
+        Self::Sponge,
+    ];
 }
 
 /// Voxel block state stored in chunk cells.
@@ -95,14 +212,26 @@ pub struct Block {
     pub kind: BlockKind,
     /// Local "front" direction used to resolve front/back face materials.
     pub front: Facing,
+    /// Kind-specific metadata such as a water level or growth stage (`0` for most kinds).
+    pub state: u8,
 }
 
 impl Block {
+    /// Construct a `kind` block facing +Z with zero state, as an item of that kind shows.
+    pub const fn of_kind(kind: BlockKind) -> Self {
+        Self {
+            kind,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
     /// Construct an air block.
     pub fn air() -> Self {
         Self {
             kind: BlockKind::Air,
             front: Facing::PosZ,
+            state: 0,
         }
     }
 
@@ -111,6 +240,7 @@ impl Block {
         Self {
             kind: BlockKind::Dirt,
             front: Facing::PosZ,
+            state: 0,
         }
     }
 
@@ -119,6 +249,7 @@ impl Block {
         Self {
             kind: BlockKind::Dirt,
             front,
+            state: 0,
         }
     }
 
@@ -127,6 +258,7 @@ impl Block {
         Self {
             kind: BlockKind::DirtWithGrass,
             front: Facing::PosZ,
+            state: 0,
         }
     }
 
@@ -135,6 +267,25 @@ impl Block {
         Self {
             kind: BlockKind::DirtWithGrass,
             front,
+            state: 0,
+        }
+    }
+
+    /// Construct a snow-capped dirt block.
+    pub fn snowy_grass() -> Self {
+        Self {
+            kind: BlockKind::SnowyGrass,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a snow-capped dirt block with an explicit local front.
+    pub fn snowy_grass_facing(front: Facing) -> Self {
+        Self {
+            kind: BlockKind::SnowyGrass,
+            front,
+            state: 0,
         }
     }
 
@@ -143,6 +294,7 @@ impl Block {
         Self {
             kind: BlockKind::Sand,
             front: Facing::PosZ,
+            state: 0,
         }
     }
 
@@ -151,6 +303,373 @@ impl Block {
         Self {
             kind: BlockKind::Sand,
             front,
+            state: 0,
+        }
+    }
+
+    /// Construct a gravel block.
+    pub fn gravel() -> Self {
+        Self {
+            kind: BlockKind::Gravel,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a gravel block with an explicit local front.
+    pub fn gravel_facing(front: Facing) -> Self {
+        Self {
+            kind: BlockKind::Gravel,
+            front,
+            state: 0,
+        }
+    }
+
+    /// Construct a stone block.
+    pub fn stone() -> Self {
+        Self {
+            kind: BlockKind::Stone,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a stone block with an explicit local front.
+    pub fn stone_facing(front: Facing) -> Self {
+        Self {
+            kind: BlockKind::Stone,
+            front,
+            state: 0,
+        }
+    }
+
+    /// Construct an upright tree trunk block.
+    #[cfg(test)]
+    pub fn log() -> Self {
+        Self {
+            kind: BlockKind::Log,
+            front: Facing::PosY,
+            state: 0,
+        }
+    }
+
+    /// Construct a tree canopy block.
+    pub fn leaves() -> Self {
+        Self {
+            kind: BlockKind::Leaves,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a bedrock block.
+    pub fn bedrock() -> Self {
+        Self {
+            kind: BlockKind::Bedrock,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a cobblestone block.
+    pub fn cobblestone() -> Self {
+        Self {
+            kind: BlockKind::Cobblestone,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a moss-grown cobblestone block.
+    pub fn mossy_cobblestone() -> Self {
+        Self {
+            kind: BlockKind::MossyCobblestone,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a tall grass decoration.
+    pub fn tall_grass() -> Self {
+        Self {
+            kind: BlockKind::TallGrass,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a flower decoration.
+    pub fn flower() -> Self {
+        Self {
+            kind: BlockKind::Flower,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a sapling.
+    pub fn sapling() -> Self {
+        Self {
+            kind: BlockKind::Sapling,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a glass block.
+    pub fn glass() -> Self {
+        Self {
+            kind: BlockKind::Glass,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a torch standing on the block below it.
+    pub fn torch() -> Self {
+        Self {
+            kind: BlockKind::Torch,
+            front: Facing::PosY,
+            state: 0,
+        }
+    }
+
+    /// Construct a slab filling the lower half of its cell.
+    pub fn slab() -> Self {
+        Self {
+            kind: BlockKind::Slab,
+            front: Facing::PosY,
+            state: 0,
+        }
+    }
+
+    /// Construct stairs rising towards -Z.
+    pub fn stairs() -> Self {
+        Self {
+            kind: BlockKind::Stairs,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a crafting table.
+    pub fn crafting_table() -> Self {
+        Self {
+            kind: BlockKind::CraftingTable,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a chest facing +Z.
+    pub fn chest() -> Self {
+        Self {
+            kind: BlockKind::Chest,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct the closed lower half of a door facing +Z, the item placing a whole door.
+    pub fn door() -> Self {
+        Self {
+            kind: BlockKind::Door,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a glowstone block.
+    pub fn glowstone() -> Self {
+        Self {
+            kind: BlockKind::Glowstone,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct an ice block.
+    pub fn ice() -> Self {
+        Self {
+            kind: BlockKind::Ice,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a cactus block.
+    #[cfg(test)]
+    pub fn cactus() -> Self {
+        Self {
+            kind: BlockKind::Cactus,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a dry sponge.
+    pub fn sponge() -> Self {
+        Self {
+            kind: BlockKind::Sponge,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a TNT block.
+    pub fn tnt() -> Self {
+        Self {
+            kind: BlockKind::Tnt,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Construct a fence post with no arms.
+    pub fn fence() -> Self {
+        Self {
+            kind: BlockKind::Fence,
+            front: Facing::PosZ,
+            state: 0,
+        }
+    }
+
+    /// Return a copy of this slab moved into the upper or lower half of its cell.
+    ///
+    /// Blocks other than slabs fill their whole cell and are returned unchanged.
+    pub fn with_slab_half(self, upper: bool) -> Self {
+        if self.kind != BlockKind::Slab {
+            return self;
+        }
+        Self {
+            front: if upper { Facing::NegY } else { Facing::PosY },
+            ..self
+        }
+    }
+
+    /// Return the boxes this block collides with, in block-local unit space.
+    ///
+    /// Boxes turn with the front, so stairs rise away from their front and a slab whose
+    /// front points down fills the upper half of its cell. An open door's panel is turned a
+    /// further quarter turn, clearing the doorway.
+    pub fn collision_boxes(&self) -> impl Iterator<Item = BlockBox> + use<> {
+        let front = if self.is_open_door() {
+            self.front.quarter_turn()
+        } else {
+            self.front
+        };
+        def_for_block_kind(self.kind)
+            .collision_boxes
+            .iter()
+            .map(move |collision_box| collision_box.oriented(front))
+            .chain(
+                self.fence_arms()
+                    .map(|facing| BlockBox::FENCE_ARM.oriented(facing)),
+            )
+    }
+
+    /// Return `true` if this block collides as exactly its whole cell, letting collision
+    /// checks skip walking its boxes.
+    pub fn fills_cell(&self) -> bool {
+        def_for_block_kind(self.kind).collision_boxes == [BlockBox::FULL]
+    }
+
+    /// Return the horizontal directions this fence reaches an arm towards.
+    ///
+    /// Arms are kept as one bit per `Facing::HORIZONTAL` entry in the state byte; blocks
+    /// other than fences have none.
+    pub fn fence_arms(&self) -> impl Iterator<Item = Facing> + use<> {
+        let arms = if self.shape() == BlockShape::Fence {
+            self.state
+        } else {
+            0
+        };
+        Facing::HORIZONTAL
+            .into_iter()
+            .enumerate()
+            .filter(move |(bit, _)| arms & (1 << bit) != 0)
+            .map(|(_, facing)| facing)
+    }
+
+    /// Return a copy of this fence with an arm towards each facing `connected` accepts.
+    pub fn with_fence_arms(self, connected: impl Fn(Facing) -> bool) -> Self {
+        let arms = Facing::HORIZONTAL
+            .into_iter()
+            .enumerate()
+            .filter(|(_, facing)| connected(*facing))
+            .fold(0, |arms, (bit, _)| arms | (1 << bit));
+        self.with_state(arms)
+    }
+
+    /// Return `true` if this is a door half swung open.
+    pub fn is_open_door(&self) -> bool {
+        self.shape() == BlockShape::Door && self.state & DOOR_OPEN != 0
+    }
+
+    /// Return a copy of this door half swung open or shut; other blocks are unchanged.
+    pub fn with_door_open(self, open: bool) -> Self {
+        if self.shape() != BlockShape::Door {
+            return self;
+        }
+        self.with_state(if open {
+            self.state | DOOR_OPEN
+        } else {
+            self.state & !DOOR_OPEN
+        })
+    }
+
+    /// Return `true` if this is a sponge that already soaked up water.
+    pub fn is_wet_sponge(&self) -> bool {
+        self.kind == BlockKind::Sponge && self.state & SPONGE_WET != 0
+    }
+
+    /// Return the item this block becomes in the inventory: its kind facing +Z, keeping only
+    /// the state that survives being carried, such as a sponge's wetness.
+    pub fn as_item(self) -> Self {
+        let kept = if self.kind == BlockKind::Sponge {
+            self.state & SPONGE_WET
+        } else {
+            0
+        };
+        Self::of_kind(self.kind).with_state(kept)
+    }
+
+    /// Return a copy of this sponge soaked with water; other blocks are unchanged.
+    pub fn soaked(self) -> Self {
+        if self.kind != BlockKind::Sponge {
+            return self;
+        }
+        self.with_state(self.state | SPONGE_WET)
+    }
+
+    /// Return the upper half standing on this lower door half, sharing its front and state.
+    pub fn door_upper_half(self) -> Self {
+        Self {
+            kind: BlockKind::DoorTop,
+            ..self
+        }
+    }
+
+    /// Return the offset from this door half to the other half, or `None` for other blocks.
+    pub fn door_partner_offset(&self) -> Option<IVec3> {
+        match self.kind {
+            BlockKind::Door => Some(IVec3::Y),
+            BlockKind::DoorTop => Some(IVec3::NEG_Y),
+            _ => None,
+        }
+    }
+
+    /// Return `true` if a fence beside this block reaches an arm to it.
+    pub fn connects_fences(&self) -> bool {
+        self.shape() == BlockShape::Fence || self.culls_neighbors()
+    }
+
+    /// Construct a water block.
+    pub fn water() -> Self {
+        Self {
+            kind: BlockKind::Water,
+            front: Facing::PosZ,
+            state: 0,
         }
     }
 
@@ -164,21 +683,131 @@ impl Block {
         def_for_block_kind(self.kind).stable
     }
 
+    /// Return a copy of this block carrying metadata byte `state`.
+    pub fn with_state(self, state: u8) -> Self {
+        Self { state, ..self }
+    }
+
+    /// Return `true` if breaking this block with `held` (or bare-handed) leaves an item behind.
+    pub fn drops_with(&self, held: Option<Tool>) -> bool {
+        def_for_block_kind(self.kind).yields_drop(held)
+    }
+
     /// Return `true` if interaction systems can operate on this block.
     pub fn is_interactable(&self) -> bool {
         def_for_block_kind(self.kind).interactable
     }
 
+    /// Return what using this block does, if interaction systems can operate on it at all.
+    pub fn use_action(&self) -> Option<BlockUse> {
+        let def = def_for_block_kind(self.kind);
+        def.on_use.filter(|_| def.interactable)
+    }
+
+    /// Return the block light level this block gives off.
+    pub fn light_emission(&self) -> u8 {
+        def_for_block_kind(self.kind).light_emission
+    }
+
+    /// Return how quickly a player standing on this block reaches their walking velocity.
+    pub fn friction(&self) -> f32 {
+        def_for_block_kind(self.kind).friction
+    }
+
+    /// Return `true` if this block stops light from passing through its cell.
+    pub fn blocks_light(&self) -> bool {
+        self.culls_neighbors()
+    }
+
+    /// Return whether grass directly beneath this block dies to dirt.
+    ///
+    /// Blocks that stop light and water smother grass; glass, slabs and other blocks that
+    /// let light through do not.
+    pub fn smothers_grass(&self) -> bool {
+        self.blocks_light() || self.kind == BlockKind::Water
+    }
+
     /// Return `true` if this block occupies space (non-air).
     pub fn is_solid(&self) -> bool {
         def_for_block_kind(self.kind).solid
     }
 
+    /// Return `true` if this block is a cross-shaped decoration.
+    ///
+    /// Decorations can be aimed at and broken, but never support placements or other
+    /// blocks, and pop off when the block below them is gone.
+    pub fn is_decoration(&self) -> bool {
+        def_for_block_kind(self.kind).shape == BlockShape::Cross
+    }
+
+    /// Return the seconds breaking this block with `held` (or bare-handed) takes while holding
+    /// the break button.
+    ///
+    /// The preferred tool speeds breaking up; tier-gated blocks take longer than their base
+    /// hardness without an adequate tool.
+    pub fn hardness(&self, held: Option<Tool>) -> f32 {
+        let def = def_for_block_kind(self.kind);
+        def.hardness / def.break_speed_multiplier(held)
+    }
+
+    /// Return `true` if the interaction ray stops at this block.
+    pub fn is_targetable(&self) -> bool {
+        self.is_solid() || self.support_offset().is_some()
+    }
+
+    /// Return the offset to the block this one hangs off, or `None` for free-standing blocks.
+    ///
+    /// Decorations and doors stand on the block below; torches attach to the block their
+    /// front points away from.
+    pub fn support_offset(&self) -> Option<IVec3> {
+        match self.shape() {
+            BlockShape::Cube | BlockShape::Slab | BlockShape::Stairs | BlockShape::Fence => None,
+            BlockShape::Cross | BlockShape::Door | BlockShape::Cactus => Some(IVec3::NEG_Y),
+            BlockShape::Torch => Some(-self.front.as_normal()),
+        }
+    }
+
+    /// Return whether this block can hang off or rest on `support`.
+    ///
+    /// Saplings need soil beneath them, cacti sand or another cactus, and an upper door
+    /// half its lower half; every other attached block takes any solid support.
+    pub fn rests_on(&self, support: Block) -> bool {
+        match self.kind {
+            BlockKind::Cactus => matches!(support.kind, BlockKind::Sand | BlockKind::Cactus),
+            BlockKind::Sapling => matches!(
+                support.kind,
+                BlockKind::Dirt | BlockKind::DirtWithGrass | BlockKind::SnowyGrass
+            ),
+            BlockKind::DoorTop => support.kind == BlockKind::Door,
+            _ => support.is_solid(),
+        }
+    }
+
+    /// Return the geometry the chunk mesher emits for this block.
+    pub fn shape(&self) -> BlockShape {
+        def_for_block_kind(self.kind).shape
+    }
+
+    /// Return the chunk mesh this block's faces are emitted into.
+    pub fn mesh_layer(&self) -> MeshLayer {
+        def_for_block_kind(self.kind).layer
+    }
+
+    /// Return how much of what lies behind this block shows through.
+    pub fn opacity(&self) -> Opacity {
+        def_for_block_kind(self.kind).opacity
+    }
+
     /// Resolve atlas texture id for one face normal.
     pub fn texture_for_face(&self, normal: IVec3) -> TextureId {
         texture_for_face(*self, normal)
     }
 
+    /// Return whether this block hides neighbor faces touching it.
+    pub fn culls_neighbors(&self) -> bool {
+        self.opacity() == Opacity::Opaque
+    }
+
     /// Return a copy of this block whose front matches the given world-space direction.
     pub fn with_front_from_direction(self, direction: Vec3) -> Self {
         let front = if def_for_block_kind(self.kind).allow_vertical_front {
@@ -189,8 +818,52 @@ impl Block {
         match self.kind {
             BlockKind::Dirt => Self::dirt_facing(front),
             BlockKind::DirtWithGrass => Self::dirt_with_grass_facing(front),
+            BlockKind::SnowyGrass => Self::snowy_grass_facing(front),
             BlockKind::Sand => Self::sand_facing(front),
-            BlockKind::Air => self,
+            BlockKind::Gravel => Self::gravel_facing(front),
+            BlockKind::Stone => Self::stone_facing(front),
+            BlockKind::Log
+            | BlockKind::Leaves
+            | BlockKind::CoalOre
+            | BlockKind::IronOre
+            | BlockKind::Cobblestone
+            | BlockKind::MossyCobblestone
+            | BlockKind::Stairs
+            | BlockKind::Chest
+            | BlockKind::Door
+            | BlockKind::DoorTop => Self { front, ..self },
+            BlockKind::Air
+            | BlockKind::Water
+            | BlockKind::Bedrock
+            | BlockKind::TallGrass
+            | BlockKind::Flower
+            | BlockKind::Glass
+            | BlockKind::Torch
+            | BlockKind::Slab
+            | BlockKind::Tnt
+            | BlockKind::Fence
+            | BlockKind::Sapling
+            | BlockKind::Planks
+            | BlockKind::CraftingTable
+            | BlockKind::Glowstone
+            | BlockKind::Ice
+            | BlockKind::Cactus
+            | BlockKind::Sponge => self,
+        }
+    }
+
+    /// Return a copy of this block oriented for placement against the face with `face_normal`.
+    ///
+    /// Axis blocks such as logs align with the clicked face; everything else faces the viewer
+    /// looking along `forward`.
+    pub fn oriented_for_placement(self, forward: Vec3, face_normal: IVec3) -> Self {
+        if def_for_block_kind(self.kind).axis_from_face {
+            Self {
+                front: Facing::from_direction(face_normal.as_vec3()),
+                ..self
+            }
+        } else {
+            self.with_front_from_direction(-forward)
         }
     }
 
@@ -213,10 +886,68 @@ impl Block {
     }
 }
 
+/// Door state bit set while the door is open.
+const DOOR_OPEN: u8 = 1;
+/// Sponge state bit set once the sponge has soaked up water.
+const SPONGE_WET: u8 = 1;
+
+/// Tunable vein shape for one generated ore.
+struct OreVein {
+    /// Ore block kind written into stone.
+    kind: BlockKind,
+    /// Vein attempts per chunk.
+    veins_per_chunk: i32,
+    /// Random-walk steps per vein (upper bound on its block count).
+    vein_size: i32,
+    /// Highest world y a vein block can occupy.
+    max_y: i32,
+}
+
+/// Ore veins generated in stone, rolled in this order from each chunk's RNG stream.
+const ORE_VEINS: [OreVein; 2] = [
+    OreVein {
+        kind: BlockKind::CoalOre,
+        veins_per_chunk: 6,
+        vein_size: 8,
+        max_y: 24,
+    },
+    OreVein {
+        kind: BlockKind::IronOre,
+        veins_per_chunk: 3,
+        vein_size: 5,
+        max_y: 12,
+    },
+];
+
+/// Salt of the per-chunk RNG stream used for ore veins.
+const ORE_RNG_SALT: u64 = 0x0de5_7a7e;
+
+/// Salt of the per-column RNG stream used for ruins.
+const RUIN_RNG_SALT: u64 = 0x7a11_5eed;
+/// Chance in thousandths that a chunk column holds a ruin.
+const RUIN_CHANCE_PER_MILLE: i32 = 40;
+/// Edge length of a ruin footprint in blocks.
+const RUIN_SIZE: i32 = 5;
+/// Tallest standing wall segment of a ruin.
+const RUIN_MAX_WALL: i32 = 3;
+/// Largest surface height difference across a footprint that still gets a ruin.
+const RUIN_MAX_SLOPE: i32 = 2;
+
+/// Salt of the per-column roll used for grass decorations.
+const DECORATION_SALT: u64 = 0xf10e_7a11;
+/// Chance that a grass column grows a flower.
+const FLOWER_CHANCE: f32 = 0.02;
+/// Chance that a grass column grows a flower or tall grass.
+const DECORATION_CHANCE: f32 = 0.14;
+
 /// Pure voxel storage for one chunk (no ECS/render handles).
+#[derive(Clone)]
 pub struct Chunk {
     /// Flat storage for CHUNK_SIZE^3 blocks in local chunk coordinates.
     blocks: Vec<Block>,
+    /// Biome of each column in `x + z * CHUNK_SIZE` order, or `None` for chunks not
+    /// generated from biome noise, whose columns all count as plains.
+    biomes: Option<Box<[Biome]>>,
 }
 
 impl Chunk {
@@ -229,49 +960,290 @@ impl Chunk {
         )
     }
 
-    /// Build terrain chunk for valid vertical layers, otherwise return an empty chunk.
-    pub fn new_streaming(coord: IVec3) -> Self {
-        if (0..VERTICAL_CHUNK_LAYERS).contains(&coord.y) {
-            Self::new_terrain(coord)
-        } else {
-            Self::new_empty()
+    /// Number of sub-surface blocks between the surface block and the stone below.
+    const DIRT_DEPTH: i32 = 3;
+
+    /// Return the `(surface, sub-surface)` blocks of a `biome` column of `height`.
+    ///
+    /// The surface follows `TerrainNoise::surface_for`; deserts keep sand under their sand.
+    fn surface_blocks(
+        biome: Biome,
+        height: i32,
+        snow_line: i32,
+        sand_patch: bool,
+    ) -> (Block, Block) {
+        match TerrainNoise::surface_for(biome, height, snow_line, sand_patch) {
+            Surface::SnowyGrass => (Block::snowy_grass(), Block::dirt()),
+            Surface::Sand if biome == Biome::Desert => (Block::sand(), Block::sand()),
+            Surface::Sand => (Block::sand(), Block::dirt()),
+            Surface::Grass => (Block::dirt_with_grass(), Block::dirt()),
+            Surface::Stone => (Block::stone(), Block::stone()),
+            Surface::Dirt => (Block::dirt(), Block::dirt()),
         }
     }
 
-    /// Generate terrain blocks for one chunk from the heightmap function.
-    pub fn new_terrain(coord: IVec3) -> Self {
+    /// Return whether chunk layer `layer` lies in the streamed vertical window.
+    pub fn is_streaming_layer(layer: i32) -> bool {
+        (MIN_CHUNK_LAYER..MAX_CHUNK_LAYER).contains(&layer)
+    }
+
+    /// Generate terrain blocks for one chunk from the `seed` world heightmap shaped by `config`.
+    pub fn new_terrain(coord: IVec3, seed: u64, config: &TerrainConfig) -> Self {
         let mut chunk = Self::new_empty();
+        let mut biomes = Vec::with_capacity((CHUNK_SIZE * CHUNK_SIZE) as usize);
         let base_x = coord.x * CHUNK_SIZE;
         let base_y = coord.y * CHUNK_SIZE;
         let base_z = coord.z * CHUNK_SIZE;
         for z in 0..CHUNK_SIZE {
             for x in 0..CHUNK_SIZE {
-                let height = TerrainNoise::height_at(base_x + x, base_z + z);
+                let (world_x, world_z) = (base_x + x, base_z + z);
+                let height = TerrainNoise::height_at(world_x, world_z, seed, config);
+                let biome = TerrainNoise::biome_at(world_x, world_z, seed, config);
+                biomes.push(biome);
+                let snow_line = TerrainNoise::snow_line_at(world_x, world_z, seed);
+                let sand_patch = TerrainNoise::is_sand_patch(world_x, world_z, height, seed);
+                let (surface, sub_surface) =
+                    Self::surface_blocks(biome, height, snow_line, sand_patch);
+                let layer_at = |world_y: i32| {
+                    if world_y == TerrainNoise::BEDROCK_Y {
+                        Block::bedrock()
+                    } else if world_y == height {
+                        surface
+                    } else if world_y >= height - Self::DIRT_DEPTH {
+                        sub_surface
+                    } else {
+                        Block::stone()
+                    }
+                };
                 for y in 0..CHUNK_SIZE {
                     let world_y = base_y + y;
-                    if world_y > height {
+                    // Never carve under sand, so generated sand never starts falling.
+                    let supports_sand =
+                        world_y < height && layer_at(world_y + 1).kind == BlockKind::Sand;
+                    if world_y > height
+                        || world_y < TerrainNoise::BEDROCK_Y
+                        || (!supports_sand
+                            && TerrainNoise::is_cave(world_x, world_y, world_z, height, seed))
+                    {
                         continue;
                     }
-                    let block = if world_y == height {
-                        Block::dirt_with_grass()
+                    chunk.set_block(IVec3::new(x, y, z), layer_at(world_y));
+                }
+            }
+        }
+        chunk.biomes = Some(biomes.into());
+        chunk.place_ores(coord, seed, config);
+        chunk.place_trees(coord, seed, config);
+        chunk.place_ruin(coord, seed, config);
+        chunk.fill_water(coord, seed, config);
+        chunk.place_decorations(coord, seed);
+        chunk
+    }
+
+    /// Sprinkle tall grass and flowers on open grass surfaces from per-column rolls.
+    ///
+    /// Runs last so trees, ruins, and water keep their cells. Only surfaces with the cell
+    /// above inside this chunk are decorated, so surfaces on a layer's top slice stay bare.
+    fn place_decorations(&mut self, coord: IVec3, seed: u64) {
+        let base = coord * CHUNK_SIZE;
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let roll = TerrainNoise::column_roll(base.x + x, base.z + z, seed, DECORATION_SALT);
+                if roll >= DECORATION_CHANCE {
+                    continue;
+                }
+                let Some(y) = (0..CHUNK_SIZE - 1)
+                    .rev()
+                    .find(|y| !self.get_block(IVec3::new(x, *y, z)).is_air())
+                else {
+                    continue;
+                };
+                let above = IVec3::new(x, y + 1, z);
+                if !self.get_block(above).is_air() {
+                    continue;
+                }
+                if self.get_block(IVec3::new(x, y, z)).kind == BlockKind::DirtWithGrass {
+                    let decoration = if roll < FLOWER_CHANCE {
+                        Block::flower()
                     } else {
-                        Block::dirt()
+                        Block::tall_grass()
                     };
-                    chunk.set_block(IVec3::new(x, y, z), block);
+                    self.set_block(above, decoration);
                 }
             }
         }
-        chunk
+    }
+
+    /// Grow blob-shaped ore veins by random walks through this chunk's stone.
+    ///
+    /// Veins only replace stone below the column surface, so air, caves, and exposed
+    /// mountain peaks stay untouched.
+    fn place_ores(&mut self, coord: IVec3, seed: u64, config: &TerrainConfig) {
+        let base = coord * CHUNK_SIZE;
+        let mut rng = ChunkRng::new(coord, seed, ORE_RNG_SALT);
+        for ore in &ORE_VEINS {
+            for _ in 0..ore.veins_per_chunk {
+                let mut cell = IVec3::new(
+                    rng.below(CHUNK_SIZE),
+                    rng.below(CHUNK_SIZE),
+                    rng.below(CHUNK_SIZE),
+                );
+                for _ in 0..ore.vein_size {
+                    let world = base + cell;
+                    if world.y <= ore.max_y
+                        && self.get_block(cell).kind == BlockKind::Stone
+                        && world.y < TerrainNoise::height_at(world.x, world.z, seed, config)
+                    {
+                        self.set_block(
+                            cell,
+                            Block {
+                                kind: ore.kind,
+                                front: Facing::PosZ,
+                                state: 0,
+                            },
+                        );
+                    }
+                    let axis = rng.below(3) as usize;
+                    cell[axis] += if rng.below(2) == 0 { -1 } else { 1 };
+                }
+            }
+        }
+    }
+
+    /// Stamp a ruin (cobblestone floor with broken walls) if this chunk column rolls one.
+    ///
+    /// The roll ignores `coord.y`, so every vertical layer agrees on the ruin and writes
+    /// its own slice. The footprint always lies inside the chunk, follows the column
+    /// heights, and is skipped on flooded or steep sites.
+    fn place_ruin(&mut self, coord: IVec3, seed: u64, config: &TerrainConfig) {
+        let mut rng = ChunkRng::new(IVec3::new(coord.x, 0, coord.z), seed, RUIN_RNG_SALT);
+        if rng.below(1000) >= RUIN_CHANCE_PER_MILLE {
+            return;
+        }
+        let origin_x = rng.below(CHUNK_SIZE - RUIN_SIZE + 1);
+        let origin_z = rng.below(CHUNK_SIZE - RUIN_SIZE + 1);
+        let base = coord * CHUNK_SIZE;
+        let mut footprint = Vec::with_capacity((RUIN_SIZE * RUIN_SIZE) as usize);
+        for dz in 0..RUIN_SIZE {
+            for dx in 0..RUIN_SIZE {
+                let (x, z) = (origin_x + dx, origin_z + dz);
+                let height = TerrainNoise::height_at(base.x + x, base.z + z, seed, config);
+                if TerrainNoise::water_level_at(base.x + x, base.z + z, seed, config) > height {
+                    return;
+                }
+                let edge = dx == 0 || dz == 0 || dx == RUIN_SIZE - 1 || dz == RUIN_SIZE - 1;
+                let wall = if edge {
+                    rng.below(RUIN_MAX_WALL + 1)
+                } else {
+                    0
+                };
+                footprint.push((x, z, height, wall));
+            }
+        }
+        let heights = footprint.iter().map(|&(_, _, height, _)| height);
+        if heights.clone().max().unwrap() - heights.min().unwrap() > RUIN_MAX_SLOPE {
+            return;
+        }
+        for (x, z, height, wall) in footprint {
+            for world_y in height..=height + RUIN_MAX_WALL {
+                // Roll before clipping so every layer sees the same stream.
+                let mossy = rng.below(3) == 0;
+                let local_y = world_y - base.y;
+                if !(0..CHUNK_SIZE).contains(&local_y) {
+                    continue;
+                }
+                let block = if world_y > height + wall {
+                    Block::air()
+                } else if mossy {
+                    Block::mossy_cobblestone()
+                } else {
+                    Block::cobblestone()
+                };
+                self.set_block(IVec3::new(x, local_y, z), block);
+            }
+        }
+    }
+
+    /// Fill open air between each column's surface and its water level with water.
+    fn fill_water(&mut self, coord: IVec3, seed: u64, config: &TerrainConfig) {
+        let base = coord * CHUNK_SIZE;
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let (world_x, world_z) = (base.x + x, base.z + z);
+                let height = TerrainNoise::height_at(world_x, world_z, seed, config);
+                let level = TerrainNoise::water_level_at(world_x, world_z, seed, config);
+                for world_y in (height + 1).max(base.y)..=level.min(base.y + CHUNK_SIZE - 1) {
+                    let local = IVec3::new(x, world_y - base.y, z);
+                    if self.get_block(local).is_air() {
+                        self.set_block(local, Block::water());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stamp trees rooted in this chunk or close enough for their canopy to reach into it.
+    ///
+    /// Each biome grows its own schematic at its own density, rooted only on that
+    /// schematic's soil, so every chunk a tree touches stamps the same tree.
+    fn place_trees(&mut self, coord: IVec3, seed: u64, config: &TerrainConfig) {
+        let base = coord * CHUNK_SIZE;
+        let registry = TreeRegistry::global();
+        for z in base.z - MAX_TREE_REACH..base.z + CHUNK_SIZE + MAX_TREE_REACH {
+            for x in base.x - MAX_TREE_REACH..base.x + CHUNK_SIZE + MAX_TREE_REACH {
+                let biome = TerrainNoise::biome_at(x, z, seed, config);
+                let Some(pick) = TerrainNoise::tree_at(x, z, seed, biome) else {
+                    continue;
+                };
+                let ground = TerrainNoise::height_at(x, z, seed, config);
+                if ground < TerrainNoise::SEA_LEVEL || TerrainNoise::river_mask(x, z, seed) > 0.0 {
+                    continue;
+                }
+                let template = registry.template(TreeKind::for_biome(biome));
+                let (surface, _) = Self::surface_blocks(
+                    biome,
+                    ground,
+                    TerrainNoise::snow_line_at(x, z, seed),
+                    TerrainNoise::is_sand_patch(x, z, ground, seed),
+                );
+                if !template.soil.contains(&surface.kind) {
+                    continue;
+                }
+                let trunk_height = template.trunk_height_for(pick);
+                self.stamp_tree(base, template, IVec3::new(x, ground, z), trunk_height);
+            }
+        }
+    }
+
+    /// Write one `template` tree rooted on world `ground` into this chunk.
+    ///
+    /// Only cells inside the chunk are written, following `TreeTemplate::fits`.
+    fn stamp_tree(
+        &mut self,
+        base: IVec3,
+        template: &TreeTemplate,
+        ground: IVec3,
+        trunk_height: i32,
+    ) {
+        for (cell, block) in template.blocks(ground, trunk_height) {
+            let local = cell - base;
+            if Self::in_bounds(local) && template.fits(block, self.get_block(local)) {
+                self.set_block(local, block);
+            }
+        }
     }
 
     /// Create an empty chunk filled with air blocks.
     pub fn new_empty() -> Self {
         let blocks = vec![Block::air(); (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize];
-        Self { blocks }
+        Self {
+            blocks,
+            biomes: None,
+        }
     }
 
     /// Convert local `(x, y, z)` coordinates to flat storage index.
-    fn index(local: IVec3) -> usize {
+    pub(crate) fn index(local: IVec3) -> usize {
         (local.x + local.y * CHUNK_SIZE + local.z * CHUNK_SIZE * CHUNK_SIZE) as usize
     }
 
@@ -282,6 +1254,16 @@ impl Chunk {
             && (0..CHUNK_SIZE).contains(&local.z)
     }
 
+    /// Return the biome of the column at local `x`, `z` (plains when out of bounds).
+    pub fn biome(&self, x: i32, z: i32) -> Biome {
+        match &self.biomes {
+            Some(biomes) if (0..CHUNK_SIZE).contains(&x) && (0..CHUNK_SIZE).contains(&z) => {
+                biomes[(x + z * CHUNK_SIZE) as usize]
+            }
+            _ => Biome::Plains,
+        }
+    }
+
     /// Read a block at local coordinates (returns air when out of bounds).
     pub fn get_block(&self, local: IVec3) -> Block {
         if !Self::in_bounds(local) {
@@ -298,11 +1280,46 @@ impl Chunk {
         let index = Self::index(local);
         self.blocks[index] = block;
     }
+
+    /// Read the metadata byte of the block at local coordinates (`0` when out of bounds).
+    pub fn get_state(&self, local: IVec3) -> u8 {
+        self.get_block(local).state
+    }
+
+    /// Write the metadata byte of the block at local coordinates, keeping its kind and front
+    /// (ignores out-of-bounds writes).
+    pub fn set_state(&mut self, local: IVec3, state: u8) {
+        if !Self::in_bounds(local) {
+            return;
+        }
+        self.blocks[Self::index(local)].state = state;
+    }
+
+    /// Return the local coordinates and blocks of every torch and glowstone in this chunk,
+    /// the blocks that get a pooled point light.
+    pub fn light_sources(&self) -> Vec<(IVec3, Block)> {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| matches!(block.kind, BlockKind::Torch | BlockKind::Glowstone))
+            .map(|(index, block)| {
+                let index = index as i32;
+                let local = IVec3::new(
+                    index % CHUNK_SIZE,
+                    index / CHUNK_SIZE % CHUNK_SIZE,
+                    index / (CHUNK_SIZE * CHUNK_SIZE),
+                );
+                (local, *block)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Block;
+    use bevy::prelude::IVec3;
+
+    use super::{Block, BlockKind, BlockUse, Chunk};
 
     /// Verify stable/falling classification for all current block variants.
     #[test]
@@ -324,5 +1341,514 @@ mod tests {
         let sand = Block::sand();
         assert!(sand.is_solid());
         assert!(!sand.is_stable());
+
+        let gravel = Block::gravel();
+        assert!(gravel.is_solid());
+        assert!(!gravel.is_stable());
+
+        let stone = Block::stone();
+        assert!(stone.is_solid());
+        assert!(stone.is_stable());
+        assert!(stone.is_interactable());
+        assert_eq!(stone.use_action(), None);
+        assert_eq!(Block::crafting_table().use_action(), Some(BlockUse::Craft));
+    }
+
+    /// Verify decorations are deterministic and grow only on open grass, that cacti stand on
+    /// sand, and that flowers and cacti both occur.
+    #[test]
+    fn decorations_grow_on_grass() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::TerrainConfig;
+
+        let config = TerrainConfig::default();
+        let mut kinds = Vec::new();
+        for x in -4..4 {
+            for z in -4..4 {
+                let coord = IVec3::new(x, 0, z);
+                let chunk = Chunk::new_terrain(coord, 0, &config);
+                assert_eq!(chunk.blocks, Chunk::new_terrain(coord, 0, &config).blocks);
+                let cells = (0..CHUNK_SIZE).flat_map(|y| {
+                    (0..CHUNK_SIZE)
+                        .flat_map(move |z| (0..CHUNK_SIZE).map(move |x| IVec3::new(x, y, z)))
+                });
+                for cell in cells {
+                    let block = chunk.get_block(cell);
+                    if block.kind == BlockKind::Cactus {
+                        assert!(block.rests_on(chunk.get_block(cell + IVec3::NEG_Y)));
+                        kinds.push(block.kind);
+                    }
+                    if !block.is_decoration() {
+                        continue;
+                    }
+                    assert_eq!(
+                        chunk.get_block(cell + IVec3::NEG_Y).kind,
+                        BlockKind::DirtWithGrass,
+                        "decoration at {cell} in chunk {coord} floats"
+                    );
+                    kinds.push(block.kind);
+                }
+            }
+        }
+        assert!(kinds.contains(&BlockKind::TallGrass));
+        assert!(kinds.contains(&BlockKind::Flower));
+        assert!(kinds.contains(&BlockKind::Cactus));
+    }
+
+    /// Verify each biome's column: surface block, three sub-surface blocks, then stone, with
+    /// the biome recorded for the column.
+    #[test]
+    fn terrain_layers_follow_biome() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{Biome, TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let column_of = |biome: Biome| {
+            (0..512)
+                .flat_map(|x| (0..512).map(move |z| (x, z)))
+                .find(|&(x, z)| {
+                    let height = TerrainNoise::height_at(x, z, 0, &config);
+                    TerrainNoise::biome_at(x, z, 0, &config) == biome
+                        && TerrainNoise::tree_at(x, z, 0, biome).is_none()
+                        && !TerrainNoise::is_sand_patch(x, z, height, 0)
+                })
+                .unwrap_or_else(|| panic!("no {biome:?} column"))
+        };
+        let (mountain_x, mountain_z) = column_of(Biome::Mountains);
+        let mountain = Chunk::surface_blocks(
+            Biome::Mountains,
+            TerrainNoise::height_at(mountain_x, mountain_z, 0, &config),
+            TerrainNoise::snow_line_at(mountain_x, mountain_z, 0),
+            false,
+        );
+        assert!(matches!(
+            mountain.0.kind,
+            BlockKind::Dirt | BlockKind::Stone | BlockKind::SnowyGrass
+        ));
+        let expected_surface = [
+            (Biome::Plains, Block::dirt_with_grass(), Block::dirt()),
+            (Biome::Desert, Block::sand(), Block::sand()),
+            (Biome::Mountains, mountain.0, mountain.1),
+        ];
+        for (biome, surface, sub_surface) in expected_surface {
+            let (x, z) = column_of(biome);
+            let height = TerrainNoise::height_at(x, z, 0, &config);
+            let coord = |y: i32| IVec3::new(x, y, z).div_euclid(IVec3::splat(CHUNK_SIZE));
+            let layer = |depth: i32| match depth {
+                0 => surface,
+                1..=3 => sub_surface,
+                _ => Block::stone(),
+            };
+            for depth in 0..=height - TerrainNoise::BEDROCK_Y {
+                let world_y = height - depth;
+                let chunk = Chunk::new_terrain(coord(world_y), 0, &config);
+                let local = IVec3::new(x, world_y, z).rem_euclid(IVec3::splat(CHUNK_SIZE));
+                let under_sand = depth > 0 && layer(depth - 1).kind == BlockKind::Sand;
+                let expected = match depth {
+                    _ if world_y == TerrainNoise::BEDROCK_Y => Block::bedrock(),
+                    _ if !under_sand && TerrainNoise::is_cave(x, world_y, z, height, 0) => {
+                        Block::air()
+                    }
+                    _ => layer(depth),
+                };
+                assert_eq!(chunk.get_block(local), expected, "{biome:?} depth {depth}");
+                assert_eq!(chunk.biome(local.x, local.z), biome);
+            }
+        }
+    }
+
+    /// Verify peaks get snowy grass over dirt and the snow line is dithered, not a flat cut.
+    #[test]
+    fn snow_caps_follow_dithered_snow_line() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{Biome, TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let (x, z) = (0..1024)
+            .flat_map(|x| (0..1024).map(move |z| (x, z)))
+            .find(|&(x, z)| TerrainNoise::height_at(x, z, 0, &config) > TerrainNoise::SNOW_LINE + 2)
+            .expect("no column above the snow band");
+        let height = TerrainNoise::height_at(x, z, 0, &config);
+        let surface = IVec3::new(x, height, z);
+        let chunk = Chunk::new_terrain(surface.div_euclid(IVec3::splat(CHUNK_SIZE)), 0, &config);
+        let local = surface.rem_euclid(IVec3::splat(CHUNK_SIZE));
+        assert_eq!(chunk.get_block(local), Block::snowy_grass());
+        if local.y > 0 && !TerrainNoise::is_cave(x, height - 1, z, height, 0) {
+            assert_eq!(chunk.get_block(local - IVec3::Y), Block::dirt());
+        }
+
+        let at_line = (0..64).map(|x| {
+            let snow_line = TerrainNoise::snow_line_at(x, 0, 0);
+            Chunk::surface_blocks(Biome::Plains, TerrainNoise::SNOW_LINE, snow_line, false).0
+        });
+        let snowy = at_line
+            .filter(|block| block.kind == BlockKind::SnowyGrass)
+            .count();
+        assert!(
+            snowy > 0 && snowy < 64,
+            "snow line is flat ({snowy}/64 snowy)"
+        );
+    }
+
+    /// Verify carved caves follow world coordinates, so they line up across chunk borders.
+    #[test]
+    fn caves_line_up_across_chunk_borders() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let left = Chunk::new_terrain(IVec3::new(-1, 0, 0), 0, &config);
+        let right = Chunk::new_terrain(IVec3::ZERO, 0, &config);
+        let mut carved = 0;
+        for (chunk, local_x, world_x) in [(&left, CHUNK_SIZE - 1, -1), (&right, 0, 0)] {
+            for z in 0..CHUNK_SIZE {
+                let height = TerrainNoise::height_at(world_x, z, 0, &config);
+                for y in 0..height.min(CHUNK_SIZE) {
+                    let cave = TerrainNoise::is_cave(world_x, y, z, height, 0);
+                    carved += usize::from(cave);
+                    assert_eq!(
+                        chunk.get_block(IVec3::new(local_x, y, z)).is_air(),
+                        cave,
+                        "border cell ({world_x}, {y}, {z})"
+                    );
+                }
+            }
+        }
+        assert!(carved > 0, "no caves along the tested border");
+    }
+
+    /// Verify oaks and conifers rooted on a chunk's last column still grow their canopy
+    /// into the neighbour, and cacti rooted on a layer's top slice grow into the layer above.
+    #[test]
+    fn trees_cross_chunk_borders() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{Biome, TerrainConfig, TerrainNoise};
+        use crate::voxel::tree_defs::{TreeKind, TreeRegistry};
+
+        let config = TerrainConfig::default();
+        let registry = TreeRegistry::global();
+        let chunk_of = |world: IVec3| {
+            let coord = world.div_euclid(IVec3::splat(CHUNK_SIZE));
+            Chunk::new_terrain(coord, 0, &config)
+                .get_block(world.rem_euclid(IVec3::splat(CHUNK_SIZE)))
+        };
+        // Root, trunk top, and template of a tree of `biome` rooted on its soil.
+        let tree_in = |biome: Biome, x: i32, z: i32| {
+            let pick = TerrainNoise::tree_at(x, z, 0, biome)?;
+            let ground = TerrainNoise::height_at(x, z, 0, &config);
+            let template = registry.template(TreeKind::for_biome(biome));
+            let (surface, _) = Chunk::surface_blocks(
+                biome,
+                ground,
+                TerrainNoise::snow_line_at(x, z, 0),
+                TerrainNoise::is_sand_patch(x, z, ground, 0),
+            );
+            (TerrainNoise::biome_at(x, z, 0, &config) == biome
+                && ground >= TerrainNoise::SEA_LEVEL
+                && TerrainNoise::river_mask(x, z, 0) == 0.0
+                && template.soil.contains(&surface.kind))
+            .then(|| {
+                let root = IVec3::new(x, ground, z);
+                (root, ground + template.trunk_height_for(pick), template)
+            })
+        };
+
+        for biome in [Biome::Plains, Biome::Mountains] {
+            let (root, top, template) = (-96..96)
+                .flat_map(|cx| (-96..96).map(move |z| (cx * CHUNK_SIZE + CHUNK_SIZE - 1, z)))
+                .find_map(|(x, z)| {
+                    let (root, top, template) = tree_in(biome, x, z)?;
+                    let clear = TerrainNoise::height_at(x + 1, z, 0, &config) < top;
+                    (clear && top.div_euclid(CHUNK_SIZE) == root.y.div_euclid(CHUNK_SIZE))
+                        .then_some((root, top, template))
+                })
+                .unwrap_or_else(|| panic!("no {biome:?} tree on a chunk border"));
+            assert_eq!(chunk_of(root + IVec3::Y), template.trunk, "{biome:?}");
+            assert_eq!(
+                chunk_of(IVec3::new(root.x, top, root.z)),
+                template.trunk,
+                "{biome:?}"
+            );
+            let across = IVec3::new(root.x + 1, top, root.z);
+            assert_eq!(chunk_of(across).kind, BlockKind::Leaves, "{biome:?}");
+        }
+
+        let (root, top, _) = (-256..256)
+            .flat_map(|x| (-256..256).map(move |z| (x, z)))
+            .find_map(|(x, z)| {
+                tree_in(Biome::Desert, x, z)
+                    .filter(|(root, _, _)| root.y.rem_euclid(CHUNK_SIZE) == CHUNK_SIZE - 1)
+            })
+            .expect("no cactus on a layer's top slice");
+        for y in root.y + 1..=top {
+            assert_eq!(chunk_of(IVec3::new(root.x, y, root.z)), Block::cactus());
+        }
+    }
+
+    /// Verify low columns fill with non-solid water up to sea level and no higher.
+    #[test]
+    fn sea_level_fills_low_columns_with_water() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let (x, z) = (0..64)
+            .flat_map(|x| (0..64).map(move |z| (x, z)))
+            .find(|&(x, z)| {
+                TerrainNoise::height_at(x, z, 0, &config) < TerrainNoise::SEA_LEVEL
+                    && TerrainNoise::river_mask(x, z, 0) == 0.0
+            })
+            .expect("no column below sea level");
+        let height = TerrainNoise::height_at(x, z, 0, &config);
+        let chunk = Chunk::new_terrain(IVec3::new(x / CHUNK_SIZE, 0, z / CHUNK_SIZE), 0, &config);
+        let local = |y: i32| IVec3::new(x % CHUNK_SIZE, y, z % CHUNK_SIZE);
+
+        for y in height + 1..=TerrainNoise::SEA_LEVEL {
+            let water = chunk.get_block(local(y));
+            assert_eq!(water, Block::water(), "y {y}");
+            assert!(!water.is_solid());
+        }
+        assert!(chunk.get_block(local(TerrainNoise::SEA_LEVEL + 1)).is_air());
+        assert!(chunk.get_block(local(height)).is_solid());
+    }
+
+    /// Verify a river crossing a chunk border is carved and flooded identically on both sides.
+    #[test]
+    fn river_trench_is_continuous_across_chunk_borders() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let flooded = |x: i32, z: i32| {
+            TerrainNoise::river_mask(x, z, 0) > 0.9
+                && TerrainNoise::water_level_at(x, z, 0, &config)
+                    > TerrainNoise::height_at(x, z, 0, &config)
+                && TerrainNoise::water_level_at(x, z, 0, &config) < CHUNK_SIZE
+        };
+        let z = (-512..512)
+            .find(|&z| flooded(-1, z) && flooded(0, z))
+            .expect("no river crossing the x = 0 chunk border");
+        let chunk_z = z.div_euclid(CHUNK_SIZE);
+        let local_z = z.rem_euclid(CHUNK_SIZE);
+        let left = Chunk::new_terrain(IVec3::new(-1, 0, chunk_z), 0, &config);
+        let right = Chunk::new_terrain(IVec3::new(0, 0, chunk_z), 0, &config);
+
+        for (chunk, local_x, world_x) in [(&left, CHUNK_SIZE - 1, -1), (&right, 0, 0)] {
+            let height = TerrainNoise::height_at(world_x, z, 0, &config);
+            let level = TerrainNoise::water_level_at(world_x, z, 0, &config);
+            for y in height + 1..=level {
+                let block = chunk.get_block(IVec3::new(local_x, y, local_z));
+                assert_eq!(block, Block::water(), "({world_x}, {y}, {z})");
+            }
+            assert!(
+                chunk
+                    .get_block(IVec3::new(local_x, level + 1, local_z))
+                    .is_air()
+            );
+        }
+    }
+
+    /// Verify ore veins are deterministic and only ever sit in stone below the surface.
+    #[test]
+    fn ore_veins_stay_buried_and_deterministic() {
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let mut ores = 0;
+        for coord in [
+            IVec3::new(0, 0, 0),
+            IVec3::new(3, 0, -2),
+            IVec3::new(-5, 1, 4),
+        ] {
+            let chunk = Chunk::new_terrain(coord, 0, &config);
+            assert!(chunk.blocks == Chunk::new_terrain(coord, 0, &config).blocks);
+            for i in 0..CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE {
+                let local = IVec3::new(
+                    i % CHUNK_SIZE,
+                    i / CHUNK_SIZE % CHUNK_SIZE,
+                    i / (CHUNK_SIZE * CHUNK_SIZE),
+                );
+                if !matches!(
+                    chunk.get_block(local).kind,
+                    BlockKind::CoalOre | BlockKind::IronOre
+                ) {
+                    continue;
+                }
+                ores += 1;
+                let world = coord * CHUNK_SIZE + local;
+                assert!(world.y < TerrainNoise::height_at(world.x, world.z, 0, &config));
+                assert!(!TerrainNoise::is_cave(
+                    world.x,
+                    world.y,
+                    world.z,
+                    i32::MAX,
+                    0
+                ));
+            }
+        }
+        assert!(ores > 0, "no ore generated in the sampled chunks");
+    }
+
+    /// Verify superflat mode streams one fixed slab at layer 0 and nothing else.
+    #[test]
+    fn superflat_streams_single_slab() {
+        use crate::terrain::{TerrainConfig, WorldGenMode};
+        use crate::voxel::generator::generator_for_mode;
+
+        let generator = generator_for_mode(3, WorldGenMode::Superflat, TerrainConfig::default());
+        let slab = generator.generate(IVec3::new(5, 0, -7));
+        let column = [
+            Block::bedrock(),
+            Block::dirt(),
+            Block::dirt(),
+            Block::dirt_with_grass(),
+        ];
+        for (y, expected) in column.into_iter().enumerate() {
+            assert_eq!(slab.get_block(IVec3::new(7, y as i32, 2)), expected);
+        }
+        assert!(slab.get_block(IVec3::new(7, 4, 2)).is_air());
+        let above = generator.generate(IVec3::new(5, 1, -7));
+        assert!(above.blocks.iter().all(Block::is_air));
+    }
+
+    /// Verify the shaped presets stream full terrain that regenerates identically.
+    #[test]
+    fn presets_stream_deterministic_terrain() {
+        use crate::terrain::{TerrainConfig, WorldGenMode};
+        use crate::voxel::generator::generator_for_mode;
+
+        let base = TerrainConfig::default();
+        let coord = IVec3::new(2, 0, -3);
+        let normal = generator_for_mode(9, WorldGenMode::Normal, base).generate(coord);
+        for mode in [WorldGenMode::Amplified, WorldGenMode::Islands] {
+            let generator = generator_for_mode(9, mode, mode.shape(base));
+            let chunk = generator.generate(coord);
+            let again = generator.generate(coord);
+            assert_eq!(chunk.blocks, again.blocks, "{mode:?} is not deterministic");
+            assert_ne!(
+                chunk.blocks, normal.blocks,
+                "{mode:?} matches normal terrain"
+            );
+            assert!(chunk.blocks.iter().any(|block| !block.is_air()));
+        }
+    }
+
+    /// Verify layers below the origin stream solid ground down to a full bedrock floor.
+    #[test]
+    fn underground_layers_stream_down_to_bedrock() {
+        use crate::terrain::{TerrainConfig, TerrainNoise, WorldGenMode};
+        use crate::voxel::generator::generator_for_mode;
+        use crate::{CHUNK_SIZE, MIN_CHUNK_LAYER};
+
+        let generator = generator_for_mode(4, WorldGenMode::Normal, TerrainConfig::default());
+        let stream = |y: i32| generator.generate(IVec3::new(1, y, 2));
+        let below_origin = stream(-1);
+        let solid = below_origin
+            .blocks
+            .iter()
+            .filter(|block| block.is_solid())
+            .count();
+        assert!(
+            solid > below_origin.blocks.len() / 2,
+            "layer -1 is mostly void"
+        );
+
+        let floor = stream(MIN_CHUNK_LAYER);
+        assert_eq!(TerrainNoise::BEDROCK_Y, MIN_CHUNK_LAYER * CHUNK_SIZE);
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                assert_eq!(floor.get_block(IVec3::new(x, 0, z)), Block::bedrock());
+            }
+        }
+        assert!(stream(MIN_CHUNK_LAYER - 1).blocks.iter().all(Block::is_air));
+        assert!(Chunk::is_streaming_layer(-1));
+        assert!(!Chunk::is_streaming_layer(MIN_CHUNK_LAYER - 1));
+    }
+
+    /// Verify ruins are deterministic per seed and stand on the surface of their columns.
+    #[test]
+    fn ruins_are_deterministic_and_follow_the_surface() {
+        use super::{RUIN_CHANCE_PER_MILLE, RUIN_MAX_WALL, RUIN_RNG_SALT};
+        use crate::CHUNK_SIZE;
+        use crate::terrain::{ChunkRng, TerrainConfig, TerrainNoise};
+
+        let config = TerrainConfig::default();
+        let is_ruin = |block: Block| {
+            matches!(
+                block.kind,
+                BlockKind::Cobblestone | BlockKind::MossyCobblestone
+            )
+        };
+        let (coord, chunk) = (-32..32)
+            .flat_map(|x| (-32..32).map(move |z| IVec3::new(x, 0, z)))
+            .filter(|&column| {
+                ChunkRng::new(column, 0, RUIN_RNG_SALT).below(1000) < RUIN_CHANCE_PER_MILLE
+            })
+            .flat_map(|column| (0..2).map(move |y| column + IVec3::Y * y))
+            .map(|coord| (coord, Chunk::new_terrain(coord, 0, &config)))
+            .find(|(_, chunk)| chunk.blocks.iter().copied().any(is_ruin))
+            .expect("no ruin in the sampled area");
+        assert!(chunk.blocks == Chunk::new_terrain(coord, 0, &config).blocks);
+
+        let base = coord * CHUNK_SIZE;
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let height = TerrainNoise::height_at(base.x + x, base.z + z, 0, &config);
+                for y in 0..CHUNK_SIZE {
+                    if is_ruin(chunk.get_block(IVec3::new(x, y, z))) {
+                        let world_y = base.y + y;
+                        assert!((height..=height + RUIN_MAX_WALL).contains(&world_y));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Verify the same seed regenerates identical terrain and a different seed changes it.
+    #[test]
+    fn world_seed_controls_terrain() {
+        use crate::terrain::TerrainConfig;
+
+        let config = TerrainConfig::default();
+        let coord = IVec3::new(2, 0, -3);
+        let seed_zero = Chunk::new_terrain(coord, 0, &config);
+        assert!(seed_zero.blocks == Chunk::new_terrain(coord, 0, &config).blocks);
+        assert!(seed_zero.blocks != Chunk::new_terrain(coord, 1, &config).blocks);
+    }
+
+    /// FNV-1a hash of a chunk's block kinds and facings, stable across runs.
+    fn chunk_fingerprint(chunk: &Chunk) -> u64 {
+        chunk
+            .blocks
+            .iter()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, block| {
+                [block.kind as u8, block.front as u8]
+                    .into_iter()
+                    .fold(hash, |hash, byte| {
+                        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+                    })
+            })
+    }
+
+    /// Golden fingerprints of seed-0 chunks; any generation change must update them knowingly.
+    #[test]
+    fn terrain_matches_golden_fingerprints() {
+        use crate::terrain::TerrainConfig;
+
+        let config = TerrainConfig::default();
+        let goldens: [(IVec3, u64); 7] = [
+            (IVec3::new(0, 0, 0), 0x2eab_cfcb_7e3d_6967),
+            (IVec3::new(-1, 0, -1), 0x5784_b46a_d252_5a4e),
+            (IVec3::new(3, 0, -5), 0xa90d_8056_d909_e171),
+            (IVec3::new(-7, 1, 2), 0xf160_4be1_6c2d_85a5),
+            (IVec3::new(12, 0, 9), 0x4d91_dd18_b810_a429),
+            (IVec3::new(-20, 1, -13), 0x6ef3_4184_be60_49e9),
+            (IVec3::new(5, -2, -3), 0xfca3_3ff6_1080_d8cc),
+        ];
+        for (coord, expected) in goldens {
+            let actual = chunk_fingerprint(&Chunk::new_terrain(coord, 0, &config));
+            assert_eq!(actual, expected, "chunk {coord} changed");
+        }
     }
 }
@@ -1,7 +1,12 @@
 use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::OnceLock;
 
-use crate::material_catalog::TextureId;
+use crate::material_catalog::{TextureId, texture_by_name};
 use crate::voxel::block_chunk::{Block, BlockKind, Facing};
+use crate::voxel::light_state::MAX_LIGHT;
 
 /// Face classification used by block face-material lookup.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -46,8 +51,193 @@ impl FaceMaterials {
     }
 }
 
+/// Geometry emitted for a block by the chunk mesher.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum BlockShape {
+    /// Full unit cube with per-face culling against solid neighbors.
+    Cube,
+    /// Two crossed diagonal quads, never culled, for plants and decorations.
+    Cross,
+    /// Thin unculled post standing on its support, or leaning against a wall beside it.
+    Torch,
+    /// Half-height cube filling the lower or upper half of its cell.
+    Slab,
+    /// Full-width lower half plus a step on the back half, rising away from the front.
+    Stairs,
+    /// Thin post with rails towards the neighbors stored in its state.
+    Fence,
+    /// Thin panel along the back of the cell, swung a quarter turn while open; one half of
+    /// a two-block door standing on the block below.
+    Door,
+    /// Column inset from the cell's sides, standing on the block below.
+    Cactus,
+}
+
+/// Axis-aligned box in block-local unit space (`0..1` on each axis; fences reach higher).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BlockBox {
+    /// Minimum corner.
+    pub min: Vec3,
+    /// Maximum corner.
+    pub max: Vec3,
+}
+
+impl BlockBox {
+    /// Whole cell.
+    pub const FULL: Self = Self {
+        min: Vec3::ZERO,
+        max: Vec3::ONE,
+    };
+    /// Collision of a fence arm reaching +Z, as tall as the fence post.
+    pub const FENCE_ARM: Self = Self {
+        min: Vec3::new(0.375, 0.0, 0.625),
+        max: Vec3::new(0.625, 1.5, 1.0),
+    };
+    /// Lower half of the cell, as taken by a bottom slab.
+    #[cfg(test)]
+    pub const LOWER_HALF: Self = Self {
+        min: Vec3::ZERO,
+        max: Vec3::new(1.0, 0.5, 1.0),
+    };
+
+    /// Upper back quarter of the cell, the step of stairs facing +Z.
+    #[cfg(test)]
+    pub const UPPER_BACK: Self = Self {
+        min: Vec3::new(0.0, 0.5, 0.0),
+        max: Vec3::new(1.0, 1.0, 0.5),
+    };
+
+    /// Return this box, given for a front of +Z or +Y, turned to face `front`.
+    ///
+    /// Horizontal fronts rotate the box about the vertical axis through the cell center;
+    /// a -Y front mirrors it top-to-bottom.
+    pub fn oriented(self, front: Facing) -> Self {
+        let turn = |p: Vec3| {
+            let d = p - Vec3::splat(0.5);
+            let turned = match front {
+                Facing::PosZ | Facing::PosY => d,
+                Facing::NegZ => Vec3::new(-d.x, d.y, -d.z),
+                Facing::PosX => Vec3::new(d.z, d.y, -d.x),
+                Facing::NegX => Vec3::new(-d.z, d.y, d.x),
+                Facing::NegY => Vec3::new(d.x, -d.y, d.z),
+            };
+            turned + Vec3::splat(0.5)
+        };
+        let (a, b) = (turn(self.min), turn(self.max));
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+}
+
+/// Collision boxes of a block filling its whole cell.
+const FULL_CELL: &[BlockBox] = &[BlockBox::FULL];
+
+/// See-through classification used by the chunk mesher's face-visibility test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum Opacity {
+    /// Fully opaque cube that hides every neighbor face touching it.
+    Opaque,
+    /// Alpha-cutout texels (leaves, decorations), a partial cell (slabs), or nothing at all
+    /// (air); hides no face,
+    /// and keeps faces shared with its own kind so canopies are not hollow.
+    Cutout,
+    /// Blended see-through block (water, glass); hides no neighbor face, and drops faces
+    /// shared with its own kind so walls and lakes show no internal faces.
+    Translucent,
+}
+
+/// Material class of a block's faces, and so the chunk mesh they are emitted into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum MeshLayer {
+    /// Shared alpha-masked atlas mesh.
+    Opaque,
+    /// Tinted, blended water mesh with a scrolling texture.
+    Water,
+    /// Untinted, blended glass mesh.
+    Glass,
+    /// Full-bright mesh of blocks that glow.
+    Emissive,
+}
+
+impl MeshLayer {
+    /// Classes a chunk gets a separate mesh and entity for once it holds such blocks.
+    pub const SEPARATE: [Self; 3] = [Self::Water, Self::Glass, Self::Emissive];
+}
+
+/// What using a block does, dispatched when the player right-clicks it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum BlockUse {
+    /// Open the crafting grid.
+    Craft,
+    /// Open the block's container storage.
+    OpenContainer,
+    /// Swing both halves of a door open or shut.
+    ToggleDoor,
+}
+
+/// Tool family that breaks matching blocks faster.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum ToolKind {
+    /// Preferred for stone-like blocks.
+    Pickaxe,
+    /// Preferred for loose ground (dirt, sand).
+    Shovel,
+    /// Preferred for wooden blocks.
+    Axe,
+}
+
+/// A held tool: family plus material tier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tool {
+    /// Tool family.
+    pub kind: ToolKind,
+    /// Material tier (`1` wood, `2` stone).
+    pub tier: u8,
+}
+
+impl Tool {
+    /// Wooden pickaxe, enough to make stone drop.
+    pub const WOOD_PICKAXE: Self = Self {
+        kind: ToolKind::Pickaxe,
+        tier: 1,
+    };
+    /// Stone pickaxe, enough to make iron ore drop.
+    pub const STONE_PICKAXE: Self = Self {
+        kind: ToolKind::Pickaxe,
+        tier: 2,
+    };
+    /// Every craftable tool, in inventory and hotbar order.
+    pub const ALL: [Self; 2] = [Self::WOOD_PICKAXE, Self::STONE_PICKAXE];
+
+    /// Return the display name of this tool, such as `StonePickaxe`.
+    pub fn name(self) -> String {
+        let material = match self.tier {
+            0 => "",
+            1 => "Wood",
+            2 => "Stone",
+            _ => "Iron",
+        };
+        format!("{material}{:?}", self.kind)
+    }
+
+    /// Break-speed multiplier of a matching tool for its tier.
+    const fn tier_speed(self) -> f32 {
+        match self.tier {
+            0 => 1.0,
+            1 => 2.0,
+            2 => 4.0,
+            _ => 6.0,
+        }
+    }
+}
+
+/// Break-speed multiplier applied when a tier-gated block is mined without an adequate tool.
+const UNDER_TIER_SPEED: f32 = 0.3;
+
 /// Runtime-extensible block definition payload.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BlockDef {
     /// Whether this block occupies volume and blocks movement.
     pub solid: bool,
@@ -57,31 +247,336 @@ pub struct BlockDef {
     pub interactable: bool,
     /// Whether this block can store vertical front directions (+Y/-Y).
     pub allow_vertical_front: bool,
+    /// Whether placement aligns the front with the clicked face normal instead of the view.
+    ///
+    /// Used by pillar-like blocks whose front/back faces are their two ends.
+    pub axis_from_face: bool,
+    /// How much of what lies behind this block shows through, deciding face culling.
+    pub opacity: Opacity,
+    /// Chunk mesh this block's faces are emitted into.
+    pub layer: MeshLayer,
+    /// Geometry the chunk mesher emits for this block.
+    pub shape: BlockShape,
+    /// Boxes a solid block collides with and is meshed from, in block-local unit space for
+    /// a front of +Z (horizontal blocks) or +Y (slabs); ignored when not solid.
+    pub collision_boxes: &'static [BlockBox],
+    /// Seconds the break button must be held on this block before it breaks (`0` = instant),
+    /// divided by `break_speed_multiplier` for the held tool.
+    pub hardness: f32,
+    /// Tool family that speeds up breaking this block, if any.
+    pub preferred_tool: Option<ToolKind>,
+    /// Minimum tier of `preferred_tool` needed for the block to drop an item (`0` = any).
+    pub required_tier: u8,
     /// Face material mapping for this block.
     pub materials: FaceMaterials,
+    /// Tiles shown on every face by `Block::state`, replacing `materials` when non-empty.
+    ///
+    /// States past the last entry show the last tile, so a crop can stop at its final stage.
+    pub stage_textures: &'static [TextureId],
+    /// What using this block does, or `None` when right-clicking it places against it.
+    pub on_use: Option<BlockUse>,
+    /// Block light level this block gives off (`0` = none, up to `MAX_LIGHT`).
+    pub light_emission: u8,
+    /// Share of the gap to the wished walking velocity a player standing on this block
+    /// closes each frame; `1.0` snaps to it, lower values slide.
+    pub friction: f32,
 }
 
-/// Air block definition.
-const AIR_DEF: BlockDef = BlockDef {
-    solid: false,
-    stable: false,
-    interactable: false,
-    allow_vertical_front: false,
-    materials: FaceMaterials {
-        top: TextureId::Dirt,
-        bottom: TextureId::Dirt,
-        front: TextureId::Dirt,
-        back: TextureId::Dirt,
-        side_left_right: TextureId::Dirt,
-    },
-};
+impl BlockDef {
+    /// Return `true` when `held` is the preferred tool at or above the required tier.
+    fn tool_is_adequate(&self, held: Option<Tool>) -> bool {
+        if self.required_tier == 0 {
+            return true;
+        }
+        held.is_some_and(|tool| {
+            Some(tool.kind) == self.preferred_tool && tool.tier >= self.required_tier
+        })
+    }
+
+    /// Break-progress speed multiplier when mining this block with `held`.
+    pub fn break_speed_multiplier(&self, held: Option<Tool>) -> f32 {
+        if !self.tool_is_adequate(held) {
+            return UNDER_TIER_SPEED;
+        }
+        match held {
+            Some(tool) if Some(tool.kind) == self.preferred_tool => tool.tier_speed(),
+            _ => 1.0,
+        }
+    }
+
+    /// Return the texture of the face with world `normal` of `block`, by its front and state.
+    pub fn texture_for_face(&self, block: Block, normal: IVec3) -> TextureId {
+        if let Some(last) = self.stage_textures.last() {
+            return self
+                .stage_textures
+                .get(usize::from(block.state))
+                .copied()
+                .unwrap_or(*last);
+        }
+        self.materials
+            .texture_for_face(face_kind_from_oriented_normal(normal, block.front))
+    }
+
+    /// Return whether breaking this block with `held` should drop an item.
+    pub fn yields_drop(&self, held: Option<Tool>) -> bool {
+        self.tool_is_adequate(held)
+    }
+}
 
-/// Dirt block definition without grass overlay.
-const DIRT_DEF: BlockDef = BlockDef {
+/// Unit-cell box as written in `blocks.ron`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BoxSpec {
+    /// Minimum corner.
+    min: [f32; 3],
+    /// Maximum corner.
+    max: [f32; 3],
+}
+
+/// Per-face texture names as written in `blocks.ron`; unset faces keep the base's texture.
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct TexturesSpec {
+    /// Texture for every face not named below.
+    all: Option<String>,
+    /// Top face texture.
+    top: Option<String>,
+    /// Bottom face texture.
+    bottom: Option<String>,
+    /// Front face texture.
+    front: Option<String>,
+    /// Back face texture.
+    back: Option<String>,
+    /// Left/right face texture.
+    sides: Option<String>,
+}
+
+/// One block entry of `blocks.ron`; unset fields keep the base's (or the default cube's) value.
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct BlockSpec {
+    /// Block whose definition this one starts from.
+    base: Option<BlockKind>,
+    /// Overrides `BlockDef::solid`.
+    solid: Option<bool>,
+    /// Overrides `BlockDef::stable`.
+    stable: Option<bool>,
+    /// Overrides `BlockDef::interactable`.
+    interactable: Option<bool>,
+    /// Overrides `BlockDef::allow_vertical_front`.
+    allow_vertical_front: Option<bool>,
+    /// Overrides `BlockDef::axis_from_face`.
+    axis_from_face: Option<bool>,
+    /// Overrides `BlockDef::opacity`.
+    opacity: Option<Opacity>,
+    /// Overrides `BlockDef::layer`.
+    layer: Option<MeshLayer>,
+    /// Overrides `BlockDef::shape`.
+    shape: Option<BlockShape>,
+    /// Overrides `BlockDef::collision_boxes`.
+    collision_boxes: Option<Vec<BoxSpec>>,
+    /// Overrides `BlockDef::hardness`.
+    hardness: Option<f32>,
+    /// Overrides `BlockDef::preferred_tool`.
+    preferred_tool: Option<ToolKind>,
+    /// Overrides `BlockDef::required_tier`.
+    required_tier: Option<u8>,
+    /// Face textures, applied over the base's.
+    textures: Option<TexturesSpec>,
+    /// Overrides `BlockDef::stage_textures` by texture name.
+    stages: Option<Vec<String>>,
+    /// Overrides `BlockDef::on_use`.
+    on_use: Option<BlockUse>,
+    /// Overrides `BlockDef::light_emission`.
+    light_emission: Option<u8>,
+    /// Overrides `BlockDef::friction`.
+    friction: Option<f32>,
+}
+
+/// Block definitions loaded from `assets/blocks.ron`, indexed by `BlockKind`.
+///
+/// The registry is read once per process and shared: `def_for_block_kind` resolves through
+/// it from any thread, including chunk-build tasks, and the app holds a copy as a resource.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct BlockRegistry {
+    /// One definition per kind, in `BlockKind::ALL` order.
+    defs: &'static [BlockDef],
+}
+
+/// Process-wide registry, loaded on first use.
+static REGISTRY: OnceLock<BlockRegistry> = OnceLock::new();
+
+impl BlockRegistry {
+    /// Block definition file, one RON map from block kind to its definition.
+    pub const PATH: &'static str = "assets/blocks.ron";
+
+    /// Return the shared registry, loading it from `PATH` on first use.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the file is missing or invalid, including unknown texture names, since
+    /// the world cannot be built without block definitions.
+    pub fn global() -> Self {
+        *REGISTRY.get_or_init(|| {
+            let text = fs::read_to_string(Self::PATH)
+                .unwrap_or_else(|err| panic!("Cannot read {}: {err}", Self::PATH));
+            Self::parse(&text).unwrap_or_else(|err| panic!("Invalid {}: {err}", Self::PATH))
+        })
+    }
+
+    /// Parse and resolve a RON block map; every `BlockKind` must be defined.
+    fn parse(text: &str) -> Result<Self, String> {
+        let specs: HashMap<BlockKind, BlockSpec> =
+            ron::from_str(text).map_err(|err| err.to_string())?;
+        let defs = BlockKind::ALL
+            .iter()
+            .map(|kind| Self::resolve(&specs, *kind, 0))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { defs: defs.leak() })
+    }
+
+    /// Resolve the definition of `kind`, applying its spec over its base chain.
+    fn resolve(
+        specs: &HashMap<BlockKind, BlockSpec>,
+        kind: BlockKind,
+        depth: usize,
+    ) -> Result<BlockDef, String> {
+        if depth > BlockKind::ALL.len() {
+            return Err(format!("{kind:?} inherits from itself"));
+        }
+        let spec = specs
+            .get(&kind)
+            .ok_or_else(|| format!("{kind:?} is not defined"))?;
+        let (base, materials) = match spec.base {
+            Some(base) => {
+                let def = Self::resolve(specs, base, depth + 1)?;
+                (def, Some(def.materials))
+            }
+            None => (DEFAULT_DEF, None),
+        };
+        let textures = spec.textures.as_ref();
+        let face = |name: Option<&String>, inherited: Option<TextureId>| match name
+            .or(textures.and_then(|textures| textures.all.as_ref()))
+        {
+            Some(name) => texture_by_name(name)
+                .map(Some)
+                .ok_or_else(|| format!("{kind:?} uses unknown texture {name:?}")),
+            None => Ok(inherited),
+        };
+        let faces = [
+            face(
+                textures.and_then(|t| t.top.as_ref()),
+                materials.map(|m| m.top),
+            )?,
+            face(
+                textures.and_then(|t| t.bottom.as_ref()),
+                materials.map(|m| m.bottom),
+            )?,
+            face(
+                textures.and_then(|t| t.front.as_ref()),
+                materials.map(|m| m.front),
+            )?,
+            face(
+                textures.and_then(|t| t.back.as_ref()),
+                materials.map(|m| m.back),
+            )?,
+            face(
+                textures.and_then(|t| t.sides.as_ref()),
+                materials.map(|m| m.side_left_right),
+            )?,
+        ];
+        let [
+            Some(top),
+            Some(bottom),
+            Some(front),
+            Some(back),
+            Some(side_left_right),
+        ] = faces
+        else {
+            return Err(format!("{kind:?} is missing a face texture"));
+        };
+        Ok(BlockDef {
+            solid: spec.solid.unwrap_or(base.solid),
+            stable: spec.stable.unwrap_or(base.stable),
+            interactable: spec.interactable.unwrap_or(base.interactable),
+            allow_vertical_front: spec
+                .allow_vertical_front
+                .unwrap_or(base.allow_vertical_front),
+            axis_from_face: spec.axis_from_face.unwrap_or(base.axis_from_face),
+            opacity: spec.opacity.unwrap_or(base.opacity),
+            layer: spec.layer.unwrap_or(base.layer),
+            shape: spec.shape.unwrap_or(base.shape),
+            collision_boxes: spec
+                .collision_boxes
+                .as_ref()
+                .map_or(base.collision_boxes, |boxes| {
+                    boxes
+                        .iter()
+                        .map(|part| BlockBox {
+                            min: Vec3::from_array(part.min),
+                            max: Vec3::from_array(part.max),
+                        })
+                        .collect::<Vec<_>>()
+                        .leak()
+                }),
+            hardness: spec.hardness.unwrap_or(base.hardness),
+            preferred_tool: spec.preferred_tool.or(base.preferred_tool),
+            required_tier: spec.required_tier.unwrap_or(base.required_tier),
+            materials: FaceMaterials {
+                top,
+                bottom,
+                front,
+                back,
+                side_left_right,
+            },
+            stage_textures: match &spec.stages {
+                Some(stages) => stages
+                    .iter()
+                    .map(|name| {
+                        texture_by_name(name)
+                            .ok_or_else(|| format!("{kind:?} uses unknown texture {name:?}"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .leak(),
+                None => base.stage_textures,
+            },
+            on_use: spec.on_use.or(base.on_use),
+            light_emission: match spec.light_emission {
+                Some(level) if level > MAX_LIGHT => {
+                    return Err(format!("{kind:?} emits light above {MAX_LIGHT}"));
+                }
+                level => level.unwrap_or(base.light_emission),
+            },
+            friction: match spec.friction {
+                Some(friction) if !(friction > 0.0 && friction <= 1.0) => {
+                    return Err(format!("{kind:?} friction {friction} is outside (0, 1]"));
+                }
+                friction => friction.unwrap_or(base.friction),
+            },
+        })
+    }
+
+    /// Return the definition of `kind`.
+    pub fn def(&self, kind: BlockKind) -> &'static BlockDef {
+        &self.defs[kind as usize]
+    }
+}
+
+/// Definition a `blocks.ron` entry without a base starts from: a solid, stable,
+/// interactable, opaque full cube (its textures are always replaced).
+const DEFAULT_DEF: BlockDef = BlockDef {
     solid: true,
     stable: true,
     interactable: true,
-    allow_vertical_front: true,
+    allow_vertical_front: false,
+    axis_from_face: false,
+    opacity: Opacity::Opaque,
+    layer: MeshLayer::Opaque,
+    shape: BlockShape::Cube,
+    collision_boxes: FULL_CELL,
+    hardness: 0.0,
+    preferred_tool: None,
+    required_tier: 0,
     materials: FaceMaterials {
         top: TextureId::Dirt,
         bottom: TextureId::Dirt,
@@ -89,36 +584,10 @@ const DIRT_DEF: BlockDef = BlockDef {
         back: TextureId::Dirt,
         side_left_right: TextureId::Dirt,
     },
-};
-
-/// Dirt block definition with grass top/front/back/left-right textures.
-const DIRT_GRASS_DEF: BlockDef = BlockDef {
-    solid: true,
-    stable: true,
-    interactable: true,
-    allow_vertical_front: false,
-    materials: FaceMaterials {
-        top: TextureId::GrassTop,
-        bottom: TextureId::Dirt,
-        front: TextureId::GrassSide,
-        back: TextureId::GrassSide,
-        side_left_right: TextureId::GrassSide,
-    },
-};
-
-/// Sand block definition affected by gravity.
-const SAND_DEF: BlockDef = BlockDef {
-    solid: true,
-    stable: false,
-    interactable: true,
-    allow_vertical_front: true,
-    materials: FaceMaterials {
-        top: TextureId::Sand,
-        bottom: TextureId::Sand,
-        front: TextureId::Sand,
-        back: TextureId::Sand,
-        side_left_right: TextureId::Sand,
-    },
+    stage_textures: &[],
+    on_use: None,
+    light_emission: 0,
+    friction: 1.0,
 };
 
 /// Resolve face class from world normal, using a block-local front orientation.
@@ -137,20 +606,189 @@ pub fn face_kind_from_oriented_normal(normal: IVec3, front: Facing) -> FaceKind
     }
 }
 
-/// Return block definition for one block kind.
-pub const fn def_for_block_kind(kind: BlockKind) -> &'static BlockDef {
-    match kind {
-        BlockKind::Air => &AIR_DEF,
-        BlockKind::Dirt => &DIRT_DEF,
-        BlockKind::DirtWithGrass => &DIRT_GRASS_DEF,
-        BlockKind::Sand => &SAND_DEF,
-    }
+/// Return block definition for one block kind, from the shared `BlockRegistry`.
+pub fn def_for_block_kind(kind: BlockKind) -> &'static BlockDef {
+    BlockRegistry::global().def(kind)
 }
 
 /// Resolve face texture id for one block face.
 pub fn texture_for_face(block: Block, normal: IVec3) -> TextureId {
-    let face = face_kind_from_oriented_normal(normal, block.front);
-    def_for_block_kind(block.kind)
-        .materials
-        .texture_for_face(face)
+    def_for_block_kind(block.kind).texture_for_face(block, normal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Return the shipped dirt definition.
+    fn dirt_def() -> BlockDef {
+        *def_for_block_kind(BlockKind::Dirt)
+    }
+
+    /// Return a stone-tier test block that needs at least a wooden pickaxe to drop.
+    fn tiered_def() -> BlockDef {
+        BlockDef {
+            preferred_tool: Some(ToolKind::Pickaxe),
+            required_tier: 1,
+            ..dirt_def()
+        }
+    }
+
+    /// Verify break speed for hand, wrong tool, and matching tools of each tier.
+    #[test]
+    fn tool_break_speed_matrix() {
+        let shovel = Tool {
+            kind: ToolKind::Shovel,
+            tier: 1,
+        };
+        assert_eq!(dirt_def().break_speed_multiplier(None), 1.0);
+        assert_eq!(dirt_def().break_speed_multiplier(Some(shovel)), 2.0);
+        assert_eq!(
+            dirt_def().break_speed_multiplier(Some(Tool::WOOD_PICKAXE)),
+            1.0
+        );
+
+        assert_eq!(tiered_def().break_speed_multiplier(None), UNDER_TIER_SPEED);
+        assert_eq!(
+            tiered_def().break_speed_multiplier(Some(shovel)),
+            UNDER_TIER_SPEED
+        );
+        assert_eq!(
+            tiered_def().break_speed_multiplier(Some(Tool::WOOD_PICKAXE)),
+            2.0
+        );
+        assert_eq!(
+            tiered_def().break_speed_multiplier(Some(Tool::STONE_PICKAXE)),
+            4.0
+        );
+    }
+
+    /// Verify logs take their axis from the clicked face and show rings only on its ends.
+    #[test]
+    fn log_orients_along_clicked_face() {
+        let upright = Block::log().oriented_for_placement(Vec3::Z, IVec3::Y);
+        assert_eq!(upright, Block::log());
+        let sideways = Block::log().oriented_for_placement(Vec3::NEG_Y, IVec3::NEG_X);
+        assert_eq!(sideways.front, Facing::NegX);
+
+        for (block, axis) in [(upright, IVec3::Y), (sideways, IVec3::X)] {
+            for normal in [
+                IVec3::X,
+                IVec3::NEG_X,
+                IVec3::Y,
+                IVec3::NEG_Y,
+                IVec3::Z,
+                IVec3::NEG_Z,
+            ] {
+                let expected = if normal.abs() == axis {
+                    TextureId::LogTop
+                } else {
+                    TextureId::LogSide
+                };
+                assert_eq!(texture_for_face(block, normal), expected, "{normal}");
+            }
+        }
+
+        let dirt = Block::dirt().oriented_for_placement(Vec3::NEG_X, IVec3::Y);
+        assert_eq!(dirt.front, Facing::PosX);
+    }
+
+    /// Verify tier-gated blocks only drop with an adequate preferred tool.
+    #[test]
+    fn tool_drop_matrix() {
+        assert!(dirt_def().yields_drop(None));
+        assert!(dirt_def().yields_drop(Some(Tool::WOOD_PICKAXE)));
+
+        assert!(!tiered_def().yields_drop(None));
+        assert!(!tiered_def().yields_drop(Some(Tool {
+            kind: ToolKind::Axe,
+            tier: 2,
+        })));
+        assert!(tiered_def().yields_drop(Some(Tool::WOOD_PICKAXE)));
+        assert!(tiered_def().yields_drop(Some(Tool::STONE_PICKAXE)));
+    }
+
+    /// Verify the shipped registry defines every kind in `ALL` order and applies bases.
+    #[test]
+    fn shipped_registry_resolves_bases() {
+        for (index, kind) in BlockKind::ALL.into_iter().enumerate() {
+            assert_eq!(kind as usize, index);
+        }
+        let stone = def_for_block_kind(BlockKind::Stone);
+        let iron = def_for_block_kind(BlockKind::IronOre);
+        assert_eq!(iron.preferred_tool, stone.preferred_tool);
+        assert_eq!(iron.required_tier, 2);
+        assert_eq!(iron.materials.front, TextureId::IronOre);
+
+        let stairs = def_for_block_kind(BlockKind::Stairs);
+        assert_eq!(
+            stairs.collision_boxes,
+            [BlockBox::LOWER_HALF, BlockBox::UPPER_BACK]
+        );
+        assert_eq!(stairs.materials, stone.materials);
+        assert_eq!(stairs.opacity, Opacity::Cutout);
+        assert!(
+            def_for_block_kind(BlockKind::Bedrock)
+                .hardness
+                .is_infinite()
+        );
+        assert_eq!(
+            def_for_block_kind(BlockKind::DirtWithGrass).materials,
+            FaceMaterials {
+                top: TextureId::GrassTop,
+                bottom: TextureId::Dirt,
+                front: TextureId::GrassSide,
+                back: TextureId::GrassSide,
+                side_left_right: TextureId::GrassSide,
+            }
+        );
+    }
+
+    /// Verify unknown textures, missing kinds, and base cycles are rejected by name.
+    #[test]
+    fn registry_rejects_bad_definitions() {
+        let shipped = fs::read_to_string(BlockRegistry::PATH).unwrap();
+        let err = BlockRegistry::parse(&shipped.replace("\"torch\"", "\"torches\"")).unwrap_err();
+        assert!(err.contains("Torch") && err.contains("torches"), "{err}");
+
+        let err =
+            BlockRegistry::parse("#![enable(implicit_some)]\n{ Air: (textures: (all: \"dirt\")) }")
+                .unwrap_err();
+        assert!(err.contains("Dirt is not defined"), "{err}");
+
+        let cycle = shipped.replace(
+            "base: Stone,\n        hardness: 1.2",
+            "base: Slab,\n        hardness: 1.2",
+        );
+        let cycle = cycle.replace(
+            "    Slab: (\n        base: Stone",
+            "    Slab: (\n        base: CoalOre",
+        );
+        let err = BlockRegistry::parse(&cycle).unwrap_err();
+        assert!(err.contains("inherits from itself"), "{err}");
+    }
+
+    /// Verify staged blocks show one tile per state on every face, holding the last one.
+    #[test]
+    fn stage_textures_follow_block_state() {
+        let crop = BlockDef {
+            stage_textures: &[TextureId::Dirt, TextureId::TallGrass, TextureId::Flower],
+            ..dirt_def()
+        };
+        for (state, expected) in [
+            (0, TextureId::Dirt),
+            (1, TextureId::TallGrass),
+            (2, TextureId::Flower),
+            (9, TextureId::Flower),
+        ] {
+            let block = Block::dirt().with_state(state);
+            for normal in [IVec3::Y, IVec3::NEG_Y, IVec3::X, IVec3::Z] {
+                assert_eq!(crop.texture_for_face(block, normal), expected);
+            }
+        }
+        assert_eq!(
+            dirt_def().texture_for_face(Block::dirt().with_state(2), IVec3::Y),
+            TextureId::Dirt
+        );
+    }
 }
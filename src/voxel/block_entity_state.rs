@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::voxel::block_chunk::{Block, BlockKind, Chunk};
+use crate::voxel::world_state::WorldState;
+
+/// Number of slots in a chest.
+pub(crate) const CHEST_SLOTS: usize = 27;
+/// Most items one container slot holds.
+pub(crate) const MAX_STACK: u32 = 64;
+
+/// One occupied container slot: a block item (see `Block::as_item`) and how many are stacked.
+pub type ItemStack = (Block, u32);
+
+/// Item storage of a container block such as a chest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Container {
+    /// Slot contents in display order; `None` is an empty slot.
+    slots: Vec<Option<ItemStack>>,
+}
+
+impl Container {
+    /// Build an empty container with `size` slots.
+    pub fn new(size: usize) -> Self {
+        Self {
+            slots: vec![None; size],
+        }
+    }
+
+    /// Return every slot in display order.
+    pub fn slots(&self) -> &[Option<ItemStack>] {
+        &self.slots
+    }
+
+    /// Add `count` items of `item`, topping up stacks of that item before using empty slots.
+    ///
+    /// Returns how many items did not fit.
+    pub fn insert(&mut self, item: Block, mut count: u32) -> u32 {
+        let item = item.as_item();
+        for (slot_item, held) in self.slots.iter_mut().flatten() {
+            if *slot_item == item {
+                let moved = count.min(MAX_STACK - *held);
+                *held += moved;
+                count -= moved;
+            }
+        }
+        for slot in self.slots.iter_mut().filter(|slot| slot.is_none()) {
+            if count == 0 {
+                break;
+            }
+            let moved = count.min(MAX_STACK);
+            *slot = Some((item, moved));
+            count -= moved;
+        }
+        count
+    }
+
+    /// Empty slot `index` and return what it held.
+    pub fn take(&mut self, index: usize) -> Option<ItemStack> {
+        self.slots.get_mut(index).and_then(Option::take)
+    }
+
+    /// Return every occupied slot's stack.
+    pub fn stacks(&self) -> impl Iterator<Item = ItemStack> + '_ {
+        self.slots.iter().flatten().copied()
+    }
+}
+
+/// Data attached to one block cell that does not fit in the block's state byte.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockEntity {
+    /// Item storage, e.g. of a chest.
+    Container(Container),
+}
+
+impl BlockEntity {
+    /// Return the fresh data a newly placed `kind` block carries, if it carries any.
+    pub fn for_kind(kind: BlockKind) -> Option<Self> {
+        match kind {
+            BlockKind::Chest => Some(Self::Container(Container::new(CHEST_SLOTS))),
+            _ => None,
+        }
+    }
+
+    /// Return the item stacks spilled when the owning block is removed.
+    pub fn spilled_stacks(&self) -> Vec<ItemStack> {
+        match self {
+            Self::Container(container) => container.stacks().collect(),
+        }
+    }
+}
+
+/// Block-attached data of loaded chunks, keyed by world block coordinate.
+///
+/// Unloading a chunk moves its entries into the edit store with its voxels, and loading it
+/// moves them back. Data whose block is replaced is detached and waits for
+/// `take_detached` to spill it into the world.
+#[derive(Default)]
+pub struct BlockEntities {
+    /// Data of each cell in loaded chunks.
+    by_cell: HashMap<IVec3, BlockEntity>,
+    /// Data whose block was replaced, with the cell it was attached to.
+    detached: Vec<(IVec3, BlockEntity)>,
+}
+
+impl BlockEntities {
+    /// Attach `entity` to the block at `pos`, replacing any previous data there.
+    pub fn insert(&mut self, pos: IVec3, entity: BlockEntity) {
+        self.by_cell.insert(pos, entity);
+    }
+
+    /// Return the container attached to `pos`, if the block there holds items.
+    pub fn container(&self, pos: IVec3) -> Option<&Container> {
+        match self.by_cell.get(&pos)? {
+            BlockEntity::Container(container) => Some(container),
+        }
+    }
+
+    /// Return the container attached to `pos` for editing, if the block there holds items.
+    pub fn container_mut(&mut self, pos: IVec3) -> Option<&mut Container> {
+        match self.by_cell.get_mut(&pos)? {
+            BlockEntity::Container(container) => Some(container),
+        }
+    }
+
+    /// Move the data at `pos` to the detached list, as its block is gone.
+    pub fn detach(&mut self, pos: IVec3) {
+        if let Some(entity) = self.by_cell.remove(&pos) {
+            self.detached.push((pos, entity));
+        }
+    }
+
+    /// Drain data detached from removed blocks.
+    pub fn take_detached(&mut self) -> Vec<(IVec3, BlockEntity)> {
+        std::mem::take(&mut self.detached)
+    }
+
+    /// Remove and return every entry inside chunk `coord`.
+    pub fn take_chunk(&mut self, coord: IVec3) -> Vec<(IVec3, BlockEntity)> {
+        let cells: Vec<IVec3> = self
+            .by_cell
+            .keys()
+            .filter(|pos| WorldState::world_to_chunk_local(**pos).0 == coord)
+            .copied()
+            .collect();
+        cells
+            .into_iter()
+            .filter_map(|pos| self.by_cell.remove_entry(&pos))
+            .collect()
+    }
+
+    /// Reattach entries stored with an unloaded chunk.
+    pub fn restore(&mut self, entries: Vec<(IVec3, BlockEntity)>) {
+        self.by_cell.extend(entries);
+    }
+}
+
+/// Voxels and block-attached data of an edited chunk while it is unloaded.
+#[derive(Clone)]
+pub struct EditedChunk {
+    /// Voxel payload restored when the chunk streams back in.
+    pub chunk: Chunk,
+    /// Block entities of the chunk, reattached when it loads.
+    pub block_entities: Vec<(IVec3, BlockEntity)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify inserts top up matching stacks first and report what does not fit.
+    #[test]
+    fn container_stacks_then_fills_empty_slots() {
+        let dirt = Block::dirt();
+        let stone = Block::stone();
+        let mut container = Container::new(2);
+        assert_eq!(container.insert(dirt, 10), 0);
+        assert_eq!(container.insert(stone, 1), 0);
+        assert_eq!(container.insert(dirt, MAX_STACK), 10);
+        assert_eq!(
+            container.slots(),
+            &[Some((dirt, MAX_STACK)), Some((stone, 1))]
+        );
+        assert_eq!(container.take(1), Some((stone, 1)));
+        assert_eq!(container.insert(dirt, 10), 0);
+        assert_eq!(
+            container.stacks().collect::<Vec<_>>(),
+            vec![(dirt, MAX_STACK), (dirt, 10)]
+        );
+    }
+
+    /// Verify wet and dry sponges stack apart and keep their state in the container.
+    #[test]
+    fn container_keeps_item_state() {
+        let mut container = Container::new(2);
+        assert_eq!(container.insert(Block::sponge().soaked(), 2), 0);
+        assert_eq!(container.insert(Block::sponge(), 1), 0);
+        assert_eq!(
+            container.slots(),
+            &[
+                Some((Block::sponge().soaked(), 2)),
+                Some((Block::sponge(), 1))
+            ]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use bevy::image::{ImageArrayLayout, ImageLoaderSettings};
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::ShaderRef;
+
+use super::{block_texture_mipmap_system, fluid_flow_system};
+use crate::material_catalog::ATLAS_TEXTURE_ORDER;
+
+/// Material of block meshes: chunks, the in-hand preview, drops, and falling blocks.
+pub type BlockMaterial = ExtendedMaterial<StandardMaterial, BlockTextureArray>;
+/// Material of chunk fluid meshes, scrolling the block texture array over time.
+pub type FluidMaterial = ExtendedMaterial<StandardMaterial, FluidTextureArray>;
+
+/// Asset path of the block textures, one tile per array layer stacked top to bottom.
+pub const BLOCK_TEXTURE_PATH: &str = "textures/atlas_array.png";
+/// Asset path of the optional block normal textures, laid out like `BLOCK_TEXTURE_PATH`.
+pub const BLOCK_NORMAL_TEXTURE_PATH: &str = "textures/atlas_array_n.png";
+/// Fragment shader lighting block meshes with their texture array layer.
+const FRAGMENT_SHADER_PATH: &str = "shaders/block_texture_array.wgsl";
+/// Prepass fragment shader cutting out transparent texels of block meshes, e.g. in shadows.
+const PREPASS_FRAGMENT_SHADER_PATH: &str = "shaders/block_texture_array_prepass.wgsl";
+/// Fragment shader of fluid meshes, sampling their texture array layer with scrolled UVs.
+const FLUID_FRAGMENT_SHADER_PATH: &str = "shaders/fluid_texture_array.wgsl";
+
+/// Standard material extension sampling block textures from a texture array.
+///
+/// `StandardMaterial` can only sample 2D textures, so block meshes carry tile-local UVs in
+/// `UV_0` and the layer of each face in the X of `UV_1`, which the standard vertex shaders
+/// pass through untouched, and these shaders sample the layer instead.
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct BlockTextureArray {
+    /// Block albedo, one layer per tile in `ATLAS_TEXTURE_ORDER`.
+    #[texture(100, dimension = "2d_array")]
+    #[sampler(101)]
+    textures: Handle<Image>,
+    /// Tangent-space block normals laid out like `textures`.
+    #[texture(102, dimension = "2d_array")]
+    #[sampler(103)]
+    normals: Option<Handle<Image>>,
+    /// Nonzero while `normals` are applied.
+    #[uniform(104)]
+    normal_mapping: u32,
+}
+
+impl MaterialExtension for BlockTextureArray {
+    fn fragment_shader() -> ShaderRef {
+        FRAGMENT_SHADER_PATH.into()
+    }
+
+    fn prepass_fragment_shader() -> ShaderRef {
+        PREPASS_FRAGMENT_SHADER_PATH.into()
+    }
+}
+
+/// Standard material extension sampling a fluid's texture array layer with UVs scrolled
+/// by `time`.
+///
+/// Fluid meshes are blended and never normal mapped, so only the albedo is bound.
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct FluidTextureArray {
+    /// Block albedo, one layer per tile in `ATLAS_TEXTURE_ORDER`.
+    #[texture(100, dimension = "2d_array")]
+    #[sampler(101)]
+    textures: Handle<Image>,
+    /// Seconds into the flow cycle, advanced by `fluid_flow_system`.
+    #[uniform(102)]
+    pub(super) time: f32,
+}
+
+impl MaterialExtension for FluidTextureArray {
+    fn fragment_shader() -> ShaderRef {
+        FLUID_FRAGMENT_SHADER_PATH.into()
+    }
+}
+
+/// Registers the texture array block and fluid materials, the mip generation of their
+/// textures, and the fluid flow clock.
+pub struct BlockMaterialPlugin;
+
+impl Plugin for BlockMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<BlockMaterial>::default())
+            .add_plugins(MaterialPlugin::<FluidMaterial>::default())
+            .add_systems(Update, (block_texture_mipmap_system, fluid_flow_system));
+    }
+}
+
+/// Load block texture images as array textures with one layer per tile.
+pub fn configure_block_texture(settings: &mut ImageLoaderSettings) {
+    settings.array_layout = Some(ImageArrayLayout::RowCount {
+        rows: ATLAS_TEXTURE_ORDER.len() as u32,
+    });
+}
+
+/// Build a block material from `base` sampling the block textures `textures`.
+pub fn block_material(base: StandardMaterial, textures: Handle<Image>) -> BlockMaterial {
+    ExtendedMaterial {
+        base,
+        extension: BlockTextureArray {
+            textures,
+            normals: None,
+            normal_mapping: 0,
+        },
+    }
+}
+
+/// Build a fluid material from `base` scrolling the block textures `textures`.
+pub fn fluid_material(base: StandardMaterial, textures: Handle<Image>) -> FluidMaterial {
+    ExtendedMaterial {
+        base,
+        extension: FluidTextureArray {
+            textures,
+            time: 0.0,
+        },
+    }
+}
+
+/// Sample the block normal textures `normals` in `material`, or stop when `None`.
+pub fn set_block_normal_map(material: &mut BlockMaterial, normals: Option<Handle<Image>>) {
+    material.extension.normal_mapping = u32::from(normals.is_some());
+    material.extension.normals = normals;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material_catalog::ATLAS_TILE_PIXELS;
+
+    /// Decode RGBA8 PNG bytes into their width, height, and pixels.
+    fn decode_rgba(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+        let mut reader = png::Decoder::new(std::io::Cursor::new(bytes))
+            .read_info()
+            .unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        pixels.truncate(info.buffer_size());
+        (info.width, info.height, pixels)
+    }
+
+    /// Verify each texture array layer holds the same tile as its slot of the strip atlas.
+    #[test]
+    fn texture_array_layers_match_strip_atlas_tiles() {
+        let tile = ATLAS_TILE_PIXELS as usize;
+        let tiles = ATLAS_TEXTURE_ORDER.len();
+        let (strip_w, strip_h, strip) =
+            decode_rgba(include_bytes!("../../../assets/textures/atlas.png"));
+        let (array_w, array_h, array) =
+            decode_rgba(include_bytes!("../../../assets/textures/atlas_array.png"));
+        assert_eq!((strip_w as usize, strip_h as usize), (tile * tiles, tile));
+        assert_eq!((array_w as usize, array_h as usize), (tile, tile * tiles));
+        let row_bytes = tile * 4;
+        for layer in 0..tiles {
+            for y in 0..tile {
+                let strip_row = (y * tiles + layer) * row_bytes;
+                let array_row = (layer * tile + y) * row_bytes;
+                assert_eq!(
+                    strip[strip_row..strip_row + row_bytes],
+                    array[array_row..array_row + row_bytes],
+                    "layer {layer} row {y}"
+                );
+            }
+        }
+    }
+}
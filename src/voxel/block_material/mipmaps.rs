@@ -0,0 +1,225 @@
+use bevy::image::{ImageFilterMode, ImageSampler, ImageSamplerDescriptor};
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use std::path::Path;
+
+use super::{BLOCK_NORMAL_TEXTURE_PATH, BLOCK_TEXTURE_PATH};
+
+/// Bytes per RGBA8 texel.
+const TEXEL_BYTES: usize = 4;
+
+/// Return the sampler of block textures: crisp texels up close, blended mip levels far away.
+fn block_texture_sampler() -> ImageSampler {
+    ImageSampler::Descriptor(ImageSamplerDescriptor {
+        min_filter: ImageFilterMode::Linear,
+        mipmap_filter: ImageFilterMode::Linear,
+        ..ImageSamplerDescriptor::nearest()
+    })
+}
+
+/// Return how many mip levels a `width` by `height` layer gets.
+///
+/// Levels halve only while both sides stay even, so every texel of a level covers exactly
+/// four texels of the one above and strip atlas tiles never share a texel.
+fn mip_level_count(mut width: u32, mut height: u32) -> u32 {
+    let mut levels = 1;
+    while width.is_multiple_of(2) && height.is_multiple_of(2) {
+        width /= 2;
+        height /= 2;
+        levels += 1;
+    }
+    levels
+}
+
+/// Convert an sRGB encoded channel to linear light.
+fn srgb_to_linear(value: u8) -> f32 {
+    let value = value as f32 / 255.0;
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear light channel to sRGB encoding.
+fn linear_to_srgb(value: f32) -> u8 {
+    let value = if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    };
+    (value * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+/// Box filter one RGBA8 level of `width` by `height` texels down to half its size.
+///
+/// Color is weighted by alpha so the transparent background of cutout tiles does not darken
+/// the edges of leaves and flowers; sRGB levels are averaged in linear light.
+fn downsample(texels: &[u8], width: usize, height: usize, srgb: bool) -> Vec<u8> {
+    let decode = |value: u8| {
+        if srgb {
+            srgb_to_linear(value)
+        } else {
+            value as f32 / 255.0
+        }
+    };
+    let encode = |value: f32| {
+        if srgb {
+            linear_to_srgb(value)
+        } else {
+            (value * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+    };
+    let (half_width, half_height) = (width / 2, height / 2);
+    let mut out = Vec::with_capacity(half_width * half_height * TEXEL_BYTES);
+    for y in 0..half_height {
+        for x in 0..half_width {
+            let mut color = Vec3::ZERO;
+            let mut plain = Vec3::ZERO;
+            let mut alpha = 0.0;
+            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                let start = ((y * 2 + dy) * width + x * 2 + dx) * TEXEL_BYTES;
+                let texel = &texels[start..start + TEXEL_BYTES];
+                let rgb = Vec3::new(decode(texel[0]), decode(texel[1]), decode(texel[2]));
+                let a = texel[3] as f32 / 255.0;
+                color += rgb * a;
+                plain += rgb;
+                alpha += a;
+            }
+            let rgb = if alpha > 0.0 {
+                color / alpha
+            } else {
+                plain / 4.0
+            };
+            out.extend([encode(rgb.x), encode(rgb.y), encode(rgb.z)]);
+            out.push((alpha / 4.0 * 255.0).round() as u8);
+        }
+    }
+    out
+}
+
+/// Append a box-filtered mip chain to every layer of an RGBA8 `image`.
+///
+/// Returns `false` and leaves the image untouched when it has no CPU data, is in another
+/// format, or already has mips.
+pub(crate) fn generate_mipmaps(image: &mut Image) -> bool {
+    let descriptor = &image.texture_descriptor;
+    let srgb = match descriptor.format {
+        TextureFormat::Rgba8UnormSrgb => true,
+        TextureFormat::Rgba8Unorm => false,
+        _ => return false,
+    };
+    if descriptor.mip_level_count != 1 {
+        return false;
+    }
+    let Some(data) = image.data.as_ref() else {
+        return false;
+    };
+    let size = descriptor.size;
+    let levels = mip_level_count(size.width, size.height);
+    let layer_bytes = (size.width * size.height) as usize * TEXEL_BYTES;
+    let mut chained = Vec::with_capacity(data.len() * 4 / 3 + TEXEL_BYTES);
+    // Mips follow their layer's base level, the layer-major order wgpu uploads by default.
+    for layer in data.chunks_exact(layer_bytes) {
+        let mut level = layer.to_vec();
+        let (mut width, mut height) = (size.width as usize, size.height as usize);
+        for _ in 1..levels {
+            let next = downsample(&level, width, height, srgb);
+            chained.append(&mut level);
+            level = next;
+            width /= 2;
+            height /= 2;
+        }
+        chained.append(&mut level);
+    }
+    image.data = Some(chained);
+    image.texture_descriptor.mip_level_count = levels;
+    true
+}
+
+/// Give the block textures a mip chain and their sampler as soon as they load.
+///
+/// Without mips, distant terrain picks a few texels of each tile per pixel and sparkles as the
+/// camera moves; with nearest-neighbor magnification, close faces keep their crisp texels.
+pub fn block_texture_mipmap_system(
+    mut events: MessageReader<AssetEvent<Image>>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for event in events.read() {
+        let AssetEvent::LoadedWithDependencies { id } = event else {
+            continue;
+        };
+        let is_block_texture = asset_server.get_path(*id).is_some_and(|path| {
+            [BLOCK_TEXTURE_PATH, BLOCK_NORMAL_TEXTURE_PATH]
+                .iter()
+                .any(|block_path| path.path() == Path::new(block_path))
+        });
+        if !is_block_texture {
+            continue;
+        }
+        let Some(image) = images.get_mut(*id) else {
+            continue;
+        };
+        if generate_mipmaps(image) {
+            image.sampler = block_texture_sampler();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension};
+
+    /// Verify every layer gets its own chain, down to one texel per 4x4 layer.
+    #[test]
+    fn mip_chains_follow_each_layer() {
+        let mut data = Vec::new();
+        for layer_value in [40, 200] {
+            for _ in 0..16 {
+                data.extend([layer_value, layer_value, layer_value, 255]);
+            }
+        }
+        let mut image = Image::new(
+            Extent3d {
+                width: 4,
+                height: 4,
+                depth_or_array_layers: 2,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8Unorm,
+            RenderAssetUsages::default(),
+        );
+        assert!(generate_mipmaps(&mut image));
+        assert_eq!(image.texture_descriptor.mip_level_count, 3);
+        let data = image.data.as_ref().unwrap();
+        let layer_bytes = (16 + 4 + 1) * TEXEL_BYTES;
+        assert_eq!(data.len(), 2 * layer_bytes);
+        assert_eq!(
+            &data[layer_bytes - TEXEL_BYTES..layer_bytes],
+            &[40, 40, 40, 255]
+        );
+        assert_eq!(
+            &data[2 * layer_bytes - TEXEL_BYTES..],
+            &[200, 200, 200, 255]
+        );
+        assert!(!generate_mipmaps(&mut image));
+    }
+
+    /// Verify transparent texels do not bleed their color into a cutout's mips.
+    #[test]
+    fn transparent_texels_do_not_tint_mips() {
+        let texels = [
+            [0, 180, 0, 255],
+            [0, 0, 0, 0],
+            [0, 0, 0, 0],
+            [0, 180, 0, 255],
+        ]
+        .concat();
+        assert_eq!(downsample(&texels, 2, 2, true), vec![0, 180, 0, 128]);
+        assert_eq!(mip_level_count(608, 16), 5);
+    }
+}
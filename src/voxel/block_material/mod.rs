@@ -0,0 +1,32 @@
+#[cfg(not(feature = "strip_atlas"))]
+mod array;
+mod mipmaps;
+#[cfg(feature = "strip_atlas")]
+mod strip;
+
+use bevy::prelude::*;
+
+#[cfg(not(feature = "strip_atlas"))]
+pub use array::{
+    BLOCK_NORMAL_TEXTURE_PATH, BLOCK_TEXTURE_PATH, BlockMaterial, BlockMaterialPlugin,
+    FluidMaterial, block_material, configure_block_texture, fluid_material, set_block_normal_map,
+};
+use mipmaps::block_texture_mipmap_system;
+#[cfg(feature = "strip_atlas")]
+pub use strip::{
+    BLOCK_NORMAL_TEXTURE_PATH, BLOCK_TEXTURE_PATH, BlockMaterial, BlockMaterialPlugin,
+    FluidMaterial, block_material, configure_block_texture, fluid_material, set_block_normal_map,
+};
+
+/// Seconds after which the fluid shaders' flow has moved a whole number of tiles, so the
+/// flow clock can wrap without a visible jump.
+const FLUID_FLOW_PERIOD_SECS: f64 = 48.0;
+
+/// Advance the flow clock of every fluid material, wrapped to `FLUID_FLOW_PERIOD_SECS` so
+/// the uniform keeps its precision in long sessions.
+fn fluid_flow_system(time: Res<Time>, mut materials: ResMut<Assets<FluidMaterial>>) {
+    let cycle = (time.elapsed_secs_f64() % FLUID_FLOW_PERIOD_SECS) as f32;
+    for (_, material) in materials.iter_mut() {
+        material.extension.time = cycle;
+    }
+}
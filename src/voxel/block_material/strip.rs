@@ -0,0 +1,82 @@
+use bevy::image::ImageLoaderSettings;
+use bevy::pbr::{ExtendedMaterial, MaterialExtension};
+use bevy::prelude::*;
+use bevy::render::render_resource::AsBindGroup;
+use bevy::shader::ShaderRef;
+
+use super::{block_texture_mipmap_system, fluid_flow_system};
+use crate::material_catalog::ATLAS_TEXTURE_ORDER;
+
+/// Material of block meshes: chunks, the in-hand preview, drops, and falling blocks.
+pub type BlockMaterial = StandardMaterial;
+/// Material of chunk fluid meshes, scrolling their strip atlas tile over time.
+pub type FluidMaterial = ExtendedMaterial<StandardMaterial, FluidStrip>;
+
+/// Asset path of the block textures, every tile side by side in one row.
+pub const BLOCK_TEXTURE_PATH: &str = "textures/atlas.png";
+/// Asset path of the optional block normal textures, laid out like `BLOCK_TEXTURE_PATH`.
+pub const BLOCK_NORMAL_TEXTURE_PATH: &str = "textures/atlas_n.png";
+
+/// Fragment shader of fluid meshes, sampling their strip atlas tile with scrolled UVs.
+const FLUID_FRAGMENT_SHADER_PATH: &str = "shaders/fluid_strip.wgsl";
+
+/// Standard material extension sampling a fluid's strip atlas tile with UVs scrolled by
+/// `time`, wrapped inside the tile.
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct FluidStrip {
+    /// Block albedo, every tile side by side in one row.
+    #[texture(100)]
+    #[sampler(101)]
+    textures: Handle<Image>,
+    /// Seconds into the flow cycle, advanced by `fluid_flow_system`.
+    #[uniform(102)]
+    pub(super) time: f32,
+    /// Tiles in the strip, to find the tile a UV lies in.
+    #[uniform(103)]
+    tiles: u32,
+}
+
+impl MaterialExtension for FluidStrip {
+    fn fragment_shader() -> ShaderRef {
+        FLUID_FRAGMENT_SHADER_PATH.into()
+    }
+}
+
+/// Registers the mip generation of the strip atlas, drawn by the built-in standard material,
+/// the fluid material, and the fluid flow clock.
+pub struct BlockMaterialPlugin;
+
+impl Plugin for BlockMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<FluidMaterial>::default())
+            .add_systems(Update, (block_texture_mipmap_system, fluid_flow_system));
+    }
+}
+
+/// Leave the strip atlas loading as a plain 2D image.
+pub fn configure_block_texture(_settings: &mut ImageLoaderSettings) {}
+
+/// Build a block material from `base` sampling the block textures `textures`.
+pub fn block_material(base: StandardMaterial, textures: Handle<Image>) -> BlockMaterial {
+    StandardMaterial {
+        base_color_texture: Some(textures),
+        ..base
+    }
+}
+
+/// Build a fluid material from `base` scrolling the block textures `textures`.
+pub fn fluid_material(base: StandardMaterial, textures: Handle<Image>) -> FluidMaterial {
+    ExtendedMaterial {
+        base,
+        extension: FluidStrip {
+            textures,
+            time: 0.0,
+            tiles: ATLAS_TEXTURE_ORDER.len() as u32,
+        },
+    }
+}
+
+/// Sample the block normal textures `normals` in `material`, or stop when `None`.
+pub fn set_block_normal_map(material: &mut BlockMaterial, normals: Option<Handle<Image>>) {
+    material.normal_map_texture = normals;
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+/// Seconds covered grass survives before it turns into dirt.
+pub(crate) const GRASS_COVER_DELAY_SECS: f32 = 2.0;
+
+#[derive(Default)]
+/// Queue of world positions due for a delayed block update, in due order.
+///
+/// Every update is scheduled with the same delay, so due times never decrease along the
+/// queue and only its front needs checking.
+pub struct BlockUpdateQueue {
+    /// Seconds the queue has advanced through, the clock due times are measured on.
+    clock: f32,
+    /// Pending positions with the clock time they are due at.
+    pending: VecDeque<(IVec3, f32)>,
+    /// Set used to deduplicate pending positions.
+    scheduled: HashSet<IVec3>,
+}
+
+impl BlockUpdateQueue {
+    /// Schedule `pos` for an update `delay` seconds from now.
+    ///
+    /// A position already pending keeps its earlier due time.
+    pub fn schedule(&mut self, pos: IVec3, delay: f32) {
+        if self.scheduled.insert(pos) {
+            self.pending.push_back((pos, self.clock + delay));
+        }
+    }
+
+    /// Advance the queue clock by `dt` seconds.
+    pub fn advance(&mut self, dt: f32) {
+        self.clock += dt;
+    }
+
+    /// Pop one position whose update is due.
+    pub fn pop_due(&mut self) -> Option<IVec3> {
+        let (pos, due) = *self.pending.front()?;
+        if due > self.clock {
+            return None;
+        }
+        self.pending.pop_front();
+        self.scheduled.remove(&pos);
+        Some(pos)
+    }
+}
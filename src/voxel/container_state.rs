@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::voxel::block_chunk::Block;
+use crate::voxel::item_state::Inventory;
+use crate::voxel::world_state::WorldState;
+
+#[derive(Resource, Default, Debug)]
+/// Container screen state: the block whose storage is shown, if any.
+pub struct ContainerMenu {
+    /// World cell of the open container block.
+    open: Option<IVec3>,
+}
+
+impl ContainerMenu {
+    /// Return the cell of the open container block, if the screen is shown.
+    pub fn open_at(&self) -> Option<IVec3> {
+        self.open
+    }
+
+    /// Show the storage of the container block at `pos`.
+    pub fn open(&mut self, pos: IVec3) {
+        self.open = Some(pos);
+    }
+
+    /// Hide the container screen.
+    pub fn close(&mut self) {
+        self.open = None;
+    }
+
+    /// Move every held `item` that fits into the open container.
+    pub fn deposit(&self, world: &mut WorldState, inventory: &mut Inventory, item: Block) {
+        let Some(container) = self
+            .open
+            .and_then(|pos| world.block_entities.container_mut(pos))
+        else {
+            return;
+        };
+        let held = inventory.take_many(item, inventory.count(item));
+        inventory.add_many(item, container.insert(item, held));
+    }
+
+    /// Move the stack in slot `index` of the open container into `inventory`.
+    pub fn withdraw(&self, world: &mut WorldState, inventory: &mut Inventory, index: usize) {
+        if let Some((item, count)) = self
+            .open
+            .and_then(|pos| world.block_entities.container_mut(pos))
+            .and_then(|container| container.take(index))
+        {
+            inventory.add_many(item, count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::block_chunk::BlockKind;
+    use crate::voxel::block_entity_state::{BlockEntity, CHEST_SLOTS, MAX_STACK};
+
+    /// Verify items move between the inventory and the open chest, and a closed screen is inert.
+    #[test]
+    fn deposit_and_withdraw_move_items_through_open_chest() {
+        let mut world = WorldState::new();
+        let chest = IVec3::new(3, 4, 5);
+        world
+            .block_entities
+            .insert(chest, BlockEntity::for_kind(BlockKind::Chest).unwrap());
+        let mut inventory = Inventory::default();
+        inventory.add_many(BlockKind::Dirt, MAX_STACK + 3);
+        let mut menu = ContainerMenu::default();
+
+        menu.deposit(&mut world, &mut inventory, Block::dirt());
+        assert_eq!(inventory.count(BlockKind::Dirt), MAX_STACK + 3);
+
+        menu.open(chest);
+        menu.deposit(&mut world, &mut inventory, Block::dirt());
+        assert_eq!(inventory.count(BlockKind::Dirt), 0);
+        let container = world.block_entities.container_mut(chest).unwrap();
+        assert_eq!(container.slots().len(), CHEST_SLOTS);
+        assert_eq!(container.slots()[1], Some((Block::dirt(), 3)));
+
+        menu.withdraw(&mut world, &mut inventory, 0);
+        assert_eq!(inventory.count(BlockKind::Dirt), MAX_STACK);
+    }
+}
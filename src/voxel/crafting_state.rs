@@ -0,0 +1,211 @@
+use bevy::prelude::*;
+
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::block_defs::Tool;
+use crate::voxel::item_state::{Inventory, ItemKind};
+
+/// Number of input slots in the crafting grid (2×2).
+pub(crate) const CRAFTING_GRID_SLOTS: usize = 4;
+
+/// One shapeless recipe: the exact grid contents it takes and the items it makes.
+struct Recipe {
+    /// Input kinds with how many grid slots each must fill.
+    inputs: &'static [(BlockKind, usize)],
+    /// Item the recipe makes.
+    output: ItemKind,
+    /// Number of items made per craft.
+    count: u32,
+}
+
+/// Every known recipe; slot order in the grid does not matter.
+const RECIPES: &[Recipe] = &[
+    Recipe {
+        inputs: &[(BlockKind::Log, 1)],
+        output: ItemKind::Block(Block::of_kind(BlockKind::Planks)),
+        count: 4,
+    },
+    Recipe {
+        inputs: &[(BlockKind::Planks, 4)],
+        output: ItemKind::Block(Block::of_kind(BlockKind::CraftingTable)),
+        count: 1,
+    },
+    Recipe {
+        inputs: &[(BlockKind::Planks, 3), (BlockKind::Log, 1)],
+        output: ItemKind::Tool(Tool::WOOD_PICKAXE),
+        count: 1,
+    },
+    Recipe {
+        inputs: &[(BlockKind::Stone, 3), (BlockKind::Log, 1)],
+        output: ItemKind::Tool(Tool::STONE_PICKAXE),
+        count: 1,
+    },
+];
+
+#[derive(Resource, Default, Debug)]
+/// Crafting screen state: whether it is open and the items laid into its grid.
+///
+/// Grid items are taken out of the `Inventory` while they sit in the grid and go back when
+/// the screen closes.
+pub struct CraftingMenu {
+    /// Whether the crafting screen is shown.
+    open: bool,
+    /// Block item in each grid slot, in reading order.
+    pub(crate) grid: [Option<Block>; CRAFTING_GRID_SLOTS],
+}
+
+impl CraftingMenu {
+    /// Return whether the crafting screen is shown.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show the crafting screen with an empty grid.
+    pub fn open(&mut self) {
+        self.open = true;
+    }
+
+    /// Hide the crafting screen, returning grid items to `inventory`.
+    pub fn close(&mut self, inventory: &mut Inventory) {
+        for slot in &mut self.grid {
+            if let Some(item) = slot.take() {
+                inventory.add(item);
+            }
+        }
+        self.open = false;
+    }
+
+    /// Move one `item` from `inventory` into the first empty grid slot.
+    ///
+    /// Returns `false` when the grid is full or no such item is held.
+    pub fn put(&mut self, inventory: &mut Inventory, item: Block) -> bool {
+        let Some(slot) = self.grid.iter_mut().find(|slot| slot.is_none()) else {
+            return false;
+        };
+        if !inventory.take(item) {
+            return false;
+        }
+        *slot = Some(item.as_item());
+        true
+    }
+
+    /// Move the item in grid slot `index` back to `inventory`.
+    pub fn take_back(&mut self, inventory: &mut Inventory, index: usize) {
+        if let Some(item) = self.grid.get_mut(index).and_then(Option::take) {
+            inventory.add(item);
+        }
+    }
+
+    /// Return the item and count the current grid crafts into, if it matches a recipe.
+    pub fn output(&self) -> Option<(ItemKind, u32)> {
+        let filled = self.grid.iter().flatten().count();
+        RECIPES
+            .iter()
+            .find(|recipe| {
+                recipe.inputs.iter().map(|(_, n)| n).sum::<usize>() == filled
+                    && recipe.inputs.iter().all(|(kind, n)| {
+                        self.grid
+                            .iter()
+                            .filter(|slot| slot.is_some_and(|item| item.kind == *kind))
+                            .count()
+                            == *n
+                    })
+            })
+            .map(|recipe| (recipe.output, recipe.count))
+    }
+
+    /// Consume the grid and add its recipe output to `inventory`.
+    ///
+    /// Returns `false` and keeps the grid when it matches no recipe.
+    pub fn craft(&mut self, inventory: &mut Inventory) -> bool {
+        let Some((kind, count)) = self.output() else {
+            return false;
+        };
+        self.grid = [None; CRAFTING_GRID_SLOTS];
+        inventory.add_many(kind, count);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify a log crafts into planks and four planks into a table, whatever the slot order.
+    #[test]
+    fn grid_crafts_matching_recipes_only() {
+        let mut inventory = Inventory::default();
+        inventory.add(BlockKind::Log);
+        inventory.add(BlockKind::Dirt);
+        let mut menu = CraftingMenu::default();
+        menu.open();
+
+        assert!(menu.put(&mut inventory, Block::of_kind(BlockKind::Log)));
+        assert!(!menu.put(&mut inventory, Block::of_kind(BlockKind::Log)));
+        assert_eq!(menu.output(), Some((BlockKind::Planks.into(), 4)));
+        assert!(menu.put(&mut inventory, Block::of_kind(BlockKind::Dirt)));
+        assert_eq!(menu.output(), None);
+        assert!(!menu.craft(&mut inventory));
+        menu.take_back(&mut inventory, 1);
+        assert!(menu.craft(&mut inventory));
+        assert_eq!(menu.grid, [None; CRAFTING_GRID_SLOTS]);
+        assert_eq!(inventory.count(BlockKind::Log), 0);
+        assert_eq!(inventory.count(BlockKind::Planks), 4);
+
+        for _ in 0..4 {
+            assert!(menu.put(&mut inventory, Block::of_kind(BlockKind::Planks)));
+        }
+        assert!(!menu.put(&mut inventory, Block::of_kind(BlockKind::Dirt)));
+        assert!(menu.craft(&mut inventory));
+        assert_eq!(inventory.count(BlockKind::CraftingTable), 1);
+        assert_eq!(inventory.count(BlockKind::Planks), 0);
+    }
+
+    /// Verify planks and stone each make a pickaxe around a log handle.
+    #[test]
+    fn pickaxes_craft_from_planks_and_stone() {
+        let mut inventory = Inventory::default();
+        inventory.add_many(BlockKind::Planks, 3);
+        inventory.add_many(BlockKind::Stone, 3);
+        inventory.add_many(BlockKind::Log, 2);
+        let mut menu = CraftingMenu::default();
+        menu.open();
+
+        for kind in [
+            BlockKind::Log,
+            BlockKind::Planks,
+            BlockKind::Planks,
+            BlockKind::Planks,
+        ] {
+            assert!(menu.put(&mut inventory, Block::of_kind(kind)));
+        }
+        assert!(menu.craft(&mut inventory));
+        for kind in [BlockKind::Stone, BlockKind::Stone, BlockKind::Log] {
+            assert!(menu.put(&mut inventory, Block::of_kind(kind)));
+        }
+        assert_eq!(menu.output(), None);
+        assert!(menu.put(&mut inventory, Block::of_kind(BlockKind::Stone)));
+        assert!(menu.craft(&mut inventory));
+
+        assert_eq!(inventory.count(Tool::WOOD_PICKAXE), 1);
+        assert_eq!(inventory.count(Tool::STONE_PICKAXE), 1);
+        assert_eq!(inventory.held().count(), 2);
+    }
+
+    /// Verify closing the screen hands every grid item back to the inventory.
+    #[test]
+    fn closing_returns_grid_items() {
+        let mut inventory = Inventory::default();
+        inventory.add_many(BlockKind::Planks, 3);
+        let mut menu = CraftingMenu::default();
+        menu.open();
+        for _ in 0..3 {
+            assert!(menu.put(&mut inventory, Block::of_kind(BlockKind::Planks)));
+        }
+        assert_eq!(inventory.count(BlockKind::Planks), 0);
+
+        menu.close(&mut inventory);
+        assert!(!menu.is_open());
+        assert_eq!(inventory.count(BlockKind::Planks), 3);
+        assert_eq!(menu.grid, [None; CRAFTING_GRID_SLOTS]);
+    }
+}
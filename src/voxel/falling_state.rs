@@ -4,6 +4,8 @@ use std::collections::{HashSet, VecDeque};
 use crate::BLOCK_SIZE;
 
 use crate::voxel::block_chunk::Block;
+use crate::voxel::material_state::WorldMaterial;
+use crate::voxel::world_state::WorldState;
 
 #[derive(Resource, Default)]
 /// Queue of world positions that need falling-support re-evaluation.
@@ -46,10 +48,24 @@ impl FallingPropagationQueue {
 #[derive(Component)]
 /// Runtime state for a block currently simulated as a falling entity.
 pub struct FallingBlock {
-    /// Block state carried by the falling entity.
+    /// Block state carried by the falling entity, written back verbatim (kind and facing) on settle.
     pub(crate) block: Block,
     /// Current vertical velocity in world units per second.
     pub(crate) velocity_y: f32,
+    /// Seconds the block has rested on ground without being able to settle there.
+    pub(crate) stalled_secs: f32,
+}
+
+#[derive(Component, Debug, PartialEq, Eq)]
+/// Entity of a block written into its chunk whose mesh does not show it yet.
+///
+/// It keeps drawing the block in its landing cell until the chunk's off-thread rebuild
+/// covering the write has been swapped in.
+pub struct SettledBlock {
+    /// Chunk the block settled into.
+    pub(crate) chunk: IVec3,
+    /// Rebuild generation of the chunk that shows the block.
+    pub(crate) generation: u64,
 }
 
 impl FallingBlock {
@@ -58,9 +74,29 @@ impl FallingBlock {
         Self {
             block,
             velocity_y: 0.0,
+            stalled_secs: 0.0,
         }
     }
 
+    /// Spawn a falling-block entity with this state at `translation`, sharing the world's
+    /// cached block mesh.
+    pub(crate) fn spawn(
+        self,
+        commands: &mut Commands,
+        meshes: &mut Assets<Mesh>,
+        world: &mut WorldState,
+        translation: Vec3,
+    ) {
+        let mesh = world.block_meshes.get_or_build(meshes, self.block);
+        commands.spawn((
+            bevy::mesh::Mesh3d(mesh),
+            WorldMaterial::Opaque,
+            Transform::from_translation(translation),
+            self,
+            Name::new("FallingBlock"),
+        ));
+    }
+
     /// Integrate vertical velocity by gravity and return the frame displacement on Y.
     pub(crate) fn integrate_vertical(&mut self, dt: f32, gravity: f32) -> f32 {
         self.velocity_y -= gravity * dt;
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use std::sync::Arc;
+
+use crate::CHUNK_SIZE;
+use crate::terrain::{TerrainConfig, WorldGenMode};
+use crate::voxel::block_chunk::{Block, Chunk};
+
+/// Source of freshly generated chunk voxels for streaming.
+///
+/// Generators run on async build tasks, so they must be pure functions of `coord`.
+pub trait ChunkGenerator {
+    /// Generate the voxels of the chunk at chunk coordinate `coord`.
+    fn generate(&self, coord: IVec3) -> Chunk;
+}
+
+/// Shared generator handle stored in `WorldState` and cloned into build tasks.
+pub type SharedChunkGenerator = Arc<dyn ChunkGenerator + Send + Sync>;
+
+/// Return the generator for a world of `mode`, built from `seed` and a preset-shaped `config`.
+pub fn generator_for_mode(
+    seed: u64,
+    mode: WorldGenMode,
+    config: TerrainConfig,
+) -> SharedChunkGenerator {
+    match mode {
+        WorldGenMode::Normal | WorldGenMode::Amplified | WorldGenMode::Islands => {
+            Arc::new(NoiseGenerator { seed, config })
+        }
+        WorldGenMode::Superflat => Arc::new(FlatGenerator {
+            height: WorldGenMode::SUPERFLAT_HEIGHT,
+        }),
+    }
+}
+
+/// Noise terrain with biomes, caves, ores, trees, ruins, water, and decorations.
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseGenerator {
+    /// World seed.
+    pub seed: u64,
+    /// Terrain shaping parameters, already carrying any `WorldGenMode::shape` preset.
+    pub config: TerrainConfig,
+}
+
+impl ChunkGenerator for NoiseGenerator {
+    fn generate(&self, coord: IVec3) -> Chunk {
+        if Chunk::is_streaming_layer(coord.y) {
+            Chunk::new_terrain(coord, self.seed, &self.config)
+        } else {
+            Chunk::new_empty()
+        }
+    }
+}
+
+/// Flat slab in layer 0: bedrock at y = 0, dirt, then grass at `height`.
+#[derive(Clone, Copy, Debug)]
+pub struct FlatGenerator {
+    /// World y of the grass surface, inside the first chunk layer.
+    pub height: i32,
+}
+
+impl ChunkGenerator for FlatGenerator {
+    fn generate(&self, coord: IVec3) -> Chunk {
+        let mut chunk = Chunk::new_empty();
+        if coord.y != 0 {
+            return chunk;
+        }
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                for y in 0..=self.height.min(CHUNK_SIZE - 1) {
+                    let block = match y {
+                        0 => Block::bedrock(),
+                        _ if y == self.height => Block::dirt_with_grass(),
+                        _ => Block::dirt(),
+                    };
+                    chunk.set_block(IVec3::new(x, y, z), block);
+                }
+            }
+        }
+        chunk
+    }
+}
+
+/// Known test pattern: stone pillars up to `height` on columns where `x + z` is even.
+#[cfg(test)]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CheckerboardGenerator {
+    /// Pillar cells fill world y in `0..height`.
+    pub(crate) height: i32,
+}
+
+#[cfg(test)]
+impl CheckerboardGenerator {
+    /// Return the block this pattern holds at world coordinate `pos`.
+    pub(crate) fn block_at(&self, pos: IVec3) -> Block {
+        if (0..self.height).contains(&pos.y) && (pos.x + pos.z).rem_euclid(2) == 0 {
+            Block::stone()
+        } else {
+            Block::air()
+        }
+    }
+}
+
+#[cfg(test)]
+impl ChunkGenerator for CheckerboardGenerator {
+    fn generate(&self, coord: IVec3) -> Chunk {
+        let mut chunk = Chunk::new_empty();
+        let base = coord * CHUNK_SIZE;
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    let local = IVec3::new(x, y, z);
+                    chunk.set_block(local, self.block_at(base + local));
+                }
+            }
+        }
+        chunk
+    }
+}
@@ -2,7 +2,119 @@ use bevy::prelude::*;
 
 use crate::player::PreviewBlock;
 use crate::voxel::block_chunk::Block;
-use crate::voxel::mesh::{build_single_block_mesh_data, mesh_from_data};
+use crate::voxel::block_defs::Tool;
+use crate::voxel::container_state::ContainerMenu;
+use crate::voxel::crafting_state::CraftingMenu;
+use crate::voxel::item_state::Inventory;
+use crate::voxel::mesh::BlockMeshCache;
+
+/// Return `true` while no block screen (crafting, container) holds the mouse.
+///
+/// Camera look and block interaction only run while this holds.
+pub fn world_controls_enabled(crafting: Res<CraftingMenu>, container: Res<ContainerMenu>) -> bool {
+    !crafting.is_open() && container.open_at().is_none()
+}
+
+#[derive(Resource, Default, PartialEq)]
+/// Block the camera is aiming at, shared by interaction feedback systems.
+pub struct CurrentTarget {
+    /// First solid block hit by the interaction ray.
+    pub hit: Option<IVec3>,
+    /// Valid placement cell attached to a visible face of `hit`.
+    pub place: Option<IVec3>,
+    /// Distance from the eye to the center of `place`, in block units.
+    pub place_distance: f32,
+}
+
+#[derive(Resource, Default, Debug, PartialEq)]
+/// Progress of breaking the block held under the cursor, readable by break feedback.
+pub struct BreakProgress {
+    /// Block being broken, or `None` when the break button is not held on a block.
+    pub target: Option<IVec3>,
+    /// Seconds the break button has been held on `target`.
+    pub elapsed: f32,
+    /// Seconds `target` takes to break.
+    pub hardness: f32,
+    /// Whether every block breaks on the first frame, ignoring hardness.
+    pub instant: bool,
+}
+
+impl BreakProgress {
+    /// Read `--instant-break` from the command line to enable creative-style breaking.
+    pub fn from_args() -> Self {
+        Self {
+            instant: std::env::args().skip(1).any(|arg| arg == "--instant-break"),
+            ..default()
+        }
+    }
+
+    /// Hold the break button on `target` for `dt` more seconds and return whether a block
+    /// of `hardness` is now broken.
+    ///
+    /// Switching to another target starts over from zero.
+    pub(crate) fn advance(&mut self, target: IVec3, hardness: f32, dt: f32) -> bool {
+        if self.target != Some(target) {
+            self.target = Some(target);
+            self.elapsed = 0.0;
+        }
+        self.hardness = hardness;
+        self.elapsed += dt;
+        self.instant || self.elapsed >= hardness
+    }
+
+    /// Return how far `target` is broken, from 0 to 1.
+    ///
+    /// `None` while no block is held, and for breaks that take no time at all.
+    pub fn fraction(&self) -> Option<f32> {
+        self.target?;
+        (!self.instant && self.hardness > 0.0).then(|| (self.elapsed / self.hardness).min(1.0))
+    }
+
+    /// Drop any progress, as when the button is released or the target is lost.
+    pub(crate) fn reset(&mut self) {
+        self.target = None;
+        self.elapsed = 0.0;
+        self.hardness = 0.0;
+    }
+}
+
+#[derive(Resource, Default, Debug, PartialEq)]
+/// Hotbar tool slot: the tool in the hand while breaking, or bare hands.
+///
+/// Only tools held in the `Inventory` can be picked; see `held_tool_system`.
+pub struct HeldTool {
+    /// Tool picked with `CYCLE_KEY`, or `None` for bare hands.
+    current: Option<Tool>,
+}
+
+impl HeldTool {
+    /// Key that switches to the next tool held in the inventory.
+    pub(crate) const CYCLE_KEY: KeyCode = KeyCode::KeyT;
+
+    /// Return the tool in the hand, or `None` when bare-handed.
+    pub fn tool(&self) -> Option<Tool> {
+        self.current
+    }
+
+    /// Empty the hand once its tool is no longer in `inventory`, as after storing it away.
+    pub(crate) fn drop_missing(&mut self, inventory: &Inventory) {
+        if self.current.is_some_and(|tool| inventory.count(tool) == 0) {
+            self.current = None;
+        }
+    }
+
+    /// Switch to the next tool in `inventory` after the current one in `Tool::ALL` order,
+    /// going back to bare hands after the last.
+    pub(crate) fn cycle(&mut self, inventory: &Inventory) {
+        let mut owned = Tool::ALL
+            .into_iter()
+            .filter(|tool| inventory.count(*tool) > 0);
+        self.current = match self.current {
+            None => owned.next(),
+            Some(current) => owned.skip_while(|tool| *tool != current).nth(1),
+        };
+    }
+}
 
 #[derive(Resource)]
 /// Placement/preview selection state for the current block variant.
@@ -17,28 +129,41 @@ impl SelectedBlock {
         Self { current }
     }
 
-    /// Hotkey for selecting grassed dirt block.
-    const SELECT_BLOCK_KEY_1: KeyCode = KeyCode::Digit1;
-    /// Hotkey for selecting plain dirt block.
-    const SELECT_BLOCK_KEY_2: KeyCode = KeyCode::Digit2;
-    /// Hotkey for selecting sand block.
-    const SELECT_BLOCK_KEY_3: KeyCode = KeyCode::Digit3;
+    /// Block-selection hotkeys and the constructor of the block each selects.
+    pub(crate) const HOTKEYS: [(KeyCode, fn() -> Block); 18] = [
+        (KeyCode::Digit1, Block::dirt_with_grass),
+        (KeyCode::Digit2, Block::dirt),
+        (KeyCode::Digit3, Block::sand),
+        (KeyCode::Digit4, Block::gravel),
+        (KeyCode::Digit5, Block::glass),
+        (KeyCode::Digit6, Block::torch),
+        (KeyCode::Digit7, Block::slab),
+        (KeyCode::Digit8, Block::stairs),
+        (KeyCode::Digit9, Block::tnt),
+        (KeyCode::Digit0, Block::fence),
+        (KeyCode::Minus, Block::sapling),
+        (KeyCode::Equal, Block::crafting_table),
+        (KeyCode::Period, Block::chest),
+        (KeyCode::Slash, Block::door),
+        (KeyCode::Backslash, Block::glowstone),
+        (KeyCode::Semicolon, Block::stone),
+        (KeyCode::Quote, Block::ice),
+        (KeyCode::Comma, Block::sponge),
+    ];
 
     /// Apply block-selection hotkeys and refresh preview mesh when selection changes.
     pub(crate) fn apply_hotkeys(
         &mut self,
         keys: &Res<ButtonInput<KeyCode>>,
         meshes: &mut ResMut<Assets<Mesh>>,
+        block_meshes: &mut BlockMeshCache,
         preview_query: &mut Query<&mut bevy::mesh::Mesh3d, With<PreviewBlock>>,
     ) {
-        if keys.just_pressed(Self::SELECT_BLOCK_KEY_1) {
-            self.set_with_preview(Block::dirt_with_grass(), meshes, preview_query);
-        }
-        if keys.just_pressed(Self::SELECT_BLOCK_KEY_2) {
-            self.set_with_preview(Block::dirt(), meshes, preview_query);
-        }
-        if keys.just_pressed(Self::SELECT_BLOCK_KEY_3) {
-            self.set_with_preview(Block::sand(), meshes, preview_query);
+        if let Some((_, block)) = Self::HOTKEYS
+            .iter()
+            .find(|(key, _)| keys.just_pressed(*key))
+        {
+            self.set_with_preview(block(), meshes, block_meshes, preview_query);
         }
     }
 
@@ -47,83 +172,65 @@ impl SelectedBlock {
         &mut self,
         block: Block,
         meshes: &mut ResMut<Assets<Mesh>>,
+        block_meshes: &mut BlockMeshCache,
         preview_query: &mut Query<&mut bevy::mesh::Mesh3d, With<PreviewBlock>>,
     ) {
         self.current = block;
-        self.update_preview_mesh(meshes, preview_query);
+        self.update_preview_mesh(meshes, block_meshes, preview_query);
     }
 
-    /// Update the preview mesh to match current selected block.
+    /// Point the preview at the shared mesh for the current selected block.
     fn update_preview_mesh(
         &self,
         meshes: &mut ResMut<Assets<Mesh>>,
+        block_meshes: &mut BlockMeshCache,
         preview_query: &mut Query<&mut bevy::mesh::Mesh3d, With<PreviewBlock>>,
     ) {
         let Ok(mut mesh_handle) = preview_query.single_mut() else {
             return;
         };
-        let new_mesh = meshes.add(mesh_from_data(build_single_block_mesh_data(self.current)));
-        *mesh_handle = bevy::mesh::Mesh3d(new_mesh);
+        *mesh_handle = bevy::mesh::Mesh3d(block_meshes.get_or_build(meshes, self.current));
     }
 }
 
-#[derive(Resource)]
-/// Cooldown timestamps for repeated break/place interactions.
-pub struct InteractionCooldown {
-    /// Last simulation time (seconds) when breaking was applied.
-    pub last_break_time: f32,
-    /// Last simulation time (seconds) when placing was applied.
-    pub last_place_time: f32,
-}
-
-impl InteractionCooldown {
-    /// Construct interaction cooldown state with "ready" timestamps.
-    pub fn new() -> Self {
-        Self {
-            last_break_time: -1.0,
-            last_place_time: -1.0,
-        }
-    }
-
-    /// Interaction cooldown in seconds.
-    const INTERACTION_COOLDOWN_SECS: f32 = 0.2;
-
-    /// Return whether break interaction is currently allowed.
-    pub(crate) fn can_break(&self, buttons: &ButtonInput<MouseButton>, time: &Time) -> bool {
-        self.can_with_button(buttons, MouseButton::Left, self.last_break_time, time)
-    }
-
-    /// Return whether place interaction is currently allowed.
-    pub(crate) fn can_place(&self, buttons: &ButtonInput<MouseButton>, time: &Time) -> bool {
-        self.can_with_button(buttons, MouseButton::Right, self.last_place_time, time)
-    }
-
-    /// Record break action timestamp.
-    pub(crate) fn mark_break(&mut self, time: &Time) {
-        let now = Self::now(time);
-        self.last_break_time = now;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify cycling walks the owned tools in order and wraps back to bare hands.
+    #[test]
+    fn held_tool_cycles_through_owned_tools() {
+        let mut inventory = Inventory::default();
+        let mut held = HeldTool::default();
+        held.cycle(&inventory);
+        assert_eq!(held.tool(), None);
+
+        inventory.add(Tool::WOOD_PICKAXE);
+        inventory.add(Tool::STONE_PICKAXE);
+        held.cycle(&inventory);
+        assert_eq!(held.tool(), Some(Tool::WOOD_PICKAXE));
+        held.cycle(&inventory);
+        assert_eq!(held.tool(), Some(Tool::STONE_PICKAXE));
+        held.cycle(&inventory);
+        assert_eq!(held.tool(), None);
+        held.cycle(&inventory);
+        held.drop_missing(&inventory);
+        assert_eq!(held.tool(), Some(Tool::WOOD_PICKAXE));
+
+        inventory.take(Tool::WOOD_PICKAXE);
+        held.drop_missing(&inventory);
+        assert_eq!(held.tool(), None);
     }
 
-    /// Record place action timestamp.
-    pub(crate) fn mark_place(&mut self, time: &Time) {
-        let now = Self::now(time);
-        self.last_place_time = now;
-    }
-
-    /// Read current elapsed seconds from Bevy time resource.
-    fn now(time: &Time) -> f32 {
-        time.elapsed_secs()
-    }
-
-    /// Generic cooldown gate for one mouse button and last-trigger timestamp.
-    fn can_with_button(
-        &self,
-        buttons: &ButtonInput<MouseButton>,
-        button: MouseButton,
-        last_time: f32,
-        time: &Time,
-    ) -> bool {
-        let now = Self::now(time);
-        buttons.pressed(button) && now - last_time >= Self::INTERACTION_COOLDOWN_SECS
+    /// Verify every hotkey is bound once and selects a distinct block kind.
+    #[test]
+    fn hotkeys_are_unique() {
+        let hotkeys = SelectedBlock::HOTKEYS;
+        for (i, (key, block)) in hotkeys.iter().enumerate() {
+            for (other_key, other_block) in &hotkeys[i + 1..] {
+                assert_ne!(key, other_key);
+                assert_ne!(block().kind, other_block().kind);
+            }
+        }
     }
 }
@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::BLOCK_SIZE;
+
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::block_defs::Tool;
+use crate::voxel::material_state::WorldMaterial;
+use crate::voxel::world_state::WorldState;
+
+/// Scale of a dropped item's cube relative to a full block.
+pub(crate) const ITEM_SCALE: f32 = 0.25;
+/// Upward speed a dropped item pops out of its broken cell with, in world units per second.
+const ITEM_POP_SPEED: f32 = 4.0;
+/// Turn rate of a dropped item about its vertical axis, in radians per second.
+const ITEM_SPIN_SPEED: f32 = 1.5;
+
+/// Anything the inventory can hold: a block to place or a tool to mine with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// A placeable block, in the state its item keeps (see `Block::as_item`).
+    Block(Block),
+    /// A tool held in the hand while breaking blocks.
+    Tool(Tool),
+}
+
+impl ItemKind {
+    /// Return the display name of this item.
+    pub fn name(self) -> String {
+        match self {
+            Self::Block(block) if block.is_wet_sponge() => "WetSponge".to_string(),
+            Self::Block(block) => format!("{:?}", block.kind),
+            Self::Tool(tool) => tool.name(),
+        }
+    }
+
+    /// Return the position of this item in inventory order: blocks in `BlockKind::ALL`
+    /// order, plain state first, then tools in `Tool::ALL` order.
+    fn order(self) -> (usize, u8) {
+        match self {
+            Self::Block(block) => (
+                BlockKind::ALL
+                    .iter()
+                    .position(|kind| *kind == block.kind)
+                    .unwrap_or(usize::MAX),
+                block.state,
+            ),
+            Self::Tool(tool) => (
+                BlockKind::ALL.len() + Tool::ALL.iter().position(|t| *t == tool).unwrap_or(0),
+                0,
+            ),
+        }
+    }
+}
+
+impl From<BlockKind> for ItemKind {
+    fn from(kind: BlockKind) -> Self {
+        Self::Block(Block::of_kind(kind))
+    }
+}
+
+impl From<Block> for ItemKind {
+    fn from(block: Block) -> Self {
+        Self::Block(block.as_item())
+    }
+}
+
+impl From<Tool> for ItemKind {
+    fn from(tool: Tool) -> Self {
+        Self::Tool(tool)
+    }
+}
+
+#[derive(Resource, Default, Debug, PartialEq)]
+/// Picked-up and crafted item counts, keyed by item, so blocks in different item states
+/// (a dry and a wet sponge) stack apart.
+pub struct Inventory {
+    /// Number of items held of each kind (kinds never picked up are absent).
+    counts: HashMap<ItemKind, u32>,
+}
+
+impl Inventory {
+    /// Add one picked-up item of `kind`.
+    pub fn add(&mut self, kind: impl Into<ItemKind>) {
+        self.add_many(kind, 1);
+    }
+
+    /// Add `count` items of `kind`.
+    pub fn add_many(&mut self, kind: impl Into<ItemKind>, count: u32) {
+        if count > 0 {
+            *self.counts.entry(kind.into()).or_default() += count;
+        }
+    }
+
+    /// Remove one item of `kind`, returning `false` when none is held.
+    pub fn take(&mut self, kind: impl Into<ItemKind>) -> bool {
+        self.take_many(kind, 1) == 1
+    }
+
+    /// Remove up to `count` items of `kind` and return how many were removed.
+    pub fn take_many(&mut self, kind: impl Into<ItemKind>, count: u32) -> u32 {
+        let kind = kind.into();
+        let Some(held) = self.counts.get_mut(&kind) else {
+            return 0;
+        };
+        let taken = count.min(*held);
+        *held -= taken;
+        if *held == 0 {
+            self.counts.remove(&kind);
+        }
+        taken
+    }
+
+    /// Return how many items of `kind` are held.
+    pub fn count(&self, kind: impl Into<ItemKind>) -> u32 {
+        self.counts.get(&kind.into()).copied().unwrap_or(0)
+    }
+
+    /// Return every held kind with its count: blocks in `BlockKind::ALL` order, then tools
+    /// in `Tool::ALL` order.
+    pub fn held(&self) -> impl Iterator<Item = (ItemKind, u32)> + '_ {
+        let mut held: Vec<(ItemKind, u32)> = self
+            .counts
+            .iter()
+            .map(|(kind, count)| (*kind, *count))
+            .collect();
+        held.sort_by_key(|(kind, _)| kind.order());
+        held.into_iter()
+    }
+
+    /// Return the held block item of `block`'s kind: the one in `block`'s item state when
+    /// held, else the first held one of that kind.
+    pub fn held_block(&self, block: Block) -> Option<Block> {
+        let wanted = block.as_item();
+        if self.count(wanted) > 0 {
+            return Some(wanted);
+        }
+        self.held().find_map(|(kind, _)| match kind {
+            ItemKind::Block(item) if item.kind == wanted.kind => Some(item),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Component)]
+/// Runtime state for a broken block lying in the world as a pickup.
+pub struct ItemDrop {
+    /// Block state the item shows, added to the inventory as its item (see `Block::as_item`).
+    pub(crate) block: Block,
+    /// Number of items the pickup is worth.
+    pub(crate) count: u32,
+    /// World-space center of the item's cube.
+    pub(crate) center: Vec3,
+    /// Current vertical velocity in world units per second.
+    pub(crate) velocity_y: f32,
+    /// Seconds since the item was dropped, driving its spin.
+    pub(crate) age: f32,
+    /// Seconds the item has spent outside loaded chunks.
+    pub(crate) stranded_secs: f32,
+}
+
+impl ItemDrop {
+    /// Half-size of the item's cube, in world units.
+    pub(crate) const HALF_SIZE: Vec3 = Vec3::splat(0.5 * ITEM_SCALE * BLOCK_SIZE);
+
+    /// Build item state for `block` popping up from `center`.
+    pub(crate) fn new(block: Block, center: Vec3) -> Self {
+        Self {
+            block,
+            count: 1,
+            center,
+            velocity_y: ITEM_POP_SPEED,
+            age: 0.0,
+            stranded_secs: 0.0,
+        }
+    }
+
+    /// Return this item worth `count` items when picked up, as for a spilled stack.
+    pub(crate) fn with_count(self, count: u32) -> Self {
+        Self { count, ..self }
+    }
+
+    /// Spawn an item entity with this state, sharing the world's cached block mesh scaled
+    /// down to `ITEM_SCALE`.
+    pub(crate) fn spawn(
+        self,
+        commands: &mut Commands,
+        meshes: &mut Assets<Mesh>,
+        world: &mut WorldState,
+    ) {
+        let mesh = world.block_meshes.get_or_build(meshes, self.block);
+        commands.spawn((
+            bevy::mesh::Mesh3d(mesh),
+            WorldMaterial::Opaque,
+            self.transform(),
+            self,
+            Name::new("ItemDrop"),
+        ));
+    }
+
+    /// Integrate vertical velocity by gravity and return the frame displacement on Y.
+    pub(crate) fn integrate_vertical(&mut self, dt: f32, gravity: f32) -> f32 {
+        self.velocity_y -= gravity * dt;
+        self.velocity_y * dt
+    }
+
+    /// Return the render transform spinning the item's cube about its center.
+    ///
+    /// The cached block mesh spans `[0, BLOCK_SIZE]`, so the translation keeps the scaled
+    /// mesh center on `center`.
+    pub(crate) fn transform(&self) -> Transform {
+        let rotation = Quat::from_rotation_y(self.age * ITEM_SPIN_SPEED);
+        Transform {
+            translation: self.center - rotation * Self::HALF_SIZE,
+            rotation,
+            scale: Vec3::splat(ITEM_SCALE),
+        }
+    }
+}
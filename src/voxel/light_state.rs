@@ -0,0 +1,301 @@
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+use crate::CHUNK_SIZE;
+
+/// Brightest light level, given off by the strongest emitters and the open sky.
+pub(crate) const MAX_LIGHT: u8 = 15;
+/// Face-neighbor offsets light spreads along.
+pub(crate) const LIGHT_NEIGHBOR_OFFSETS: [IVec3; 6] = [
+    IVec3::X,
+    IVec3::NEG_X,
+    IVec3::Y,
+    IVec3::NEG_Y,
+    IVec3::Z,
+    IVec3::NEG_Z,
+];
+/// Cells per side of a `LightSnapshot`: the chunk plus one neighbor cell on every side.
+const SNAPSHOT_SIZE: i32 = CHUNK_SIZE + 2;
+/// Sky light lost per block a cell sits below the highest light-blocking block of its column.
+pub(crate) const SKY_LIGHT_FALLOFF: i32 = 2;
+/// Sky light levels taken off gameplay light at midnight; open sky still reads as moonlight.
+pub(crate) const NIGHT_SKY_DARKENING: u8 = 11;
+
+/// Return the light level gameplay rules see in a cell with `sky` and `block` light while
+/// the time of day takes `sky_darkening` levels off sky light.
+pub(crate) fn combined_light(sky: u8, block: u8, sky_darkening: u8) -> u8 {
+    sky.saturating_sub(sky_darkening).max(block)
+}
+
+/// Return the sky light of a cell at height `y` under its column's `roof`.
+///
+/// `roof` is the height of the column's highest light-blocking block, `None` when the column
+/// has none. Cells at or above the roof get full sky light.
+pub(crate) fn sky_light_below(roof: Option<i32>, y: i32) -> u8 {
+    roof.filter(|roof| *roof > y).map_or(MAX_LIGHT, |roof| {
+        (i32::from(MAX_LIGHT) - (roof - y) * SKY_LIGHT_FALLOFF).max(0) as u8
+    })
+}
+
+/// Block light levels of one chunk's cells.
+///
+/// Most chunks hold no emitter and no light reaches them, so storage is only allocated once
+/// a cell gets lit.
+#[derive(Clone, Default)]
+pub struct ChunkLight {
+    /// Light level per cell in `Chunk` index order, or `None` while every cell is dark.
+    levels: Option<Box<[u8]>>,
+}
+
+impl ChunkLight {
+    /// Return the flat index of local coordinates.
+    fn index(local: IVec3) -> usize {
+        (local.x + local.y * CHUNK_SIZE + local.z * CHUNK_SIZE * CHUNK_SIZE) as usize
+    }
+
+    /// Return the light level at local coordinates inside the chunk.
+    pub fn get(&self, local: IVec3) -> u8 {
+        self.levels
+            .as_ref()
+            .map_or(0, |levels| levels[Self::index(local)])
+    }
+
+    /// Set the light level at local coordinates inside the chunk.
+    pub fn set(&mut self, local: IVec3, level: u8) {
+        if level == 0 && self.levels.is_none() {
+            return;
+        }
+        let levels = self
+            .levels
+            .get_or_insert_with(|| vec![0; (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize].into());
+        levels[Self::index(local)] = level;
+    }
+
+    /// Return `true` when no cell of the chunk has ever been lit.
+    pub fn is_dark(&self) -> bool {
+        self.levels.is_none()
+    }
+}
+
+/// Sky light levels of one chunk's cells, from how deep each lies below its column's roof.
+///
+/// Chunks above the terrain are open to the sky everywhere, so storage is only allocated
+/// once a cell lies under a roof.
+#[derive(Clone, Default)]
+pub struct ChunkSkyLight {
+    /// Sky light level per cell in `Chunk` index order, or `None` while every cell is open.
+    levels: Option<Box<[u8]>>,
+}
+
+impl ChunkSkyLight {
+    /// Return the sky light level at local coordinates inside the chunk.
+    pub fn get(&self, local: IVec3) -> u8 {
+        self.levels
+            .as_ref()
+            .map_or(MAX_LIGHT, |levels| levels[ChunkLight::index(local)])
+    }
+
+    /// Set the sky light level at local coordinates, returning `true` when it changed.
+    pub fn set(&mut self, local: IVec3, level: u8) -> bool {
+        if level == self.get(local) {
+            return false;
+        }
+        let levels = self.levels.get_or_insert_with(|| {
+            vec![MAX_LIGHT; (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize].into()
+        });
+        levels[ChunkLight::index(local)] = level;
+        true
+    }
+
+    /// Return `true` when every cell of the chunk has always been open to the sky.
+    pub fn is_open(&self) -> bool {
+        self.levels.is_none()
+    }
+}
+
+/// Pending block light work: cells to spread light from and cells whose light was taken away.
+///
+/// Removals run first and queue the brighter cells around the darkened area as adds, so
+/// light refills it from whatever sources remain. Chunks whose meshes show a changed level
+/// are collected until the lighting system rebuilds them.
+#[derive(Default)]
+pub struct LightQueue {
+    /// Cells whose light should spread to their neighbors.
+    adds: VecDeque<IVec3>,
+    /// Darkened cells with the level they had, whose light must be taken from neighbors too.
+    removals: VecDeque<(IVec3, u8)>,
+    /// Chunks whose meshes are stale after light levels changed.
+    touched: HashSet<IVec3>,
+}
+
+impl LightQueue {
+    /// Queue the light of `pos` to spread to its neighbors.
+    pub fn enqueue_add(&mut self, pos: IVec3) {
+        self.adds.push_back(pos);
+    }
+
+    /// Queue the light `level` just taken from `pos` to be removed from its neighbors.
+    pub fn enqueue_removal(&mut self, pos: IVec3, level: u8) {
+        self.removals.push_back((pos, level));
+    }
+
+    /// Pop the next removal, if any are pending.
+    pub fn pop_removal(&mut self) -> Option<(IVec3, u8)> {
+        self.removals.pop_front()
+    }
+
+    /// Pop the next add, if any are pending.
+    pub fn pop_add(&mut self) -> Option<IVec3> {
+        self.adds.pop_front()
+    }
+
+    /// Return `true` when no light work is pending.
+    pub fn is_empty(&self) -> bool {
+        self.adds.is_empty() && self.removals.is_empty()
+    }
+
+    /// Record that chunk `coord` needs a remesh to show new light levels.
+    pub fn mark_touched(&mut self, coord: IVec3) {
+        self.touched.insert(coord);
+    }
+
+    /// Return `true` when chunks are waiting for a remesh to show new light levels.
+    pub fn has_touched(&self) -> bool {
+        !self.touched.is_empty()
+    }
+
+    /// Drain the chunks recorded by `mark_touched`.
+    pub fn take_touched(&mut self) -> HashSet<IVec3> {
+        std::mem::take(&mut self.touched)
+    }
+}
+
+/// Light levels around one chunk, copied out for the mesh builder.
+///
+/// Covers the chunk and a one-cell border so faces on the chunk border read the light of
+/// the neighbor chunk cell they face.
+pub(crate) struct LightSnapshot {
+    /// Block light per cell, indexed from local `(-1, -1, -1)`.
+    levels: Vec<u8>,
+    /// Sky light per cell, which each cell receives on top of its block light.
+    sky: Vec<u8>,
+}
+
+impl LightSnapshot {
+    /// Build a snapshot with every cell dark under sky light `sky`.
+    pub(crate) fn new(sky: u8) -> Self {
+        let cells = (SNAPSHOT_SIZE * SNAPSHOT_SIZE * SNAPSHOT_SIZE) as usize;
+        Self {
+            levels: vec![0; cells],
+            sky: vec![sky; cells],
+        }
+    }
+
+    /// Return the flat index of local coordinates, or `None` outside the covered cells.
+    fn index(local: IVec3) -> Option<usize> {
+        let padded = local + IVec3::ONE;
+        (padded.cmpge(IVec3::ZERO).all() && padded.cmplt(IVec3::splat(SNAPSHOT_SIZE)).all()).then(
+            || {
+                (padded.x + padded.y * SNAPSHOT_SIZE + padded.z * SNAPSHOT_SIZE * SNAPSHOT_SIZE)
+                    as usize
+            },
+        )
+    }
+
+    /// Set the block light level of local coordinates, ignoring cells outside the snapshot.
+    pub(crate) fn set(&mut self, local: IVec3, level: u8) {
+        if let Some(index) = Self::index(local) {
+            self.levels[index] = level;
+        }
+    }
+
+    /// Set the sky light level of local coordinates, ignoring cells outside the snapshot.
+    pub(crate) fn set_sky(&mut self, local: IVec3, level: u8) {
+        if let Some(index) = Self::index(local) {
+            self.sky[index] = level;
+        }
+    }
+
+    /// Return the light level a face towards local cell `local` is lit with.
+    pub(crate) fn level(&self, local: IVec3) -> u8 {
+        Self::index(local).map_or(0, |index| self.levels[index].max(self.sky[index]))
+    }
+}
+
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+/// Tuning of the pooled torch and glowstone point lights.
+pub struct LightingConfig {
+    /// Distance from the camera, in blocks, within which pooled lights flicker.
+    pub flicker_radius_blocks: f32,
+    /// Noise samples per second driving the flicker; higher is more nervous.
+    pub flicker_rate_hz: f32,
+    /// Largest fraction of a light's intensity the flicker adds or takes away.
+    pub flicker_intensity: f32,
+    /// Largest shift of a light's green and blue channels, warming or cooling the flame.
+    pub flicker_color_jitter: f32,
+}
+
+impl Default for LightingConfig {
+    fn default() -> Self {
+        Self {
+            flicker_radius_blocks: 24.0,
+            flicker_rate_hz: 7.0,
+            flicker_intensity: 0.18,
+            flicker_color_jitter: 0.06,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+/// Flicker state of one pooled point light.
+pub struct LightFlicker {
+    /// Noise seed of the source block the light currently follows, so each flame keeps its
+    /// own pattern no matter which pooled light lands on it.
+    pub(crate) seed: u32,
+}
+
+impl LightFlicker {
+    /// Build flicker state seeded from the world cell of the light's source block.
+    pub(crate) fn for_cell(cell: IVec3) -> Self {
+        Self {
+            seed: (cell.x as u32)
+                .wrapping_mul(374_761_393)
+                .wrapping_add((cell.y as u32).wrapping_mul(1_440_662_683))
+                .wrapping_add((cell.z as u32).wrapping_mul(668_265_263)),
+        }
+    }
+
+    /// Return the intensity scale and color of the light at `time` seconds, flickering
+    /// around `base` as `config` allows.
+    pub(crate) fn sample(self, time: f32, base: Color, config: &LightingConfig) -> (f32, Color) {
+        let t = time * config.flicker_rate_hz;
+        let brightness = value_noise(self.seed, t);
+        // A second stream, offset so color does not pulse in step with brightness.
+        let warmth = value_noise(self.seed ^ 0x5bd1_e995, t * 0.5);
+        let base = LinearRgba::from(base);
+        let shift = warmth * config.flicker_color_jitter;
+        let color = LinearRgba::new(
+            base.red,
+            (base.green * (1.0 + shift)).max(0.0),
+            (base.blue * (1.0 + shift * 2.0)).max(0.0),
+            base.alpha,
+        );
+        (1.0 + brightness * config.flicker_intensity, color.into())
+    }
+}
+
+/// Return smooth 1D value noise in `[-1, 1]` for stream `seed` at `t`.
+fn value_noise(seed: u32, t: f32) -> f32 {
+    let knot = |i: i32| {
+        let mut n = (i as u32).wrapping_mul(0x9e37_79b9) ^ seed;
+        n ^= n >> 15;
+        n = n.wrapping_mul(0x2c1b_3c6d);
+        n ^= n >> 12;
+        (n & 0x00ff_ffff) as f32 / 0x00ff_ffff as f32 * 2.0 - 1.0
+    };
+    let i = t.floor();
+    let f = t - i;
+    let fade = f * f * (3.0 - 2.0 * f);
+    let (a, b) = (knot(i as i32), knot(i as i32 + 1));
+    a + (b - a) * fade
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::voxel::block_defs::MeshLayer;
+use crate::voxel::block_material::{BlockMaterial, FluidMaterial};
+
+/// Shared materials of world meshes: one per chunk mesh class, plus the TNT flash.
+#[derive(Resource, Clone, Default)]
+pub struct WorldMaterials {
+    /// Alpha-masked material of opaque chunk meshes and single-block meshes.
+    pub opaque: Handle<BlockMaterial>,
+    /// Blended material of glass and ice chunk meshes.
+    pub transparent: Handle<BlockMaterial>,
+    /// Full-bright material of glowing blocks' chunk meshes.
+    pub emissive: Handle<BlockMaterial>,
+    /// Blended material of water chunk meshes, its texture drifting over time.
+    pub fluid: Handle<FluidMaterial>,
+    /// Brightened material primed TNT flashes with.
+    pub flash: Handle<BlockMaterial>,
+}
+
+/// Which of the `WorldMaterials` an entity's mesh is drawn with.
+///
+/// `world_material_system` attaches the material whenever this is added or changed, so
+/// world code picks materials without holding their handles.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorldMaterial {
+    /// `WorldMaterials::opaque`.
+    Opaque,
+    /// `WorldMaterials::transparent`.
+    Transparent,
+    /// `WorldMaterials::emissive`.
+    Emissive,
+    /// `WorldMaterials::fluid`.
+    Fluid,
+    /// `WorldMaterials::flash`.
+    Flash,
+}
+
+impl WorldMaterial {
+    /// Return the material chunk meshes of class `layer` are drawn with.
+    pub fn for_layer(layer: MeshLayer) -> Self {
+        match layer {
+            MeshLayer::Opaque => Self::Opaque,
+            MeshLayer::Water => Self::Fluid,
+            MeshLayer::Glass => Self::Transparent,
+            MeshLayer::Emissive => Self::Emissive,
+        }
+    }
+}
+
+impl WorldMaterials {
+    /// Return the handle of `material`, or `None` for the fluid material, which is of
+    /// another type.
+    pub fn block_material(&self, material: WorldMaterial) -> Option<&Handle<BlockMaterial>> {
+        match material {
+            WorldMaterial::Opaque => Some(&self.opaque),
+            WorldMaterial::Transparent => Some(&self.transparent),
+            WorldMaterial::Emissive => Some(&self.emissive),
+            WorldMaterial::Flash => Some(&self.flash),
+            WorldMaterial::Fluid => None,
+        }
+    }
+}
@@ -1,10 +1,29 @@
 use bevy::prelude::*;
 
-use crate::material_catalog::{atlas_tile_index, atlas_tiles_x, needs_v_flip};
+use crate::material_catalog::{ATLAS_TILE_PIXELS, atlas_tile_index, atlas_tiles_x, needs_v_flip};
 use crate::voxel::block_chunk::Block;
 use crate::voxel::mesh_types::FaceUv;
 
+/// Tile-space inset keeping UVs half a texel inside their tile.
+///
+/// In the strip atlas, filtering at a tile border otherwise blends in the neighboring
+/// tile's pixels, e.g. green grass lines along dirt faces. Texture array layers are
+/// clamped on their own, so they sample their full tile.
+const TILE_INSET: f32 = if cfg!(feature = "strip_atlas") {
+    0.5 / ATLAS_TILE_PIXELS as f32
+} else {
+    0.0
+};
+
+/// Clamp a tile-space fraction to the inset part of the tile.
+fn inset(fraction: f32) -> f32 {
+    fraction.clamp(TILE_INSET, 1.0 - TILE_INSET)
+}
+
 /// Atlas helper for block-face tile selection and UV generation.
+///
+/// UVs address the tile's texture array layer, or its slot of the 1xN strip atlas with
+/// the `strip_atlas` feature.
 pub(super) struct BlockAtlas;
 
 impl BlockAtlas {
@@ -21,29 +40,116 @@ impl BlockAtlas {
         }
     }
 
-    /// Build UVs for one tile in the 1xN horizontal atlas.
+    /// Resolve UVs for the quads of a cross-shaped block, upright on every quad.
+    pub(super) fn cross_uvs(block: Block) -> FaceUv {
+        let tile = atlas_tile_index(block.texture_for_face(IVec3::Y));
+        Self::face_uvs_flipped_v(tile)
+    }
+
+    /// Resolve upright UVs covering the `min..max` part of a block face's tile.
+    ///
+    /// `min` and `max` are tile-space fractions with V growing downwards, mapped onto quads
+    /// in `FACE_DEFS` corner order (bottom, top, top, bottom on side faces).
+    pub(super) fn sub_tile_uvs(block: Block, normal: IVec3, min: Vec2, max: Vec2) -> FaceUv {
+        let tile = atlas_tile_index(block.texture_for_face(normal));
+        let u0 = Self::atlas_u(tile, min.x);
+        let u1 = Self::atlas_u(tile, max.x);
+        let (v0, v1) = (inset(min.y), inset(max.y));
+        FaceUv(
+            [
+                Vec2::new(u0, v1),
+                Vec2::new(u0, v0),
+                Vec2::new(u1, v0),
+                Vec2::new(u1, v1),
+            ],
+            tile,
+        )
+    }
+
+    /// Return the U of tile-space fraction `fraction` across `tile`, inset from its borders.
+    fn atlas_u(tile: u32, fraction: f32) -> f32 {
+        if cfg!(feature = "strip_atlas") {
+            (tile as f32 + inset(fraction)) / atlas_tiles_x()
+        } else {
+            inset(fraction)
+        }
+    }
+
+    /// Build UVs covering one whole tile.
     fn face_uvs(tile: u32) -> FaceUv {
-        let atlas_tiles_x = atlas_tiles_x();
-        let u0 = tile as f32 / atlas_tiles_x;
-        let u1 = (tile as f32 + 1.0) / atlas_tiles_x;
-        FaceUv([
-            Vec2::new(u0, 0.0),
-            Vec2::new(u0, 1.0),
-            Vec2::new(u1, 1.0),
-            Vec2::new(u1, 0.0),
-        ])
+        let u0 = Self::atlas_u(tile, 0.0);
+        let u1 = Self::atlas_u(tile, 1.0);
+        let (v0, v1) = (inset(0.0), inset(1.0));
+        FaceUv(
+            [
+                Vec2::new(u0, v0),
+                Vec2::new(u0, v1),
+                Vec2::new(u1, v1),
+                Vec2::new(u1, v0),
+            ],
+            tile,
+        )
     }
 
     /// Build UVs for one tile with V flipped (used by grass-side orientation fix).
     fn face_uvs_flipped_v(tile: u32) -> FaceUv {
-        let atlas_tiles_x = atlas_tiles_x();
-        let u0 = tile as f32 / atlas_tiles_x;
-        let u1 = (tile as f32 + 1.0) / atlas_tiles_x;
-        FaceUv([
-            Vec2::new(u0, 1.0),
-            Vec2::new(u0, 0.0),
-            Vec2::new(u1, 0.0),
-            Vec2::new(u1, 1.0),
-        ])
+        let u0 = Self::atlas_u(tile, 0.0);
+        let u1 = Self::atlas_u(tile, 1.0);
+        let (v0, v1) = (inset(0.0), inset(1.0));
+        FaceUv(
+            [
+                Vec2::new(u0, v1),
+                Vec2::new(u0, v0),
+                Vec2::new(u1, v0),
+                Vec2::new(u1, v1),
+            ],
+            tile,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::material_catalog::ATLAS_TEXTURE_ORDER;
+
+    /// Verify face UVs stay half a texel inside their own slot of the strip atlas.
+    #[cfg(feature = "strip_atlas")]
+    #[test]
+    fn face_uvs_stay_inside_their_tile() {
+        let texel_u = 1.0 / (atlas_tiles_x() * ATLAS_TILE_PIXELS as f32);
+        let texel_v = 1.0 / ATLAS_TILE_PIXELS as f32;
+        for tile in 0..ATLAS_TEXTURE_ORDER.len() as u32 {
+            let tile_u0 = tile as f32 / atlas_tiles_x();
+            let tile_u1 = (tile + 1) as f32 / atlas_tiles_x();
+            for FaceUv(corners, _) in [
+                BlockAtlas::face_uvs(tile),
+                BlockAtlas::face_uvs_flipped_v(tile),
+            ] {
+                for uv in corners {
+                    let u_margin = (uv.x - tile_u0).min(tile_u1 - uv.x);
+                    let v_margin = uv.y.min(1.0 - uv.y);
+                    assert!((u_margin - 0.5 * texel_u).abs() < 1e-6, "tile {tile} {uv}");
+                    assert!((v_margin - 0.5 * texel_v).abs() < 1e-6, "tile {tile} {uv}");
+                }
+            }
+        }
+    }
+
+    /// Verify face UVs span their whole texture array layer and name the tile as the layer.
+    #[cfg(not(feature = "strip_atlas"))]
+    #[test]
+    fn face_uvs_cover_their_array_layer() {
+        for tile in 0..ATLAS_TEXTURE_ORDER.len() as u32 {
+            for FaceUv(corners, layer) in [
+                BlockAtlas::face_uvs(tile),
+                BlockAtlas::face_uvs_flipped_v(tile),
+            ] {
+                assert_eq!(layer, tile);
+                let min = corners.into_iter().reduce(Vec2::min).unwrap();
+                let max = corners.into_iter().reduce(Vec2::max).unwrap();
+                assert_eq!((min, max), (Vec2::ZERO, Vec2::ONE), "tile {tile}");
+            }
+        }
     }
 }
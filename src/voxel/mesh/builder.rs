@@ -1,85 +1,588 @@
+use bevy::mesh::VertexAttributeValues;
 use bevy::prelude::*;
+use std::cell::RefCell;
 
+use crate::terrain::Biome;
 use crate::{BLOCK_SIZE, CHUNK_SIZE};
 
-use crate::voxel::block_chunk::{Block, Chunk};
+use crate::voxel::block_chunk::{Block, BlockKind, Chunk};
+use crate::voxel::block_defs::{BlockBox, BlockShape, MeshLayer, Opacity};
+use crate::voxel::light_state::{LightSnapshot, MAX_LIGHT};
 use crate::voxel::mesh::atlas::BlockAtlas;
-use crate::voxel::mesh_types::{FACE_DEFS, FaceUv, FaceVertices, MeshData};
+use crate::voxel::mesh_types::{
+    CROSS_QUADS, ChunkNeighbors, FACE_DEFS, FaceUv, FaceVertices, MeshData, fence_parts,
+    torch_bounds,
+};
 
-/// Build mesh data for all visible faces in one chunk.
+/// Darkening applied to faces pointing directly away from the sun in cheap-shadow mode.
+const SUN_AWAY_DARKENING: f32 = 0.45;
+/// Darkening of a cube face corner per occluding block around it.
+const AO_STEP: f32 = 0.1;
+/// Stronger `AO_STEP` used in cheap-shadow mode, standing in for the missing shadow maps.
+const CHEAP_SHADOW_AO_STEP: f32 = 0.18;
+/// Color multiplier of faces pointing along each axis when no sun direction is baked,
+/// faking a sun overhead: tops full, X sides brighter than Z sides, bottoms darkest.
+const FACE_DIRECTION_SHADES: [(IVec3, f32); 6] = [
+    (IVec3::Y, 1.0),
+    (IVec3::NEG_Y, 0.5),
+    (IVec3::X, 0.8),
+    (IVec3::NEG_X, 0.8),
+    (IVec3::Z, 0.6),
+    (IVec3::NEG_Z, 0.6),
+];
+/// Weight of the latest build in the rolling faces-per-build estimate of `MeshScratch`.
+const SCRATCH_ESTIMATE_WEIGHT: f32 = 0.125;
+/// Most vertices a mesh can have while a 16-bit index still addresses every one.
+pub(super) const MAX_U16_INDEXED_VERTICES: usize = u16::MAX as usize + 1;
+/// Brightness of a face lit by neither the sky nor any block, so caves are not pitch black.
+const MIN_LIGHT_BRIGHTNESS: f32 = 0.08;
+/// Part of the torch tile mapped onto the sides of the post: the stick under its flame.
+const TORCH_SIDE_UV: (Vec2, Vec2) = (
+    Vec2::new(7.0 / 16.0, 6.0 / 16.0),
+    Vec2::new(9.0 / 16.0, 1.0),
+);
+/// Part of the torch tile mapped onto the top of the post: the flame.
+const TORCH_TOP_UV: (Vec2, Vec2) = (
+    Vec2::new(7.0 / 16.0, 6.0 / 16.0),
+    Vec2::new(9.0 / 16.0, 8.0 / 16.0),
+);
+/// Part of the torch tile mapped onto the bottom of the post: the stick's end.
+const TORCH_BOTTOM_UV: (Vec2, Vec2) = (
+    Vec2::new(7.0 / 16.0, 14.0 / 16.0),
+    Vec2::new(9.0 / 16.0, 1.0),
+);
+
+/// Build mesh data for all visible opaque faces in one chunk.
 ///
-/// For each solid block, this method iterates `FACE_DEFS`, culls hidden faces by
-/// checking the neighbor block, and appends one quad per visible face.
-pub(crate) fn build_chunk_mesh_data(chunk: &Chunk) -> MeshData {
-    let mut positions: Vec<Vec3> = Vec::new();
-    let mut normals: Vec<Vec3> = Vec::new();
-    let mut uvs: Vec<Vec2> = Vec::new();
-    let mut indices: Vec<u32> = Vec::new();
+/// For each opaque block, this method iterates `FACE_DEFS`, culls hidden faces by
+/// checking the neighbor block, and appends one quad per visible face. Faces on the chunk
+/// border check the touching cell of the neighbor chunk in `neighbors`, and stay when that
+/// neighbor is not loaded. Cross-shaped blocks instead emit their `CROSS_QUADS` unculled,
+/// torches their unculled post, slabs and stairs their collision boxes, and fences their
+/// post and the rails their state connects; faces of these boxes inside the cell are never
+/// culled. Each face gets a baked color multiplier: its `tint_for_face` in the biome of its
+/// column, times a sun-facing shade with `sun_shading` set to the direction towards the
+/// sun, or the fixed `FACE_DIRECTION_SHADES` one otherwise. With `light` set, that
+/// multiplier is also scaled by the light level of the cell each face looks into: the
+/// neighbor cell for cube faces and the block's own cell for the thinner shapes. Cube face
+/// corners are also darkened by ambient occlusion from the blocks around them, stronger
+/// with `sun_shading` set.
+pub(crate) fn build_chunk_mesh_data(
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    sun_shading: Option<Vec3>,
+    light: Option<&LightSnapshot>,
+) -> MeshData {
+    build_layer_mesh_data(chunk, neighbors, sun_shading, light, MeshLayer::Opaque)
+}
+
+/// Build mesh data for the faces of one chunk in separate mesh class `layer`.
+///
+/// These faces get no baked light: water and glass are lit through their blended materials,
+/// and emissive blocks glow at full brightness. For water, faces towards water in a neighbor
+/// chunk are culled like any other, and faces towards a neighbor that is not loaded are
+/// dropped, so lakes spanning a chunk border do not show internal walls.
+pub(crate) fn build_chunk_layer_mesh_data(
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    layer: MeshLayer,
+) -> MeshData {
+    build_layer_mesh_data(chunk, neighbors, None, None, layer)
+}
+
+/// Return whether the face of `block` towards `neighbor` is hidden.
+///
+/// Only opaque neighbors hide a face, so faces behind leaves and glass stay. Translucent
+/// blocks also drop faces shared with their own kind, and water drops faces towards
+/// neighbor chunks that are not loaded.
+fn face_hidden(chunk: &Chunk, neighbors: &ChunkNeighbors, block: Block, neighbor: IVec3) -> bool {
+    let other = if Chunk::in_bounds(neighbor) {
+        chunk.get_block(neighbor)
+    } else {
+        match neighbors.block(neighbor) {
+            Some(other) => other,
+            None => return block.mesh_layer() == MeshLayer::Water,
+        }
+    };
+    if other.culls_neighbors() {
+        return true;
+    }
+    block.opacity() == Opacity::Translucent && other.kind == block.kind
+}
+
+/// Buffers a chunk layer mesh is built into, kept between builds.
+///
+/// Builds fill the buffers, then hand them over to the returned `MeshData` without copying
+/// them, so the mesh keeps the very storage the faces were written to. The next build
+/// reserves fresh buffers at the estimated size up front instead of regrowing them from
+/// empty.
+#[derive(Default)]
+pub(super) struct MeshScratch {
+    pub(super) positions: Vec<Vec3>,
+    pub(super) normals: Vec<Vec3>,
+    pub(super) uvs: Vec<Vec3>,
+    pub(super) tangents: Vec<Vec4>,
+    indices: Vec<u32>,
+    /// Chunk cell index that emitted each quad.
+    pub(super) quad_cells: Vec<u16>,
+    /// Local cell whose light each emitted quad takes.
+    face_cells: Vec<IVec3>,
+    /// Biome tint each emitted quad takes.
+    face_tints: Vec<Vec3>,
+    /// Occluding blocks around each emitted vertex, `0` to `3`.
+    vertex_ao: Vec<u8>,
+    /// Rolling average of faces per build, reserved up front so buffers rarely regrow.
+    estimated_faces: f32,
+}
+
+thread_local! {
+    /// Scratch buffers of the chunk builds running on this thread.
+    pub(super) static MESH_SCRATCH: RefCell<MeshScratch> = RefCell::default();
+}
+
+impl MeshScratch {
+    /// Clear the buffers of the previous build and reserve room for the estimated faces.
+    pub(super) fn begin(&mut self) {
+        let faces = self.estimated_faces.ceil() as usize;
+        self.positions.clear();
+        self.positions.reserve(faces * 4);
+        self.normals.clear();
+        self.normals.reserve(faces * 4);
+        self.uvs.clear();
+        self.uvs.reserve(faces * 4);
+        self.tangents.clear();
+        self.tangents.reserve(faces * 4);
+        self.indices.clear();
+        self.indices.reserve(faces * 6);
+        self.quad_cells.clear();
+        self.quad_cells.reserve(faces);
+        self.face_cells.clear();
+        self.face_cells.reserve(faces);
+        self.face_tints.clear();
+        self.face_tints.reserve(faces);
+        self.vertex_ao.clear();
+        self.vertex_ao.reserve(faces * 4);
+    }
+
+    /// Bake the per-vertex colors, fold this build into the estimate, and hand the
+    /// buffers out.
+    fn finish(&mut self, sun_shading: Option<Vec3>, light: Option<&LightSnapshot>) -> MeshData {
+        let faces = self.face_tints.len() as f32;
+        self.estimated_faces += (faces - self.estimated_faces) * SCRATCH_ESTIMATE_WEIGHT;
+        self.mesh_data(sun_shading, light)
+    }
+
+    /// Hand the buffers out with their per-vertex colors baked.
+    pub(super) fn mesh_data(
+        &mut self,
+        sun_shading: Option<Vec3>,
+        light: Option<&LightSnapshot>,
+    ) -> MeshData {
+        let colors = self.colors(sun_shading, light);
+        MeshData {
+            positions: hand_off(&mut self.positions),
+            normals: hand_off(&mut self.normals),
+            uvs: hand_off(&mut self.uvs),
+            tangents: hand_off(&mut self.tangents),
+            indices: hand_off(&mut self.indices),
+            colors,
+            quad_cells: hand_off(&mut self.quad_cells),
+        }
+    }
+
+    /// Return the baked color multiplier of every emitted vertex.
+    pub(super) fn colors(
+        &self,
+        sun_shading: Option<Vec3>,
+        light: Option<&LightSnapshot>,
+    ) -> Vec<[f32; 4]> {
+        self.normals
+            .iter()
+            .enumerate()
+            .map(|(vertex, normal)| {
+                let sun = sun_shading.map_or_else(
+                    || direction_shade(*normal),
+                    |to_sun| sun_face_shade(*normal, to_sun),
+                );
+                let lit = light.map_or(1.0, |light| {
+                    light_brightness(light.level(self.face_cells[vertex / 4]))
+                });
+                let ao = ao_brightness(self.vertex_ao[vertex], sun_shading.is_some());
+                let color = self.face_tints[vertex / 4] * sun * lit * ao;
+                [color.x, color.y, color.z, 1.0]
+            })
+            .collect()
+    }
+}
+
+/// Move a filled scratch buffer out, leaving an empty one for the next build to reserve.
+///
+/// A buffer using less than half its room, as after a build far below the estimate, is
+/// copied out at its exact size instead and kept, so meshes never carry much spare room.
+fn hand_off<T: Clone>(buffer: &mut Vec<T>) -> Vec<T> {
+    if buffer.len() * 2 >= buffer.capacity() {
+        std::mem::take(buffer)
+    } else {
+        buffer.clone()
+    }
+}
 
+/// Build mesh data for the blocks of one chunk in mesh `layer`.
+fn build_layer_mesh_data(
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    sun_shading: Option<Vec3>,
+    light: Option<&LightSnapshot>,
+    layer: MeshLayer,
+) -> MeshData {
+    MESH_SCRATCH.with_borrow_mut(|scratch| {
+        scratch.begin();
+        fill_layer_scratch(scratch, chunk, neighbors, layer);
+        scratch.finish(sun_shading, light)
+    })
+}
+
+/// Append the faces of the blocks of one chunk in mesh `layer` to the cleared `scratch`.
+fn fill_layer_scratch(
+    scratch: &mut MeshScratch,
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    layer: MeshLayer,
+) {
     for z in 0..CHUNK_SIZE {
         for y in 0..CHUNK_SIZE {
             for x in 0..CHUNK_SIZE {
-                let local = IVec3::new(x, y, z);
-                let block = chunk.get_block(local);
-                if block.is_air() {
-                    continue;
-                }
-                let base = local.as_vec3() * BLOCK_SIZE;
-                for face in FACE_DEFS {
-                    let neighbor = local + face.neighbor;
-                    // This face's neighbor isn't air, don't draw it.
-                    if chunk.get_block(neighbor).is_solid() {
-                        continue;
-                    }
-                    add_face(
-                        &mut positions,
-                        &mut normals,
-                        &mut uvs,
-                        &mut indices,
-                        // Expand unit-cube corners into world-space quad vertices.
-                        FaceVertices([
-                            base + face.corners[0].as_vec3() * BLOCK_SIZE,
-                            base + face.corners[1].as_vec3() * BLOCK_SIZE,
-                            base + face.corners[2].as_vec3() * BLOCK_SIZE,
-                            base + face.corners[3].as_vec3() * BLOCK_SIZE,
-                        ]),
-                        BlockAtlas::face_uvs_for_face(block, face.normal),
-                        face.normal.as_vec3(),
-                    );
-                }
+                emit_cell(scratch, chunk, neighbors, layer, IVec3::new(x, y, z));
             }
         }
     }
+}
 
-    MeshData {
+/// Append the faces the block at `local` shows in mesh `layer` to `scratch`, recording the
+/// cell as the owner of each.
+#[inline]
+pub(super) fn emit_cell(
+    scratch: &mut MeshScratch,
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    layer: MeshLayer,
+    local: IVec3,
+) {
+    emit_cell_faces(scratch, chunk, neighbors, layer, local);
+    scratch
+        .quad_cells
+        .resize(scratch.positions.len() / 4, Chunk::index(local) as u16);
+    scratch.vertex_ao.resize(scratch.positions.len(), 0);
+}
+
+/// Append the faces the block at `local` shows in mesh `layer` to `scratch`.
+#[inline]
+fn emit_cell_faces(
+    scratch: &mut MeshScratch,
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    layer: MeshLayer,
+    local: IVec3,
+) {
+    let MeshScratch {
         positions,
         normals,
         uvs,
+        tangents,
         indices,
+        face_cells,
+        face_tints,
+        vertex_ao,
+        ..
+    } = scratch;
+
+    let block = chunk.get_block(local);
+    if block.is_air() || block.mesh_layer() != layer {
+        return;
+    }
+    let biome = chunk.biome(local.x, local.z);
+    let base = local.as_vec3() * BLOCK_SIZE;
+    if block.shape() == BlockShape::Cross {
+        for corners in CROSS_QUADS {
+            let vertices = corners.map(|corner| base + corner.as_vec3() * BLOCK_SIZE);
+            let normal = (vertices[1] - vertices[0])
+                .cross(vertices[3] - vertices[0])
+                .normalize();
+            add_face(
+                positions,
+                normals,
+                uvs,
+                tangents,
+                indices,
+                FaceVertices(vertices),
+                BlockAtlas::cross_uvs(block),
+                normal,
+            );
+        }
+        face_cells.resize(positions.len() / 4, local);
+        tint_new_faces(face_tints, normals, block, biome);
+        return;
+    }
+    if block.shape() == BlockShape::Torch {
+        add_torch(positions, normals, uvs, tangents, indices, base, block);
+        face_cells.resize(positions.len() / 4, local);
+        tint_new_faces(face_tints, normals, block, biome);
+        return;
+    }
+    if matches!(
+        block.shape(),
+        BlockShape::Slab
+            | BlockShape::Stairs
+            | BlockShape::Fence
+            | BlockShape::Door
+            | BlockShape::Cactus
+    ) {
+        add_boxes(
+            positions,
+            normals,
+            uvs,
+            tangents,
+            indices,
+            base,
+            block,
+            |normal| face_hidden(chunk, neighbors, block, local + normal),
+        );
+        face_cells.resize(positions.len() / 4, local);
+        tint_new_faces(face_tints, normals, block, biome);
+        return;
+    }
+    for face in FACE_DEFS {
+        let neighbor = local + face.neighbor;
+        // This face's neighbor covers it, don't draw it.
+        if face_hidden(chunk, neighbors, block, neighbor) {
+            continue;
+        }
+        add_face(
+            positions,
+            normals,
+            uvs,
+            tangents,
+            indices,
+            // Expand unit-cube corners into world-space quad vertices.
+            FaceVertices([
+                base + face.corners[0].as_vec3() * BLOCK_SIZE,
+                base + face.corners[1].as_vec3() * BLOCK_SIZE,
+                base + face.corners[2].as_vec3() * BLOCK_SIZE,
+                base + face.corners[3].as_vec3() * BLOCK_SIZE,
+            ]),
+            BlockAtlas::face_uvs_for_face(block, face.normal),
+            face.normal.as_vec3(),
+        );
+        face_cells.push(neighbor);
+        if layer == MeshLayer::Opaque {
+            vertex_ao.extend(
+                face.corners.map(|corner| {
+                    corner_occlusion(chunk, neighbors, neighbor, face.normal, corner)
+                }),
+            );
+        } else {
+            vertex_ao.extend([0; 4]);
+        }
+    }
+    tint_new_faces(face_tints, normals, block, biome);
+}
+
+/// Return the color grass of `biome` is tinted with, from lush plains to dry desert.
+///
+/// The grass top tile is a grayscale base that the plains tint turns back into its
+/// original green.
+fn grass_tint(biome: Biome) -> Vec3 {
+    match biome {
+        Biome::Plains => Vec3::new(0.58, 1.0, 0.23),
+        Biome::Desert => Vec3::new(0.82, 0.86, 0.32),
+        Biome::Mountains => Vec3::new(0.45, 0.78, 0.36),
+    }
+}
+
+/// Return the color multiplier for the face of `block` with unit `normal` in a `biome`
+/// column.
+///
+/// Grass tops take the full biome tint. Grass sides keep their painted plains fringe over
+/// dirt, so they are only shifted by the biome's tint relative to plains.
+fn tint_for_face(block: Block, biome: Biome, normal: Vec3) -> Vec3 {
+    if block.kind != BlockKind::DirtWithGrass || normal.y < -0.5 {
+        return Vec3::ONE;
+    }
+    if normal.y > 0.5 {
+        grass_tint(biome)
+    } else {
+        grass_tint(biome) / grass_tint(Biome::Plains)
+    }
+}
+
+/// Append the `tint_for_face` of every quad emitted for `block` since the last call.
+fn tint_new_faces(face_tints: &mut Vec<Vec3>, normals: &[Vec3], block: Block, biome: Biome) {
+    let new_normals = normals[face_tints.len() * 4..].iter().step_by(4);
+    face_tints.extend(new_normals.map(|normal| tint_for_face(block, biome, *normal)));
+}
+
+/// Return the `FACE_DIRECTION_SHADES` multiplier for a face with unit `normal`.
+///
+/// Faces between axes, such as the diagonal quads of cross blocks, blend the shades of the
+/// axes they point along.
+fn direction_shade(normal: Vec3) -> f32 {
+    FACE_DIRECTION_SHADES
+        .into_iter()
+        .map(|(axis, shade)| axis.as_vec3().dot(normal).max(0.0).powi(2) * shade)
+        .sum()
+}
+
+/// Return the color multiplier for a face with `normal` lit from direction `to_sun`.
+///
+/// Sun-facing faces keep full brightness; the factor falls off linearly with the
+/// facing angle down to `1.0 - SUN_AWAY_DARKENING` for faces pointing away.
+fn sun_face_shade(normal: Vec3, to_sun: Vec3) -> f32 {
+    let facing = normal.dot(to_sun.normalize_or_zero()).clamp(-1.0, 1.0);
+    1.0 - SUN_AWAY_DARKENING * (1.0 - facing) * 0.5
+}
+
+/// Return how many of the three blocks around a cube face corner occlude it, `0` to `3`.
+///
+/// `cell` is the cell the face looks into and `corner` the corner in unit-cube
+/// coordinates. The two side blocks and the diagonal one in that cell's layer count; two
+/// sides already close the corner off fully. Cells of unloaded neighbors and of chunks
+/// past an edge do not occlude.
+fn corner_occlusion(
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    cell: IVec3,
+    normal: IVec3,
+    corner: IVec3,
+) -> u8 {
+    let occludes = |offset: IVec3| {
+        let pos = cell + offset;
+        let block = if Chunk::in_bounds(pos) {
+            Some(chunk.get_block(pos))
+        } else {
+            neighbors.block(pos)
+        };
+        block.is_some_and(|block| block.culls_neighbors())
+    };
+    let mut sides = IVec3::AXES
+        .into_iter()
+        .filter(|axis| axis.dot(normal) == 0)
+        .map(|axis| if axis.dot(corner) > 0 { axis } else { -axis });
+    let (Some(first), Some(second)) = (sides.next(), sides.next()) else {
+        return 0;
+    };
+    let (first_side, second_side) = (occludes(first), occludes(second));
+    if first_side && second_side {
+        return 3;
+    }
+    u8::from(first_side) + u8::from(second_side) + u8::from(occludes(first + second))
+}
+
+/// Return the color multiplier of a vertex with `occlusion` occluding blocks around it,
+/// using the stronger steps in cheap-shadow mode.
+fn ao_brightness(occlusion: u8, cheap_shadows: bool) -> f32 {
+    let step = if cheap_shadows {
+        CHEAP_SHADOW_AO_STEP
+    } else {
+        AO_STEP
+    };
+    1.0 - step * f32::from(occlusion)
+}
+
+/// Rescale the baked colors of a chunk mesh from sun direction `from` to `to`.
+///
+/// The sun-facing shade is the only factor of the baked colors that depends on the sun,
+/// so dividing the old shade out and multiplying the new one in matches a rebuild with
+/// `to` without remeshing.
+pub(crate) fn retint_sun_shading(mesh: &mut Mesh, from: Vec3, to: Vec3) {
+    let Some(VertexAttributeValues::Float32x3(normals)) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
+    else {
+        return;
+    };
+    let scales: Vec<f32> = normals
+        .iter()
+        .map(|normal| {
+            let normal = Vec3::from_array(*normal);
+            sun_face_shade(normal, to) / sun_face_shade(normal, from)
+        })
+        .collect();
+    if let Some(VertexAttributeValues::Float32x4(colors)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_COLOR)
+    {
+        for (color, scale) in colors.iter_mut().zip(scales) {
+            for channel in &mut color[..3] {
+                *channel *= scale;
+            }
+        }
+    }
+}
+
+/// Return the color multiplier for a face lit with light `level`.
+///
+/// Brightness rises slowly through the low levels and steeply towards `MAX_LIGHT`, so light
+/// fades out gradually around a source instead of in even steps.
+fn light_brightness(level: u8) -> f32 {
+    let fraction = f32::from(level.min(MAX_LIGHT)) / f32::from(MAX_LIGHT);
+    let curve = fraction / (4.0 - 3.0 * fraction);
+    MIN_LIGHT_BRIGHTNESS + (1.0 - MIN_LIGHT_BRIGHTNESS) * curve
+}
+
+/// Return `indices` as a 16-bit index buffer when a mesh of `vertex_count` vertices fits
+/// one, and as a 32-bit buffer otherwise.
+fn index_buffer(indices: Vec<u32>, vertex_count: usize) -> bevy::mesh::Indices {
+    if vertex_count <= MAX_U16_INDEXED_VERTICES {
+        bevy::mesh::Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+    } else {
+        bevy::mesh::Indices::U32(indices)
     }
 }
 
 /// Convert intermediate mesh buffers into a Bevy `Mesh`.
+///
+/// Tile UVs go to `UV_0` and, unless the strip atlas is in use, the texture array layer of
+/// every vertex to the X of `UV_1`.
+///
+/// Meshes addressable with 16-bit indices, which is nearly every chunk, get a 16-bit index
+/// buffer at half the memory.
 pub(crate) fn mesh_from_data(data: MeshData) -> Mesh {
     let mut mesh = Mesh::new(
         bevy::render::render_resource::PrimitiveTopology::TriangleList,
         bevy::asset::RenderAssetUsages::default(),
     );
+    let vertex_count = data.positions.len();
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, data.positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, data.normals);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, data.uvs);
-    mesh.insert_indices(bevy::mesh::Indices::U32(data.indices));
+    let uvs: Vec<Vec2> = data.uvs.iter().map(|uv| uv.truncate()).collect();
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    #[cfg(not(feature = "strip_atlas"))]
+    {
+        let layers: Vec<Vec2> = data.uvs.iter().map(|uv| Vec2::new(uv.z, 0.0)).collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_1, layers);
+    }
+    mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, data.tangents);
+    if !data.colors.is_empty() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, data.colors);
+    }
+    mesh.insert_indices(index_buffer(data.indices, vertex_count));
     mesh
 }
 
+/// Convert chunk layer mesh data into a Bevy mesh and the owning cell of each of its quads,
+/// which `remesh_cells` needs to patch the mesh later.
+pub(crate) fn chunk_mesh_from_data(mut data: MeshData) -> (Mesh, Vec<u16>) {
+    let quad_cells = std::mem::take(&mut data.quad_cells);
+    (mesh_from_data(data), quad_cells)
+}
+
 /// Append one quad face to mesh buffers as two indexed triangles.
 ///
 /// The quad is emitted in the given vertex order and expanded into indices:
 /// `(0, 1, 2)` and `(0, 2, 3)`.
+#[allow(clippy::too_many_arguments)]
 fn add_face(
     positions: &mut Vec<Vec3>,
     normals: &mut Vec<Vec3>,
-    uvs: &mut Vec<Vec2>,
+    uvs: &mut Vec<Vec3>,
+    tangents: &mut Vec<Vec4>,
     indices: &mut Vec<u32>,
     vertices: FaceVertices,
     uv: FaceUv,
@@ -89,116 +592,293 @@ fn add_face(
     let start = positions.len() as u32;
     positions.extend_from_slice(&vertices.0);
     normals.extend_from_slice(&[normal, normal, normal, normal]);
-    uvs.extend_from_slice(&uv.0);
+    let layer = uv.1 as f32;
+    uvs.extend(uv.0.map(|corner| corner.extend(layer)));
+    let tangent = face_tangent(&vertices, &uv, normal);
+    tangents.extend_from_slice(&[tangent, tangent, tangent, tangent]);
     indices.extend_from_slice(&[start, start + 1, start + 2, start, start + 2, start + 3]);
 }
 
+/// Append the six faces of a torch post with its cell's minimum corner at `base`.
+///
+/// The post is too thin to be covered by neighbors, so no face is culled.
+fn add_torch(
+    positions: &mut Vec<Vec3>,
+    normals: &mut Vec<Vec3>,
+    uvs: &mut Vec<Vec3>,
+    tangents: &mut Vec<Vec4>,
+    indices: &mut Vec<u32>,
+    base: Vec3,
+    block: Block,
+) {
+    let (min, max) = torch_bounds(block.front);
+    for face in FACE_DEFS {
+        let (uv_min, uv_max) = match face.normal.y {
+            1 => TORCH_TOP_UV,
+            -1 => TORCH_BOTTOM_UV,
+            _ => TORCH_SIDE_UV,
+        };
+        add_face(
+            positions,
+            normals,
+            uvs,
+            tangents,
+            indices,
+            FaceVertices(
+                face.corners
+                    .map(|corner| base + (min + corner.as_vec3() * (max - min)) * BLOCK_SIZE),
+            ),
+            BlockAtlas::sub_tile_uvs(block, face.normal, uv_min, uv_max),
+            face.normal.as_vec3(),
+        );
+    }
+}
+
+/// Append the faces of a slab, stairs, or fence, one box at a time, with the cell's minimum
+/// corner at `base`.
+///
+/// Faces flush with the cell boundary are skipped when `hidden` reports their neighbor in
+/// direction `normal` covers them; faces inside the cell always show. Each face samples
+/// the part of the tile matching its extent so the texture is not squashed.
+#[allow(clippy::too_many_arguments)]
+fn add_boxes(
+    positions: &mut Vec<Vec3>,
+    normals: &mut Vec<Vec3>,
+    uvs: &mut Vec<Vec3>,
+    tangents: &mut Vec<Vec4>,
+    indices: &mut Vec<u32>,
+    base: Vec3,
+    block: Block,
+    hidden: impl Fn(IVec3) -> bool,
+) {
+    let parts: Vec<BlockBox> = if block.shape() == BlockShape::Fence {
+        fence_parts(block)
+    } else {
+        block.collision_boxes().collect()
+    };
+    for part in parts {
+        for face in FACE_DEFS {
+            if box_face_on_cell_boundary(part, face.normal) && hidden(face.normal) {
+                continue;
+            }
+            let (uv_min, uv_max) = box_face_uv_rect(part, face.normal);
+            add_face(
+                positions,
+                normals,
+                uvs,
+                tangents,
+                indices,
+                FaceVertices(face.corners.map(|corner| {
+                    base + (part.min + corner.as_vec3() * (part.max - part.min)) * BLOCK_SIZE
+                })),
+                BlockAtlas::sub_tile_uvs(block, face.normal, uv_min, uv_max),
+                face.normal.as_vec3(),
+            );
+        }
+    }
+}
+
+/// Return whether the face of `part` with `normal` lies on the boundary of its cell.
+fn box_face_on_cell_boundary(part: BlockBox, normal: IVec3) -> bool {
+    let (near, far) = (part.min.to_array(), part.max.to_array());
+    let axis = normal.abs().max_position();
+    if normal[axis] > 0 {
+        far[axis] == 1.0
+    } else {
+        near[axis] == 0.0
+    }
+}
+
+/// Return the tile-space rectangle a face of `part` samples, matching `FACE_DEFS` corners.
+fn box_face_uv_rect(part: BlockBox, normal: IVec3) -> (Vec2, Vec2) {
+    let (min, max) = (part.min, part.max);
+    let (u0, u1) = match (normal.x, normal.z) {
+        (1, _) => (min.z, max.z),
+        (-1, _) => (1.0 - max.z, 1.0 - min.z),
+        (_, 1) => (1.0 - max.x, 1.0 - min.x),
+        _ => (min.x, max.x),
+    };
+    let (v0, v1) = match normal.y {
+        1 => (1.0 - max.z, 1.0 - min.z),
+        -1 => (min.z, max.z),
+        _ => (1.0 - max.y, 1.0 - min.y),
+    };
+    (Vec2::new(u0, v0), Vec2::new(u1, v1))
+}
+
+/// Compute the tangent of one flat quad analytically from its corners and UVs.
+///
+/// Quads are flat, so one tangent covers all four vertices. The `w` sign keeps
+/// `cross(normal, tangent) * w` aligned with the +V direction, including V-flipped tiles.
+fn face_tangent(vertices: &FaceVertices, uv: &FaceUv, normal: Vec3) -> Vec4 {
+    let edge1 = vertices.0[1] - vertices.0[0];
+    let edge2 = vertices.0[3] - vertices.0[0];
+    let duv1 = uv.0[1] - uv.0[0];
+    let duv2 = uv.0[3] - uv.0[0];
+    let det = duv1.x * duv2.y - duv2.x * duv1.y;
+    if det == 0.0 {
+        return Vec4::new(1.0, 0.0, 0.0, 1.0);
+    }
+    let tangent = ((edge1 * duv2.y - edge2 * duv1.y) / det).normalize_or_zero();
+    let bitangent = (edge2 * duv1.x - edge1 * duv2.x) / det;
+    let sign = if normal.cross(tangent).dot(bitangent) < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+    tangent.extend(sign)
+}
+
 /// Build mesh data for a single block (used for in-hand preview).
 pub(crate) fn build_single_block_mesh_data(block: Block) -> MeshData {
     let mut positions: Vec<Vec3> = Vec::new();
     let mut normals: Vec<Vec3> = Vec::new();
-    let mut uvs: Vec<Vec2> = Vec::new();
+    let mut uvs: Vec<Vec3> = Vec::new();
+    let mut tangents: Vec<Vec4> = Vec::new();
     let mut indices: Vec<u32> = Vec::new();
 
-    let fx = 0.0;
-    let fy = 0.0;
-    let fz = 0.0;
-    // +X (right) face
-    add_face(
-        &mut positions,
-        &mut normals,
-        &mut uvs,
-        &mut indices,
-        FaceVertices([
-            Vec3::new(fx + BLOCK_SIZE, fy, fz),
-            Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz),
-            Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
-            Vec3::new(fx + BLOCK_SIZE, fy, fz + BLOCK_SIZE),
-        ]),
-        BlockAtlas::face_uvs_for_face(block, IVec3::new(1, 0, 0)),
-        Vec3::new(1.0, 0.0, 0.0),
-    );
-    // -X (left) face
-    add_face(
-        &mut positions,
-        &mut normals,
-        &mut uvs,
-        &mut indices,
-        FaceVertices([
-            Vec3::new(fx, fy, fz + BLOCK_SIZE),
-            Vec3::new(fx, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
-            Vec3::new(fx, fy + BLOCK_SIZE, fz),
-            Vec3::new(fx, fy, fz),
-        ]),
-        BlockAtlas::face_uvs_for_face(block, IVec3::new(-1, 0, 0)),
-        Vec3::new(-1.0, 0.0, 0.0),
-    );
-    // +Y (top) face
-    add_face(
-        &mut positions,
-        &mut normals,
-        &mut uvs,
-        &mut indices,
-        FaceVertices([
-            Vec3::new(fx, fy + BLOCK_SIZE, fz),
-            Vec3::new(fx, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
-            Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
-            Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz),
-        ]),
-        BlockAtlas::face_uvs_for_face(block, IVec3::new(0, 1, 0)),
-        Vec3::new(0.0, 1.0, 0.0),
-    );
-    // -Y (bottom) face
-    add_face(
-        &mut positions,
-        &mut normals,
-        &mut uvs,
-        &mut indices,
-        FaceVertices([
-            Vec3::new(fx, fy, fz + BLOCK_SIZE),
-            Vec3::new(fx, fy, fz),
-            Vec3::new(fx + BLOCK_SIZE, fy, fz),
-            Vec3::new(fx + BLOCK_SIZE, fy, fz + BLOCK_SIZE),
-        ]),
-        BlockAtlas::face_uvs_for_face(block, IVec3::new(0, -1, 0)),
-        Vec3::new(0.0, -1.0, 0.0),
-    );
-    // +Z (front) face
-    add_face(
-        &mut positions,
-        &mut normals,
-        &mut uvs,
-        &mut indices,
-        FaceVertices([
-            Vec3::new(fx + BLOCK_SIZE, fy, fz + BLOCK_SIZE),
-            Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
-            Vec3::new(fx, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
-            Vec3::new(fx, fy, fz + BLOCK_SIZE),
-        ]),
-        BlockAtlas::face_uvs_for_face(block, IVec3::new(0, 0, 1)),
-        Vec3::new(0.0, 0.0, 1.0),
-    );
-    // -Z (back) face
-    add_face(
-        &mut positions,
-        &mut normals,
-        &mut uvs,
-        &mut indices,
-        FaceVertices([
-            Vec3::new(fx, fy, fz),
-            Vec3::new(fx, fy + BLOCK_SIZE, fz),
-            Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz),
-            Vec3::new(fx + BLOCK_SIZE, fy, fz),
-        ]),
-        BlockAtlas::face_uvs_for_face(block, IVec3::new(0, 0, -1)),
-        Vec3::new(0.0, 0.0, -1.0),
-    );
+    match block.shape() {
+        BlockShape::Torch => add_torch(
+            &mut positions,
+            &mut normals,
+            &mut uvs,
+            &mut tangents,
+            &mut indices,
+            Vec3::ZERO,
+            block,
+        ),
+        BlockShape::Slab
+        | BlockShape::Stairs
+        | BlockShape::Fence
+        | BlockShape::Door
+        | BlockShape::Cactus => add_boxes(
+            &mut positions,
+            &mut normals,
+            &mut uvs,
+            &mut tangents,
+            &mut indices,
+            Vec3::ZERO,
+            block,
+            |_| false,
+        ),
+        BlockShape::Cube | BlockShape::Cross => {
+            let fx = 0.0;
+            let fy = 0.0;
+            let fz = 0.0;
+            // +X (right) face
+            add_face(
+                &mut positions,
+                &mut normals,
+                &mut uvs,
+                &mut tangents,
+                &mut indices,
+                FaceVertices([
+                    Vec3::new(fx + BLOCK_SIZE, fy, fz),
+                    Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz),
+                    Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
+                    Vec3::new(fx + BLOCK_SIZE, fy, fz + BLOCK_SIZE),
+                ]),
+                BlockAtlas::face_uvs_for_face(block, IVec3::new(1, 0, 0)),
+                Vec3::new(1.0, 0.0, 0.0),
+            );
+            // -X (left) face
+            add_face(
+                &mut positions,
+                &mut normals,
+                &mut uvs,
+                &mut tangents,
+                &mut indices,
+                FaceVertices([
+                    Vec3::new(fx, fy, fz + BLOCK_SIZE),
+                    Vec3::new(fx, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
+                    Vec3::new(fx, fy + BLOCK_SIZE, fz),
+                    Vec3::new(fx, fy, fz),
+                ]),
+                BlockAtlas::face_uvs_for_face(block, IVec3::new(-1, 0, 0)),
+                Vec3::new(-1.0, 0.0, 0.0),
+            );
+            // +Y (top) face
+            add_face(
+                &mut positions,
+                &mut normals,
+                &mut uvs,
+                &mut tangents,
+                &mut indices,
+                FaceVertices([
+                    Vec3::new(fx, fy + BLOCK_SIZE, fz),
+                    Vec3::new(fx, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
+                    Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
+                    Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz),
+                ]),
+                BlockAtlas::face_uvs_for_face(block, IVec3::new(0, 1, 0)),
+                Vec3::new(0.0, 1.0, 0.0),
+            );
+            // -Y (bottom) face
+            add_face(
+                &mut positions,
+                &mut normals,
+                &mut uvs,
+                &mut tangents,
+                &mut indices,
+                FaceVertices([
+                    Vec3::new(fx, fy, fz + BLOCK_SIZE),
+                    Vec3::new(fx, fy, fz),
+                    Vec3::new(fx + BLOCK_SIZE, fy, fz),
+                    Vec3::new(fx + BLOCK_SIZE, fy, fz + BLOCK_SIZE),
+                ]),
+                BlockAtlas::face_uvs_for_face(block, IVec3::new(0, -1, 0)),
+                Vec3::new(0.0, -1.0, 0.0),
+            );
+            // +Z (front) face
+            add_face(
+                &mut positions,
+                &mut normals,
+                &mut uvs,
+                &mut tangents,
+                &mut indices,
+                FaceVertices([
+                    Vec3::new(fx + BLOCK_SIZE, fy, fz + BLOCK_SIZE),
+                    Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
+                    Vec3::new(fx, fy + BLOCK_SIZE, fz + BLOCK_SIZE),
+                    Vec3::new(fx, fy, fz + BLOCK_SIZE),
+                ]),
+                BlockAtlas::face_uvs_for_face(block, IVec3::new(0, 0, 1)),
+                Vec3::new(0.0, 0.0, 1.0),
+            );
+            // -Z (back) face
+            add_face(
+                &mut positions,
+                &mut normals,
+                &mut uvs,
+                &mut tangents,
+                &mut indices,
+                FaceVertices([
+                    Vec3::new(fx, fy, fz),
+                    Vec3::new(fx, fy + BLOCK_SIZE, fz),
+                    Vec3::new(fx + BLOCK_SIZE, fy + BLOCK_SIZE, fz),
+                    Vec3::new(fx + BLOCK_SIZE, fy, fz),
+                ]),
+                BlockAtlas::face_uvs_for_face(block, IVec3::new(0, 0, -1)),
+                Vec3::new(0.0, 0.0, -1.0),
+            );
+        }
+    }
 
+    let colors = normals
+        .iter()
+        .map(|normal| {
+            let color = tint_for_face(block, Biome::Plains, *normal) * direction_shade(*normal);
+            [color.x, color.y, color.z, 1.0]
+        })
+        .collect();
     MeshData {
         positions,
         normals,
         uvs,
+        tangents,
         indices,
+        colors,
+        quad_cells: Vec::new(),
     }
 }
 
@@ -206,3 +886,490 @@ pub(crate) fn build_single_block_mesh_data(block: Block) -> MeshData {
 pub fn build_single_block_mesh(block: Block) -> Mesh {
     mesh_from_data(build_single_block_mesh_data(block))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::block_chunk::Facing;
+
+    /// Verify meshes switch from 16-bit to 32-bit indices right past the vertex count 16-bit
+    /// indices address, and block meshes use 16-bit ones.
+    #[test]
+    fn index_width_follows_vertex_count() {
+        let mesh_with = |vertex_count: usize| {
+            let last = vertex_count as u32 - 1;
+            mesh_from_data(MeshData {
+                positions: vec![Vec3::ZERO; vertex_count],
+                normals: vec![Vec3::Y; vertex_count],
+                uvs: vec![Vec3::ZERO; vertex_count],
+                tangents: vec![Vec4::X; vertex_count],
+                indices: vec![0, last - 1, last],
+                colors: Vec::new(),
+                quad_cells: Vec::new(),
+            })
+        };
+        let fits = mesh_with(MAX_U16_INDEXED_VERTICES);
+        match fits.indices() {
+            Some(bevy::mesh::Indices::U16(indices)) => assert_eq!(indices[2], u16::MAX),
+            other => panic!("expected 16-bit indices, got {other:?}"),
+        }
+        let over = mesh_with(MAX_U16_INDEXED_VERTICES + 1);
+        match over.indices() {
+            Some(bevy::mesh::Indices::U32(indices)) => assert_eq!(indices[2], 65_536),
+            other => panic!("expected 32-bit indices, got {other:?}"),
+        }
+        assert!(matches!(
+            build_single_block_mesh(Block::sand()).indices(),
+            Some(bevy::mesh::Indices::U16(_))
+        ));
+    }
+
+    /// Verify analytic tangents follow +U on each face and stay orthogonal to the normal.
+    #[test]
+    fn face_tangents_follow_u_axis() {
+        let data = build_single_block_mesh_data(Block::dirt_with_grass());
+        assert_eq!(data.tangents.len(), data.positions.len());
+        for face in 0..data.positions.len() / 4 {
+            let i = face * 4;
+            let tangent = data.tangents[i];
+            assert_eq!(tangent.w.abs(), 1.0);
+            assert!(tangent.truncate().dot(data.normals[i]).abs() < 1e-5);
+            // Moving along the tangent from corner 0 reaches the corner with the larger U.
+            let (u_far, p_far) = [1, 2, 3]
+                .map(|k| (data.uvs[i + k].x - data.uvs[i].x, data.positions[i + k]))
+                .into_iter()
+                .fold(
+                    (0.0, Vec3::ZERO),
+                    |best, item| if item.0 > best.0 { item } else { best },
+                );
+            assert!(u_far > 0.0);
+            assert!(tangent.truncate().dot(p_far - data.positions[i]) > 0.0);
+        }
+    }
+
+    /// Verify a cross block emits its four diagonal quads and does not hide its ground's top.
+    #[test]
+    fn cross_blocks_emit_unculled_diagonal_quads() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(1, 1, 1), Block::tall_grass());
+        let alone = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(alone.positions.len(), CROSS_QUADS.len() * 4);
+        assert!(
+            alone
+                .normals
+                .iter()
+                .all(|normal| normal.y == 0.0 && normal.x != 0.0 && normal.z != 0.0)
+        );
+
+        chunk.set_block(IVec3::new(1, 0, 1), Block::dirt_with_grass());
+        let grounded = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(grounded.positions.len(), (CROSS_QUADS.len() + 6) * 4);
+    }
+
+    /// Verify torches emit an unculled post that hides no neighbor face, flush against the
+    /// wall they hang on.
+    #[test]
+    fn torch_emits_thin_unculled_post() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(1, 0, 1), Block::stone());
+        chunk.set_block(IVec3::new(1, 1, 1), Block::torch());
+        let standing = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(standing.positions.len(), (6 + 6) * 4);
+        let post = &standing.positions[..];
+        assert!(post.iter().filter(|p| p.y > 1.0).all(|p| {
+            (p.x - 1.5).abs() <= 1.0 / 16.0 + 1e-5 && (p.z - 1.5).abs() <= 1.0 / 16.0 + 1e-5
+        }));
+
+        let wall = Block::torch().oriented_for_placement(Vec3::NEG_X, IVec3::X);
+        let hanging = build_single_block_mesh_data(wall);
+        assert_eq!(hanging.positions.len(), 6 * 4);
+        let min_x = hanging
+            .positions
+            .iter()
+            .map(|p| p.x)
+            .fold(f32::MAX, f32::min);
+        let min_y = hanging
+            .positions
+            .iter()
+            .map(|p| p.y)
+            .fold(f32::MAX, f32::min);
+        assert_eq!(min_x, 0.0);
+        assert!(min_y > 0.0);
+    }
+
+    /// Verify slabs emit a half-height cube whose mid-cell face is never culled and which
+    /// hides no face of its neighbors.
+    #[test]
+    fn slab_culls_only_faces_flush_with_its_cell() {
+        for upper in [false, true] {
+            let slab = Block::slab().with_slab_half(upper);
+            let mut chunk = Chunk::new_empty();
+            chunk.set_block(IVec3::new(1, 0, 1), Block::stone());
+            chunk.set_block(IVec3::new(1, 1, 1), slab);
+            chunk.set_block(IVec3::new(1, 2, 1), Block::stone());
+            let data = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+            assert_eq!(data.positions.len(), (5 + 6 + 6) * 4);
+
+            let slab_ys: Vec<f32> = data
+                .positions
+                .iter()
+                .filter(|p| p.y > 1.0 && p.y < 2.0)
+                .map(|p| p.y)
+                .collect();
+            assert!(!slab_ys.is_empty());
+            assert!(slab_ys.iter().all(|y| *y == 1.5));
+            let expected_box = if upper {
+                BlockBox::LOWER_HALF.oriented(Facing::NegY)
+            } else {
+                BlockBox::LOWER_HALF
+            };
+            assert_eq!(
+                slab.collision_boxes().collect::<Vec<_>>(),
+                vec![expected_box]
+            );
+        }
+    }
+
+    /// Verify leaves keep faces shared with leaves and with opaque blocks, which cull them.
+    #[test]
+    fn leaves_cull_only_against_opaque_blocks() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(1, 1, 1), Block::leaves());
+        chunk.set_block(IVec3::new(2, 1, 1), Block::leaves());
+        let canopy = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(canopy.positions.len(), 12 * 4);
+
+        chunk.set_block(IVec3::new(1, 2, 1), Block::stone());
+        let capped = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        // The lower leaf loses its top face; the stone keeps its bottom face over the leaf.
+        assert_eq!(capped.positions.len(), (11 + 6) * 4);
+    }
+
+    /// Verify glass meshes on its own layer, hides faces shared with glass, and hides no
+    /// opaque face behind it.
+    #[test]
+    fn glass_culls_only_against_glass_and_opaque_blocks() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(1, 1, 1), Block::glass());
+        chunk.set_block(IVec3::new(2, 1, 1), Block::glass());
+        chunk.set_block(IVec3::new(1, 2, 1), Block::stone());
+        chunk.set_block(IVec3::new(3, 1, 1), Block::stone());
+
+        let glass =
+            build_chunk_layer_mesh_data(&chunk, &ChunkNeighbors::default(), MeshLayer::Glass);
+        assert_eq!(glass.positions.len(), (4 + 4) * 4);
+        let opaque = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(opaque.positions.len(), (6 + 6) * 4);
+        assert!(
+            build_chunk_layer_mesh_data(&chunk, &ChunkNeighbors::default(), MeshLayer::Water)
+                .is_empty()
+        );
+    }
+
+    /// Verify glowing blocks mesh into the emissive class, still hiding the opaque faces
+    /// against them.
+    #[test]
+    fn glowstone_meshes_into_the_emissive_class() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(1, 1, 1), Block::glowstone());
+        chunk.set_block(IVec3::new(2, 1, 1), Block::stone());
+        let neighbors = ChunkNeighbors::default();
+
+        let emissive = build_chunk_layer_mesh_data(&chunk, &neighbors, MeshLayer::Emissive);
+        assert_eq!(emissive.positions.len(), 5 * 4);
+        let opaque = build_chunk_mesh_data(&chunk, &neighbors, None, None);
+        assert_eq!(opaque.positions.len(), 5 * 4);
+        assert!(
+            opaque
+                .quad_cells
+                .iter()
+                .all(|cell| { *cell as usize == Chunk::index(IVec3::new(2, 1, 1)) })
+        );
+    }
+
+    /// Verify builds sharing the thread's scratch buffers leave nothing behind for the next.
+    #[test]
+    fn scratch_reuse_does_not_leak_between_builds() {
+        let terrain = Chunk::new_terrain(IVec3::ZERO, 0, &crate::terrain::TerrainConfig::default());
+        let mut single = Chunk::new_empty();
+        single.set_block(IVec3::new(4, 4, 4), Block::stone());
+        let neighbors = ChunkNeighbors::default();
+
+        let first = build_chunk_mesh_data(&terrain, &neighbors, None, None);
+        assert!(!first.is_empty());
+        let small = build_chunk_mesh_data(&single, &neighbors, None, None);
+        assert_eq!(small.positions.len(), 6 * 4);
+        assert_eq!(small.colors.len(), 6 * 4);
+        assert_eq!(small.indices.len(), 6 * 6);
+        // The small build was copied out of the room the terrain estimate reserved.
+        assert_eq!(small.positions.capacity(), small.positions.len());
+        assert!(MESH_SCRATCH.with_borrow(|scratch| scratch.positions.capacity() > 6 * 4));
+        let again = build_chunk_mesh_data(&terrain, &neighbors, None, None);
+        // The full build handed its buffers over rather than copying them.
+        assert!(MESH_SCRATCH.with_borrow(|scratch| scratch.positions.capacity() == 0));
+        assert_eq!(again.positions, first.positions);
+        assert_eq!(again.indices, first.indices);
+        assert_eq!(again.colors, first.colors);
+    }
+
+    /// Verify border faces cull against the touching cell of a loaded neighbor chunk and
+    /// stay towards a neighbor that is not loaded, while water drops them.
+    #[test]
+    fn border_faces_cull_against_neighbor_chunks() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(CHUNK_SIZE - 1, 4, 4), Block::stone());
+        chunk.set_block(IVec3::new(0, 4, 4), Block::water());
+        let mut east = Chunk::new_empty();
+        east.set_block(IVec3::new(0, 4, 4), Block::stone());
+        let west = Chunk::new_empty();
+        let neighbors = ChunkNeighbors::from_fn(|offset| match offset {
+            IVec3::X => Some(&east),
+            IVec3::NEG_X => Some(&west),
+            _ => None,
+        });
+        assert_eq!(neighbors.missing().len(), 4);
+        assert_eq!(
+            neighbors.block(IVec3::new(CHUNK_SIZE, 4, 4)),
+            Some(Block::stone())
+        );
+        assert_eq!(neighbors.block(IVec3::new(4, CHUNK_SIZE, 4)), None);
+
+        let unloaded = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(unloaded.positions.len(), 6 * 4);
+        let bordered = build_chunk_mesh_data(&chunk, &neighbors, None, None);
+        assert_eq!(bordered.positions.len(), 5 * 4);
+        assert!(!bordered.normals.contains(&Vec3::X));
+
+        let water =
+            build_chunk_layer_mesh_data(&chunk, &ChunkNeighbors::default(), MeshLayer::Water);
+        assert_eq!(water.positions.len(), 5 * 4);
+        let water = build_chunk_layer_mesh_data(&chunk, &neighbors, MeshLayer::Water);
+        assert_eq!(water.positions.len(), 6 * 4);
+    }
+
+    /// Verify cheap-shadow face shading darkens faces turned away from the sun.
+    #[test]
+    fn sun_shading_darkens_faces_away_from_sun() {
+        let to_sun = Vec3::new(1.0, 1.0, 0.0);
+        let up = sun_face_shade(Vec3::Y, to_sun);
+        let side = sun_face_shade(Vec3::Z, to_sun);
+        let down = sun_face_shade(Vec3::NEG_Y, to_sun);
+        assert!(up > side && side > down);
+        assert!((sun_face_shade(to_sun.normalize(), to_sun) - 1.0).abs() < 1e-5);
+        assert!(
+            (sun_face_shade(-to_sun.normalize(), to_sun) - (1.0 - SUN_AWAY_DARKENING)).abs() < 1e-5
+        );
+
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 4, 4), Block::stone());
+        let shaded = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), Some(to_sun), None);
+        assert_eq!(shaded.colors.len(), shaded.positions.len());
+        assert!(
+            shaded
+                .colors
+                .iter()
+                .zip(&shaded.normals)
+                .all(|(color, normal)| color[0] == sun_face_shade(*normal, to_sun))
+        );
+    }
+
+    /// Verify cube face corners next to a block darken, more so in cheap-shadow mode, and
+    /// faces clear of blocks stay at full brightness.
+    #[test]
+    fn vertex_ao_darkens_corners_next_to_blocks() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 4, 4), Block::stone());
+        chunk.set_block(IVec3::new(5, 5, 4), Block::stone());
+        let top_colors = |sun_shading: Option<Vec3>| {
+            let data = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), sun_shading, None);
+            data.positions
+                .iter()
+                .zip(&data.normals)
+                .zip(&data.colors)
+                .filter(|((position, normal), _)| **normal == Vec3::Y && position.y == 5.0)
+                .map(|((position, _), color)| (position.x, color[0]))
+                .collect::<Vec<_>>()
+        };
+        let plain = top_colors(None);
+        assert_eq!(plain.len(), 4);
+        for (x, shade) in plain {
+            let expected = if x == 5.0 { 1.0 - AO_STEP } else { 1.0 };
+            assert!((shade - expected).abs() < 1e-5, "x {x}: {shade}");
+        }
+        for (x, shade) in top_colors(Some(Vec3::Y)) {
+            let expected = if x == 5.0 {
+                1.0 - CHEAP_SHADOW_AO_STEP
+            } else {
+                1.0
+            };
+            assert!((shade - expected).abs() < 1e-5, "x {x}: {shade}");
+        }
+        assert_eq!(
+            corner_occlusion(
+                &chunk,
+                &ChunkNeighbors::default(),
+                IVec3::new(4, 5, 4),
+                IVec3::Y,
+                IVec3::new(1, 1, 1)
+            ),
+            1
+        );
+        chunk.set_block(IVec3::new(4, 5, 5), Block::stone());
+        assert_eq!(
+            corner_occlusion(
+                &chunk,
+                &ChunkNeighbors::default(),
+                IVec3::new(4, 5, 4),
+                IVec3::Y,
+                IVec3::new(1, 1, 1)
+            ),
+            3
+        );
+    }
+
+    /// Verify re-tinting a mesh to a new sun direction matches rebuilding it with that one.
+    #[test]
+    fn sun_retint_matches_rebuild() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 4, 4), Block::stone());
+        chunk.set_block(IVec3::new(5, 5, 4), Block::dirt_with_grass());
+        let from = Vec3::new(1.0, 0.3, 0.2);
+        let to = Vec3::new(-0.4, 1.0, 0.6);
+        let build = |to_sun| {
+            mesh_from_data(build_chunk_mesh_data(
+                &chunk,
+                &ChunkNeighbors::default(),
+                Some(to_sun),
+                None,
+            ))
+        };
+        let mut mesh = build(from);
+        retint_sun_shading(&mut mesh, from, to);
+        let colors = |mesh: &Mesh| match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+            Some(VertexAttributeValues::Float32x4(colors)) => colors.clone(),
+            _ => panic!("mesh has no colors"),
+        };
+        let retinted = colors(&mesh);
+        let rebuilt = colors(&build(to));
+        assert_eq!(retinted.len(), rebuilt.len());
+        for (a, b) in retinted.iter().zip(&rebuilt) {
+            assert!(Vec4::from_array(*a).abs_diff_eq(Vec4::from_array(*b), 1e-5));
+        }
+    }
+
+    /// Verify grass tops take their column's biome tint, grass sides only its shift from
+    /// plains, and other blocks none; the preview uses the plains tint.
+    #[test]
+    fn grass_is_tinted_by_biome() {
+        let top = |data: &MeshData| {
+            let vertex = data.normals.iter().position(|n| *n == Vec3::Y).unwrap();
+            Vec3::from_slice(&data.colors[vertex][..3])
+        };
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 4, 4), Block::dirt_with_grass());
+        let grass = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(top(&grass), grass_tint(Biome::Plains));
+        let side = grass.normals.iter().position(|n| *n == Vec3::X).unwrap();
+        assert_eq!(grass.colors[side], [0.8, 0.8, 0.8, 1.0]);
+        assert_eq!(
+            top(&build_single_block_mesh_data(Block::dirt_with_grass())),
+            grass_tint(Biome::Plains)
+        );
+        chunk.set_block(IVec3::new(4, 4, 4), Block::dirt());
+        let dirt = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        assert_eq!(top(&dirt), Vec3::ONE);
+
+        let side_shift = tint_for_face(Block::dirt_with_grass(), Biome::Mountains, Vec3::X);
+        assert!(
+            (side_shift * grass_tint(Biome::Plains))
+                .abs_diff_eq(grass_tint(Biome::Mountains), 1e-5)
+        );
+        assert_ne!(grass_tint(Biome::Desert), grass_tint(Biome::Plains));
+    }
+
+    /// Verify faces are tinted by direction without a baked sun, in chunks and in the
+    /// single-block mesh alike.
+    #[test]
+    fn faces_are_shaded_by_direction_without_sun() {
+        let shade_of = |data: &MeshData, normal: Vec3| {
+            let vertex = data.normals.iter().position(|n| *n == normal).unwrap();
+            data.colors[vertex][0]
+        };
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 4, 4), Block::stone());
+        let chunk_data = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, None);
+        let single = build_single_block_mesh_data(Block::stone());
+        for data in [&chunk_data, &single] {
+            assert_eq!(data.colors.len(), data.positions.len());
+            assert_eq!(shade_of(data, Vec3::Y), 1.0);
+            assert_eq!(shade_of(data, Vec3::NEG_X), 0.8);
+            assert_eq!(shade_of(data, Vec3::Z), 0.6);
+            assert_eq!(shade_of(data, Vec3::NEG_Y), 0.5);
+        }
+        let diagonal = direction_shade(Vec3::new(1.0, 0.0, 1.0).normalize());
+        assert!((diagonal - 0.7).abs() < 1e-5);
+    }
+
+    /// Verify baked light brightens cube faces by the level of the cell they look into.
+    #[test]
+    fn block_light_brightens_faces_towards_lit_cells() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 4, 4), Block::stone());
+        let mut light = LightSnapshot::new(0);
+        light.set(IVec3::new(4, 5, 4), MAX_LIGHT);
+        light.set(IVec3::new(5, 4, 4), 7);
+
+        let data = build_chunk_mesh_data(&chunk, &ChunkNeighbors::default(), None, Some(&light));
+        assert_eq!(data.colors.len(), data.positions.len());
+        let shade = |normal: Vec3| {
+            let vertex = data.normals.iter().position(|n| *n == normal).unwrap();
+            data.colors[vertex][0]
+        };
+        assert!((shade(Vec3::Y) - 1.0).abs() < 1e-5);
+        assert!(shade(Vec3::X) < shade(Vec3::Y));
+        assert!(shade(Vec3::NEG_X) < shade(Vec3::X));
+        assert!(
+            (shade(Vec3::NEG_X) - MIN_LIGHT_BRIGHTNESS * direction_shade(Vec3::NEG_X)).abs() < 1e-5
+        );
+
+        let daylight = build_chunk_mesh_data(
+            &chunk,
+            &ChunkNeighbors::default(),
+            None,
+            Some(&LightSnapshot::new(MAX_LIGHT)),
+        );
+        assert!(
+            daylight
+                .colors
+                .iter()
+                .zip(&daylight.normals)
+                .all(|(color, normal)| color[0] == direction_shade(*normal))
+        );
+    }
+
+    /// Time remeshing one surface chunk 1,000 times, for comparing builder changes.
+    ///
+    /// Run with `cargo test --release remesh_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn remesh_benchmark() {
+        let config = crate::terrain::TerrainConfig::default();
+        let chunk = (-4..4)
+            .map(|y| Chunk::new_terrain(IVec3::new(0, y, 0), 0, &config))
+            .max_by_key(|chunk| {
+                build_chunk_mesh_data(chunk, &ChunkNeighbors::default(), None, None)
+                    .indices
+                    .len()
+            })
+            .unwrap();
+        let neighbors = ChunkNeighbors::default();
+        let start = std::time::Instant::now();
+        let mut faces = 0;
+        for _ in 0..1_000 {
+            let data = build_chunk_mesh_data(&chunk, &neighbors, None, None);
+            faces = data.indices.len() / 6;
+            std::hint::black_box(mesh_from_data(data));
+        }
+        println!("1000 remeshes of {faces} faces: {:?}", start.elapsed());
+    }
+}
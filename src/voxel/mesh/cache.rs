@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::voxel::block_chunk::Block;
+use crate::voxel::mesh::build_single_block_mesh;
+
+/// Shared single-block meshes keyed by block state.
+///
+/// The in-hand preview and falling blocks reuse these handles instead of adding a mesh
+/// asset per selection change or per spawned entity.
+#[derive(Default)]
+pub struct BlockMeshCache {
+    /// Mesh handle per block state (kind and facing).
+    handles: HashMap<Block, Handle<Mesh>>,
+}
+
+impl BlockMeshCache {
+    /// Return the shared mesh for `block`, building it on first use.
+    pub(crate) fn get_or_build(&mut self, meshes: &mut Assets<Mesh>, block: Block) -> Handle<Mesh> {
+        self.handles
+            .entry(block)
+            .or_insert_with(|| meshes.add(build_single_block_mesh(block)))
+            .clone()
+    }
+
+    /// Number of cached block meshes.
+    pub(crate) fn len(&self) -> usize {
+        self.handles.len()
+    }
+}
+
+/// Live mesh asset counts attributed to the subsystems that own them.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeshBudget {
+    /// Chunk meshes, one per loaded chunk plus one per chunk with water and one with glass.
+    pub chunks: usize,
+    /// Shared block meshes used by the preview and falling blocks.
+    pub block_meshes: usize,
+    /// Mesh assets not owned by a registry (sun quad, leaked per-event adds).
+    pub other: usize,
+}
+
+impl MeshBudget {
+    /// Non-chunk mesh assets tolerated before the budget reports an overrun.
+    pub(crate) const NON_CHUNK_CAP: usize = 64;
+
+    /// Return whether non-chunk mesh assets exceed the cap.
+    pub(crate) fn over_cap(&self) -> bool {
+        self.block_meshes + self.other > Self::NON_CHUNK_CAP
+    }
+}
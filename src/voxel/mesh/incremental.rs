@@ -0,0 +1,365 @@
+use bevy::mesh::{Indices, VertexAttributeValues};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::CHUNK_SIZE;
+
+use crate::voxel::block_chunk::Chunk;
+use crate::voxel::block_defs::MeshLayer;
+use crate::voxel::light_state::LightSnapshot;
+use crate::voxel::mesh::builder::{
+    MAX_U16_INDEXED_VERTICES, MESH_SCRATCH, chunk_mesh_from_data, emit_cell,
+};
+use crate::voxel::mesh_types::ChunkNeighbors;
+
+/// Replace the quads `cells` emitted into the `layer` mesh of `chunk` with the faces they
+/// show now.
+///
+/// `quad_cells` holds the owning cell of every quad of `mesh`, as recorded by the full
+/// build, and is kept in step. The quads of `cells` are swap-removed and their current faces
+/// appended, so the work scales with the edited cells rather than the chunk. `sun_shading`
+/// and `light` must be what a full build of the layer would get.
+///
+/// Returns `false` without touching the mesh when it is not a non-empty chunk layer mesh in
+/// step with `quad_cells`, or would outgrow its 16-bit index buffer; the caller then
+/// rebuilds it in full. A layer without faces before the edit is replaced by the edited
+/// cells' faces, since no other cell shows any.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn remesh_cells(
+    mesh: &mut Mesh,
+    quad_cells: &mut Vec<u16>,
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    sun_shading: Option<Vec3>,
+    light: Option<&LightSnapshot>,
+    layer: MeshLayer,
+    cells: &HashSet<IVec3>,
+) -> bool {
+    let mut edited = [false; (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize];
+    for cell in cells {
+        edited[Chunk::index(*cell)] = true;
+    }
+    let removed: Vec<usize> = (0..quad_cells.len())
+        .filter(|quad| edited[quad_cells[*quad] as usize])
+        .collect();
+
+    let mut cells: Vec<IVec3> = cells.iter().copied().collect();
+    cells.sort_by_key(|cell| Chunk::index(*cell));
+    MESH_SCRATCH.with_borrow_mut(|scratch| {
+        scratch.begin();
+        for cell in cells {
+            emit_cell(scratch, chunk, neighbors, layer, cell);
+        }
+        if removed.is_empty() && scratch.quad_cells.is_empty() {
+            return true;
+        }
+        if quad_cells.is_empty() {
+            // Cells outside the edit still show no faces, so the edited cells' faces are
+            // the whole layer.
+            (*mesh, *quad_cells) = chunk_mesh_from_data(scratch.mesh_data(sun_shading, light));
+            return true;
+        }
+        if !is_chunk_layer_mesh(mesh, quad_cells.len()) {
+            return false;
+        }
+        let quads = quad_cells.len() - removed.len() + scratch.quad_cells.len();
+        if matches!(mesh.indices(), Some(Indices::U16(_))) && quads * 4 > MAX_U16_INDEXED_VERTICES {
+            return false;
+        }
+
+        // Fill each removed slot, highest first, with the current last quad.
+        let mut moves = Vec::with_capacity(removed.len());
+        let mut kept = quad_cells.len();
+        for &quad in removed.iter().rev() {
+            kept -= 1;
+            if quad != kept {
+                quad_cells[quad] = quad_cells[kept];
+                moves.push((kept, quad));
+            }
+        }
+        quad_cells.truncate(kept);
+        quad_cells.extend_from_slice(&scratch.quad_cells);
+
+        let colors = scratch.colors(sun_shading, light);
+        let patch = QuadPatch { moves, kept };
+        if let Some(VertexAttributeValues::Float32x3(values)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            patch.apply(values, scratch.positions.iter().map(|v| v.to_array()));
+        }
+        if let Some(VertexAttributeValues::Float32x3(values)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            patch.apply(values, scratch.normals.iter().map(|v| v.to_array()));
+        }
+        if let Some(VertexAttributeValues::Float32x2(values)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0)
+        {
+            patch.apply(values, scratch.uvs.iter().map(|v| v.truncate().to_array()));
+        }
+        if let Some(VertexAttributeValues::Float32x2(values)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_UV_1)
+        {
+            patch.apply(values, scratch.uvs.iter().map(|v| [v.z, 0.0]));
+        }
+        if let Some(VertexAttributeValues::Float32x4(values)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_TANGENT)
+        {
+            patch.apply(values, scratch.tangents.iter().map(|v| v.to_array()));
+        }
+        if let Some(VertexAttributeValues::Float32x4(values)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_COLOR)
+        {
+            patch.apply(values, colors);
+        }
+        match mesh.indices_mut() {
+            Some(Indices::U16(indices)) => resize_quad_indices(indices, quads, |i| i as u16),
+            Some(Indices::U32(indices)) => resize_quad_indices(indices, quads, |i| i),
+            None => {}
+        }
+        true
+    })
+}
+
+/// Return whether `mesh` has every attribute of a chunk layer build, laid out as `quads`
+/// indexed quads.
+fn is_chunk_layer_mesh(mesh: &Mesh, quads: usize) -> bool {
+    quads > 0
+        && mesh.count_vertices() == quads * 4
+        && mesh
+            .indices()
+            .is_some_and(|indices| indices.len() == quads * 6)
+        && [
+            Mesh::ATTRIBUTE_POSITION,
+            Mesh::ATTRIBUTE_NORMAL,
+            Mesh::ATTRIBUTE_UV_0,
+            Mesh::ATTRIBUTE_TANGENT,
+            Mesh::ATTRIBUTE_COLOR,
+        ]
+        .into_iter()
+        .all(|attribute| mesh.contains_attribute(attribute))
+}
+
+/// Quad moves and final length shared by every vertex attribute of one incremental remesh.
+struct QuadPatch {
+    /// `(from, to)` quad slots, applied in order, that fill the removed quads.
+    moves: Vec<(usize, usize)>,
+    /// Quads left once the removed ones are gone.
+    kept: usize,
+}
+
+impl QuadPatch {
+    /// Apply the moves to one attribute's per-vertex `values`, drop the removed quads, and
+    /// append the vertices of the new ones.
+    fn apply<T: Copy>(&self, values: &mut Vec<T>, appended: impl IntoIterator<Item = T>) {
+        for &(from, to) in &self.moves {
+            values.copy_within(from * 4..from * 4 + 4, to * 4);
+        }
+        values.truncate(self.kept * 4);
+        values.extend(appended);
+    }
+}
+
+/// Truncate or extend a quad index buffer to `quads` quads of two triangles each.
+fn resize_quad_indices<T>(indices: &mut Vec<T>, quads: usize, index: fn(u32) -> T) {
+    indices.truncate(quads * 6);
+    for quad in indices.len() / 6..quads {
+        let start = quad as u32 * 4;
+        indices.extend([start, start + 1, start + 2, start, start + 2, start + 3].map(index));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::block_chunk::Block;
+    use crate::voxel::mesh::builder::{build_chunk_layer_mesh_data, build_chunk_mesh_data};
+
+    /// Set `block` at `local` and return the cells whose faces the edit may change.
+    fn edit(chunk: &mut Chunk, local: IVec3, block: Block) -> HashSet<IVec3> {
+        chunk.set_block(local, block);
+        crate::voxel::world::neighborhood(local)
+            .filter(|cell| Chunk::in_bounds(*cell))
+            .collect()
+    }
+
+    /// Return every quad of `mesh` as the bits of its vertex attributes, sorted, so meshes
+    /// with the same faces in another order compare equal.
+    fn face_set(mesh: &Mesh) -> Vec<Vec<u32>> {
+        let attributes = [
+            Mesh::ATTRIBUTE_POSITION,
+            Mesh::ATTRIBUTE_NORMAL,
+            Mesh::ATTRIBUTE_UV_0,
+            Mesh::ATTRIBUTE_UV_1,
+            Mesh::ATTRIBUTE_TANGENT,
+            Mesh::ATTRIBUTE_COLOR,
+        ]
+        .map(|attribute| match mesh.attribute(attribute) {
+            Some(VertexAttributeValues::Float32x2(values)) => {
+                values.iter().map(|v| v.to_vec()).collect::<Vec<_>>()
+            }
+            Some(VertexAttributeValues::Float32x3(values)) => {
+                values.iter().map(|v| v.to_vec()).collect()
+            }
+            Some(VertexAttributeValues::Float32x4(values)) => {
+                values.iter().map(|v| v.to_vec()).collect()
+            }
+            _ => vec![Vec::new(); mesh.count_vertices()],
+        });
+        let mut quads: Vec<Vec<u32>> = (0..mesh.count_vertices() / 4)
+            .map(|quad| {
+                (quad * 4..quad * 4 + 4)
+                    .flat_map(|vertex| attributes.iter().flat_map(move |a| a[vertex].clone()))
+                    .map(f32::to_bits)
+                    .collect()
+            })
+            .collect();
+        quads.sort();
+        quads
+    }
+
+    /// Assert `mesh` indexes its vertices as consecutive quads of two triangles.
+    fn assert_quad_indices(mesh: &Mesh) {
+        let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+        assert_eq!(indices.len(), mesh.count_vertices() / 4 * 6);
+        for (quad, triangles) in indices.chunks(6).enumerate() {
+            let start = quad * 4;
+            assert_eq!(
+                triangles,
+                [start, start + 1, start + 2, start, start + 2, start + 3]
+            );
+        }
+    }
+
+    /// Verify patching the cells around each edit yields the same faces as a full rebuild,
+    /// for opaque and water meshes alike, including edits that empty and refill a cell.
+    #[test]
+    fn incremental_remesh_matches_full_rebuild() {
+        let neighbors = ChunkNeighbors::default();
+        let mut chunk = Chunk::new_empty();
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                for y in 0..4 {
+                    chunk.set_block(IVec3::new(x, y, z), Block::stone());
+                }
+                chunk.set_block(IVec3::new(x, 4, z), Block::dirt_with_grass());
+            }
+        }
+        chunk.set_block(IVec3::new(8, 5, 8), Block::water());
+        chunk.set_block(IVec3::new(9, 5, 8), Block::water());
+        let (mut opaque, mut opaque_cells) =
+            chunk_mesh_from_data(build_chunk_mesh_data(&chunk, &neighbors, None, None));
+        let (mut water, mut water_cells) = chunk_mesh_from_data(build_chunk_layer_mesh_data(
+            &chunk,
+            &neighbors,
+            MeshLayer::Water,
+        ));
+
+        let edits = [
+            (IVec3::new(3, 4, 3), Block::air()),
+            (IVec3::new(3, 3, 3), Block::air()),
+            (IVec3::new(3, 4, 3), Block::stone()),
+            (IVec3::new(0, 5, 0), Block::torch()),
+            (IVec3::new(5, 5, 5), Block::slab()),
+            (IVec3::new(10, 5, 8), Block::stone()),
+            (IVec3::new(8, 4, 8), Block::air()),
+            (IVec3::new(8, 5, 8), Block::air()),
+            (IVec3::new(15, 4, 15), Block::tall_grass()),
+            (IVec3::new(2, 5, 2), Block::water()),
+        ];
+        for (local, block) in edits {
+            let cells = edit(&mut chunk, local, block);
+            assert!(remesh_cells(
+                &mut opaque,
+                &mut opaque_cells,
+                &chunk,
+                &neighbors,
+                None,
+                None,
+                MeshLayer::Opaque,
+                &cells,
+            ));
+            assert!(remesh_cells(
+                &mut water,
+                &mut water_cells,
+                &chunk,
+                &neighbors,
+                None,
+                None,
+                MeshLayer::Water,
+                &cells,
+            ));
+            let (full, full_cells) =
+                chunk_mesh_from_data(build_chunk_mesh_data(&chunk, &neighbors, None, None));
+            assert_eq!(face_set(&opaque), face_set(&full), "after editing {local}");
+            assert_quad_indices(&opaque);
+            let mut sorted_cells = opaque_cells.clone();
+            sorted_cells.sort();
+            let mut full_sorted = full_cells;
+            full_sorted.sort();
+            assert_eq!(sorted_cells, full_sorted);
+            let (full_water, _) = chunk_mesh_from_data(build_chunk_layer_mesh_data(
+                &chunk,
+                &neighbors,
+                MeshLayer::Water,
+            ));
+            assert_eq!(
+                face_set(&water),
+                face_set(&full_water),
+                "after editing {local}"
+            );
+        }
+    }
+
+    /// Time 1,000 single-block edits of a dense chunk patched in place against full rebuilds.
+    ///
+    /// Run with `cargo test --release incremental_remesh_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn incremental_remesh_benchmark() {
+        let neighbors = ChunkNeighbors::default();
+        let mut chunk = Chunk::new_empty();
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    if (x + y + z) % 2 == 0 {
+                        chunk.set_block(IVec3::new(x, y, z), Block::stone());
+                    }
+                }
+            }
+        }
+        let center = IVec3::splat(CHUNK_SIZE / 2);
+        // Alternately break and restore the center block.
+        let toggled = |edit_index: usize| [Block::air(), Block::stone()][edit_index % 2];
+
+        let mut full_chunk = chunk.clone();
+        let start = std::time::Instant::now();
+        let mut faces = 0;
+        for edit_index in 0..1_000 {
+            edit(&mut full_chunk, center, toggled(edit_index));
+            let data = build_chunk_mesh_data(&full_chunk, &neighbors, None, None);
+            faces = data.indices.len() / 6;
+            std::hint::black_box(chunk_mesh_from_data(data));
+        }
+        let full = start.elapsed();
+
+        let (mut mesh, mut quad_cells) =
+            chunk_mesh_from_data(build_chunk_mesh_data(&chunk, &neighbors, None, None));
+        let start = std::time::Instant::now();
+        for edit_index in 0..1_000 {
+            let cells = edit(&mut chunk, center, toggled(edit_index));
+            assert!(remesh_cells(
+                &mut mesh,
+                &mut quad_cells,
+                &chunk,
+                &neighbors,
+                None,
+                None,
+                MeshLayer::Opaque,
+                &cells,
+            ));
+        }
+        let incremental = start.elapsed();
+        println!("1000 edits of a {faces}-face chunk: full {full:?}, incremental {incremental:?}");
+    }
+}
@@ -1,5 +1,11 @@
 mod atlas;
 mod builder;
+mod cache;
+mod incremental;
 
 pub use builder::build_single_block_mesh;
-pub(crate) use builder::{build_chunk_mesh_data, build_single_block_mesh_data, mesh_from_data};
+pub(crate) use builder::{
+    build_chunk_layer_mesh_data, build_chunk_mesh_data, chunk_mesh_from_data, retint_sun_shading,
+};
+pub use cache::{BlockMeshCache, MeshBudget};
+pub(crate) use incremental::remesh_cells;
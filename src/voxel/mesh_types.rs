@@ -1,15 +1,146 @@
 use bevy::prelude::*;
 
+use crate::CHUNK_SIZE;
+
+use crate::voxel::block_chunk::{Block, Chunk, Facing};
+use crate::voxel::block_defs::BlockBox;
+
+/// Offsets of the six face-neighbor chunks whose borders a `ChunkNeighbors` snapshot holds.
+pub(crate) const NEIGHBOR_CHUNK_OFFSETS: [IVec3; 6] = [
+    IVec3::X,
+    IVec3::NEG_X,
+    IVec3::Y,
+    IVec3::NEG_Y,
+    IVec3::Z,
+    IVec3::NEG_Z,
+];
+
+/// Half width of a torch post in unit-cube space (one texel of a 16-texel tile).
+const TORCH_HALF_WIDTH: f32 = 1.0 / 16.0;
+/// Height of a torch post in unit-cube space.
+const TORCH_HEIGHT: f32 = 10.0 / 16.0;
+/// Height a wall torch is lifted off the floor of its cell.
+const WALL_TORCH_LIFT: f32 = 3.0 / 16.0;
+
+/// Visible post of a fence, narrower than its collision and one cell tall.
+const FENCE_POST: BlockBox = BlockBox {
+    min: Vec3::new(0.375, 0.0, 0.375),
+    max: Vec3::new(0.625, 1.0, 0.625),
+};
+/// Lower and upper rail of a fence arm reaching +Z from the post.
+const FENCE_RAILS: [BlockBox; 2] = [
+    BlockBox {
+        min: Vec3::new(0.4375, 0.375, 0.625),
+        max: Vec3::new(0.5625, 0.5625, 1.0),
+    },
+    BlockBox {
+        min: Vec3::new(0.4375, 0.75, 0.625),
+        max: Vec3::new(0.5625, 0.9375, 1.0),
+    },
+];
+
 /// Raw mesh buffers assembled before uploading to a Bevy `Mesh`.
 pub struct MeshData {
     /// Vertex positions in world/chunk mesh space (`Vec<Vec3>`).
     pub(crate) positions: Vec<Vec3>,
     /// Per-vertex normals used by lighting (`Vec<Vec3>`).
     pub(crate) normals: Vec<Vec3>,
-    /// Per-vertex UV coordinates for texture atlas sampling (`Vec<Vec2>`).
-    pub(crate) uvs: Vec<Vec2>,
+    /// Per-vertex tile UV coordinates in `xy` and block texture layer in `z`.
+    pub(crate) uvs: Vec<Vec3>,
+    /// Per-vertex tangents (`xyz` along +U, `w` bitangent sign) for normal mapping.
+    pub(crate) tangents: Vec<Vec4>,
     /// Triangle index buffer (u32).
     pub(crate) indices: Vec<u32>,
+    /// Per-vertex color multipliers baked from face direction and light (empty when unshaded).
+    pub(crate) colors: Vec<[f32; 4]>,
+    /// `Chunk` cell index that emitted each quad (empty outside chunk meshes).
+    pub(crate) quad_cells: Vec<u16>,
+}
+
+impl MeshData {
+    /// Return `true` when no faces were emitted.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+/// Blocks of the face-neighbor chunks touching one chunk, copied out for the mesh builder.
+///
+/// Only each neighbor's layer of cells against the chunk is kept, which is all the builder
+/// needs to cull faces on the chunk border.
+#[derive(Clone, Default)]
+pub(crate) struct ChunkNeighbors {
+    /// Touching layer of each neighbor in `NEIGHBOR_CHUNK_OFFSETS` order, or `None` while that
+    /// neighbor is not loaded.
+    borders: [Option<Vec<Block>>; 6],
+}
+
+impl ChunkNeighbors {
+    /// Copy the touching layer of every neighbor `neighbor_at` returns for a chunk offset.
+    pub(crate) fn from_fn<'a>(mut neighbor_at: impl FnMut(IVec3) -> Option<&'a Chunk>) -> Self {
+        Self {
+            borders: NEIGHBOR_CHUNK_OFFSETS.map(|offset| {
+                let chunk = neighbor_at(offset)?;
+                let edge = if offset.max_element() > 0 {
+                    0
+                } else {
+                    CHUNK_SIZE - 1
+                };
+                let mut layer = Vec::with_capacity((CHUNK_SIZE * CHUNK_SIZE) as usize);
+                for b in 0..CHUNK_SIZE {
+                    for a in 0..CHUNK_SIZE {
+                        let local = match Self::layer_axis(offset) {
+                            0 => IVec3::new(edge, a, b),
+                            1 => IVec3::new(a, edge, b),
+                            _ => IVec3::new(a, b, edge),
+                        };
+                        layer.push(chunk.get_block(local));
+                    }
+                }
+                Some(layer)
+            }),
+        }
+    }
+
+    /// Return the axis (`0` = X, `1` = Y, `2` = Z) a face-neighbor offset points along.
+    fn layer_axis(offset: IVec3) -> usize {
+        if offset.x != 0 {
+            0
+        } else if offset.y != 0 {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Return the block in the cell one step outside the chunk at local `local`.
+    ///
+    /// Returns `None` while the neighbor owning the cell is not loaded, and for cells past
+    /// an edge or corner, which no face looks into.
+    pub(crate) fn block(&self, local: IVec3) -> Option<Block> {
+        let offset = local.div_euclid(IVec3::splat(CHUNK_SIZE));
+        let index = NEIGHBOR_CHUNK_OFFSETS
+            .iter()
+            .position(|neighbor| *neighbor == offset)?;
+        let layer = self.borders[index].as_ref()?;
+        let inside = local.rem_euclid(IVec3::splat(CHUNK_SIZE));
+        let (a, b) = match Self::layer_axis(offset) {
+            0 => (inside.y, inside.z),
+            1 => (inside.x, inside.z),
+            _ => (inside.x, inside.y),
+        };
+        Some(layer[(a + b * CHUNK_SIZE) as usize])
+    }
+
+    /// Return the offsets of the neighbors that were not loaded when the snapshot was taken.
+    pub(crate) fn missing(&self) -> Vec<IVec3> {
+        NEIGHBOR_CHUNK_OFFSETS
+            .into_iter()
+            .zip(&self.borders)
+            .filter(|(_, border)| border.is_none())
+            .map(|(offset, _)| offset)
+            .collect()
+    }
 }
 
 /// Table row describing one cube face for mesh generation.
@@ -31,6 +162,8 @@ pub(crate) struct FaceDef {
 pub(crate) struct FaceUv(
     /// Face UV coordinates in quad-vertex order.
     pub(crate) [Vec2; 4],
+    /// Block texture layer, the tile's index in `ATLAS_TEXTURE_ORDER`, the face samples.
+    pub(crate) u32,
 );
 
 /// Vertex payload for one quad face in vertex order.
@@ -110,3 +243,71 @@ pub(crate) const FACE_DEFS: [FaceDef; 6] = [
         ],
     },
 ];
+
+/// Unit-cube corners of the quads emitted for cross-shaped blocks.
+///
+/// The two diagonal planes are each listed in both windings so they show from either
+/// side under back-face culling. Corner order matches `FACE_DEFS`: bottom, top, top, bottom.
+pub(crate) const CROSS_QUADS: [[IVec3; 4]; 4] = [
+    [
+        IVec3::new(0, 0, 0),
+        IVec3::new(0, 1, 0),
+        IVec3::new(1, 1, 1),
+        IVec3::new(1, 0, 1),
+    ],
+    [
+        IVec3::new(1, 0, 1),
+        IVec3::new(1, 1, 1),
+        IVec3::new(0, 1, 0),
+        IVec3::new(0, 0, 0),
+    ],
+    [
+        IVec3::new(1, 0, 0),
+        IVec3::new(1, 1, 0),
+        IVec3::new(0, 1, 1),
+        IVec3::new(0, 0, 1),
+    ],
+    [
+        IVec3::new(0, 0, 1),
+        IVec3::new(0, 1, 1),
+        IVec3::new(1, 1, 0),
+        IVec3::new(1, 0, 0),
+    ],
+];
+
+/// Return the unit-cube min/max corners of a torch post whose support lies opposite `front`.
+///
+/// Standing torches sit centered on the floor of their cell; wall torches are lifted and
+/// pushed flush against the wall they hang on.
+pub(crate) fn torch_bounds(front: Facing) -> (Vec3, Vec3) {
+    let normal = front.as_normal().as_vec3();
+    let (center, floor) = if normal.y > 0.0 {
+        (Vec3::new(0.5, 0.0, 0.5), 0.0)
+    } else {
+        let center = Vec3::splat(0.5) - normal * (0.5 - TORCH_HALF_WIDTH);
+        (center, WALL_TORCH_LIFT)
+    };
+    (
+        Vec3::new(
+            center.x - TORCH_HALF_WIDTH,
+            floor,
+            center.z - TORCH_HALF_WIDTH,
+        ),
+        Vec3::new(
+            center.x + TORCH_HALF_WIDTH,
+            floor + TORCH_HEIGHT,
+            center.z + TORCH_HALF_WIDTH,
+        ),
+    )
+}
+
+/// Return the boxes drawn for a fence: its post plus two rails towards each arm.
+pub(crate) fn fence_parts(block: Block) -> Vec<BlockBox> {
+    std::iter::once(FENCE_POST)
+        .chain(
+            block
+                .fence_arms()
+                .flat_map(|facing| FENCE_RAILS.map(|rail| rail.oriented(facing))),
+        )
+        .collect()
+}
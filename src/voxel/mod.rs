@@ -1,19 +1,66 @@
 mod block_chunk;
 mod block_defs;
+mod block_entity_state;
+mod block_material;
+mod block_update_state;
+mod container_state;
+mod crafting_state;
 mod falling_state;
+mod generator;
 mod interaction_state;
+mod item_state;
+mod light_state;
+mod material_state;
 mod mesh;
 mod mesh_types;
+mod random_tick_state;
+mod sapling_state;
 mod systems;
+mod tnt_state;
+mod tree_defs;
+mod water_state;
 mod world;
 mod world_state;
 
-pub use block_chunk::Block;
+pub use block_chunk::{Block, BlockKind};
+pub use block_defs::BlockRegistry;
+pub use block_material::{
+    BLOCK_NORMAL_TEXTURE_PATH, BLOCK_TEXTURE_PATH, BlockMaterial, BlockMaterialPlugin,
+    FluidMaterial, block_material, configure_block_texture, fluid_material, set_block_normal_map,
+};
+pub use container_state::ContainerMenu;
+pub use crafting_state::CraftingMenu;
 pub use falling_state::FallingPropagationQueue;
-pub use interaction_state::{InteractionCooldown, SelectedBlock};
-pub use mesh::build_single_block_mesh;
+pub use generator::{ChunkGenerator, generator_for_mode};
+pub use interaction_state::{
+    BreakProgress, CurrentTarget, HeldTool, SelectedBlock, world_controls_enabled,
+};
+pub use item_state::{Inventory, ItemKind};
+pub use light_state::LightingConfig;
+pub use material_state::{WorldMaterial, WorldMaterials};
+pub use mesh::MeshBudget;
+pub use random_tick_state::RandomTicks;
 pub use systems::{
-    block_interaction_system, chunk_loading_system, spawn_falling_blocks_system,
-    update_falling_blocks_system,
+    TorchLightPool, block_interaction_system, block_light_system, block_update_system,
+    block_use_system, chunk_loading_system, chunk_remesh_system, column_cache_report_system,
+    current_target_system, falling_collider_system, held_tool_system, item_pickup_system,
+    light_flicker_system, mesh_budget_system, primed_tnt_system, random_tick_system,
+    sapling_growth_system, settled_block_system, spawn_falling_blocks_system,
+    spill_block_entities_system, sponge_system, steady_light_system, terrain_config_reload_system,
+    throw_item_system, tnt_ignite_system, torch_light_system, update_falling_blocks_system,
+    update_item_drops_system, water_flow_system, wireframe_toggle_system, world_material_system,
 };
-pub use world_state::WorldState;
+pub use tnt_state::{Explosion, TntConfig};
+pub use tree_defs::TreeRegistry;
+pub use water_state::WaterTickTimer;
+pub use world_state::{WorldBorder, WorldState};
+
+pub(crate) use block_chunk::Chunk;
+pub(crate) use block_entity_state::CHEST_SLOTS;
+pub(crate) use crafting_state::CRAFTING_GRID_SLOTS;
+pub(crate) use light_state::NIGHT_SKY_DARKENING;
+#[cfg(test)]
+pub(crate) use systems::{IGNITE_KEY, MESH_BUDGET_REPORT_KEY, THROW_KEY, WIREFRAME_KEY};
+pub(crate) use world::RAY_MAX_DISTANCE_BLOCKS;
+#[cfg(test)]
+pub(crate) use world_state::ChunkData;
@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::terrain::ChunkRng;
+use crate::voxel::block_chunk::BlockKind;
+use crate::voxel::systems::{spread_grass_tick, wither_grass_tick};
+use crate::voxel::world_state::WorldState;
+
+/// Random cells ticked per loaded chunk each second by default.
+const DEFAULT_TICKS_PER_CHUNK_PER_SECOND: u32 = 3;
+/// Salt separating the random tick stream from terrain feature streams.
+const RANDOM_TICK_RNG_SALT: u64 = 0x7261_6e64_5f74_6963;
+
+/// Behavior run when a random tick lands on a block of the kind it is registered for.
+///
+/// Receives the ticked cell and the scheduler's random stream, and returns the chunk it
+/// edited, if any, so the scheduler can remesh every edit of a frame together.
+pub type RandomTickHandler = fn(&mut WorldState, IVec3, &mut ChunkRng) -> Option<IVec3>;
+
+/// Random tick scheduler state: tick rate, random stream, and per-kind handlers.
+///
+/// Slow block behaviors such as grass spreading register a handler here instead of each
+/// sampling the world on their own.
+#[derive(Resource)]
+pub struct RandomTicks {
+    /// Random cells ticked per loaded chunk near the player each second.
+    pub ticks_per_chunk_per_second: u32,
+    /// Fraction of a tick per chunk carried over to the next frame.
+    carry: f32,
+    /// Stream picking the ticked cells and feeding handlers.
+    pub(crate) rng: ChunkRng,
+    /// Handler of each block kind that reacts to random ticks.
+    handlers: HashMap<BlockKind, RandomTickHandler>,
+}
+
+impl Default for RandomTicks {
+    fn default() -> Self {
+        let mut ticks = Self::with_seed(0);
+        ticks.register(BlockKind::Dirt, spread_grass_tick);
+        ticks.register(BlockKind::DirtWithGrass, wither_grass_tick);
+        ticks
+    }
+}
+
+impl RandomTicks {
+    /// Build a scheduler with no handlers whose random stream starts from `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            ticks_per_chunk_per_second: DEFAULT_TICKS_PER_CHUNK_PER_SECOND,
+            carry: 0.0,
+            rng: ChunkRng::new(IVec3::ZERO, seed, RANDOM_TICK_RNG_SALT),
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Run `handler` whenever a random tick lands on a `kind` block, replacing any previous one.
+    pub fn register(&mut self, kind: BlockKind, handler: RandomTickHandler) {
+        self.handlers.insert(kind, handler);
+    }
+
+    /// Return the handler registered for `kind`, if any.
+    pub fn handler(&self, kind: BlockKind) -> Option<RandomTickHandler> {
+        self.handlers.get(&kind).copied()
+    }
+
+    /// Advance by `delta_secs` and return how many ticks each chunk gets this frame.
+    pub fn due(&mut self, delta_secs: f32) -> u32 {
+        self.carry += self.ticks_per_chunk_per_second as f32 * delta_secs;
+        let due = self.carry.floor();
+        self.carry -= due;
+        due as u32
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use crate::terrain::ChunkRng;
+use crate::voxel::tree_defs::{TreeKind, TreeRegistry};
+
+/// Shortest seconds a sapling waits before trying to grow.
+const SAPLING_MIN_GROW_SECS: f32 = 30.0;
+/// Extra random seconds on top of the shortest wait, at most.
+const SAPLING_GROW_JITTER_SECS: i32 = 30;
+/// Seconds an obstructed sapling waits before trying again.
+pub(crate) const SAPLING_RETRY_SECS: f32 = 10.0;
+/// Least light a sapling needs at its cell to grow; darker saplings wait and retry.
+pub(crate) const SAPLING_MIN_LIGHT: u8 = 9;
+/// Salt separating the sapling stream from terrain feature streams.
+const SAPLING_RNG_SALT: u64 = 0x7361_706c_696e_6721;
+
+#[derive(Component, Debug)]
+/// Growth timer for one placed sapling, living on a marker entity.
+pub struct SaplingGrowth {
+    /// World cell of the sapling.
+    pub(crate) cell: IVec3,
+    /// Trunk height of the tree it grows into.
+    pub(crate) trunk_height: i32,
+    /// Countdown to the next growth attempt.
+    pub(crate) timer: Timer,
+}
+
+impl SaplingGrowth {
+    /// Build growth state for a sapling at `cell` with a random delay and trunk height.
+    pub(crate) fn new(cell: IVec3) -> Self {
+        let mut rng = ChunkRng::new(cell, 0, SAPLING_RNG_SALT);
+        let delay = SAPLING_MIN_GROW_SECS + rng.below(SAPLING_GROW_JITTER_SECS + 1) as f32;
+        let trunks = &TreeRegistry::global().template(TreeKind::Oak).trunk_height;
+        let trunk_height = trunks.start() + rng.below(trunks.end() - trunks.start() + 1);
+        Self {
+            cell,
+            trunk_height,
+            timer: Timer::from_seconds(delay, TimerMode::Once),
+        }
+    }
+
+    /// Spawn the marker entity carrying this growth state.
+    pub(crate) fn spawn(self, commands: &mut Commands) {
+        commands.spawn((self, Name::new("SaplingGrowth")));
+    }
+}
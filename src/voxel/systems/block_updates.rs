@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::voxel::block_chunk::Block;
+use crate::voxel::systems::grass::grass_smothered;
+use crate::voxel::world_state::WorldState;
+
+/// Max due block updates processed per frame to avoid long spikes.
+const MAX_BLOCK_UPDATES_PER_FRAME: usize = 64;
+
+/// Return what a due update turns the block at `pos` into, or `None` to leave it.
+///
+/// Grass still smothered by the block above dies to dirt; grass uncovered in the meantime
+/// stays, which is how removing the cover cancels the update.
+fn updated_block(world: &WorldState, pos: IVec3) -> Option<Block> {
+    grass_smothered(world, pos).then(Block::dirt)
+}
+
+/// Apply delayed block updates once they are due, a bounded batch per frame.
+pub fn block_update_system(
+    time: Res<Time>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    world.block_updates.advance(time.delta_secs());
+    let mut touched: HashSet<IVec3> = HashSet::new();
+    for _ in 0..MAX_BLOCK_UPDATES_PER_FRAME {
+        let Some(pos) = world.block_updates.pop_due() else {
+            break;
+        };
+        if let Some(next) = updated_block(&world, pos) {
+            touched.extend(world.set_block_world_loaded(pos, next));
+        }
+    }
+    world.rebuild_touched_chunk_meshes(&mut meshes, touched);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::Chunk;
+    use crate::voxel::block_update_state::GRASS_COVER_DELAY_SECS;
+    use crate::voxel::world_state::ChunkData;
+
+    /// Run due block updates on `world` without remeshing.
+    fn run_due_updates(world: &mut WorldState) {
+        while let Some(pos) = world.block_updates.pop_due() {
+            if let Some(next) = updated_block(world, pos) {
+                world.set_block_world_loaded(pos, next);
+            }
+        }
+    }
+
+    /// Verify covered grass dies only after the delay, and uncovering it first cancels that.
+    #[test]
+    fn covered_grass_dies_after_delay_unless_uncovered() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        let (kept, killed) = (IVec3::new(2, 1, 2), IVec3::new(5, 1, 2));
+        chunk.set_block(kept, Block::dirt_with_grass());
+        chunk.set_block(killed, Block::dirt_with_grass());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        for grass in [kept, killed] {
+            world.set_block_world_loaded(grass + IVec3::Y, Block::stone());
+            world.block_updates.schedule(grass, GRASS_COVER_DELAY_SECS);
+        }
+        world.block_updates.advance(GRASS_COVER_DELAY_SECS * 0.5);
+        run_due_updates(&mut world);
+        assert_eq!(
+            world.get_block_world(killed),
+            Some(Block::dirt_with_grass())
+        );
+
+        world.set_block_world_loaded(kept + IVec3::Y, Block::air());
+        world.block_updates.advance(GRASS_COVER_DELAY_SECS * 0.5);
+        run_due_updates(&mut world);
+        assert_eq!(world.get_block_world(killed), Some(Block::dirt()));
+        assert_eq!(world.get_block_world(kept), Some(Block::dirt_with_grass()));
+    }
+}
@@ -0,0 +1,263 @@
+use bevy::pbr::wireframe::Wireframe;
+use bevy::prelude::*;
+
+use crate::terrain::ColumnCache;
+use crate::voxel::mesh::MeshBudget;
+use crate::voxel::world_state::WorldState;
+
+/// Hotkey that logs the current mesh budget and column cache use.
+pub(crate) const MESH_BUDGET_REPORT_KEY: KeyCode = KeyCode::F3;
+/// Hotkey that toggles wireframe drawing of chunk meshes.
+pub(crate) const WIREFRAME_KEY: KeyCode = KeyCode::F4;
+
+/// Toggle wireframe drawing of every loaded chunk mesh, separate class meshes included.
+///
+/// Chunks streamed in while the view is on are spawned with wireframes already; other meshes
+/// such as the preview block keep drawing normally.
+pub fn wireframe_toggle_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    world: Option<ResMut<WorldState>>,
+) {
+    let Some(mut world) = world else {
+        return;
+    };
+    if !keys.just_pressed(WIREFRAME_KEY) {
+        return;
+    }
+    world.wireframe = !world.wireframe;
+    for data in world.chunks.values() {
+        let layer_entities = data
+            .layer_meshes
+            .values()
+            .map(|layer_mesh| layer_mesh.entity);
+        for entity in std::iter::once(data.entity).chain(layer_entities) {
+            if world.wireframe {
+                commands.entity(entity).insert(Wireframe);
+            } else {
+                commands.entity(entity).remove::<Wireframe>();
+            }
+        }
+    }
+}
+
+/// Attribute live mesh assets to their registries and report cap overruns.
+pub fn mesh_budget_system(
+    meshes: Res<Assets<Mesh>>,
+    world: Option<Res<WorldState>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut budget: ResMut<MeshBudget>,
+) {
+    let Some(world) = world else {
+        return;
+    };
+    let chunks = world
+        .chunks
+        .values()
+        .map(|data| 1 + data.layer_meshes.len())
+        .sum();
+    let block_meshes = world.block_meshes.len();
+    let next = MeshBudget {
+        chunks,
+        block_meshes,
+        other: meshes.len().saturating_sub(chunks + block_meshes),
+    };
+    if next.over_cap() && !budget.over_cap() {
+        warn!(
+            "Mesh assets over budget: {} block meshes + {} unattributed > {}",
+            next.block_meshes,
+            next.other,
+            MeshBudget::NON_CHUNK_CAP
+        );
+    }
+    if keys.just_pressed(MESH_BUDGET_REPORT_KEY) {
+        info!(
+            "Mesh assets: {} chunk, {} block, {} unattributed",
+            next.chunks, next.block_meshes, next.other
+        );
+    }
+    budget.set_if_neq(next);
+}
+
+/// Report how many columns the runtime column cache holds and how much noise it evaluated.
+pub fn column_cache_report_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    column_cache: Option<Res<ColumnCache>>,
+) {
+    let Some(column_cache) = column_cache else {
+        return;
+    };
+    if keys.just_pressed(MESH_BUDGET_REPORT_KEY) {
+        info!(
+            "Column cache: {} columns, {} noise evaluations",
+            column_cache.cached_columns(),
+            column_cache.evaluations()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::journal::SessionJournal;
+    use crate::player::PreviewBlock;
+    use crate::voxel::block_chunk::{Block, Chunk};
+    use crate::voxel::falling_state::FallingBlock;
+    use crate::voxel::generator::FlatGenerator;
+    use crate::voxel::interaction_state::SelectedBlock;
+    use crate::voxel::systems::update_falling_blocks_system;
+    use crate::{BLOCK_SIZE, CHUNK_SIZE};
+
+    /// Verify a minute of selection churn, falling blocks, and breaks keeps mesh assets bounded.
+    #[test]
+    fn mesh_assets_stay_bounded_under_stress() {
+        const FRAMES: usize = 60 * 60;
+        const FALLING_BLOCKS: usize = 200;
+        const BREAKS: i32 = 50;
+        const SELECT_KEYS: [KeyCode; 3] = [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3];
+
+        let mut app = App::new();
+        app.insert_resource(WorldState::new())
+            .insert_resource(SessionJournal::new())
+            .insert_resource(SelectedBlock::new(Block::dirt()))
+            .init_resource::<MeshBudget>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(Update, (update_falling_blocks_system, mesh_budget_system));
+        app.world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 mut meshes: ResMut<Assets<Mesh>>,
+                 mut world: ResMut<WorldState>| {
+                    world.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                    // Floor for the falling sand plus a ceiling slab to break.
+                    let mut chunk = Chunk::new_empty();
+                    for x in 0..CHUNK_SIZE {
+                        for z in 0..CHUNK_SIZE {
+                            chunk.set_block(IVec3::new(x, 0, z), Block::dirt());
+                            chunk.set_block(IVec3::new(x, CHUNK_SIZE - 1, z), Block::dirt());
+                        }
+                    }
+                    world.chunks.get_mut(&IVec3::ZERO).unwrap().chunk = chunk;
+                    let preview = world.block_meshes.get_or_build(&mut meshes, Block::dirt());
+                    commands.spawn((bevy::mesh::Mesh3d(preview), PreviewBlock));
+                },
+            )
+            .unwrap();
+
+        for frame in 0..FRAMES {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.reset_all();
+            keys.press(SELECT_KEYS[frame % SELECT_KEYS.len()]);
+            app.world_mut()
+                .run_system_once(
+                    move |mut commands: Commands,
+                          keys: Res<ButtonInput<KeyCode>>,
+                          mut meshes: ResMut<Assets<Mesh>>,
+                          mut world: ResMut<WorldState>,
+                          mut selected: ResMut<SelectedBlock>,
+                          mut preview_query: Query<
+                        &mut bevy::mesh::Mesh3d,
+                        With<PreviewBlock>,
+                    >| {
+                        selected.apply_hotkeys(
+                            &keys,
+                            &mut meshes,
+                            &mut world.block_meshes,
+                            &mut preview_query,
+                        );
+                        if frame < FALLING_BLOCKS {
+                            let column = frame as i32 % CHUNK_SIZE;
+                            let translation =
+                                Vec3::new(column as f32, 10.0, (frame as i32 / CHUNK_SIZE) as f32)
+                                    * BLOCK_SIZE;
+                            FallingBlock::new(Block::sand()).spawn(
+                                &mut commands,
+                                &mut meshes,
+                                &mut world,
+                                translation,
+                            );
+                        }
+                        if (frame as i32) < BREAKS {
+                            let target = IVec3::new(
+                                frame as i32 % CHUNK_SIZE,
+                                CHUNK_SIZE - 1,
+                                frame as i32 / CHUNK_SIZE,
+                            );
+                            assert!(world.break_block(&mut meshes, target));
+                        }
+                    },
+                )
+                .unwrap();
+            app.update();
+        }
+
+        let budget = *app.world().resource::<MeshBudget>();
+        assert_eq!(budget.chunks, 1);
+        assert!(budget.block_meshes <= 3, "{budget:?}");
+        assert_eq!(budget.other, 0, "{budget:?}");
+        assert!(app.world().resource::<Assets<Mesh>>().len() <= 4);
+    }
+
+    /// Verify the toggle reaches loaded chunks and their class mesh entities, applies to
+    /// chunks streamed in while it is on, and leaves other meshes alone.
+    #[test]
+    fn wireframe_toggle_covers_loaded_and_streamed_chunks() {
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        world.generator = Arc::new(FlatGenerator { height: 3 });
+        app.insert_resource(world)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .add_systems(Update, wireframe_toggle_system);
+        let spawn_chunk = move |coord: IVec3| {
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+                let pool = coord * CHUNK_SIZE + IVec3::new(2, 3, 2);
+                world.set_blocks_world_loaded(&mut meshes, [(pool, Block::water())]);
+                world.create_missing_layer_meshes(&mut commands, &mut meshes);
+            }
+        };
+        let press = |app: &mut App| {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.reset_all();
+            keys.press(WIREFRAME_KEY);
+            app.update();
+        };
+        let wireframes = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), (With<bevy::mesh::Mesh3d>, With<Wireframe>)>()
+                .iter(app.world())
+                .count()
+        };
+
+        app.world_mut()
+            .run_system_once(spawn_chunk(IVec3::ZERO))
+            .unwrap();
+        app.world_mut()
+            .spawn((bevy::mesh::Mesh3d::default(), PreviewBlock));
+        press(&mut app);
+        assert!(app.world().resource::<WorldState>().wireframe);
+        assert_eq!(wireframes(&mut app), 2);
+
+        app.world_mut()
+            .run_system_once(spawn_chunk(IVec3::X))
+            .unwrap();
+        assert_eq!(wireframes(&mut app), 4);
+
+        press(&mut app);
+        assert!(!app.world().resource::<WorldState>().wireframe);
+        assert_eq!(wireframes(&mut app), 0);
+    }
+}
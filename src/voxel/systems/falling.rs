@@ -1,16 +1,23 @@
 use bevy::prelude::*;
 use std::collections::HashSet;
 
-use crate::GRAVITY;
+use crate::journal::{JournalEvent, SessionJournal};
+use crate::player::PlayerBody;
+use crate::terrain::TerrainNoise;
+use crate::{BLOCK_SIZE, GRAVITY};
 
 use crate::voxel::FallingPropagationQueue;
 use crate::voxel::block_chunk::Block;
-use crate::voxel::falling_state::FallingBlock;
-use crate::voxel::mesh::build_single_block_mesh;
-use crate::voxel::world_state::WorldState;
+use crate::voxel::falling_state::{FallingBlock, SettledBlock};
+use crate::voxel::item_state::ItemDrop;
+use crate::voxel::world_state::{ChunkEntityRecord, TransientCollider, WorldState};
 
 /// Max propagation nodes processed per frame to avoid long spikes.
 const MAX_PROPAGATION_STEPS_PER_FRAME: usize = 256;
+/// Max falling blocks registered as colliders around each player per frame.
+const MAX_COLLIDERS_PER_PLAYER: usize = 8;
+/// Seconds a falling block may rest on ground it cannot settle on before it pops as an item.
+const SETTLE_GIVE_UP_SECS: f32 = 1.0;
 
 /// Return whether a block at `world_pos` should detach and become a falling entity.
 fn should_start_falling(world: &WorldState, world_pos: IVec3, block: Block) -> bool {
@@ -20,10 +27,19 @@ fn should_start_falling(world: &WorldState, world_pos: IVec3, block: Block) -> b
 
     // Unstable blocks (sand-like): only check support below.
     let below = world_pos + IVec3::new(0, -1, 0);
-    below.y >= 0 && !world.is_solid_at_world_pos(below)
+    below.y >= TerrainNoise::BEDROCK_Y && !world.is_solid_at_world_pos(below)
+}
+
+/// Return whether an attached block at `world_pos` (decoration, torch) lost its support.
+fn should_pop_attached(world: &WorldState, world_pos: IVec3, block: Block) -> bool {
+    !world.has_attachment_support(world_pos, block)
 }
 
 /// Process falling propagation queue and spawn falling entities for unstable positions.
+///
+/// Decorations, torches and doors whose support is gone are removed outright instead of
+/// falling; a popped torch's light goes out with the chunk remesh. Cells around a popped
+/// block are checked next, so the upper half of a door goes with its lower half.
 pub fn spawn_falling_blocks_system(
     mut commands: Commands,
     mut queue: ResMut<FallingPropagationQueue>,
@@ -31,6 +47,7 @@ pub fn spawn_falling_blocks_system(
     mut meshes: ResMut<Assets<Mesh>>,
 ) {
     let mut to_spawn: Vec<(IVec3, Block)> = Vec::new();
+    let mut touched: HashSet<IVec3> = HashSet::new();
     for _ in 0..MAX_PROPAGATION_STEPS_PER_FRAME {
         let Some(world_pos) = queue.pop() else {
             break;
@@ -38,33 +55,26 @@ pub fn spawn_falling_blocks_system(
         let Some(block) = world.get_block_world(world_pos) else {
             continue;
         };
-        if should_start_falling(&world, world_pos, block) {
+        if should_pop_attached(&world, world_pos, block) {
+            touched.extend(world.set_block_world_loaded(world_pos, Block::air()));
+            queue.enqueue_with_neighbors(world_pos);
+        } else if should_start_falling(&world, world_pos, block) {
             to_spawn.push((world_pos, block));
         }
     }
 
-    if to_spawn.is_empty() {
-        return;
-    }
-
-    let mut touched: HashSet<IVec3> = HashSet::new();
     for (world_pos, block) in to_spawn {
-        let (chunk_coord, local) = WorldState::world_to_chunk_local(world_pos);
-        let Some(chunk_data) = world.chunks.get_mut(&chunk_coord) else {
+        let Some(chunk_coord) = world.set_block_world_loaded(world_pos, Block::air()) else {
             continue;
         };
-        chunk_data.chunk.set_block(local, Block::air());
         touched.insert(chunk_coord);
 
-        let mesh = meshes.add(build_single_block_mesh(block));
-        let translation = Block::world_translation(world_pos);
-        commands.spawn((
-            bevy::mesh::Mesh3d(mesh),
-            bevy::pbr::MeshMaterial3d(world.material.clone()),
-            Transform::from_translation(translation),
-            FallingBlock::new(block),
-            Name::new("FallingBlock"),
-        ));
+        FallingBlock::new(block).spawn(
+            &mut commands,
+            &mut meshes,
+            &mut world,
+            Block::world_translation(world_pos),
+        );
 
         // Block removal may destabilize surrounding neighbors.
         queue.enqueue_with_neighbors(world_pos);
@@ -73,16 +83,48 @@ pub fn spawn_falling_blocks_system(
     world.rebuild_touched_chunk_meshes(&mut meshes, touched);
 }
 
+/// Despawn falling blocks owned by chunks about to unload and park them with those chunks.
+pub(crate) fn park_falling_blocks(
+    commands: &mut Commands,
+    world: &mut WorldState,
+    unloading: &HashSet<IVec3>,
+    query: &Query<(Entity, &Transform, &FallingBlock)>,
+) {
+    for (entity, transform, falling) in query {
+        let owner = WorldState::entity_owner_chunk(transform.translation);
+        if !unloading.contains(&owner) {
+            continue;
+        }
+        world.park_chunk_entity(
+            owner,
+            ChunkEntityRecord::FallingBlock {
+                translation: transform.translation,
+                velocity_y: falling.velocity_y,
+                block: falling.block,
+            },
+        );
+        commands.entity(entity).despawn();
+    }
+}
+
 /// Simulate falling-block entities and settle them into chunk voxels on landing.
+///
+/// The entity of a settled block goes once the chunk mesh shows the block: in the same
+/// frame when the mesh is patched, or, when the chunk is left to an off-thread rebuild,
+/// as a `SettledBlock` that `settled_block_system` removes once that rebuild is swapped
+/// in. A block that rests on ground for `SETTLE_GIVE_UP_SECS` without being able to settle,
+/// such as in a cell another block already took, pops as an item instead.
 pub fn update_falling_blocks_system(
     mut commands: Commands,
     time: Res<Time>,
     mut world: ResMut<WorldState>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut query: Query<(Entity, &mut Transform, &mut FallingBlock)>,
+    mut journal: ResMut<SessionJournal>,
 ) {
     let dt = time.delta_secs();
     let mut touched: HashSet<IVec3> = HashSet::new();
+    let mut settled: Vec<(Entity, IVec3)> = Vec::new();
 
     for (entity, mut transform, mut falling) in &mut query {
         let mut next = transform.translation;
@@ -90,13 +132,38 @@ pub fn update_falling_blocks_system(
 
         let (below, landing_block) = FallingBlock::landing_probe(next);
 
-        if below.y >= 0 && world.is_solid_at_world_pos(below) {
-            if let Some(chunk_coord) =
-                world.settle_falling_block(&mut commands, &mut meshes, landing_block, falling.block)
-            {
-                touched.insert(chunk_coord);
-            }
-            commands.entity(entity).despawn();
+        if below.y >= TerrainNoise::BEDROCK_Y && world.is_solid_at_world_pos(below) {
+            // Keep the entity alive unless the voxel write succeeded, so the block is never
+            // missing from both the entity and the chunk mesh.
+            let Some(chunk_coord) = world.settle_falling_block(
+                &mut commands,
+                &mut meshes,
+                landing_block,
+                falling.block,
+            ) else {
+                falling.velocity_y = 0.0;
+                falling.stalled_secs += dt;
+                if falling.stalled_secs >= SETTLE_GIVE_UP_SECS {
+                    let center = transform.translation + Vec3::splat(0.5 * BLOCK_SIZE);
+                    ItemDrop::new(falling.block, center).spawn(
+                        &mut commands,
+                        &mut meshes,
+                        &mut world,
+                    );
+                    commands.entity(entity).despawn();
+                }
+                continue;
+            };
+            touched.insert(chunk_coord);
+            journal.record(
+                JournalEvent::FallingSettle {
+                    pos: landing_block,
+                    kind: falling.block.kind,
+                },
+                &time,
+            );
+            transform.translation = Block::world_translation(landing_block);
+            settled.push((entity, chunk_coord));
             continue;
         }
 
@@ -104,4 +171,720 @@ pub fn update_falling_blocks_system(
     }
 
     world.rebuild_touched_chunk_meshes(&mut meshes, touched);
+    for (entity, chunk) in settled {
+        match world.remesh_dirty.unshown(chunk) {
+            Some(generation) => {
+                commands
+                    .entity(entity)
+                    .remove::<FallingBlock>()
+                    .insert(SettledBlock { chunk, generation });
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+}
+
+/// Despawn settled-block entities once their chunk's mesh shows the block, or the chunk
+/// unloaded with it.
+pub fn settled_block_system(
+    mut commands: Commands,
+    world: Res<WorldState>,
+    query: Query<(Entity, &SettledBlock)>,
+) {
+    for (entity, settled) in &query {
+        if !world.chunks.contains_key(&settled.chunk)
+            || world
+                .remesh_dirty
+                .is_shown(settled.chunk, settled.generation)
+        {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Register the falling blocks nearest each player as this frame's transient colliders.
+pub fn falling_collider_system(
+    mut world: ResMut<WorldState>,
+    players: Query<&Transform, With<PlayerBody>>,
+    falling_query: Query<(Entity, &Transform, &FallingBlock)>,
+) {
+    world.transient_colliders.clear();
+    let mut registered: HashSet<Entity> = HashSet::new();
+    for player in &players {
+        let mut nearest: Vec<(f32, Entity, Vec3, f32)> = falling_query
+            .iter()
+            .map(|(entity, transform, falling)| {
+                let distance = transform.translation.distance_squared(player.translation);
+                (distance, entity, transform.translation, falling.velocity_y)
+            })
+            .collect();
+        nearest.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (_, entity, min, velocity_y) in nearest.into_iter().take(MAX_COLLIDERS_PER_PLAYER) {
+            if registered.insert(entity) {
+                world.transient_colliders.push(TransientCollider {
+                    min,
+                    max: min + Vec3::splat(BLOCK_SIZE),
+                    velocity_y,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::prelude::*;
+
+    use super::*;
+    use crate::CHUNK_SIZE;
+    use crate::terrain::TerrainConfig;
+    use crate::voxel::block_chunk::{BlockKind, Chunk, Facing};
+    use crate::voxel::world_state::ChunkData;
+
+    /// Verify sand directly over a carved cave detaches while sand on bedrock stays put.
+    #[test]
+    fn sand_over_carved_cave_starts_falling() {
+        let chunk = Chunk::new_terrain(IVec3::ZERO, 0, &TerrainConfig::default());
+        let roof = (0..CHUNK_SIZE)
+            .flat_map(|x| (0..CHUNK_SIZE).map(move |z| (x, z)))
+            .flat_map(|(x, z)| (1..CHUNK_SIZE - 1).map(move |y| IVec3::new(x, y, z)))
+            .find(|&cell| {
+                chunk.get_block(cell).is_air() && chunk.get_block(cell + IVec3::Y).is_solid()
+            })
+            .expect("no cave roof in the test chunk");
+        let supported = IVec3::new(0, 1, 0);
+
+        let mut world = WorldState::new();
+        let mut chunk = chunk;
+        chunk.set_block(supported - IVec3::Y, Block::bedrock());
+        chunk.set_block(roof + IVec3::Y, Block::sand());
+        chunk.set_block(supported, Block::sand());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        assert!(should_start_falling(&world, roof + IVec3::Y, Block::sand()));
+        assert!(!should_start_falling(&world, supported, Block::sand()));
+    }
+
+    /// Verify decorations pop off once their ground is gone instead of falling.
+    #[test]
+    fn decoration_pops_when_ground_breaks() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(2, 0, 2), Block::dirt_with_grass());
+        chunk.set_block(IVec3::new(2, 1, 2), Block::flower());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let flower = IVec3::new(2, 1, 2);
+        assert!(!should_pop_attached(&world, flower, Block::flower()));
+
+        world.set_block_world_loaded(IVec3::new(2, 0, 2), Block::air());
+        assert!(should_pop_attached(&world, flower, Block::flower()));
+        assert!(!should_start_falling(&world, flower, Block::flower()));
+    }
+
+    /// Verify a wall torch pops off, leaving air and no light, once its wall is broken.
+    #[test]
+    fn wall_torch_pops_when_wall_breaks() {
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        let wall = IVec3::new(2, 1, 2);
+        let torch = Block::torch().oriented_for_placement(Vec3::NEG_X, IVec3::X);
+        chunk.set_block(wall, Block::stone());
+        chunk.set_block(wall + IVec3::X, torch);
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        assert!(!should_pop_attached(&world, wall + IVec3::X, torch));
+        assert_eq!(world.chunks[&IVec3::ZERO].light_sources.len(), 1);
+
+        world.set_block_world_loaded(wall, Block::air());
+        let mut queue = FallingPropagationQueue::default();
+        queue.enqueue_with_neighbors(wall);
+        app.insert_resource(world)
+            .insert_resource(queue)
+            .init_resource::<Assets<Mesh>>()
+            .add_systems(Update, spawn_falling_blocks_system);
+        app.update();
+
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(world.get_block_world(wall + IVec3::X), Some(Block::air()));
+        assert!(world.chunks[&IVec3::ZERO].light_sources.is_empty());
+        let falling_count = app
+            .world_mut()
+            .query::<&FallingBlock>()
+            .iter(app.world())
+            .count();
+        assert_eq!(falling_count, 0);
+    }
+
+    /// Verify a door pops as a whole, leaving no upper half behind, once its floor is broken.
+    #[test]
+    fn door_pops_when_floor_breaks() {
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        let floor = IVec3::new(2, 1, 2);
+        chunk.set_block(floor, Block::stone());
+        chunk.set_block(floor + IVec3::Y, Block::door());
+        chunk.set_block(floor + IVec3::Y * 2, Block::door().door_upper_half());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        assert!(!should_pop_attached(
+            &world,
+            floor + IVec3::Y,
+            Block::door()
+        ));
+
+        world.set_block_world_loaded(floor, Block::air());
+        let mut queue = FallingPropagationQueue::default();
+        queue.enqueue_with_neighbors(floor);
+        app.insert_resource(world)
+            .insert_resource(queue)
+            .init_resource::<Assets<Mesh>>()
+            .add_systems(Update, spawn_falling_blocks_system);
+        app.update();
+
+        let world = app.world().resource::<WorldState>();
+        for y in 1..=2 {
+            assert_eq!(
+                world.get_block_world(floor + IVec3::Y * y),
+                Some(Block::air())
+            );
+        }
+    }
+
+    /// Verify a cactus stack pops once the sand under it is broken, and only stands on sand.
+    #[test]
+    fn cactus_pops_when_sand_breaks() {
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        let floor = IVec3::new(2, 1, 2);
+        chunk.set_block(floor - IVec3::Y, Block::stone());
+        chunk.set_block(floor, Block::sand());
+        chunk.set_block(floor + IVec3::Y, Block::cactus());
+        chunk.set_block(floor + IVec3::Y * 2, Block::cactus());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        assert!(!should_pop_attached(
+            &world,
+            floor + IVec3::Y * 2,
+            Block::cactus()
+        ));
+        assert!(should_pop_attached(&world, floor, Block::cactus()));
+
+        world.set_block_world_loaded(floor, Block::air());
+        let mut queue = FallingPropagationQueue::default();
+        queue.enqueue_with_neighbors(floor);
+        app.insert_resource(world)
+            .insert_resource(queue)
+            .init_resource::<Assets<Mesh>>()
+            .add_systems(Update, spawn_falling_blocks_system);
+        app.update();
+
+        let world = app.world().resource::<WorldState>();
+        for y in 1..=2 {
+            assert_eq!(
+                world.get_block_world(floor + IVec3::Y * y),
+                Some(Block::air())
+            );
+        }
+    }
+
+    /// Verify generated beaches and sand spots all rest on solid ground.
+    #[test]
+    fn generated_sand_never_starts_falling() {
+        let config = TerrainConfig::default();
+        let mut world = WorldState::new();
+        for x in -4..4 {
+            for z in -4..4 {
+                for y in -1..2 {
+                    let coord = IVec3::new(x, y, z);
+                    world.chunks.insert(
+                        coord,
+                        ChunkData::new(
+                            Chunk::new_terrain(coord, 0, &config),
+                            Handle::<Mesh>::default(),
+                            Entity::PLACEHOLDER,
+                        ),
+                    );
+                }
+            }
+        }
+
+        let mut sand = 0;
+        for (coord, data) in &world.chunks {
+            for i in 0..CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE {
+                let local = IVec3::new(
+                    i % CHUNK_SIZE,
+                    i / CHUNK_SIZE % CHUNK_SIZE,
+                    i / (CHUNK_SIZE * CHUNK_SIZE),
+                );
+                let block = data.chunk.get_block(local);
+                if block.kind != BlockKind::Sand {
+                    continue;
+                }
+                sand += 1;
+                let world_pos = *coord * CHUNK_SIZE + local;
+                assert!(
+                    !should_start_falling(&world, world_pos, block),
+                    "generated sand at {world_pos} is unsupported"
+                );
+            }
+        }
+        assert!(sand > 0, "no sand generated in the sampled region");
+    }
+
+    /// Verify a detached sand block is always present as either an entity or a voxel.
+    #[test]
+    fn falling_block_is_never_missing_between_detach_and_settle() {
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(2, 0, 2), Block::dirt());
+        chunk.set_block(IVec3::new(2, 6, 2), Block::sand());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut queue = FallingPropagationQueue::default();
+        queue.enqueue(IVec3::new(2, 6, 2));
+        app.insert_resource(world)
+            .insert_resource(queue)
+            .insert_resource(SessionJournal::new())
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (spawn_falling_blocks_system, update_falling_blocks_system).chain(),
+            );
+
+        let column_has_sand = |app: &App| {
+            let world = app.world().resource::<WorldState>();
+            (0..CHUNK_SIZE)
+                .any(|y| world.get_block_world(IVec3::new(2, y, 2)) == Some(Block::sand()))
+        };
+        for _ in 0..120 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+            let falling_count = app
+                .world_mut()
+                .query::<&FallingBlock>()
+                .iter(app.world())
+                .count();
+            assert!(
+                column_has_sand(&app) || falling_count == 1,
+                "sand vanished from both the chunk and the falling entity"
+            );
+        }
+
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(
+            world.get_block_world(IVec3::new(2, 1, 2)),
+            Some(Block::sand())
+        );
+        assert_eq!(
+            world.get_block_world(IVec3::new(2, 6, 2)),
+            Some(Block::air())
+        );
+    }
+
+    /// Verify a detached block lands with its exact kind and facing.
+    #[test]
+    fn falling_block_keeps_facing_through_settle() {
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        let oriented = Block::sand_facing(Facing::NegX);
+        chunk.set_block(IVec3::new(5, 0, 5), Block::dirt());
+        chunk.set_block(IVec3::new(5, 4, 5), oriented);
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut queue = FallingPropagationQueue::default();
+        queue.enqueue(IVec3::new(5, 4, 5));
+        app.insert_resource(world)
+            .insert_resource(queue)
+            .insert_resource(SessionJournal::new())
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (spawn_falling_blocks_system, update_falling_blocks_system).chain(),
+            );
+
+        app.update();
+        let carried = app
+            .world_mut()
+            .query::<&FallingBlock>()
+            .single(app.world())
+            .unwrap()
+            .block;
+        assert_eq!(carried, oriented);
+
+        for _ in 0..120 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(world.get_block_world(IVec3::new(5, 1, 5)), Some(oriented));
+    }
+
+    /// Build an app with one meshed flat chunk and sand falling onto it from
+    /// `height_above_ground` blocks over its landing cell, running the fall and settle
+    /// systems and the chunk remesh every update.
+    ///
+    /// Returns the app and the cell the sand lands in.
+    fn falling_sand_app(height_above_ground: f32) -> (App, IVec3) {
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::tasks::{AsyncComputeTaskPool, TaskPool};
+        use std::sync::Arc;
+
+        use crate::voxel::generator::FlatGenerator;
+        use crate::voxel::systems::streaming::chunk_remesh_system;
+
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        world.generator = Arc::new(FlatGenerator { height: 3 });
+        app.insert_resource(world)
+            .insert_resource(SessionJournal::new())
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(Update, update_falling_blocks_system)
+            .add_systems(
+                PostUpdate,
+                (chunk_remesh_system, settled_block_system).chain(),
+            );
+        app.world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 mut meshes: ResMut<Assets<Mesh>>,
+                 mut world: ResMut<WorldState>| {
+                    world.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                },
+            )
+            .unwrap();
+        let world = app.world().resource::<WorldState>();
+        let landing = (0..CHUNK_SIZE)
+            .map(|y| IVec3::new(4, y, 4))
+            .find(|&cell| world.get_block_world(cell) == Some(Block::air()))
+            .expect("flat chunk has no air");
+        let start = Block::world_translation(landing) + Vec3::Y * height_above_ground;
+        app.world_mut()
+            .run_system_once(
+                move |mut commands: Commands,
+                      mut meshes: ResMut<Assets<Mesh>>,
+                      mut world: ResMut<WorldState>| {
+                    FallingBlock::new(Block::sand()).spawn(
+                        &mut commands,
+                        &mut meshes,
+                        &mut world,
+                        start,
+                    );
+                },
+            )
+            .unwrap();
+        (app, landing)
+    }
+
+    /// Advance `app` by one 60 Hz frame and return whether the chunk mesh has faces for
+    /// `cell` and how many falling or settled block entities are left.
+    fn step_and_observe(app: &mut App, cell: IVec3) -> (bool, usize) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+        app.update();
+        let index = Chunk::index(cell) as u16;
+        let meshed = app.world().resource::<WorldState>().chunks[&IVec3::ZERO]
+            .quad_cells
+            .contains(&index);
+        let entities = app
+            .world_mut()
+            .query_filtered::<Entity, Or<(With<FallingBlock>, With<SettledBlock>)>>()
+            .iter(app.world())
+            .count();
+        (meshed, entities)
+    }
+
+    /// Verify landing sand is patched into the chunk mesh in the frame its entity goes, so
+    /// it always shows as either the entity or chunk faces.
+    #[test]
+    fn settled_block_shows_in_mesh_the_frame_it_lands() {
+        let (mut app, landing) = falling_sand_app(4.5);
+        let mut landed = false;
+        for _ in 0..120 {
+            let (meshed, entities) = step_and_observe(&mut app, landing);
+            assert!(
+                meshed || entities == 1,
+                "sand vanished from entity and mesh"
+            );
+            let settled = app
+                .world()
+                .resource::<WorldState>()
+                .get_block_world(landing)
+                == Some(Block::sand());
+            if settled && !landed {
+                assert!(
+                    meshed,
+                    "settled sand missing from the mesh in its landing frame"
+                );
+                assert_eq!(entities, 0, "entity outlived the mesh patch");
+                landed = true;
+            }
+        }
+        assert!(landed, "sand never settled");
+    }
+
+    /// Verify sand landing in a chunk left to an off-thread rebuild keeps its entity in the
+    /// landing cell until that rebuild is swapped in.
+    #[test]
+    fn settled_block_waits_for_off_thread_rebuild() {
+        let (mut app, landing) = falling_sand_app(0.5);
+        app.world_mut()
+            .resource_mut::<WorldState>()
+            .remesh_dirty
+            .mark(IVec3::ZERO);
+
+        let (meshed, entities) = step_and_observe(&mut app, landing);
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(world.get_block_world(landing), Some(Block::sand()));
+        assert!(!meshed && entities == 1);
+        let transform = app
+            .world_mut()
+            .query_filtered::<&Transform, With<SettledBlock>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(transform.translation, Block::world_translation(landing));
+
+        for _ in 0..10_000 {
+            let (meshed, entities) = step_and_observe(&mut app, landing);
+            assert!(
+                meshed || entities == 1,
+                "sand vanished from entity and mesh"
+            );
+            if entities == 0 {
+                return;
+            }
+            std::thread::yield_now();
+        }
+        panic!("off-thread rebuild never released the settled block");
+    }
+
+    /// Verify a block that cannot settle, because its landing cell is taken, pops as an item
+    /// instead of hovering forever, leaving the cell's block alone.
+    #[test]
+    fn blocked_falling_block_pops_as_item() {
+        let (mut app, landing) = falling_sand_app(0.5);
+        app.world_mut()
+            .resource_mut::<WorldState>()
+            .set_block_world_loaded(landing, Block::stone());
+        for _ in 0..30 {
+            let (_, entities) = step_and_observe(&mut app, landing);
+            assert_eq!(entities, 1, "gave up before SETTLE_GIVE_UP_SECS");
+        }
+        for _ in 0..60 {
+            step_and_observe(&mut app, landing);
+        }
+
+        let (_, entities) = step_and_observe(&mut app, landing);
+        assert_eq!(entities, 0);
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(world.get_block_world(landing), Some(Block::stone()));
+        let drop = app
+            .world_mut()
+            .query::<&ItemDrop>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(drop.block, Block::sand());
+    }
+
+    /// Verify a falling block unloads with its owner chunk and respawns unchanged on reload.
+    #[test]
+    fn falling_block_round_trips_through_chunk_unload() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        use crate::MAX_CHUNK_LAYER;
+
+        let mut ecs = World::new();
+        ecs.insert_resource(WorldState::new());
+        ecs.init_resource::<Assets<Mesh>>();
+        let coord = IVec3::new(0, MAX_CHUNK_LAYER, 0);
+        let translation = Vec3::new(4.0, (MAX_CHUNK_LAYER * CHUNK_SIZE + 5) as f32, 4.0);
+        let block = Block::sand_facing(Facing::NegX);
+        let velocity_y = -3.0;
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+                FallingBlock {
+                    velocity_y,
+                    ..FallingBlock::new(block)
+                }
+                .spawn(&mut commands, &mut meshes, &mut world, translation);
+            },
+        )
+        .unwrap();
+
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut world: ResMut<WorldState>,
+                  query: Query<(Entity, &Transform, &FallingBlock)>| {
+                park_falling_blocks(&mut commands, &mut world, &HashSet::from([coord]), &query);
+                world.unload_chunk(&mut commands, coord);
+            },
+        )
+        .unwrap();
+        assert_eq!(ecs.query::<&FallingBlock>().iter(&ecs).count(), 0);
+        assert_eq!(ecs.resource::<WorldState>().chunk_entities[&coord].len(), 1);
+
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+            },
+        )
+        .unwrap();
+        let (transform, restored) = ecs
+            .query::<(&Transform, &FallingBlock)>()
+            .single(&ecs)
+            .unwrap();
+        assert_eq!(transform.translation, translation);
+        assert_eq!(restored.block, block);
+        assert_eq!(restored.velocity_y, velocity_y);
+        assert!(ecs.resource::<WorldState>().chunk_entities.is_empty());
+    }
+
+    /// Verify border-straddling entities belong to the chunk containing their center.
+    #[test]
+    fn entity_owner_chunk_uses_center() {
+        let edge = (CHUNK_SIZE - 1) as f32;
+        assert_eq!(
+            WorldState::entity_owner_chunk(Vec3::new(edge + 0.4, 2.0, 2.0)),
+            IVec3::ZERO
+        );
+        assert_eq!(
+            WorldState::entity_owner_chunk(Vec3::new(edge + 0.6, 2.0, 2.0)),
+            IVec3::X
+        );
+    }
+
+    /// Verify a player standing on already-falling sand rides it down and lands with it.
+    #[test]
+    fn player_rides_falling_sand_down() {
+        use crate::player::{Player, PlayerRecovered, Velocity, physics_system};
+        use crate::scene::SpawnPoint;
+        use crate::{STAND_EYE_HEIGHT, STAND_HALF_SIZE};
+
+        let mut app = App::new();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(2, 0, 2), Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        app.insert_resource(world)
+            .insert_resource(SessionJournal::new())
+            .insert_resource(SpawnPoint {
+                ground: IVec3::ZERO,
+            })
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_message::<PlayerRecovered>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (
+                    update_falling_blocks_system,
+                    falling_collider_system,
+                    physics_system,
+                )
+                    .chain(),
+            );
+        // Mid-fall sand already moving faster than a player starting from rest.
+        app.world_mut().spawn((
+            Transform::from_translation(Vec3::new(2.0, 8.0, 2.0)),
+            FallingBlock {
+                velocity_y: -6.0,
+                ..FallingBlock::new(Block::sand())
+            },
+        ));
+        let mut player = Player::new_standing(10.4, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+        player.on_ground = true;
+        let player_entity = app
+            .world_mut()
+            .spawn((
+                PlayerBody,
+                player,
+                Velocity::default(),
+                Transform::from_translation(Vec3::new(2.5, 9.0 + STAND_HALF_SIZE.y, 2.5)),
+            ))
+            .id();
+
+        let mut rode_frames = 0;
+        for _ in 0..120 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+            let feet = app
+                .world()
+                .get::<Transform>(player_entity)
+                .unwrap()
+                .translation
+                .y
+                - STAND_HALF_SIZE.y;
+            let sand_top = app
+                .world_mut()
+                .query_filtered::<&Transform, With<FallingBlock>>()
+                .iter(app.world())
+                .next()
+                .map(|transform| transform.translation.y + BLOCK_SIZE);
+            if let Some(sand_top) = sand_top {
+                assert!(
+                    (feet - sand_top).abs() < 0.01,
+                    "player feet {feet} drifted from falling sand top {sand_top}"
+                );
+                rode_frames += 1;
+            }
+        }
+
+        assert!(rode_frames > 10);
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(
+            world.get_block_world(IVec3::new(2, 1, 2)),
+            Some(Block::sand())
+        );
+        let feet = app
+            .world()
+            .get::<Transform>(player_entity)
+            .unwrap()
+            .translation
+            .y
+            - STAND_HALF_SIZE.y;
+        assert!((feet - 2.0).abs() < 0.05, "player landed at {feet}");
+        assert!(app.world().get::<Player>(player_entity).unwrap().on_ground);
+    }
 }
@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+
+use crate::terrain::ChunkRng;
+
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::world_state::WorldState;
+
+/// Least light the cell above dirt needs for grass to spread onto it.
+const GRASS_SPREAD_MIN_LIGHT: u8 = 9;
+
+/// Return whether the block at `pos` is grass smothered by the block above it.
+pub(crate) fn grass_smothered(world: &WorldState, pos: IVec3) -> bool {
+    world
+        .get_block_world(pos)
+        .is_some_and(|block| block.kind == BlockKind::DirtWithGrass)
+        && world
+            .get_block_world(pos + IVec3::Y)
+            .is_some_and(|above| above.smothers_grass())
+}
+
+/// Return whether the block at `pos` is bare dirt that grass spreads onto.
+///
+/// Dirt needs a cell above it that does not smother grass, lit to at least
+/// `GRASS_SPREAD_MIN_LIGHT`, and a grass block among the 26 cells around it.
+fn dirt_takes_grass(world: &WorldState, pos: IVec3) -> bool {
+    if world
+        .get_block_world(pos)
+        .is_none_or(|block| block.kind != BlockKind::Dirt)
+        || world
+            .get_block_world(pos + IVec3::Y)
+            .is_none_or(|above| above.smothers_grass())
+        || world.light_at(pos + IVec3::Y) < GRASS_SPREAD_MIN_LIGHT
+    {
+        return false;
+    }
+    (-1..=1).any(|x| {
+        (-1..=1).any(|y| {
+            (-1..=1).any(|z| {
+                world
+                    .get_block_world(pos + IVec3::new(x, y, z))
+                    .is_some_and(|block| block.kind == BlockKind::DirtWithGrass)
+            })
+        })
+    })
+}
+
+/// Random tick handler for dirt: grow grass onto it when it takes grass.
+///
+/// Returns the chunk it converted the dirt in.
+pub(crate) fn spread_grass_tick(
+    world: &mut WorldState,
+    pos: IVec3,
+    _rng: &mut ChunkRng,
+) -> Option<IVec3> {
+    if !dirt_takes_grass(world, pos) {
+        return None;
+    }
+    world.set_block_world_loaded(pos, Block::dirt_with_grass())
+}
+
+/// Random tick handler for grass: turn it into dirt while the block above smothers it.
+///
+/// Returns the chunk it converted the grass in.
+pub(crate) fn wither_grass_tick(
+    world: &mut WorldState,
+    pos: IVec3,
+    _rng: &mut ChunkRng,
+) -> Option<IVec3> {
+    if !grass_smothered(world, pos) {
+        return None;
+    }
+    world.set_block_world_loaded(pos, Block::dirt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::Chunk;
+    use crate::voxel::light_state::NIGHT_SKY_DARKENING;
+    use crate::voxel::world_state::ChunkData;
+
+    /// Verify only sky-exposed dirt within one cell of grass takes grass.
+    #[test]
+    fn dirt_takes_grass_only_when_exposed_beside_grass() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        for x in 0..8 {
+            chunk.set_block(IVec3::new(x, 2, 4), Block::dirt());
+        }
+        chunk.set_block(IVec3::new(0, 3, 4), Block::dirt_with_grass());
+        chunk.set_block(IVec3::new(2, 3, 4), Block::stone());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        // Diagonal neighbor of the grass block, open to the sky.
+        assert!(dirt_takes_grass(&world, IVec3::new(1, 2, 4)));
+        // Grass is diagonally above, but the dirt is covered.
+        assert!(!dirt_takes_grass(&world, IVec3::new(0, 2, 4)));
+        // Beside grass-adjacent dirt only, not grass itself.
+        assert!(!dirt_takes_grass(&world, IVec3::new(3, 2, 4)));
+        // Not dirt at all.
+        assert!(!dirt_takes_grass(&world, IVec3::new(0, 3, 4)));
+        assert!(!dirt_takes_grass(&world, IVec3::new(2, 3, 4)));
+        // Too dark at night.
+        world.sky_darkening = NIGHT_SKY_DARKENING;
+        assert!(!dirt_takes_grass(&world, IVec3::new(1, 2, 4)));
+    }
+
+    /// Verify random ticks run headless kill grass under stone but not under glass or a slab,
+    /// regrow grass on exposed dirt, and patch the chunk mesh in the same run.
+    #[test]
+    fn random_ticks_wither_covered_grass_and_regrow_exposed_dirt() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        use crate::voxel::generator::FlatGenerator;
+        use crate::voxel::random_tick_state::RandomTicks;
+        use crate::voxel::systems::random_tick_system;
+
+        let mut ecs = World::new();
+        let mut world = WorldState::new();
+        world.generator = Arc::new(FlatGenerator { height: 3 });
+        world.center = IVec3::ZERO;
+        ecs.insert_resource(world);
+        ecs.init_resource::<Assets<Mesh>>();
+        let mut ticks = RandomTicks::default();
+        ticks.ticks_per_chunk_per_second = 1 << 16;
+        ecs.insert_resource(ticks);
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        ecs.insert_resource(time);
+
+        let under_stone = IVec3::new(2, 3, 2);
+        let under_glass = IVec3::new(5, 3, 2);
+        let under_slab = IVec3::new(8, 3, 2);
+        let bared = IVec3::new(12, 3, 12);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                world.set_blocks_world_loaded(
+                    &mut meshes,
+                    [
+                        (under_stone + IVec3::Y, Block::stone()),
+                        (under_glass + IVec3::Y, Block::glass()),
+                        (under_slab + IVec3::Y, Block::slab()),
+                        (bared, Block::dirt()),
+                    ],
+                );
+            },
+        )
+        .unwrap();
+        let mesh = ecs.resource::<WorldState>().chunks[&IVec3::ZERO]
+            .mesh
+            .clone();
+        let uvs_before = ecs
+            .resource::<Assets<Mesh>>()
+            .get(&mesh)
+            .unwrap()
+            .attribute(Mesh::ATTRIBUTE_UV_0)
+            .cloned();
+
+        ecs.run_system_once(random_tick_system).unwrap();
+
+        let world = ecs.resource::<WorldState>();
+        assert_eq!(world.get_block_world(under_stone), Some(Block::dirt()));
+        assert_eq!(
+            world.get_block_world(under_glass),
+            Some(Block::dirt_with_grass())
+        );
+        assert_eq!(
+            world.get_block_world(under_slab),
+            Some(Block::dirt_with_grass())
+        );
+        assert_eq!(world.get_block_world(bared), Some(Block::dirt_with_grass()));
+        assert!(world.edited_cells.is_empty());
+        let uvs_after = ecs
+            .resource::<Assets<Mesh>>()
+            .get(&mesh)
+            .unwrap()
+            .attribute(Mesh::ATTRIBUTE_UV_0)
+            .cloned();
+        assert_ne!(uvs_after, uvs_before);
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::voxel::block_chunk::BlockKind;
+use crate::voxel::sapling_state::{SAPLING_MIN_LIGHT, SAPLING_RETRY_SECS, SaplingGrowth};
+use crate::voxel::world_state::WorldState;
+
+/// Count down sapling growth timers and grow saplings whose timer ran out into trees.
+///
+/// A marker whose loaded cell no longer holds a sapling is despawned. An obstructed sapling stays and tries again after `SAPLING_RETRY_SECS`; one in an unloaded
+/// chunk just waits.
+pub fn sapling_growth_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<(Entity, &mut SaplingGrowth)>,
+) {
+    for (entity, mut growth) in &mut query {
+        let Some(block) = world.get_block_world(growth.cell) else {
+            continue;
+        };
+        if block.kind != BlockKind::Sapling {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if !growth.timer.tick(time.delta()).is_finished() {
+            continue;
+        }
+        if world.light_at(growth.cell) >= SAPLING_MIN_LIGHT
+            && world.grow_tree(&mut meshes, growth.cell, growth.trunk_height)
+        {
+            commands.entity(entity).despawn();
+        } else {
+            growth
+                .timer
+                .set_duration(Duration::from_secs_f32(SAPLING_RETRY_SECS));
+            growth.timer.reset();
+        }
+    }
+}
@@ -1,9 +1,19 @@
 use bevy::prelude::*;
 
+use crate::BLOCK_SIZE;
+
+use crate::cooldown::{ActionCooldowns, ActionId};
+use crate::journal::{JournalEvent, SessionJournal};
 use crate::player::PreviewBlock;
-use crate::player::{Player, PlayerBody};
+use crate::player::{Health, Player, PlayerBody};
 use crate::voxel::FallingPropagationQueue;
-use crate::voxel::interaction_state::{InteractionCooldown, SelectedBlock};
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::block_defs::BlockUse;
+use crate::voxel::container_state::ContainerMenu;
+use crate::voxel::crafting_state::CraftingMenu;
+use crate::voxel::interaction_state::{BreakProgress, CurrentTarget, HeldTool, SelectedBlock};
+use crate::voxel::item_state::Inventory;
+use crate::voxel::systems::items::drop_broken_block;
 use crate::voxel::world_state::WorldState;
 
 /// Return `true` only when `candidate` is one of six face-neighbors of `center`.
@@ -12,7 +22,161 @@ fn is_face_neighbor(center: IVec3, candidate: IVec3) -> bool {
     d.x.abs() + d.y.abs() + d.z.abs() == 1
 }
 
+/// Return the placement cell for a raymarch result when it attaches to a visible face.
+///
+/// Decorations never support an attachment: aiming at one replaces it in its own cell.
+/// Torches support nothing at all. Cells outside the world border are never placeable.
+fn placement_cell(
+    world: &WorldState,
+    eye: Vec3,
+    hit: Option<IVec3>,
+    last_empty: Option<IVec3>,
+) -> Option<IVec3> {
+    let hit = hit?;
+    let target = if world
+        .get_block_world(hit)
+        .is_some_and(|block| block.is_decoration())
+    {
+        hit
+    } else {
+        let target = last_empty?;
+        (world.is_solid_at_world_pos(hit)
+            && is_face_neighbor(hit, target)
+            && world.attachment_face_visible(eye, hit, target))
+        .then_some(target)?
+    };
+    world.border.contains_block(target).then_some(target)
+}
+
+/// Return the normal of the face a placement into `target` attaches to.
+///
+/// Replacing a decoration in its own cell counts as placing on the ground below it.
+fn placement_face_normal(hit: Option<IVec3>, target: IVec3) -> IVec3 {
+    hit.map(|hit| target - hit)
+        .filter(|normal| *normal != IVec3::ZERO)
+        .unwrap_or(IVec3::Y)
+}
+
+/// Return whether a slab placed into `target` takes the upper half of its cell.
+///
+/// Clicking a top face gives a bottom slab and a bottom face a top slab; on a side face the
+/// half follows whether the ray from `eye` along `direction` met it above its middle.
+fn placement_in_upper_half(eye: Vec3, direction: Vec3, hit: Option<IVec3>, target: IVec3) -> bool {
+    let normal = placement_face_normal(hit, target);
+    match (normal.y, hit) {
+        (1, _) | (_, None) => false,
+        (-1, _) => true,
+        (_, Some(hit)) => {
+            let normal = normal.as_vec3();
+            let face_center = Block::world_translation(hit)
+                + Vec3::splat(0.5 * BLOCK_SIZE)
+                + normal * (0.5 * BLOCK_SIZE);
+            let along = direction.dot(normal);
+            if along == 0.0 {
+                return false;
+            }
+            let point = eye + direction * ((face_center - eye).dot(normal) / along);
+            point.y > face_center.y
+        }
+    }
+}
+
+/// Refresh the shared aimed-block target from the camera ray.
+pub fn current_target_system(
+    world: Res<WorldState>,
+    camera_query: Query<&GlobalTransform, With<bevy::camera::Camera3d>>,
+    mut target: ResMut<CurrentTarget>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let eye = camera_transform.translation();
+    let (hit, last_empty) = world
+        .raymarch_from_camera(camera_transform)
+        .unwrap_or((None, None));
+    let place = placement_cell(&world, eye, hit, last_empty);
+    let place_distance = place.map_or(0.0, |cell| {
+        let center = Block::world_translation(cell) + Vec3::splat(0.5 * BLOCK_SIZE);
+        center.distance(eye) / BLOCK_SIZE
+    });
+    target.set_if_neq(CurrentTarget {
+        hit,
+        place,
+        place_distance,
+    });
+}
+
+/// Return whether the block at `hit` has a use action, so right-clicking it uses it.
+fn is_usable(world: &WorldState, hit: Option<IVec3>) -> bool {
+    hit.and_then(|cell| world.get_block_world(cell))
+        .is_some_and(|block| block.use_action().is_some())
+}
+
+/// Dispatch a right-click on the aimed-at block to its use action.
+///
+/// Blocks without a use action are left to placement in `block_interaction_system`. Uses
+/// are rate limited by `ActionId::Use`, counted only when they take effect.
+#[allow(clippy::too_many_arguments)]
+pub fn block_use_system(
+    buttons: Res<ButtonInput<MouseButton>>,
+    time: Res<Time>,
+    mut cooldowns: ResMut<ActionCooldowns>,
+    target: Res<CurrentTarget>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut crafting: ResMut<CraftingMenu>,
+    mut container: ResMut<ContainerMenu>,
+) {
+    let now = time.elapsed_secs();
+    if !buttons.just_pressed(MouseButton::Right) || !cooldowns.ready(ActionId::Use, now) {
+        return;
+    }
+    let Some((cell, action)) = target.hit.and_then(|cell| {
+        world
+            .get_block_world(cell)
+            .and_then(|block| block.use_action())
+            .map(|action| (cell, action))
+    }) else {
+        return;
+    };
+    let used = match action {
+        BlockUse::Craft => {
+            crafting.open();
+            true
+        }
+        BlockUse::OpenContainer => {
+            container.open(cell);
+            true
+        }
+        BlockUse::ToggleDoor => world.toggle_door(&mut meshes, cell),
+    };
+    if used {
+        cooldowns.mark(ActionId::Use, now);
+    }
+}
+
+/// Switch the held tool on its hotkey and empty the hand once the tool leaves the inventory.
+pub fn held_tool_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    inventory: Res<Inventory>,
+    mut held: ResMut<HeldTool>,
+) {
+    if keys.just_pressed(HeldTool::CYCLE_KEY) {
+        held.cycle(&inventory);
+    }
+    if inventory.is_changed() {
+        held.drop_missing(&inventory);
+    }
+}
+
 /// Handle block breaking and placing with cooldown and preview updates.
+///
+/// A block breaks once the break button has been held on it for its hardness with the held
+/// tool, which also decides whether it drops an item; releasing
+/// the button or aiming elsewhere starts the progress over. Right-clicking a usable block
+/// uses it (see `block_use_system`) instead of placing against it. Placing uses up a held
+/// item of the selected kind when there is one and restores that item's state, so a wet
+/// sponge goes back down wet; without one the selection is placed as is.
 #[allow(clippy::too_many_arguments)]
 pub fn block_interaction_system(
     mut commands: Commands,
@@ -20,59 +184,138 @@ pub fn block_interaction_system(
     mut world: ResMut<WorldState>,
     mut meshes: ResMut<Assets<Mesh>>,
     time: Res<Time>,
-    mut cooldown: ResMut<InteractionCooldown>,
+    mut cooldowns: ResMut<ActionCooldowns>,
     camera_query: Query<&GlobalTransform, With<bevy::camera::Camera3d>>,
     mut selected: ResMut<SelectedBlock>,
     mut preview_query: Query<&mut bevy::mesh::Mesh3d, With<PreviewBlock>>,
     keys: Res<ButtonInput<KeyCode>>,
-    player_query: Query<(&Transform, &Player), With<PlayerBody>>,
+    mut player_query: Query<(&mut Transform, &mut Player), With<PlayerBody>>,
     mut falling_queue: ResMut<FallingPropagationQueue>,
+    mut health_query: Query<&mut Health, With<PlayerBody>>,
+    mut journal: ResMut<SessionJournal>,
+    mut progress: ResMut<BreakProgress>,
+    (held_tool, mut inventory): (Res<HeldTool>, ResMut<Inventory>),
 ) {
-    selected.apply_hotkeys(&keys, &mut meshes, &mut preview_query);
+    selected.apply_hotkeys(
+        &keys,
+        &mut meshes,
+        &mut world.block_meshes,
+        &mut preview_query,
+    );
 
     let Ok(camera_transform) = camera_query.single() else {
         return;
     };
-    // Rate limit repeated interactions.
-    let can_break = cooldown.can_break(buttons.as_ref(), &time);
-    let can_place = cooldown.can_place(buttons.as_ref(), &time);
+    let now = time.elapsed_secs();
+    if !buttons.pressed(MouseButton::Left) {
+        progress.reset();
+    }
+    let can_break = buttons.pressed(MouseButton::Left);
+    let can_place = buttons.pressed(MouseButton::Right);
     if !can_break && !can_place {
         return;
     }
 
     let Some((hit, last_empty)) = world.raymarch_from_camera(camera_transform) else {
+        progress.reset();
         return;
     };
 
-    // Break the first solid block hit.
+    // Break the first solid block hit once it has been held long enough.
     if can_break {
         if let Some(target_world) = hit {
-            if !world.break_block(&mut meshes, target_world) {
-                return;
+            let from = world.get_block_world(target_world);
+            let hardness = from.map_or(0.0, |block| block.hardness(held_tool.tool()));
+            // Rate limit repeated breaks once the hold completes.
+            if progress.advance(target_world, hardness, time.delta_secs())
+                && cooldowns.ready(ActionId::Break, now)
+            {
+                if !world.break_block(&mut meshes, target_world) {
+                    return;
+                }
+                cooldowns.mark(ActionId::Break, now);
+                progress.reset();
+                if let Some(block) = from {
+                    drop_broken_block(
+                        &mut commands,
+                        &mut meshes,
+                        &mut world,
+                        target_world,
+                        block,
+                        held_tool.tool(),
+                    );
+                }
+                journal.record(
+                    JournalEvent::BlockChange {
+                        pos: target_world,
+                        from: from.map_or(BlockKind::Air, |block| block.kind),
+                        to: BlockKind::Air,
+                    },
+                    &time,
+                );
+                falling_queue.enqueue_with_neighbors(target_world);
+                if let Some(partner) = from.and_then(|block| block.door_partner_offset()) {
+                    falling_queue.enqueue_with_neighbors(target_world + partner);
+                }
+                // Drop players standing on the broken cell on the very next physics step.
+                for (transform, mut player) in &mut player_query {
+                    if player.support_footprint_contains(transform.translation, target_world) {
+                        player.on_ground = false;
+                    }
+                }
+                // Breaking blocks forfeits spawn protection.
+                for mut health in &mut health_query {
+                    health.cancel_spawn_protection();
+                }
             }
-            falling_queue.enqueue_with_neighbors(target_world);
-            cooldown.mark_break(&time);
         } else {
+            progress.reset();
             return;
         }
     }
 
-    // Place on the last empty position before a hit.
+    // Place on the last empty position before a hit, attaching to a face the eye can see.
+    let held_item = inventory.held_block(selected.current);
+    let placing = held_item.map_or(selected.current, |item| {
+        selected.current.with_state(item.state)
+    });
     if can_place
-        && let (Some(hit_world), Some(target_world)) = (hit, last_empty)
-        && is_face_neighbor(hit_world, target_world)
+        && !is_usable(&world, hit)
+        && let Some(target_world) =
+            placement_cell(&world, camera_transform.translation(), hit, last_empty)
+        && cooldowns.ready(ActionId::Place, now)
+        && let from = world
+            .get_block_world(target_world)
+            .map_or(BlockKind::Air, |block| block.kind)
         && world.place_block(
             &mut commands,
             &mut meshes,
-            &player_query,
+            &mut player_query,
             camera_transform.forward().as_vec3(),
+            placement_face_normal(hit, target_world),
             target_world,
-            selected.current,
+            placing.with_slab_half(placement_in_upper_half(
+                camera_transform.translation(),
+                camera_transform.forward().as_vec3(),
+                hit,
+                target_world,
+            )),
         )
     {
+        if let Some(item) = held_item {
+            inventory.take(item);
+        }
+        cooldowns.mark(ActionId::Place, now);
+        journal.record(
+            JournalEvent::BlockChange {
+                pos: target_world,
+                from,
+                to: selected.current.kind,
+            },
+            &time,
+        );
         // Re-check placed block immediately so unsupported gravity blocks fall right away.
         falling_queue.enqueue(target_world);
-        cooldown.mark_place(&time);
     }
 }
 
@@ -83,12 +326,12 @@ mod tests {
     use super::*;
     use crate::voxel::WorldState;
     use crate::voxel::block_chunk::{Block, Chunk};
-    use crate::voxel::world_state::ChunkData;
+    use crate::voxel::world_state::{ChunkData, WorldBorder};
 
     /// Verify raymarch reports first solid hit and last empty block before that hit.
     #[test]
     fn raymarch_reports_hit_and_last_empty() {
-        let mut world = WorldState::new(Handle::<StandardMaterial>::default());
+        let mut world = WorldState::new();
         let mut chunk = Chunk::new_empty();
         chunk.set_block(IVec3::new(3, 0, 0), Block::dirt());
         world.chunks.insert(
@@ -103,4 +346,507 @@ mod tests {
         assert_eq!(hit, Some(IVec3::new(3, 0, 0)));
         assert_eq!(last_empty, Some(IVec3::new(2, 0, 0)));
     }
+
+    /// Verify slab halves follow the clicked face, and on side faces the height of the hit.
+    #[test]
+    fn slab_half_follows_hit_height() {
+        let eye = Vec3::new(0.5, 0.5, 0.5);
+        let wall = Some(IVec3::new(3, 0, 0));
+        let toward = |point: Vec3| (point - eye).normalize();
+        let side_target = IVec3::new(2, 0, 0);
+        assert!(placement_in_upper_half(
+            eye,
+            toward(Vec3::new(3.0, 0.8, 0.5)),
+            wall,
+            side_target
+        ));
+        assert!(!placement_in_upper_half(
+            eye,
+            toward(Vec3::new(3.0, 0.2, 0.5)),
+            wall,
+            side_target
+        ));
+        assert!(!placement_in_upper_half(
+            eye,
+            Vec3::NEG_Y,
+            Some(IVec3::NEG_Y),
+            IVec3::ZERO
+        ));
+        assert!(placement_in_upper_half(
+            eye,
+            Vec3::Y,
+            Some(IVec3::Y),
+            IVec3::ZERO
+        ));
+    }
+
+    /// Verify the targeting ray passes through water to the lake bed and the player sinks.
+    #[test]
+    fn raymarch_and_collision_ignore_water() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(2, 0, 2), Block::dirt());
+        for y in 1..4 {
+            chunk.set_block(IVec3::new(2, y, 2), Block::water());
+        }
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        let (hit, last_empty) =
+            world.raymarch_hit_and_last_empty(Vec3::new(2.5, 5.5, 2.5), Vec3::NEG_Y);
+        assert_eq!(hit, Some(IVec3::new(2, 0, 2)));
+        assert_eq!(last_empty, Some(IVec3::new(2, 1, 2)));
+        assert!(!world.intersects_solid(Vec3::new(2.5, 2.0, 2.5), Vec3::splat(0.3)));
+    }
+
+    /// Verify the ray stops at decorations, and placing on one replaces it in place.
+    #[test]
+    fn decorations_are_targetable_but_never_support_placement() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(2, 0, 2), Block::dirt_with_grass());
+        chunk.set_block(IVec3::new(2, 1, 2), Block::tall_grass());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        let eye = Vec3::new(2.5, 5.5, 2.5);
+        let (hit, last_empty) = world.raymarch_hit_and_last_empty(eye, Vec3::NEG_Y);
+        assert_eq!(hit, Some(IVec3::new(2, 1, 2)));
+        assert_eq!(
+            super::placement_cell(&world, eye, hit, last_empty),
+            Some(IVec3::new(2, 1, 2))
+        );
+        assert!(!world.intersects_solid(Vec3::new(2.5, 1.5, 2.5), Vec3::splat(0.3)));
+    }
+
+    /// Verify placement against a block right at the world border is refused past its plane.
+    #[test]
+    fn placement_outside_border_is_rejected() {
+        let mut world = WorldState::new();
+        world.border = WorldBorder { half_extent: 4 };
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(3, 0, 2), Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        let hit = Some(IVec3::new(3, 0, 2));
+        let inside = Vec3::new(2.5, 0.5, 2.5);
+        assert_eq!(
+            super::placement_cell(&world, inside, hit, Some(IVec3::new(2, 0, 2))),
+            Some(IVec3::new(2, 0, 2))
+        );
+        let beyond = Vec3::new(5.5, 0.5, 2.5);
+        assert_eq!(
+            super::placement_cell(&world, beyond, hit, Some(IVec3::new(4, 0, 2))),
+            None
+        );
+    }
+
+    /// Verify placement is rejected on back faces and faces hidden around a corner.
+    #[test]
+    fn attachment_face_must_be_visible_from_eye() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(3, 0, 0), Block::dirt());
+        chunk.set_block(IVec3::new(2, 0, 2), Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let hit = IVec3::new(3, 0, 0);
+        let target = IVec3::new(3, 0, 1);
+
+        // +Z face seen from the -Z side faces away from the eye.
+        assert!(!world.attachment_face_visible(Vec3::new(0.5, 0.5, 0.5), hit, target));
+        // +Z face seen from beyond the corner block at (2, 0, 2) is occluded.
+        assert!(!world.attachment_face_visible(Vec3::new(0.5, 0.5, 3.5), hit, target));
+        // Same face from an unobstructed eye position stays placeable.
+        assert!(world.attachment_face_visible(Vec3::new(3.5, 0.5, 4.5), hit, target));
+    }
+
+    /// Verify glancing-angle placements found by the raymarch still pass the visibility check.
+    #[test]
+    fn glancing_placement_stays_valid() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(3, 0, 0), Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        let eye = Vec3::new(1.5, 0.5, 1.4);
+        let direction = (Vec3::new(3.8, 0.5, 0.9) - eye).normalize();
+        let (hit, last_empty) = world.raymarch_hit_and_last_empty(eye, direction);
+        assert_eq!(hit, Some(IVec3::new(3, 0, 0)));
+        assert_eq!(last_empty, Some(IVec3::new(3, 0, 1)));
+        assert!(world.attachment_face_visible(eye, hit.unwrap(), last_empty.unwrap()));
+    }
+
+    /// Verify breaking the only block under a standing player drops them within two ticks.
+    #[test]
+    fn breaking_support_block_drops_player() {
+        use std::time::Duration;
+
+        use bevy::ecs::system::RunSystemOnce;
+
+        use crate::player::{PlayerRecovered, Velocity, physics_system};
+        use crate::scene::SpawnPoint;
+        use crate::{STAND_EYE_HEIGHT, STAND_HALF_SIZE};
+
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(4, 2, 4), Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut app = App::new();
+        app.insert_resource(world)
+            .insert_resource(FallingPropagationQueue::default())
+            .insert_resource(SessionJournal::new())
+            .insert_resource(SelectedBlock::new(Block::dirt()))
+            .insert_resource(ActionCooldowns::default())
+            .insert_resource(BreakProgress {
+                instant: true,
+                ..default()
+            })
+            .insert_resource(SpawnPoint {
+                ground: IVec3::ZERO,
+            })
+            .init_resource::<HeldTool>()
+            .init_resource::<Inventory>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_message::<PlayerRecovered>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(Update, block_interaction_system);
+
+        let mut player = Player::new_standing(10.4, STAND_HALF_SIZE, STAND_EYE_HEIGHT);
+        player.on_ground = true;
+        let body = Vec3::new(4.5, 3.0 + STAND_HALF_SIZE.y, 4.5);
+        let player_entity = app
+            .world_mut()
+            .spawn((
+                PlayerBody,
+                player,
+                Velocity(Vec3::ZERO),
+                Transform::from_translation(body),
+                Health::new_spawned(20.0),
+            ))
+            .id();
+        app.world_mut().spawn((
+            bevy::camera::Camera3d::default(),
+            GlobalTransform::from(
+                Transform::from_translation(body + Vec3::Y * 0.85).looking_to(Vec3::NEG_Y, Vec3::Z),
+            ),
+        ));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+
+        app.update();
+        let world = app.world().resource::<WorldState>();
+        assert_eq!(
+            world.get_block_world(IVec3::new(4, 2, 4)),
+            Some(Block::air())
+        );
+        assert!(!app.world().get::<Player>(player_entity).unwrap().on_ground);
+        let mut drops = app
+            .world_mut()
+            .query::<&crate::voxel::item_state::ItemDrop>();
+        assert_eq!(
+            drops
+                .iter(app.world())
+                .map(|item| item.block)
+                .collect::<Vec<_>>(),
+            vec![Block::dirt()]
+        );
+
+        for _ in 0..2 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.world_mut().run_system_once(physics_system).unwrap();
+        }
+        let velocity = app.world().get::<Velocity>(player_entity).unwrap();
+        assert!(
+            velocity.0.y < 0.0,
+            "player still supported: {:?}",
+            velocity.0
+        );
+    }
+
+    /// Verify a wet sponge stays wet from breaking through pickup to placing it again, and
+    /// does not soak up water a second time.
+    #[test]
+    fn wet_sponge_keeps_state_through_inventory() {
+        use crate::voxel::item_state::{ItemDrop, ItemKind};
+        use crate::voxel::systems::items::item_pickup_system;
+        use crate::{STAND_EYE_HEIGHT, STAND_HALF_SIZE};
+
+        let sponge = IVec3::new(4, 3, 4);
+        let wet = Block::sponge().soaked();
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(sponge - IVec3::Y, Block::stone());
+        chunk.set_block(sponge, wet);
+        chunk.set_block(sponge + IVec3::X, Block::water());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut app = App::new();
+        app.insert_resource(world)
+            .insert_resource(FallingPropagationQueue::default())
+            .insert_resource(SessionJournal::new())
+            .insert_resource(SelectedBlock::new(Block::sponge()))
+            .insert_resource(ActionCooldowns::default())
+            .insert_resource(BreakProgress {
+                instant: true,
+                ..default()
+            })
+            .init_resource::<HeldTool>()
+            .init_resource::<Inventory>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (block_interaction_system, item_pickup_system).chain(),
+            );
+        app.world_mut().spawn((
+            bevy::camera::Camera3d::default(),
+            GlobalTransform::from(
+                Transform::from_translation(Vec3::new(4.5, 6.0, 4.5))
+                    .looking_to(Vec3::NEG_Y, Vec3::Z),
+            ),
+        ));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        let center = app
+            .world_mut()
+            .query::<&ItemDrop>()
+            .single(app.world())
+            .unwrap()
+            .center;
+        let picker = app
+            .world_mut()
+            .spawn((
+                PlayerBody,
+                Player::new_standing(10.4, STAND_HALF_SIZE, STAND_EYE_HEIGHT),
+                Transform::from_translation(center),
+            ))
+            .id();
+        let mut buttons = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        buttons.release(MouseButton::Left);
+        buttons.clear();
+        app.update();
+        app.world_mut().despawn(picker);
+        let inventory = app.world().resource::<Inventory>();
+        assert_eq!(inventory.count(wet), 1);
+        assert_eq!(inventory.count(BlockKind::Sponge), 0);
+        assert_eq!(
+            inventory.held().collect::<Vec<_>>(),
+            [(ItemKind::Block(wet), 1)]
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        app.update();
+        let world = app.world().resource::<WorldState>();
+        assert!(world.get_block_world(sponge).unwrap().is_wet_sponge());
+        assert_eq!(
+            world.get_block_world(sponge + IVec3::X),
+            Some(Block::water())
+        );
+        assert!(world.sponge_queue.is_empty());
+        assert_eq!(app.world().resource::<Inventory>().held().count(), 0);
+    }
+
+    /// Verify a block breaks only after the button is held on it for its hardness, and
+    /// releasing or aiming at another block starts over.
+    #[test]
+    fn breaking_takes_hardness_seconds_of_holding() {
+        use std::time::Duration;
+
+        let stone = IVec3::new(4, 2, 4);
+        let dirt = IVec3::new(6, 2, 4);
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(stone, Block::stone());
+        chunk.set_block(dirt, Block::dirt());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut app = App::new();
+        app.insert_resource(world)
+            .insert_resource(FallingPropagationQueue::default())
+            .insert_resource(SessionJournal::new())
+            .insert_resource(SelectedBlock::new(Block::dirt()))
+            .insert_resource(ActionCooldowns::default())
+            .init_resource::<BreakProgress>()
+            .init_resource::<HeldTool>()
+            .init_resource::<Inventory>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(Update, block_interaction_system);
+        let camera = app
+            .world_mut()
+            .spawn((
+                bevy::camera::Camera3d::default(),
+                GlobalTransform::default(),
+            ))
+            .id();
+        let aim_at = |app: &mut App, cell: IVec3| {
+            let eye = cell.as_vec3() + Vec3::new(0.5, 3.0, 0.5);
+            *app.world_mut().get_mut::<GlobalTransform>(camera).unwrap() = GlobalTransform::from(
+                Transform::from_translation(eye).looking_to(Vec3::NEG_Y, Vec3::Z),
+            );
+        };
+        let step = |app: &mut App, secs: f32| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+            app.update();
+        };
+        let block_at =
+            |app: &App, cell: IVec3| app.world().resource::<WorldState>().get_block_world(cell);
+        let hardness = Block::stone().hardness(None);
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+
+        aim_at(&mut app, stone);
+        step(&mut app, 0.6 * hardness);
+        aim_at(&mut app, dirt);
+        step(&mut app, 0.0);
+        aim_at(&mut app, stone);
+        step(&mut app, 0.6 * hardness);
+        assert_eq!(block_at(&app, stone), Some(Block::stone()));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(MouseButton::Left);
+        step(&mut app, 0.0);
+        assert_eq!(
+            *app.world().resource::<BreakProgress>(),
+            BreakProgress::default()
+        );
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        step(&mut app, 0.6 * hardness);
+        assert_eq!(block_at(&app, stone), Some(Block::stone()));
+        step(&mut app, 0.6 * hardness);
+        assert_eq!(block_at(&app, stone), Some(Block::air()));
+        assert_eq!(app.world().resource::<BreakProgress>().target, None);
+    }
+
+    /// Verify a held pickaxe speeds stone breaking up and makes it drop, while bare hands
+    /// break it slowly for nothing.
+    #[test]
+    fn held_pickaxe_mines_stone_for_a_drop() {
+        use std::time::Duration;
+
+        use crate::voxel::block_defs::Tool;
+        use crate::voxel::item_state::ItemDrop;
+
+        let picked = IVec3::new(4, 2, 4);
+        let by_hand = IVec3::new(6, 2, 4);
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(picked, Block::stone());
+        chunk.set_block(by_hand, Block::stone());
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut inventory = Inventory::default();
+        inventory.add(Tool::WOOD_PICKAXE);
+        let mut held = HeldTool::default();
+        held.cycle(&inventory);
+        let mut app = App::new();
+        app.insert_resource(world)
+            .insert_resource(FallingPropagationQueue::default())
+            .insert_resource(SessionJournal::new())
+            .insert_resource(SelectedBlock::new(Block::dirt()))
+            .insert_resource(ActionCooldowns::default())
+            .insert_resource(inventory)
+            .insert_resource(held)
+            .init_resource::<BreakProgress>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(Update, (held_tool_system, block_interaction_system).chain());
+        let camera = app
+            .world_mut()
+            .spawn((
+                bevy::camera::Camera3d::default(),
+                GlobalTransform::default(),
+            ))
+            .id();
+        let aim_at = |app: &mut App, cell: IVec3| {
+            let eye = cell.as_vec3() + Vec3::new(0.5, 3.0, 0.5);
+            *app.world_mut().get_mut::<GlobalTransform>(camera).unwrap() = GlobalTransform::from(
+                Transform::from_translation(eye).looking_to(Vec3::NEG_Y, Vec3::Z),
+            );
+        };
+        let step = |app: &mut App, secs: f32| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+            app.update();
+        };
+        let drops = |app: &mut App| {
+            app.world_mut()
+                .query::<&ItemDrop>()
+                .iter(app.world())
+                .count()
+        };
+        let with_pick = Block::stone().hardness(Some(Tool::WOOD_PICKAXE));
+        let bare = Block::stone().hardness(None);
+        assert!(with_pick < bare);
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+
+        aim_at(&mut app, picked);
+        step(&mut app, 1.1 * with_pick);
+        assert_eq!(
+            app.world().resource::<WorldState>().get_block_world(picked),
+            Some(Block::air())
+        );
+        assert_eq!(drops(&mut app), 1);
+
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .take(Tool::WOOD_PICKAXE);
+        aim_at(&mut app, by_hand);
+        step(&mut app, 1.1 * with_pick);
+        step(&mut app, bare);
+        assert_eq!(
+            app.world()
+                .resource::<WorldState>()
+                .get_block_world(by_hand),
+            Some(Block::air())
+        );
+        assert_eq!(drops(&mut app), 1);
+    }
 }
@@ -0,0 +1,412 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::cooldown::{ActionCooldowns, ActionId};
+use crate::player::{Player, PlayerBody};
+use crate::{BLOCK_SIZE, GRAVITY};
+
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::block_defs::Tool;
+use crate::voxel::interaction_state::SelectedBlock;
+use crate::voxel::item_state::{Inventory, ItemDrop};
+use crate::voxel::world_state::{ChunkEntityRecord, WorldState};
+
+/// Key that throws one held item of the selected block kind.
+pub(crate) const THROW_KEY: KeyCode = KeyCode::KeyQ;
+/// Distance from the player's collider within which items are picked up, in world units.
+const PICKUP_RADIUS: f32 = 1.0 * BLOCK_SIZE;
+/// Distance ahead of the eye a thrown item appears at, in block lengths.
+const THROW_DISTANCE_BLOCKS: f32 = 2.0;
+/// Seconds an item may spend in unloaded chunks or below the world before it despawns.
+const STRANDED_ITEM_TIMEOUT_SECS: f32 = 5.0;
+
+/// Fall, land, and spin dropped items, despawning those stranded outside loaded chunks.
+///
+/// Items collide with the same solid boxes the player does and rest on their tops. An item
+/// whose cell is not loaded (an unloaded chunk, or below the lowest chunk layer) falls
+/// freely and despawns after `STRANDED_ITEM_TIMEOUT_SECS`.
+pub fn update_item_drops_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    world: Res<WorldState>,
+    mut query: Query<(Entity, &mut Transform, &mut ItemDrop)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut transform, mut item) in &mut query {
+        item.age += dt;
+        let cell = (item.center / BLOCK_SIZE).floor().as_ivec3();
+        if world.get_block_world(cell).is_none() {
+            item.stranded_secs += dt;
+            if item.stranded_secs >= STRANDED_ITEM_TIMEOUT_SECS {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        } else {
+            item.stranded_secs = 0.0;
+        }
+
+        let mut next = item.center;
+        next.y += item.integrate_vertical(dt, GRAVITY);
+        match world.highest_solid_top(next, ItemDrop::HALF_SIZE) {
+            Some(top) if item.velocity_y <= 0.0 => {
+                next.y = top + ItemDrop::HALF_SIZE.y;
+                item.velocity_y = 0.0;
+            }
+            Some(_) => {
+                next.y = item.center.y;
+                item.velocity_y = 0.0;
+            }
+            None => {}
+        }
+        item.center = next;
+        *transform = item.transform();
+    }
+}
+
+/// Despawn items owned by chunks about to unload and park them with those chunks.
+///
+/// An item belongs to the chunk holding the cell its center lies in.
+pub(crate) fn park_item_drops(
+    commands: &mut Commands,
+    world: &mut WorldState,
+    unloading: &HashSet<IVec3>,
+    query: &Query<(Entity, &ItemDrop)>,
+) {
+    for (entity, item) in query {
+        let cell = Block::world_coord_from_position(item.center);
+        let owner = WorldState::entity_owner_chunk(Block::world_translation(cell));
+        if !unloading.contains(&owner) {
+            continue;
+        }
+        world.park_chunk_entity(
+            owner,
+            ChunkEntityRecord::ItemDrop {
+                center: item.center,
+                velocity_y: item.velocity_y,
+                age: item.age,
+                block: item.block,
+                count: item.count,
+            },
+        );
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Pick up items within `PICKUP_RADIUS` of a player's collider into the inventory.
+pub fn item_pickup_system(
+    mut commands: Commands,
+    players: Query<(&Transform, &Player), With<PlayerBody>>,
+    items: Query<(Entity, &ItemDrop)>,
+    mut inventory: ResMut<Inventory>,
+) {
+    for (entity, item) in &items {
+        let picked = players.iter().any(|(transform, player)| {
+            let body = transform.translation;
+            let nearest = item
+                .center
+                .clamp(body - player.half_size, body + player.half_size);
+            nearest.distance_squared(item.center) <= PICKUP_RADIUS * PICKUP_RADIUS
+        });
+        if picked {
+            inventory.add_many(item.block, item.count);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Throw one held item of the selected kind ahead of the camera on `THROW_KEY`.
+///
+/// Nothing is thrown, and the `ActionId::Throw` cooldown is left untouched, when no such
+/// item is held or the spot ahead is solid or not loaded.
+#[allow(clippy::too_many_arguments)]
+pub fn throw_item_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut cooldowns: ResMut<ActionCooldowns>,
+    mut inventory: ResMut<Inventory>,
+    selected: Res<SelectedBlock>,
+    camera_query: Query<&GlobalTransform, With<bevy::camera::Camera3d>>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let now = time.elapsed_secs();
+    if !keys.just_pressed(THROW_KEY) || !cooldowns.ready(ActionId::Throw, now) {
+        return;
+    }
+    let Ok(camera) = camera_query.single() else {
+        return;
+    };
+    let center =
+        camera.translation() + camera.forward().as_vec3() * (THROW_DISTANCE_BLOCKS * BLOCK_SIZE);
+    let open = world
+        .get_block_world(Block::world_coord_from_position(center))
+        .is_some_and(|block| !block.is_solid());
+    let Some(item) = inventory
+        .held_block(selected.current)
+        .filter(|item| open && inventory.take(*item))
+    else {
+        return;
+    };
+    ItemDrop::new(item, center).spawn(&mut commands, &mut meshes, &mut world);
+    cooldowns.mark(ActionId::Throw, now);
+}
+
+/// Drop the item breaking `block` at `world_pos` with `held` leaves, if any.
+///
+/// Either half of a door drops the one door item.
+pub(crate) fn drop_broken_block(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    world: &mut WorldState,
+    world_pos: IVec3,
+    block: Block,
+    held: Option<Tool>,
+) {
+    if !block.drops_with(held) {
+        return;
+    }
+    let center = Block::world_translation(world_pos) + Vec3::splat(0.5 * BLOCK_SIZE);
+    let item = match block.kind {
+        BlockKind::Door | BlockKind::DoorTop => Block::door(),
+        _ => block.as_item(),
+    };
+    ItemDrop::new(item, center).spawn(commands, meshes, world);
+}
+
+/// Spill the items held by block entities whose block was removed, one drop per stack.
+///
+/// Covers every way a block goes, whether broken by hand, blown up, or replaced.
+pub fn spill_block_entities_system(
+    mut commands: Commands,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    for (pos, entity) in world.block_entities.take_detached() {
+        let center = Block::world_translation(pos) + Vec3::splat(0.5 * BLOCK_SIZE);
+        for (item, count) in entity.spilled_stacks() {
+            ItemDrop::new(item, center).with_count(count).spawn(
+                &mut commands,
+                &mut meshes,
+                &mut world,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::voxel::block_chunk::{BlockKind, Chunk};
+    use crate::voxel::world_state::ChunkData;
+    use crate::{CHUNK_SIZE, STAND_EYE_HEIGHT, STAND_HALF_SIZE};
+
+    /// Build an app running the item systems over `world`, with an empty inventory.
+    fn item_app(world: WorldState) -> App {
+        let mut app = App::new();
+        app.insert_resource(world)
+            .init_resource::<Inventory>()
+            .init_resource::<Time>()
+            .add_systems(
+                Update,
+                (update_item_drops_system, item_pickup_system).chain(),
+            );
+        app
+    }
+
+    /// Advance the app by `frames` steps of 1/60 s.
+    fn run_frames(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+    }
+
+    /// Verify a dropped item pops up, lands flush on the ground, and is picked up into the
+    /// inventory once a player comes close.
+    #[test]
+    fn dropped_item_lands_and_is_picked_up() {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                chunk.set_block(IVec3::new(x, 0, z), Block::stone());
+            }
+        }
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let mut app = item_app(world);
+        let center = Vec3::new(4.5, 1.5, 4.5) * BLOCK_SIZE;
+        let item = app
+            .world_mut()
+            .spawn((Transform::default(), ItemDrop::new(Block::dirt(), center)))
+            .id();
+
+        run_frames(&mut app, 60);
+        let landed = app.world().get::<ItemDrop>(item).unwrap();
+        assert_eq!(landed.center.xz(), center.xz());
+        assert!((landed.center.y - (BLOCK_SIZE + ItemDrop::HALF_SIZE.y)).abs() < 1e-4);
+        assert_eq!(landed.velocity_y, 0.0);
+
+        app.world_mut().spawn((
+            PlayerBody,
+            Player::new_standing(10.0, STAND_HALF_SIZE, STAND_EYE_HEIGHT),
+            Transform::from_translation(Vec3::new(6.0, 1.0 + STAND_HALF_SIZE.y, 4.5)),
+        ));
+        run_frames(&mut app, 1);
+        assert!(app.world().get_entity(item).is_ok());
+
+        app.world_mut()
+            .query_filtered::<&mut Transform, With<PlayerBody>>()
+            .single_mut(app.world_mut())
+            .unwrap()
+            .translation
+            .x = 5.0;
+        run_frames(&mut app, 1);
+        assert!(app.world().get_entity(item).is_err());
+        assert_eq!(
+            app.world().resource::<Inventory>().count(BlockKind::Dirt),
+            1
+        );
+    }
+
+    /// Verify an item outside loaded chunks despawns only after the stranded timeout.
+    #[test]
+    fn stranded_item_despawns_after_timeout() {
+        let mut app = item_app(WorldState::new());
+        let item = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                ItemDrop::new(Block::sand(), Vec3::splat(0.5 * BLOCK_SIZE)),
+            ))
+            .id();
+
+        let timeout_frames = (STRANDED_ITEM_TIMEOUT_SECS * 60.0) as usize;
+        run_frames(&mut app, timeout_frames - 10);
+        assert!(app.world().get_entity(item).is_ok());
+        run_frames(&mut app, 20);
+        assert!(app.world().get_entity(item).is_err());
+        assert_eq!(*app.world().resource::<Inventory>(), Inventory::default());
+    }
+
+    /// Verify a dropped item unloads with its owner chunk and respawns unchanged on reload.
+    #[test]
+    fn item_drop_round_trips_through_chunk_unload() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        use crate::MAX_CHUNK_LAYER;
+
+        let mut ecs = World::new();
+        ecs.insert_resource(WorldState::new());
+        ecs.init_resource::<Assets<Mesh>>();
+        let coord = IVec3::new(0, MAX_CHUNK_LAYER, 0);
+        let center = Vec3::new(4.5, (MAX_CHUNK_LAYER * CHUNK_SIZE + 5) as f32 + 0.5, 4.5);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+                ItemDrop::new(Block::sand(), center).with_count(3).spawn(
+                    &mut commands,
+                    &mut meshes,
+                    &mut world,
+                );
+            },
+        )
+        .unwrap();
+
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut world: ResMut<WorldState>,
+                  query: Query<(Entity, &ItemDrop)>| {
+                park_item_drops(&mut commands, &mut world, &HashSet::from([coord]), &query);
+                world.unload_chunk(&mut commands, coord);
+            },
+        )
+        .unwrap();
+        assert_eq!(ecs.query::<&ItemDrop>().iter(&ecs).count(), 0);
+        assert_eq!(ecs.resource::<WorldState>().chunk_entities[&coord].len(), 1);
+
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+            },
+        )
+        .unwrap();
+        let restored = ecs.query::<&ItemDrop>().single(&ecs).unwrap();
+        assert_eq!(restored.center, center);
+        assert_eq!(restored.block, Block::sand());
+        assert_eq!(restored.count, 3);
+        assert!(ecs.resource::<WorldState>().chunk_entities.is_empty());
+    }
+
+    /// Verify throwing spends one held item of the selected kind and its cooldown, while a
+    /// throw with nothing to throw leaves the cooldown ready.
+    #[test]
+    fn throw_spends_an_item_and_only_then_the_cooldown() {
+        let mut world = WorldState::new();
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(
+                Chunk::new_empty(),
+                Handle::<Mesh>::default(),
+                Entity::PLACEHOLDER,
+            ),
+        );
+        let mut app = App::new();
+        app.insert_resource(world)
+            .insert_resource(SelectedBlock::new(Block::sand()))
+            .insert_resource(ActionCooldowns::default().with_interval(ActionId::Throw, 10.0))
+            .init_resource::<Inventory>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Time>()
+            .add_systems(Update, throw_item_system);
+        app.world_mut().spawn((
+            bevy::camera::Camera3d::default(),
+            GlobalTransform::from(Transform::from_xyz(4.5, 4.5, 4.5).looking_to(Vec3::X, Vec3::Y)),
+        ));
+        let throw = |app: &mut App| {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.release(THROW_KEY);
+            keys.clear();
+            keys.press(THROW_KEY);
+            app.update();
+        };
+        let thrown = |app: &mut App| {
+            app.world_mut()
+                .query::<&ItemDrop>()
+                .iter(app.world())
+                .count()
+        };
+
+        throw(&mut app);
+        assert_eq!(thrown(&mut app), 0);
+        assert!(
+            app.world()
+                .resource::<ActionCooldowns>()
+                .ready(ActionId::Throw, 0.0)
+        );
+
+        app.world_mut()
+            .resource_mut::<Inventory>()
+            .add_many(BlockKind::Sand, 2);
+        throw(&mut app);
+        assert_eq!(thrown(&mut app), 1);
+        assert_eq!(
+            app.world().resource::<Inventory>().count(BlockKind::Sand),
+            1
+        );
+        throw(&mut app);
+        assert_eq!(thrown(&mut app), 1);
+    }
+}
@@ -0,0 +1,377 @@
+use bevy::prelude::*;
+
+use crate::{BLOCK_SIZE, CHUNK_SIZE};
+
+use crate::voxel::block_chunk::Block;
+use crate::voxel::block_chunk::BlockKind;
+use crate::voxel::light_state::{LIGHT_NEIGHBOR_OFFSETS, LightFlicker, LightingConfig};
+use crate::voxel::mesh_types::torch_bounds;
+use crate::voxel::world_state::WorldState;
+
+/// Max queued light cells processed per frame, so a large change spreads over several frames.
+const MAX_LIGHT_STEPS_PER_FRAME: usize = 4096;
+/// Most torch and glowstone point lights alive at once; farther sources stay unlit.
+const MAX_TORCH_LIGHTS: usize = 16;
+/// Chunk ring distance around the streaming center searched for light sources to light.
+const TORCH_LIGHT_CHUNK_RADIUS: i32 = 2;
+/// Warm flame color of torch lights.
+const TORCH_LIGHT_COLOR: Color = Color::srgb(1.0, 0.72, 0.42);
+/// Luminous power of one torch light, in lumens.
+const TORCH_LIGHT_INTENSITY: f32 = 60_000.0;
+/// Distance a torch light reaches, in world units.
+const TORCH_LIGHT_RANGE: f32 = 12.0 * BLOCK_SIZE;
+
+/// Marker for the pooled point lights that follow nearby torches and glowstone.
+#[derive(Component)]
+pub struct TorchLight;
+
+/// Fixed pool of point lights reused for the torches and glowstone nearest the camera.
+///
+/// Each source gets no entity of its own, so placing many torches never adds lights beyond
+/// `MAX_TORCH_LIGHTS`; lights without a source to follow are hidden.
+#[derive(Resource, Default)]
+pub struct TorchLightPool {
+    /// Pooled light entities, spawned on first use.
+    lights: Vec<Entity>,
+}
+
+/// Return the world cells and light positions of torches and glowstone in chunks around the
+/// streaming center, nearest to `eye` first and capped at `MAX_TORCH_LIGHTS`.
+///
+/// Torches shine from their flame, glowstone from the middle of its block.
+fn nearest_torch_flames(world: &WorldState, eye: Vec3) -> Vec<(IVec3, Vec3)> {
+    let mut flames: Vec<(IVec3, Vec3)> = world
+        .chunks
+        .iter()
+        .filter(|(coord, _)| {
+            WorldState::ring_distance(world.center, **coord) <= TORCH_LIGHT_CHUNK_RADIUS
+                && (coord.y - world.center.y).abs() <= TORCH_LIGHT_CHUNK_RADIUS
+        })
+        .flat_map(|(coord, data)| {
+            data.light_sources.iter().map(move |(local, block)| {
+                let cell = *coord * CHUNK_SIZE + *local;
+                let flame = if block.kind == BlockKind::Torch {
+                    let (min, max) = torch_bounds(block.front);
+                    Vec3::new((min.x + max.x) * 0.5, max.y, (min.z + max.z) * 0.5)
+                } else {
+                    Vec3::splat(0.5)
+                };
+                (cell, Block::world_translation(cell) + flame * BLOCK_SIZE)
+            })
+        })
+        .collect();
+    flames.sort_by(|(_, a), (_, b)| a.distance_squared(eye).total_cmp(&b.distance_squared(eye)));
+    flames.truncate(MAX_TORCH_LIGHTS);
+    flames
+}
+
+/// Move pooled torch lights onto the light sources nearest the camera and hide the rest.
+///
+/// Source lists are refreshed whenever a chunk mesh is built, so lights appear with a
+/// placed torch's mesh and go out when it is broken or pops off. A light moving onto a
+/// new source takes that source's flicker seed.
+pub fn torch_light_system(
+    mut commands: Commands,
+    world: Res<WorldState>,
+    camera_query: Query<&GlobalTransform, With<bevy::camera::Camera3d>>,
+    mut pool: ResMut<TorchLightPool>,
+    mut lights: Query<(&mut Transform, &mut Visibility, &mut LightFlicker), With<TorchLight>>,
+) {
+    if pool.lights.is_empty() {
+        pool.lights = (0..MAX_TORCH_LIGHTS)
+            .map(|_| {
+                commands
+                    .spawn((
+                        TorchLight,
+                        PointLight {
+                            color: TORCH_LIGHT_COLOR,
+                            intensity: TORCH_LIGHT_INTENSITY,
+                            range: TORCH_LIGHT_RANGE,
+                            shadows_enabled: false,
+                            ..default()
+                        },
+                        Transform::default(),
+                        Visibility::Hidden,
+                        LightFlicker::default(),
+                    ))
+                    .id()
+            })
+            .collect();
+        return;
+    }
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let flames = nearest_torch_flames(&world, camera_transform.translation());
+    for (index, entity) in pool.lights.iter().enumerate() {
+        let Ok((mut transform, mut visibility, mut flicker)) = lights.get_mut(*entity) else {
+            continue;
+        };
+        match flames.get(index) {
+            Some((cell, flame)) => {
+                if transform.translation != *flame {
+                    transform.translation = *flame;
+                    flicker.set_if_neq(LightFlicker::for_cell(*cell));
+                }
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
+/// Flicker the intensity and warmth of pooled lights within the configured radius of the
+/// camera; farther lights burn steadily.
+pub fn light_flicker_system(
+    time: Res<Time>,
+    config: Res<LightingConfig>,
+    camera_query: Query<&GlobalTransform, With<bevy::camera::Camera3d>>,
+    mut lights: Query<(&Transform, &Visibility, &LightFlicker, &mut PointLight), With<TorchLight>>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let eye = camera_transform.translation();
+    let radius = config.flicker_radius_blocks * BLOCK_SIZE;
+    for (transform, visibility, flicker, mut light) in &mut lights {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        let (scale, color) = if transform.translation.distance_squared(eye) <= radius * radius {
+            flicker.sample(time.elapsed_secs(), TORCH_LIGHT_COLOR, &config)
+        } else {
+            (1.0, TORCH_LIGHT_COLOR)
+        };
+        light.intensity = TORCH_LIGHT_INTENSITY * scale;
+        light.color = color;
+    }
+}
+
+/// Keep every pooled light at its steady intensity and color while flicker is disabled.
+pub fn steady_light_system(mut lights: Query<&mut PointLight, With<TorchLight>>) {
+    for mut light in &mut lights {
+        if light.intensity != TORCH_LIGHT_INTENSITY || light.color != TORCH_LIGHT_COLOR {
+            light.intensity = TORCH_LIGHT_INTENSITY;
+            light.color = TORCH_LIGHT_COLOR;
+        }
+    }
+}
+
+/// Process up to `budget` queued block light cells.
+///
+/// Removals go first: each darkened cell darkens the neighbors its light reached, and
+/// queues brighter neighbors lit by other sources to spread back. Adds then hand every
+/// neighbor that does not block light one level less than the cell's own.
+fn step_block_light(world: &mut WorldState, budget: usize) {
+    for _ in 0..budget {
+        if let Some((pos, level)) = world.light_queue.pop_removal() {
+            for offset in LIGHT_NEIGHBOR_OFFSETS {
+                let neighbor = pos + offset;
+                let Some(neighbor_level) = world.block_light(neighbor) else {
+                    continue;
+                };
+                if neighbor_level == 0 {
+                    continue;
+                }
+                if neighbor_level >= level {
+                    world.light_queue.enqueue_add(neighbor);
+                    continue;
+                }
+                world.set_block_light(neighbor, 0);
+                world.light_queue.enqueue_removal(neighbor, neighbor_level);
+                // An emitter keeps its own light whatever reached it from elsewhere.
+                let emission = world
+                    .get_block_world(neighbor)
+                    .map_or(0, |block| block.light_emission());
+                if emission > 0 {
+                    world.set_block_light(neighbor, emission);
+                    world.light_queue.enqueue_add(neighbor);
+                }
+            }
+            continue;
+        }
+        let Some(pos) = world.light_queue.pop_add() else {
+            return;
+        };
+        let Some(level) = world.block_light(pos) else {
+            continue;
+        };
+        if level <= 1 {
+            continue;
+        }
+        for offset in LIGHT_NEIGHBOR_OFFSETS {
+            let neighbor = pos + offset;
+            let open = world
+                .get_block_world(neighbor)
+                .is_some_and(|block| !block.blocks_light());
+            if open
+                && world
+                    .block_light(neighbor)
+                    .is_some_and(|lit| lit < level - 1)
+            {
+                world.set_block_light(neighbor, level - 1);
+                world.light_queue.enqueue_add(neighbor);
+            }
+        }
+    }
+}
+
+/// Spread queued block light changes and rebuild the meshes of chunks whose block or sky
+/// light changed.
+pub fn block_light_system(mut world: ResMut<WorldState>, mut meshes: ResMut<Assets<Mesh>>) {
+    if world.light_queue.is_empty() && !world.light_queue.has_touched() {
+        return;
+    }
+    step_block_light(&mut world, MAX_LIGHT_STEPS_PER_FRAME);
+    let touched = world.light_queue.take_touched();
+    world.rebuild_touched_chunk_meshes(&mut meshes, touched);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::voxel::Chunk;
+    use crate::voxel::light_state::MAX_LIGHT;
+    use crate::voxel::world_state::ChunkData;
+
+    /// Verify fifty torches light only the pool's worth, nearest first, at their flames.
+    #[test]
+    fn torch_lights_are_capped_to_nearest() {
+        let mut world = WorldState::new();
+        world.center = IVec3::ZERO;
+        let mut chunk = Chunk::new_empty();
+        for i in 0..50 {
+            chunk.set_block(
+                IVec3::new(i % CHUNK_SIZE, 1, i / CHUNK_SIZE),
+                Block::torch(),
+            );
+        }
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+
+        let flames = nearest_torch_flames(&world, Vec3::ZERO);
+        assert_eq!(flames.len(), MAX_TORCH_LIGHTS);
+        let (min, max) = torch_bounds(Block::torch().front);
+        assert_eq!(
+            flames[0],
+            (
+                IVec3::new(0, 1, 0),
+                Vec3::new(0.0, 1.0, 0.0) + Vec3::new(0.5, max.y, 0.5) * BLOCK_SIZE
+            )
+        );
+        assert!(min.y == 0.0 && max.y < 1.0);
+        assert!(
+            flames
+                .windows(2)
+                .all(|pair| pair[0].1.length_squared() <= pair[1].1.length_squared())
+        );
+    }
+
+    /// Verify pooled lights near the camera flicker, each on its own pattern and within the
+    /// configured bounds, while far lights and disabled flicker stay steady.
+    #[test]
+    fn near_lights_flicker_and_far_lights_stay_steady() {
+        let config = LightingConfig::default();
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(config)
+            .add_systems(Update, light_flicker_system);
+        app.world_mut()
+            .spawn((bevy::camera::Camera3d::default(), GlobalTransform::IDENTITY));
+        let light = |app: &mut App, at: Vec3, cell: IVec3| {
+            app.world_mut()
+                .spawn((
+                    TorchLight,
+                    PointLight::default(),
+                    Transform::from_translation(at),
+                    Visibility::Inherited,
+                    LightFlicker::for_cell(cell),
+                ))
+                .id()
+        };
+        let near = light(&mut app, Vec3::X * 4.0, IVec3::new(4, 0, 0));
+        let twin = light(&mut app, Vec3::X * 4.0, IVec3::new(5, 0, 0));
+        let far = light(&mut app, Vec3::X * 40.0 * BLOCK_SIZE, IVec3::new(40, 0, 0));
+
+        let mut samples = Vec::new();
+        for _ in 0..12 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(70));
+            app.update();
+            let get = |entity| *app.world().get::<PointLight>(entity).unwrap();
+            let (near, twin, far) = (get(near), get(twin), get(far));
+            let scale = near.intensity / TORCH_LIGHT_INTENSITY;
+            assert!((scale - 1.0).abs() <= config.flicker_intensity + 1e-4);
+            assert_eq!(far.intensity, TORCH_LIGHT_INTENSITY);
+            assert_eq!(far.color, TORCH_LIGHT_COLOR);
+            samples.push((near.intensity, twin.intensity, near.color));
+        }
+        assert!(samples.windows(2).any(|pair| pair[0].0 != pair[1].0));
+        assert!(samples.iter().any(|(near, twin, _)| near != twin));
+        assert!(
+            samples
+                .iter()
+                .any(|(_, _, color)| *color != TORCH_LIGHT_COLOR)
+        );
+
+        app.world_mut()
+            .run_system_once(steady_light_system)
+            .unwrap();
+        let steady = app.world().get::<PointLight>(near).unwrap();
+        assert_eq!(steady.intensity, TORCH_LIGHT_INTENSITY);
+        assert_eq!(steady.color, TORCH_LIGHT_COLOR);
+    }
+
+    /// Verify glowstone light fades one level per block, stops at stone, spreads into the
+    /// next chunk, and goes out when the glowstone is broken.
+    #[test]
+    fn block_light_spreads_and_is_removed() {
+        let mut world = WorldState::new();
+        for coord in [IVec3::ZERO, IVec3::X] {
+            world.chunks.insert(
+                coord,
+                ChunkData::new(
+                    Chunk::new_empty(),
+                    Handle::<Mesh>::default(),
+                    Entity::PLACEHOLDER,
+                ),
+            );
+        }
+        let source = IVec3::new(12, 8, 8);
+        world.set_block_world_loaded(IVec3::new(12, 8, 6), Block::stone());
+        world.set_block_world_loaded(source, Block::glowstone());
+        step_block_light(&mut world, usize::MAX);
+        assert!(world.light_queue.is_empty());
+
+        assert_eq!(world.block_light(source), Some(MAX_LIGHT));
+        assert_eq!(
+            world.block_light(source + IVec3::Y * 3),
+            Some(MAX_LIGHT - 3)
+        );
+        assert_eq!(world.block_light(IVec3::new(12, 8, 6)), Some(0));
+        // Around the stone, two blocks further than the straight line would be.
+        assert_eq!(world.block_light(IVec3::new(12, 8, 5)), Some(MAX_LIGHT - 5));
+        assert_eq!(world.block_light(IVec3::new(18, 8, 8)), Some(MAX_LIGHT - 6));
+        assert_eq!(world.block_light(source + IVec3::X * 15), Some(0));
+        assert!(world.light_queue.take_touched().contains(&IVec3::X));
+
+        world.set_block_world_loaded(source, Block::air());
+        step_block_light(&mut world, usize::MAX);
+        for chunk_data in world.chunks.values() {
+            for z in 0..CHUNK_SIZE {
+                for y in 0..CHUNK_SIZE {
+                    for x in 0..CHUNK_SIZE {
+                        assert_eq!(chunk_data.light.get(IVec3::new(x, y, z)), 0);
+                    }
+                }
+            }
+        }
+    }
+}
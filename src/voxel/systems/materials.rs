@@ -0,0 +1,86 @@
+use bevy::pbr::MeshMaterial3d;
+use bevy::prelude::*;
+
+use crate::voxel::block_material::{BlockMaterial, FluidMaterial};
+use crate::voxel::material_state::{WorldMaterial, WorldMaterials};
+
+/// Draw every entity whose `WorldMaterial` was added or changed with that material.
+///
+/// Runs after the frame's world updates, so chunk meshes spawned or TNT flashes switched
+/// this frame render with their material the same frame.
+pub fn world_material_system(
+    mut commands: Commands,
+    materials: Option<Res<WorldMaterials>>,
+    changed: Query<(Entity, &WorldMaterial), Changed<WorldMaterial>>,
+) {
+    let Some(materials) = materials else {
+        return;
+    };
+    for (entity, material) in &changed {
+        let mut entity = commands.entity(entity);
+        match materials.block_material(*material) {
+            Some(handle) => {
+                entity
+                    .try_remove::<MeshMaterial3d<FluidMaterial>>()
+                    .try_insert(MeshMaterial3d(handle.clone()));
+            }
+            None => {
+                entity
+                    .try_remove::<MeshMaterial3d<BlockMaterial>>()
+                    .try_insert(MeshMaterial3d(materials.fluid.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify entities get their material when spawned and a new one when it switches,
+    /// including across the block and fluid material types.
+    #[test]
+    fn entities_follow_their_world_material() {
+        let mut app = App::new();
+        let block_materials = Assets::<BlockMaterial>::default();
+        let fluid_materials = Assets::<FluidMaterial>::default();
+        let materials = WorldMaterials {
+            opaque: block_materials.reserve_handle(),
+            transparent: block_materials.reserve_handle(),
+            emissive: block_materials.reserve_handle(),
+            fluid: fluid_materials.reserve_handle(),
+            flash: block_materials.reserve_handle(),
+        };
+        app.insert_resource(materials.clone())
+            .add_systems(Update, world_material_system);
+        let tnt = app.world_mut().spawn(WorldMaterial::Opaque).id();
+        let water = app.world_mut().spawn(WorldMaterial::Fluid).id();
+        app.update();
+        let block_material = |app: &App, entity| {
+            app.world()
+                .get::<MeshMaterial3d<BlockMaterial>>(entity)
+                .map(|material| material.0.clone())
+        };
+        assert_eq!(block_material(&app, tnt), Some(materials.opaque.clone()));
+        assert_eq!(
+            app.world()
+                .get::<MeshMaterial3d<FluidMaterial>>(water)
+                .map(|material| material.0.clone()),
+            Some(materials.fluid.clone())
+        );
+
+        *app.world_mut().get_mut::<WorldMaterial>(tnt).unwrap() = WorldMaterial::Flash;
+        *app.world_mut().get_mut::<WorldMaterial>(water).unwrap() = WorldMaterial::Emissive;
+        app.update();
+        assert_eq!(block_material(&app, tnt), Some(materials.flash.clone()));
+        assert_eq!(
+            block_material(&app, water),
+            Some(materials.emissive.clone())
+        );
+        assert!(
+            app.world()
+                .get::<MeshMaterial3d<FluidMaterial>>(water)
+                .is_none()
+        );
+    }
+}
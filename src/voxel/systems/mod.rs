@@ -1,7 +1,45 @@
+mod block_updates;
+mod diagnostics;
 mod falling;
+mod grass;
+mod growth;
 mod interaction;
+mod items;
+mod lighting;
+mod materials;
+mod random_tick;
 mod streaming;
+mod tnt;
+mod water;
 
-pub use falling::{spawn_falling_blocks_system, update_falling_blocks_system};
-pub use interaction::block_interaction_system;
-pub use streaming::chunk_loading_system;
+pub use block_updates::block_update_system;
+pub use diagnostics::{column_cache_report_system, mesh_budget_system, wireframe_toggle_system};
+pub use falling::{
+    falling_collider_system, settled_block_system, spawn_falling_blocks_system,
+    update_falling_blocks_system,
+};
+pub use growth::sapling_growth_system;
+pub use interaction::{
+    block_interaction_system, block_use_system, current_target_system, held_tool_system,
+};
+pub use items::{
+    item_pickup_system, spill_block_entities_system, throw_item_system, update_item_drops_system,
+};
+pub use lighting::{
+    TorchLightPool, block_light_system, light_flicker_system, steady_light_system,
+    torch_light_system,
+};
+pub use materials::world_material_system;
+pub use random_tick::random_tick_system;
+pub use streaming::{chunk_loading_system, chunk_remesh_system, terrain_config_reload_system};
+pub use tnt::{primed_tnt_system, tnt_ignite_system};
+pub use water::{sponge_system, water_flow_system};
+
+#[cfg(test)]
+pub(crate) use diagnostics::{MESH_BUDGET_REPORT_KEY, WIREFRAME_KEY};
+#[cfg(test)]
+pub(crate) use items::THROW_KEY;
+#[cfg(test)]
+pub(crate) use tnt::IGNITE_KEY;
+
+pub(crate) use grass::{spread_grass_tick, wither_grass_tick};
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::CHUNK_SIZE;
+
+use crate::voxel::random_tick_state::RandomTicks;
+use crate::voxel::world_state::WorldState;
+
+/// Chunk radius around the streaming center whose chunks receive random ticks.
+const RANDOM_TICK_CHUNK_RADIUS: i32 = 2;
+
+/// Return the loaded chunks near the streaming center that receive random ticks, in a fixed
+/// order so a seeded stream always picks the same cells.
+///
+/// Chunks still queued or building are skipped.
+fn ticked_chunks(world: &WorldState) -> Vec<IVec3> {
+    let mut coords: Vec<IVec3> = world
+        .chunks
+        .keys()
+        .filter(|coord| {
+            WorldState::ring_distance(world.center, **coord) <= RANDOM_TICK_CHUNK_RADIUS
+                && (coord.y - world.center.y).abs() <= RANDOM_TICK_CHUNK_RADIUS
+                && !world.in_flight.contains_key(*coord)
+                && !world.pending.contains(*coord)
+        })
+        .copied()
+        .collect();
+    coords.sort_by_key(|coord| (coord.x, coord.y, coord.z));
+    coords
+}
+
+/// Tick `per_chunk` random cells of every ticked chunk and return the chunks handlers edited.
+fn run_random_ticks(
+    world: &mut WorldState,
+    ticks: &mut RandomTicks,
+    per_chunk: u32,
+) -> HashSet<IVec3> {
+    let mut touched = HashSet::new();
+    for coord in ticked_chunks(world) {
+        for _ in 0..per_chunk {
+            let local = IVec3::new(
+                ticks.rng.below(CHUNK_SIZE),
+                ticks.rng.below(CHUNK_SIZE),
+                ticks.rng.below(CHUNK_SIZE),
+            );
+            let pos = coord * CHUNK_SIZE + local;
+            let Some(handler) = world
+                .get_block_world(pos)
+                .and_then(|block| ticks.handler(block.kind))
+            else {
+                continue;
+            };
+            touched.extend(handler(world, pos, &mut ticks.rng));
+        }
+    }
+    touched
+}
+
+/// Give random cells of the chunks around the player a chance to update.
+///
+/// Every ticked chunk gets `RandomTicks::ticks_per_chunk_per_second` ticks a second, spread
+/// over frames; the edits of one frame are remeshed together.
+pub fn random_tick_system(
+    time: Res<Time>,
+    mut ticks: ResMut<RandomTicks>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let per_chunk = ticks.due(time.delta_secs());
+    if per_chunk == 0 {
+        return;
+    }
+    let touched = run_random_ticks(&mut world, &mut ticks, per_chunk);
+    world.rebuild_touched_chunk_meshes(&mut meshes, touched);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terrain::ChunkRng;
+    use crate::voxel::block_chunk::{Block, BlockKind, Chunk};
+    use crate::voxel::world_state::ChunkData;
+
+    /// Test handler turning the ticked dirt into stone.
+    fn petrify(world: &mut WorldState, pos: IVec3, _rng: &mut ChunkRng) -> Option<IVec3> {
+        world.set_block_world_loaded(pos, Block::stone())
+    }
+
+    /// Return a world whose chunks around the origin are solid dirt, with one chunk pending.
+    fn dirt_world() -> WorldState {
+        let mut world = WorldState::new();
+        world.center = IVec3::ZERO;
+        let mut chunk = Chunk::new_empty();
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    chunk.set_block(IVec3::new(x, y, z), Block::dirt());
+                }
+            }
+        }
+        for coord in [IVec3::ZERO, IVec3::X, IVec3::NEG_Z] {
+            world.chunks.insert(
+                coord,
+                ChunkData::new(
+                    chunk.clone(),
+                    Handle::<Mesh>::default(),
+                    Entity::PLACEHOLDER,
+                ),
+            );
+        }
+        world.pending.push_back(IVec3::NEG_Z);
+        world
+    }
+
+    /// Return the cells of `world` that became stone.
+    fn stone_cells(world: &WorldState) -> Vec<IVec3> {
+        let mut cells: Vec<IVec3> = world
+            .chunks
+            .iter()
+            .flat_map(|(coord, data)| {
+                let origin = *coord * CHUNK_SIZE;
+                (0..CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE).filter_map(move |i| {
+                    let local = IVec3::new(
+                        i % CHUNK_SIZE,
+                        i / CHUNK_SIZE % CHUNK_SIZE,
+                        i / (CHUNK_SIZE * CHUNK_SIZE),
+                    );
+                    (data.chunk.get_block(local).kind == BlockKind::Stone).then_some(origin + local)
+                })
+            })
+            .collect();
+        cells.sort_by_key(|cell| (cell.x, cell.y, cell.z));
+        cells
+    }
+
+    /// Verify ticks reach registered kinds only, skip pending chunks, and repeat under a seed.
+    #[test]
+    fn random_ticks_are_seeded_and_skip_pending_chunks() {
+        let run = |seed| {
+            let mut world = dirt_world();
+            let mut ticks = RandomTicks::with_seed(seed);
+            assert!(run_random_ticks(&mut world, &mut ticks, 8).is_empty());
+            ticks.register(BlockKind::Dirt, petrify);
+            let touched = run_random_ticks(&mut world, &mut ticks, 8);
+            assert_eq!(touched, HashSet::from([IVec3::ZERO, IVec3::X]));
+            stone_cells(&world)
+        };
+        let first = run(7);
+        assert!(!first.is_empty() && first.len() <= 16);
+        assert!(first.iter().all(|cell| cell.z >= 0));
+        assert_eq!(run(7), first);
+        assert_ne!(run(8), first);
+    }
+
+    /// Verify fractional tick rates carry over between frames.
+    #[test]
+    fn due_ticks_carry_fractions() {
+        let mut ticks = RandomTicks::with_seed(0);
+        ticks.ticks_per_chunk_per_second = 3;
+        assert_eq!(ticks.due(0.25), 0);
+        assert_eq!(ticks.due(0.25), 1);
+        assert_eq!(ticks.due(1.0), 3);
+    }
+}
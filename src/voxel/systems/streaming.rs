@@ -1,14 +1,31 @@
 use bevy::prelude::*;
 use bevy::tasks::AsyncComputeTaskPool;
+use std::collections::HashSet;
 
+use crate::journal::{JournalEvent, SessionJournal};
+use crate::terrain::{ColumnCache, TerrainConfig, TerrainConfigWatch, WorldGenMode, WorldSeed};
+use crate::voxel::falling_state::FallingBlock;
+use crate::voxel::generator::generator_for_mode;
+use crate::voxel::item_state::ItemDrop;
+use crate::voxel::systems::falling::park_falling_blocks;
+use crate::voxel::systems::items::park_item_drops;
+use crate::voxel::systems::tnt::park_primed_tnt;
+use crate::voxel::tnt_state::PrimedTnt;
 use crate::voxel::world_state::WorldState;
+use crate::{REMESHES_PER_FRAME, SUN_RETINTS_PER_FRAME, UNLOADS_PER_FRAME};
 
 /// Stream chunks around camera: schedule builds, unload far chunks, apply finished results.
+#[allow(clippy::too_many_arguments)]
 pub fn chunk_loading_system(
     mut commands: Commands,
     mut world: ResMut<WorldState>,
     mut meshes: ResMut<Assets<Mesh>>,
     camera_query: Query<&GlobalTransform, With<bevy::camera::Camera3d>>,
+    time: Res<Time>,
+    mut journal: ResMut<SessionJournal>,
+    falling_query: Query<(Entity, &Transform, &FallingBlock)>,
+    item_query: Query<(Entity, &ItemDrop)>,
+    tnt_query: Query<(Entity, &PrimedTnt)>,
 ) {
     let task_pool = AsyncComputeTaskPool::get();
     let Some(center) = world.update_center_from_camera(&camera_query) else {
@@ -16,21 +33,85 @@ pub fn chunk_loading_system(
     };
 
     // Desired chunk set in a 3D window (x/z radius + vertical layers).
-    let needed = WorldState::build_needed_chunk_set(center);
+    let mut needed = world.build_needed_chunk_set(center, world.streaming_radius());
+    world.extend_needed_with_edited(center, &mut needed);
     world.sync_needed_set(needed);
 
     world.enqueue_needed_chunks();
 
-    // Unload chunks that fall outside the needed set.
-    let to_remove = world.collect_unneeded_loaded_chunks();
+    // Unload chunks that fall outside the needed set, farthest first and a bounded batch per frame.
+    let to_remove = world.collect_unneeded_loaded_chunks(UNLOADS_PER_FRAME);
+    // Dynamic entities leave with their chunk and come back when it reloads.
+    let unloading: HashSet<IVec3> = to_remove.iter().copied().collect();
+    park_falling_blocks(&mut commands, &mut world, &unloading, &falling_query);
+    park_item_drops(&mut commands, &mut world, &unloading, &item_query);
+    park_primed_tnt(&mut commands, &mut world, &unloading, &tnt_query);
     for coord in to_remove {
         world.unload_chunk(&mut commands, coord);
+        journal.record(JournalEvent::ChunkUnload { coord }, &time);
     }
+    world.step_effective_view_distance(time.delta_secs());
 
     // Start a limited number of async chunk builds per frame.
     world.spawn_chunk_build_tasks(task_pool);
 
     // Collect finished async tasks.
     let finished = world.collect_finished_chunk_tasks();
-    world.apply_finished_chunk_results(&mut commands, &mut meshes, finished);
+    let loaded = world.apply_finished_chunk_results(&mut commands, &mut meshes, finished);
+    for coord in loaded {
+        journal.record(JournalEvent::ChunkLoad { coord }, &time);
+    }
+
+    // Spread shading-change remeshes over frames instead of stalling on the whole world.
+    world.process_remesh_queue(&mut meshes, REMESHES_PER_FRAME);
+    world.process_sun_queue(&mut meshes, SUN_RETINTS_PER_FRAME);
+}
+
+/// Create class meshes chunks gained blocks for, swap in finished off-thread chunk
+/// remeshes, and start the ones edits queued this frame.
+///
+/// Runs after the frame's edits, so a chunk queued for a full rebuild usually shows its
+/// new mesh one frame later.
+pub fn chunk_remesh_system(
+    mut commands: Commands,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    world.create_missing_layer_meshes(&mut commands, &mut meshes);
+    world.apply_finished_remeshes(&mut meshes);
+    world.spawn_remesh_tasks(AsyncComputeTaskPool::get());
+}
+
+/// Reload `assets/terrain.ron` when it changes and regenerate unedited chunks with it.
+#[allow(clippy::too_many_arguments)]
+pub fn terrain_config_reload_system(
+    mut commands: Commands,
+    mut world: ResMut<WorldState>,
+    mut config: ResMut<TerrainConfig>,
+    seed: Res<WorldSeed>,
+    gen_mode: Res<WorldGenMode>,
+    mut column_cache: ResMut<ColumnCache>,
+    mut watch: Local<TerrainConfigWatch>,
+    falling_query: Query<(Entity, &Transform, &FallingBlock)>,
+    item_query: Query<(Entity, &ItemDrop)>,
+    tnt_query: Query<(Entity, &PrimedTnt)>,
+) {
+    let Some(reloaded) = watch.poll() else {
+        return;
+    };
+    if reloaded == *config {
+        return;
+    }
+    info!("Reloaded {}, regenerating chunks", TerrainConfig::PATH);
+    *config = reloaded;
+    let shaped = gen_mode.shape(reloaded);
+    world.generator = generator_for_mode(seed.0, *gen_mode, shaped);
+    column_cache.set_config(shaped);
+
+    let unedited = world.unedited_loaded_chunks();
+    let unloading: HashSet<IVec3> = unedited.iter().copied().collect();
+    park_falling_blocks(&mut commands, &mut world, &unloading, &falling_query);
+    park_item_drops(&mut commands, &mut world, &unloading, &item_query);
+    park_primed_tnt(&mut commands, &mut world, &unloading, &tnt_query);
+    world.regenerate_chunks(&mut commands, &unedited);
 }
@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::BLOCK_SIZE;
+use crate::journal::{JournalEvent, SessionJournal};
+use crate::player::{Health, Player, PlayerBody, Velocity};
+use crate::voxel::FallingPropagationQueue;
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::interaction_state::CurrentTarget;
+use crate::voxel::material_state::WorldMaterial;
+use crate::voxel::tnt_state::{Explosion, PrimedTnt, TntConfig, blast_hit};
+use crate::voxel::world_state::{ChunkEntityRecord, WorldState};
+
+/// Key that primes the TNT block under the crosshair.
+pub(crate) const IGNITE_KEY: KeyCode = KeyCode::KeyF;
+
+/// Prime the aimed-at TNT block when the ignite key is pressed.
+///
+/// The block leaves the chunk at once and burns as a flashing entity in its cell, so the
+/// blocks it rested on or held up react as if it had been broken.
+pub fn tnt_ignite_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    target: Res<CurrentTarget>,
+    config: Res<TntConfig>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut falling_queue: ResMut<FallingPropagationQueue>,
+) {
+    if !keys.just_pressed(IGNITE_KEY) {
+        return;
+    }
+    let Some(cell) = target.hit else {
+        return;
+    };
+    if world
+        .get_block_world(cell)
+        .is_none_or(|block| block.kind != BlockKind::Tnt)
+    {
+        return;
+    }
+    let Some(chunk_coord) = world.set_block_world_loaded(cell, Block::air()) else {
+        return;
+    };
+    world.rebuild_touched_chunk_meshes(&mut meshes, [chunk_coord]);
+    falling_queue.enqueue_with_neighbors(cell);
+    PrimedTnt::new(cell, *config).spawn(&mut commands, &mut meshes, &mut world);
+}
+
+/// Despawn primed TNT owned by chunks about to unload and park it with those chunks.
+///
+/// Parked fuses stop burning until their chunk loads again.
+pub(crate) fn park_primed_tnt(
+    commands: &mut Commands,
+    world: &mut WorldState,
+    unloading: &HashSet<IVec3>,
+    query: &Query<(Entity, &PrimedTnt)>,
+) {
+    for (entity, tnt) in query {
+        let owner = WorldState::entity_owner_chunk(Block::world_translation(tnt.cell));
+        if !unloading.contains(&owner) {
+            continue;
+        }
+        world.park_chunk_entity(
+            owner,
+            ChunkEntityRecord::PrimedTnt {
+                cell: tnt.cell,
+                fuse_left: tnt.fuse_left,
+                blast_radius: tnt.blast_radius,
+            },
+        );
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Burn primed TNT fuses, flash their material, and explode those that run out.
+///
+/// Each explosion is journaled and announced as an `Explosion` message, and players within
+/// reach take damage and knockback that fall off with their distance from the blast.
+#[allow(clippy::too_many_arguments)]
+pub fn primed_tnt_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut falling_queue: ResMut<FallingPropagationQueue>,
+    mut journal: ResMut<SessionJournal>,
+    mut explosions: MessageWriter<Explosion>,
+    mut query: Query<(Entity, &mut PrimedTnt, &mut WorldMaterial)>,
+    mut players: Query<(&Transform, &mut Velocity, &mut Player, &mut Health), With<PlayerBody>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut tnt, mut material) in &mut query {
+        if tnt.burn(dt) {
+            let removed =
+                world.explode(&mut meshes, &mut falling_queue, tnt.cell, tnt.blast_radius);
+            journal.record(
+                JournalEvent::Explosion {
+                    pos: tnt.cell,
+                    removed,
+                },
+                &time,
+            );
+            let center = Block::world_translation(tnt.cell) + Vec3::splat(0.5 * BLOCK_SIZE);
+            explosions.write(Explosion {
+                center,
+                radius: tnt.blast_radius,
+            });
+            for (transform, mut velocity, mut player, mut health) in &mut players {
+                let Some(hit) = blast_hit(center, transform.translation, tnt.blast_radius) else {
+                    continue;
+                };
+                velocity.0 += hit.knockback;
+                player.on_ground = false;
+                health.apply_damage(hit.damage);
+            }
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let shown = if tnt.flashing() {
+            WorldMaterial::Flash
+        } else {
+            WorldMaterial::Opaque
+        };
+        material.set_if_neq(shown);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify a primed fuse alternates its flash and explodes once the fuse has burnt down.
+    #[test]
+    fn primed_tnt_flashes_then_explodes_after_fuse() {
+        let config = TntConfig::default();
+        let mut tnt = PrimedTnt::new(IVec3::ZERO, config);
+        assert!(tnt.flashing());
+        assert!(!tnt.burn(0.2));
+        assert!(!tnt.flashing());
+        assert!(!tnt.burn(0.25));
+        assert!(tnt.flashing());
+
+        let mut burnt = 0.45;
+        while !tnt.burn(0.1) {
+            burnt += 0.1;
+            assert!(burnt < config.fuse_secs + 0.1, "fuse never ran out");
+        }
+        assert!(burnt + 0.1 >= config.fuse_secs - 1e-3);
+    }
+
+    /// Verify primed TNT unloads with its owner chunk and respawns with its fuse on reload.
+    #[test]
+    fn primed_tnt_round_trips_through_chunk_unload() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        use crate::{CHUNK_SIZE, MAX_CHUNK_LAYER};
+
+        let mut ecs = World::new();
+        ecs.insert_resource(WorldState::new());
+        ecs.init_resource::<Assets<Mesh>>();
+        let coord = IVec3::new(0, MAX_CHUNK_LAYER, 0);
+        let cell = IVec3::new(4, MAX_CHUNK_LAYER * CHUNK_SIZE + 5, 4);
+        let mut primed = PrimedTnt::new(cell, TntConfig::default());
+        primed.burn(1.0);
+        let fuse_left = primed.fuse_left;
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+                PrimedTnt {
+                    cell,
+                    fuse_left,
+                    blast_radius: primed.blast_radius,
+                }
+                .spawn(&mut commands, &mut meshes, &mut world);
+            },
+        )
+        .unwrap();
+
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut world: ResMut<WorldState>,
+                  query: Query<(Entity, &PrimedTnt)>| {
+                park_primed_tnt(&mut commands, &mut world, &HashSet::from([coord]), &query);
+                world.unload_chunk(&mut commands, coord);
+            },
+        )
+        .unwrap();
+        assert_eq!(ecs.query::<&PrimedTnt>().iter(&ecs).count(), 0);
+
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut world: ResMut<WorldState>| {
+                world.ensure_chunk(&mut commands, &mut meshes, coord);
+            },
+        )
+        .unwrap();
+        let restored = ecs.query::<&PrimedTnt>().single(&ecs).unwrap();
+        assert_eq!(*restored, primed);
+        assert!(ecs.resource::<WorldState>().chunk_entities.is_empty());
+    }
+}
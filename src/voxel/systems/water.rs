@@ -0,0 +1,338 @@
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+use crate::voxel::block_chunk::{Block, BlockKind};
+use crate::voxel::water_state::{
+    WATER_FALLING_LEVEL, WATER_SOURCE_LEVEL, WATER_SPREAD_LIMIT, WaterTickTimer,
+};
+use crate::voxel::world_state::WorldState;
+
+/// Max queued positions evaluated per water tick, so a broken dam spreads over several ticks.
+const MAX_WATER_UPDATES_PER_TICK: usize = 256;
+/// Horizontal face-neighbor offsets water spreads along.
+const HORIZONTAL_OFFSETS: [IVec3; 4] = [IVec3::X, IVec3::NEG_X, IVec3::Z, IVec3::NEG_Z];
+/// Face-neighbor steps through connected water a sponge reaches.
+const SPONGE_RADIUS: u32 = 4;
+/// Max queued sponges soaking up water per frame, so a row of placed sponges spreads its
+/// work over several frames.
+const MAX_SPONGES_PER_FRAME: usize = 4;
+/// Face-neighbor offsets a sponge follows connected water along.
+const FACE_OFFSETS: [IVec3; 6] = [
+    IVec3::X,
+    IVec3::NEG_X,
+    IVec3::Y,
+    IVec3::NEG_Y,
+    IVec3::Z,
+    IVec3::NEG_Z,
+];
+
+/// Return the flow level of the block at `pos` if it is water.
+fn flow_level(world: &WorldState, pos: IVec3) -> Option<u8> {
+    world
+        .get_block_world(pos)
+        .filter(|block| block.kind == BlockKind::Water)?;
+    world.get_state_world(pos)
+}
+
+/// Return whether water at `pos` rests on something and so spreads sideways.
+///
+/// Water above air or other water keeps falling instead; unloaded cells below hold it.
+fn spreads_sideways(world: &WorldState, pos: IVec3) -> bool {
+    world
+        .get_block_world(pos - IVec3::Y)
+        .is_none_or(|below| !below.is_air() && below.kind != BlockKind::Water)
+}
+
+/// Return the flow level the water feeding `pos` gives it, or `None` when nothing feeds it.
+fn fed_level(world: &WorldState, pos: IVec3) -> Option<u8> {
+    if world
+        .get_block_world(pos + IVec3::Y)
+        .is_some_and(|above| above.kind == BlockKind::Water)
+    {
+        return Some(WATER_FALLING_LEVEL);
+    }
+    HORIZONTAL_OFFSETS
+        .into_iter()
+        .filter_map(|offset| {
+            let neighbor = pos + offset;
+            let level = flow_level(world, neighbor)?;
+            (level < WATER_SPREAD_LIMIT && spreads_sideways(world, neighbor)).then_some(level + 1)
+        })
+        .min()
+}
+
+/// Return what the cell at `pos` should become this tick, or `None` to leave it.
+///
+/// Only air and flowing water change: sources and other blocks hold their cell.
+fn next_water_block(world: &WorldState, pos: IVec3) -> Option<Block> {
+    let block = world.get_block_world(pos)?;
+    let flowing = flow_level(world, pos).is_some_and(|level| level != WATER_SOURCE_LEVEL);
+    if !block.is_air() && !flowing {
+        return None;
+    }
+    let next = fed_level(world, pos).map_or(Block::air(), |level| Block::water().with_state(level));
+    (next != block).then_some(next)
+}
+
+/// Run one water tick and return the chunks it touched.
+///
+/// Evaluates only the cells queued before the tick, up to `MAX_WATER_UPDATES_PER_TICK`;
+/// writes go through the regular block writes, which queue the next ring for later ticks.
+fn step_water(world: &mut WorldState) -> HashSet<IVec3> {
+    let batch = world.water_queue.len().min(MAX_WATER_UPDATES_PER_TICK);
+    let mut touched: HashSet<IVec3> = HashSet::new();
+    for _ in 0..batch {
+        let Some(pos) = world.water_queue.pop() else {
+            break;
+        };
+        let Some(next) = next_water_block(world, pos) else {
+            continue;
+        };
+        // Flowing water that only changes level keeps its block and rewrites the level.
+        let written = if world
+            .get_block_world(pos)
+            .is_some_and(|block| block.kind == next.kind)
+        {
+            world.set_state_world_loaded(pos, next.state)
+        } else {
+            world.set_block_world_loaded(pos, next)
+        };
+        touched.extend(written);
+    }
+    touched
+}
+
+/// Return the water cells connected to `sponge` within `SPONGE_RADIUS` face-neighbor steps.
+fn water_near_sponge(world: &WorldState, sponge: IVec3) -> Vec<IVec3> {
+    let mut seen = HashSet::from([sponge]);
+    let mut frontier = VecDeque::from([(sponge, 0)]);
+    let mut cells = Vec::new();
+    while let Some((pos, steps)) = frontier.pop_front() {
+        if steps == SPONGE_RADIUS {
+            continue;
+        }
+        for offset in FACE_OFFSETS {
+            let neighbor = pos + offset;
+            if seen.insert(neighbor) && flow_level(world, neighbor).is_some() {
+                cells.push(neighbor);
+                frontier.push_back((neighbor, steps + 1));
+            }
+        }
+    }
+    cells
+}
+
+/// Drain the water around the dry sponge at `sponge` and soak it, returning the touched chunks.
+///
+/// A sponge that finds no water stays dry; one already wet or no longer there does nothing.
+fn absorb_water(world: &mut WorldState, sponge: IVec3) -> HashSet<IVec3> {
+    let mut touched = HashSet::new();
+    let Some(block) = world
+        .get_block_world(sponge)
+        .filter(|block| block.kind == BlockKind::Sponge && !block.is_wet_sponge())
+    else {
+        return touched;
+    };
+    let cells = water_near_sponge(world, sponge);
+    if cells.is_empty() {
+        return touched;
+    }
+    for cell in cells {
+        touched.extend(world.set_block_world_loaded(cell, Block::air()));
+    }
+    touched.extend(world.set_block_world_loaded(sponge, block.soaked()));
+    touched
+}
+
+/// Let up to `MAX_SPONGES_PER_FRAME` queued sponges soak up water and return the touched chunks.
+fn soak_sponges(world: &mut WorldState) -> HashSet<IVec3> {
+    let mut touched = HashSet::new();
+    for _ in 0..MAX_SPONGES_PER_FRAME {
+        let Some(sponge) = world.sponge_queue.pop_front() else {
+            break;
+        };
+        touched.extend(absorb_water(world, sponge));
+    }
+    touched
+}
+
+/// Drain the water around newly placed sponges.
+///
+/// Every chunk the sponges of one frame touched is remeshed once.
+pub fn sponge_system(mut world: ResMut<WorldState>, mut meshes: ResMut<Assets<Mesh>>) {
+    if world.sponge_queue.is_empty() {
+        return;
+    }
+    let touched = soak_sponges(&mut world);
+    world.rebuild_touched_chunk_meshes(&mut meshes, touched);
+}
+
+/// Spread and recede flowing water on each `WaterTickTimer` tick.
+///
+/// Every block edit queues its cell and neighbors, so water reacts to breaking, placing,
+/// and landing sand alike. Each touched chunk is remeshed once per tick.
+pub fn water_flow_system(
+    time: Res<Time>,
+    mut timer: ResMut<WaterTickTimer>,
+    mut world: ResMut<WorldState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    let touched = step_water(&mut world);
+    world.rebuild_touched_chunk_meshes(&mut meshes, touched);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::Chunk;
+    use crate::voxel::world_state::ChunkData;
+
+    /// Build a world of one loaded chunk at the origin with a stone floor at `y = 0`.
+    fn floored_world() -> WorldState {
+        let mut world = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        for x in 0..crate::CHUNK_SIZE {
+            for z in 0..crate::CHUNK_SIZE {
+                chunk.set_block(IVec3::new(x, 0, z), Block::stone());
+            }
+        }
+        world.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        world
+    }
+
+    /// Run water ticks until nothing is queued.
+    fn settle(world: &mut WorldState) {
+        for _ in 0..200 {
+            if world.water_queue.len() == 0 {
+                return;
+            }
+            step_water(world);
+        }
+        panic!("water never settled");
+    }
+
+    /// Return the flow level at `pos`, or `None` when it holds no water.
+    fn level_at(world: &WorldState, pos: IVec3) -> Option<u8> {
+        flow_level(world, pos)
+    }
+
+    /// Verify a source spreads one level per cell along the floor and stops at the limit,
+    /// then recedes entirely once the source is removed.
+    #[test]
+    fn source_spreads_to_limit_and_recedes_without_it() {
+        let mut world = floored_world();
+        let source = IVec3::new(8, 1, 8);
+        world.set_block_world_loaded(source, Block::water());
+        settle(&mut world);
+
+        assert_eq!(level_at(&world, source), Some(WATER_SOURCE_LEVEL));
+        for distance in 1..=i32::from(WATER_SPREAD_LIMIT) {
+            assert_eq!(
+                level_at(&world, source + IVec3::new(-distance, 0, 0)),
+                Some(distance as u8)
+            );
+        }
+        assert_eq!(level_at(&world, source + IVec3::new(-8, 0, 0)), None);
+        assert_eq!(level_at(&world, source + IVec3::new(4, 0, 4)), None);
+        assert_eq!(level_at(&world, source + IVec3::new(3, 0, 3)), Some(6));
+        assert_eq!(level_at(&world, source + IVec3::Y), None);
+
+        world.set_block_world_loaded(source, Block::air());
+        settle(&mut world);
+        let chunk = &world.chunks[&IVec3::ZERO].chunk;
+        for x in 0..crate::CHUNK_SIZE {
+            for z in 0..crate::CHUNK_SIZE {
+                assert!(chunk.get_block(IVec3::new(x, 1, z)).is_air());
+            }
+        }
+    }
+
+    /// Verify water poured off a pillar falls to the floor and spreads afresh from there.
+    #[test]
+    fn water_falls_down_and_spreads_where_it_lands() {
+        let mut world = floored_world();
+        for y in 1..=4 {
+            world.set_block_world_loaded(IVec3::new(8, y, 8), Block::stone());
+        }
+        let source = IVec3::new(8, 5, 8);
+        world.set_block_world_loaded(source, Block::water());
+        settle(&mut world);
+
+        for y in 1..5 {
+            assert_eq!(
+                level_at(&world, IVec3::new(9, y, 8)),
+                Some(WATER_FALLING_LEVEL)
+            );
+        }
+        assert_eq!(level_at(&world, IVec3::new(9, 5, 8)), Some(1));
+        assert_eq!(level_at(&world, IVec3::new(10, 5, 8)), None);
+        assert_eq!(level_at(&world, IVec3::new(10, 1, 8)), Some(2));
+        assert_eq!(level_at(&world, IVec3::new(9, 1, 9)), Some(2));
+    }
+
+    /// Verify a placed sponge drains the connected water in its reach, turns wet, and a wet
+    /// sponge never absorbs again.
+    #[test]
+    fn sponge_absorbs_nearby_water_once() {
+        let mut world = floored_world();
+        for x in 0..crate::CHUNK_SIZE {
+            for z in 0..crate::CHUNK_SIZE {
+                world.set_block_world_loaded(IVec3::new(x, 1, z), Block::water());
+            }
+        }
+        let sponge = IVec3::new(8, 1, 8);
+        world.set_block_world_loaded(sponge, Block::sponge());
+        world.sponge_queue.push_back(sponge);
+
+        assert_eq!(soak_sponges(&mut world), HashSet::from([IVec3::ZERO]));
+        assert!(world.get_block_world(sponge).unwrap().is_wet_sponge());
+        assert_eq!(level_at(&world, sponge + IVec3::new(4, 0, 0)), None);
+        assert_eq!(level_at(&world, sponge + IVec3::new(2, 0, -2)), None);
+        assert_eq!(
+            level_at(&world, sponge + IVec3::new(5, 0, 0)),
+            Some(WATER_SOURCE_LEVEL)
+        );
+        assert_eq!(
+            level_at(&world, sponge + IVec3::new(3, 0, 2)),
+            Some(WATER_SOURCE_LEVEL)
+        );
+
+        world.set_block_world_loaded(sponge + IVec3::X, Block::water());
+        world.sponge_queue.push_back(sponge);
+        assert!(soak_sponges(&mut world).is_empty());
+        assert_eq!(
+            level_at(&world, sponge + IVec3::X),
+            Some(WATER_SOURCE_LEVEL)
+        );
+    }
+
+    /// Verify one frame soaks at most `MAX_SPONGES_PER_FRAME` queued sponges.
+    #[test]
+    fn sponge_pass_caps_its_work() {
+        let mut world = floored_world();
+        for x in 0..=MAX_SPONGES_PER_FRAME as i32 {
+            world.sponge_queue.push_back(IVec3::new(x, 1, 0));
+        }
+        soak_sponges(&mut world);
+        assert_eq!(world.sponge_queue.len(), 1);
+    }
+
+    /// Verify one tick evaluates at most `MAX_WATER_UPDATES_PER_TICK` queued cells.
+    #[test]
+    fn water_tick_caps_its_work() {
+        let mut world = floored_world();
+        for i in 0..100 {
+            world.water_queue.enqueue(IVec3::new(i, 40, 0));
+            world.water_queue.enqueue(IVec3::new(i, 41, 0));
+            world.water_queue.enqueue(IVec3::new(i, 42, 0));
+        }
+        step_water(&mut world);
+        assert_eq!(world.water_queue.len(), 300 - MAX_WATER_UPDATES_PER_TICK);
+    }
+}
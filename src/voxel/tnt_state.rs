@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use crate::BLOCK_SIZE;
+
+use crate::voxel::block_chunk::Block;
+use crate::voxel::material_state::WorldMaterial;
+use crate::voxel::world_state::WorldState;
+
+/// Seconds a primed TNT block spends between each swap of its flash material.
+const TNT_FLASH_PERIOD_SECS: f32 = 0.25;
+/// Reach of the blast on players, as a multiple of the carved radius.
+const BLAST_REACH_FACTOR: f32 = 2.0;
+/// Damage a player takes at the blast center, falling off linearly to zero at the reach.
+const BLAST_MAX_DAMAGE: f32 = 16.0;
+/// Speed a player is flung with at the blast center, in world units per second.
+const BLAST_MAX_KNOCKBACK: f32 = 24.0;
+/// Upward share mixed into the knockback direction so blasts lift players off the ground.
+const BLAST_KNOCKBACK_LIFT: f32 = 0.5;
+
+/// Damage and knockback one blast deals to a player.
+#[derive(Debug, PartialEq)]
+pub(crate) struct BlastHit {
+    /// Hit points to take off.
+    pub(crate) damage: f32,
+    /// Velocity to add, in world units per second.
+    pub(crate) knockback: Vec3,
+}
+
+/// Return the share of full blast strength felt `distance` blocks from a blast that
+/// carves `radius` blocks: `1` at the center, falling linearly to `0` at the reach.
+pub(crate) fn blast_falloff(distance: f32, radius: f32) -> f32 {
+    let reach = radius * BLAST_REACH_FACTOR;
+    if reach <= 0.0 {
+        return 0.0;
+    }
+    (1.0 - distance / reach).clamp(0.0, 1.0)
+}
+
+/// Return what a blast of `radius` blocks centered on `center` does to a player whose body
+/// is centered on `body`, or `None` when the player is out of reach.
+pub(crate) fn blast_hit(center: Vec3, body: Vec3, radius: f32) -> Option<BlastHit> {
+    let offset = body - center;
+    let falloff = blast_falloff(offset.length() / BLOCK_SIZE, radius);
+    if falloff <= 0.0 {
+        return None;
+    }
+    let direction = (offset.normalize_or(Vec3::Y) + Vec3::Y * BLAST_KNOCKBACK_LIFT).normalize();
+    Some(BlastHit {
+        damage: BLAST_MAX_DAMAGE * falloff,
+        knockback: direction * (BLAST_MAX_KNOCKBACK * falloff),
+    })
+}
+
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+/// Sent when primed TNT explodes, for cosmetic reactions such as camera shake and dust.
+pub struct Explosion {
+    /// World-space center of the blast.
+    pub center: Vec3,
+    /// Radius of the carved sphere, in blocks.
+    pub radius: f32,
+}
+
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+/// Fuse length and blast size of primed TNT.
+pub struct TntConfig {
+    /// Seconds between priming and the explosion.
+    pub fuse_secs: f32,
+    /// Radius of the carved sphere, in blocks from the TNT cell.
+    pub blast_radius: f32,
+}
+
+impl Default for TntConfig {
+    fn default() -> Self {
+        Self {
+            fuse_secs: 3.0,
+            blast_radius: 4.0,
+        }
+    }
+}
+
+#[derive(Component, Debug, PartialEq)]
+/// Runtime state for a TNT block whose fuse is burning.
+pub struct PrimedTnt {
+    /// World cell the TNT was primed in and explodes from.
+    pub(crate) cell: IVec3,
+    /// Seconds of fuse left before the explosion.
+    pub(crate) fuse_left: f32,
+    /// Radius of the carved sphere, in blocks.
+    pub(crate) blast_radius: f32,
+}
+
+impl PrimedTnt {
+    /// Build primed state for TNT at `cell` with the configured fuse and blast.
+    pub(crate) fn new(cell: IVec3, config: TntConfig) -> Self {
+        Self {
+            cell,
+            fuse_left: config.fuse_secs,
+            blast_radius: config.blast_radius,
+        }
+    }
+
+    /// Spawn a primed TNT entity in its cell, sharing the world's cached block mesh.
+    pub(crate) fn spawn(
+        self,
+        commands: &mut Commands,
+        meshes: &mut Assets<Mesh>,
+        world: &mut WorldState,
+    ) {
+        let mesh = world.block_meshes.get_or_build(meshes, Block::tnt());
+        commands.spawn((
+            bevy::mesh::Mesh3d(mesh),
+            WorldMaterial::Flash,
+            Transform::from_translation(Block::world_translation(self.cell)),
+            self,
+            Name::new("PrimedTnt"),
+        ));
+    }
+
+    /// Burn `dt` seconds of fuse and return whether the TNT explodes now.
+    pub(crate) fn burn(&mut self, dt: f32) -> bool {
+        self.fuse_left -= dt;
+        self.fuse_left <= 0.0
+    }
+
+    /// Return whether the flash material is showing, alternating every flash period.
+    pub(crate) fn flashing(&self) -> bool {
+        ((self.fuse_left.max(0.0) / TNT_FLASH_PERIOD_SECS) as u32).is_multiple_of(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify blast damage and knockback fall off linearly with distance and vanish past
+    /// the reach, pushing players away from the center.
+    #[test]
+    fn blast_damage_falls_off_with_distance() {
+        let radius = 4.0;
+        let center = Vec3::splat(0.5 * BLOCK_SIZE);
+        let at = |blocks: f32| blast_hit(center, center + Vec3::X * blocks * BLOCK_SIZE, radius);
+
+        assert_eq!(blast_falloff(0.0, radius), 1.0);
+        let close = at(1.0).unwrap();
+        let mid = at(radius).unwrap();
+        let far = at(1.5 * radius).unwrap();
+        assert!(close.damage > mid.damage && mid.damage > far.damage);
+        assert!((mid.damage - 0.5 * BLAST_MAX_DAMAGE).abs() < 1e-4);
+        assert!((mid.knockback.length() - 0.5 * BLAST_MAX_KNOCKBACK).abs() < 1e-4);
+        assert!(mid.knockback.x > 0.0 && mid.knockback.y > 0.0);
+        assert!(at(-radius).unwrap().knockback.x < 0.0);
+        assert_eq!(at(2.0 * radius), None);
+        assert_eq!(at(3.0 * radius), None);
+    }
+}
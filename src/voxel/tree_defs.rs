@@ -0,0 +1,261 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+use crate::terrain::Biome;
+use crate::voxel::block_chunk::{Block, BlockKind};
+
+/// Farthest a schematic may reach horizontally from its trunk, in blocks, so chunk
+/// generation knows how far outside a chunk to look for trees reaching into it.
+pub(crate) const MAX_TREE_REACH: i32 = 2;
+
+/// Tree shape grown by generation and saplings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum TreeKind {
+    /// Round broadleaf crown on a short trunk, sparse over the plains.
+    Oak,
+    /// Tall trunk with a narrow tiered crown, on mountain slopes.
+    Conifer,
+    /// Bare cactus column rooted in desert sand.
+    Cactus,
+}
+
+impl TreeKind {
+    /// Every tree kind, each of which `trees.ron` must define.
+    pub const ALL: [Self; 3] = [Self::Oak, Self::Conifer, Self::Cactus];
+
+    /// Return the tree generation grows in `biome`.
+    pub fn for_biome(biome: Biome) -> Self {
+        match biome {
+            Biome::Plains => Self::Oak,
+            Biome::Mountains => Self::Conifer,
+            Biome::Desert => Self::Cactus,
+        }
+    }
+}
+
+/// One tree schematic as written in `trees.ron`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TreeSpec {
+    /// Surface blocks the tree roots on.
+    soil: Vec<BlockKind>,
+    /// Block the soil under the trunk turns into; unset keeps the soil.
+    ground: Option<BlockKind>,
+    /// Block stacked into the trunk.
+    trunk: BlockKind,
+    /// Shortest and tallest trunk, in blocks above the ground.
+    trunk_height: (i32, i32),
+    /// Height of the lowest canopy layer relative to the trunk top.
+    #[serde(default)]
+    canopy_offset: i32,
+    /// Canopy layers from the bottom up, each rows along +Z of cells along +X.
+    #[serde(default)]
+    canopy: Vec<Vec<String>>,
+}
+
+/// Resolved tree schematic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeTemplate {
+    /// Surface blocks the tree roots on.
+    pub soil: Vec<BlockKind>,
+    /// Block the soil under the trunk turns into, if it changes.
+    pub ground: Option<Block>,
+    /// Block stacked into the trunk.
+    pub trunk: Block,
+    /// Shortest and tallest trunk, in blocks.
+    pub trunk_height: RangeInclusive<i32>,
+    /// Leaves cells as offsets from the trunk top.
+    pub canopy: Vec<IVec3>,
+}
+
+impl TreeTemplate {
+    /// Return the trunk height for `pick` in `[0, 1)` spread over the trunk range.
+    pub fn trunk_height_for(&self, pick: f32) -> i32 {
+        let (low, high) = (*self.trunk_height.start(), *self.trunk_height.end());
+        let span = high - low + 1;
+        low + ((pick * span as f32) as i32).clamp(0, span - 1)
+    }
+
+    /// Return the blocks of this tree rooted on world `ground`: the ground block when it
+    /// changes, the canopy leaves, then the trunk.
+    pub(crate) fn blocks(&self, ground: IVec3, trunk_height: i32) -> Vec<(IVec3, Block)> {
+        let top = ground + IVec3::Y * trunk_height;
+        let mut blocks: Vec<(IVec3, Block)> = self
+            .ground
+            .map(|block| (ground, block))
+            .into_iter()
+            .collect();
+        blocks.extend(
+            self.canopy
+                .iter()
+                .map(|offset| (top + *offset, Block::leaves())),
+        );
+        blocks.extend((1..=trunk_height).map(|dy| (ground + IVec3::Y * dy, self.trunk)));
+        blocks
+    }
+
+    /// Return whether tree block `block` may replace `existing`: the trunk fills air and
+    /// replaces leaves and the sapling it grows from, leaves fill air only, and the ground
+    /// always changes.
+    pub(crate) fn fits(&self, block: Block, existing: Block) -> bool {
+        if block == self.trunk {
+            matches!(
+                existing.kind,
+                BlockKind::Air | BlockKind::Leaves | BlockKind::Sapling
+            )
+        } else if block.kind == BlockKind::Leaves {
+            existing.is_air()
+        } else {
+            true
+        }
+    }
+}
+
+/// Tree schematics loaded from `assets/trees.ron`, indexed by `TreeKind`.
+///
+/// Read once per process and shared like the block registry, so chunk-build tasks can
+/// stamp trees from any thread.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TreeRegistry {
+    /// One template per kind, in `TreeKind::ALL` order.
+    templates: &'static [TreeTemplate],
+}
+
+/// Process-wide registry, loaded on first use.
+static REGISTRY: OnceLock<TreeRegistry> = OnceLock::new();
+
+impl TreeRegistry {
+    /// Tree schematic file, one RON map from tree kind to its schematic.
+    pub const PATH: &'static str = "assets/trees.ron";
+
+    /// Return the shared registry, loading it from `PATH` on first use.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the file is missing or invalid, since terrain cannot be generated
+    /// without its trees.
+    pub fn global() -> Self {
+        *REGISTRY.get_or_init(|| {
+            let text = fs::read_to_string(Self::PATH)
+                .unwrap_or_else(|err| panic!("Cannot read {}: {err}", Self::PATH));
+            Self::parse(&text).unwrap_or_else(|err| panic!("Invalid {}: {err}", Self::PATH))
+        })
+    }
+
+    /// Return the template of `kind`.
+    pub fn template(&self, kind: TreeKind) -> &'static TreeTemplate {
+        &self.templates[kind as usize]
+    }
+
+    /// Parse and resolve a RON tree map; every `TreeKind` must be defined.
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut specs: HashMap<TreeKind, TreeSpec> =
+            ron::from_str(text).map_err(|err| err.to_string())?;
+        let templates = TreeKind::ALL
+            .iter()
+            .map(|kind| {
+                let spec = specs
+                    .remove(kind)
+                    .ok_or_else(|| format!("{kind:?} is not defined"))?;
+                Self::resolve(spec).map_err(|err| format!("{kind:?}: {err}"))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(Self {
+            templates: templates.leak(),
+        })
+    }
+
+    /// Resolve one schematic, reading `#` canopy cells centered on the trunk.
+    fn resolve(spec: TreeSpec) -> Result<TreeTemplate, String> {
+        let (low, high) = spec.trunk_height;
+        if low < 1 || high < low {
+            return Err(format!("bad trunk height ({low}, {high})"));
+        }
+        let mut canopy = Vec::new();
+        for (layer, rows) in spec.canopy.iter().enumerate() {
+            let side = rows.len() as i32;
+            let reach = side / 2;
+            if side % 2 == 0 || reach > MAX_TREE_REACH {
+                return Err(format!(
+                    "canopy layer {layer} is {side} rows; use an odd count up to {}",
+                    2 * MAX_TREE_REACH + 1
+                ));
+            }
+            for (row, cells) in rows.iter().enumerate() {
+                if cells.chars().count() as i32 != side {
+                    return Err(format!("canopy layer {layer} row {row} is not {side} wide"));
+                }
+                for (column, cell) in cells.chars().enumerate() {
+                    let offset = IVec3::new(
+                        column as i32 - reach,
+                        spec.canopy_offset + layer as i32,
+                        row as i32 - reach,
+                    );
+                    match cell {
+                        '#' => canopy.push(offset),
+                        '.' => {}
+                        other => return Err(format!("unknown canopy cell '{other}'")),
+                    }
+                }
+            }
+        }
+        Ok(TreeTemplate {
+            soil: spec.soil,
+            ground: spec.ground.map(Block::of_kind),
+            // Stand the trunk upright, as if placed on the ground.
+            trunk: Block::of_kind(spec.trunk).oriented_for_placement(Vec3::NEG_Z, IVec3::Y),
+            trunk_height: low..=high,
+            canopy,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify the shipped schematics: oaks round, conifers taller with a narrower crown,
+    /// and cacti bare columns on sand.
+    #[test]
+    fn shipped_trees_differ_by_biome() {
+        let registry = TreeRegistry::global();
+        let oak = registry.template(TreeKind::Oak);
+        let conifer = registry.template(TreeKind::Conifer);
+        let cactus = registry.template(TreeKind::Cactus);
+        let widest = |template: &TreeTemplate| {
+            let mut per_layer: HashMap<i32, usize> = HashMap::new();
+            for offset in &template.canopy {
+                *per_layer.entry(offset.y).or_default() += 1;
+            }
+            per_layer.into_values().max().unwrap_or(0)
+        };
+
+        assert!(conifer.trunk_height.end() > oak.trunk_height.end());
+        assert!(widest(conifer) < widest(oak));
+        assert_eq!(oak.ground, Some(Block::dirt()));
+        assert!(cactus.canopy.is_empty());
+        assert_eq!(cactus.soil, [BlockKind::Sand]);
+        assert_eq!(cactus.trunk, Block::cactus());
+
+        let blocks = oak.blocks(IVec3::ZERO, 4);
+        assert_eq!(blocks[0], (IVec3::ZERO, Block::dirt()));
+        assert_eq!(blocks.last(), Some(&(IVec3::Y * 4, Block::log())));
+        assert_eq!(oak.trunk_height_for(0.0), *oak.trunk_height.start());
+        assert_eq!(oak.trunk_height_for(0.999), *oak.trunk_height.end());
+    }
+
+    /// Verify malformed schematics are rejected with the tree and problem named.
+    #[test]
+    fn registry_rejects_bad_schematics() {
+        let shipped = fs::read_to_string(TreeRegistry::PATH).unwrap();
+        let err = TreeRegistry::parse(&shipped.replacen("\"#####\"", "\"##x##\"", 1)).unwrap_err();
+        assert!(err.contains("Oak") && err.contains("'x'"), "{err}");
+        let err = TreeRegistry::parse("{ Oak: (soil: [], trunk: Log, trunk_height: (4, 6)) }")
+            .unwrap_err();
+        assert!(err.contains("Conifer is not defined"), "{err}");
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+/// Flow level (block state) of a water source; flowing water counts up from it.
+pub(crate) const WATER_SOURCE_LEVEL: u8 = 0;
+/// Flow level of water fed from above, so a waterfall spreads afresh where it lands.
+pub(crate) const WATER_FALLING_LEVEL: u8 = 1;
+/// Highest flow level; water at this level spreads no further sideways.
+pub(crate) const WATER_SPREAD_LIMIT: u8 = 7;
+/// Seconds between two water flow ticks.
+const WATER_TICK_SECS: f32 = 0.25;
+
+#[derive(Default)]
+/// Queue of world positions whose water flow needs re-evaluation.
+pub struct WaterFlowQueue {
+    /// Pending positions to process.
+    pending: VecDeque<IVec3>,
+    /// Set used to deduplicate pending positions.
+    scheduled: HashSet<IVec3>,
+}
+
+impl WaterFlowQueue {
+    /// Enqueue one world block position for re-evaluation.
+    pub fn enqueue(&mut self, pos: IVec3) {
+        if self.scheduled.insert(pos) {
+            self.pending.push_back(pos);
+        }
+    }
+
+    /// Enqueue one world position and all six face-neighbors.
+    ///
+    /// Unlike falling support, flow reaches in every direction: a changed cell can feed or
+    /// starve the cells beside and below it, and start or stop a waterfall above it.
+    pub fn enqueue_with_neighbors(&mut self, pos: IVec3) {
+        self.enqueue(pos);
+        for offset in [
+            IVec3::X,
+            IVec3::NEG_X,
+            IVec3::Y,
+            IVec3::NEG_Y,
+            IVec3::Z,
+            IVec3::NEG_Z,
+        ] {
+            self.enqueue(pos + offset);
+        }
+    }
+
+    /// Pop one pending position from the queue.
+    pub fn pop(&mut self) -> Option<IVec3> {
+        let pos = self.pending.pop_front()?;
+        self.scheduled.remove(&pos);
+        Some(pos)
+    }
+
+    /// Number of pending positions.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[derive(Resource)]
+/// Repeating timer pacing water flow, so water spreads one cell per tick.
+pub struct WaterTickTimer(pub Timer);
+
+impl Default for WaterTickTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(WATER_TICK_SECS, TimerMode::Repeating))
+    }
+}
@@ -1,3 +1,4 @@
+use bevy::pbr::wireframe::Wireframe;
 use bevy::prelude::*;
 use bevy::tasks::AsyncComputeTaskPool;
 use futures_lite::future;
@@ -5,44 +6,270 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::BLOCK_SIZE;
 use crate::player::{Player, PlayerBody};
-use crate::{CHUNK_SIZE, LOADS_PER_FRAME, MAX_IN_FLIGHT, VERTICAL_CHUNK_LAYERS, VIEW_DISTANCE};
+use crate::terrain::{TerrainConfig, WorldGenMode};
+use crate::{
+    CHUNK_SIZE, LOADS_PER_FRAME, MAX_CHUNK_LAYER, MAX_IN_FLIGHT, MIN_CHUNK_LAYER, RING_GROW_SECS,
+    VIEW_DISTANCE,
+};
 
-use crate::voxel::block_chunk::{Block, Chunk};
-use crate::voxel::mesh::{build_chunk_mesh_data, mesh_from_data};
-use crate::voxel::mesh_types::MeshData;
-use crate::voxel::world_state::{ChunkBuildOutput, ChunkData, WorldState};
+use crate::voxel::block_chunk::{Block, BlockKind, Chunk, Facing};
+use crate::voxel::block_defs::MeshLayer;
+use crate::voxel::block_entity_state::{BlockEntities, BlockEntity, EditedChunk};
+use crate::voxel::block_update_state::{BlockUpdateQueue, GRASS_COVER_DELAY_SECS};
+use crate::voxel::falling_state::{FallingBlock, FallingPropagationQueue};
+use crate::voxel::generator::generator_for_mode;
+use crate::voxel::item_state::ItemDrop;
+use crate::voxel::light_state::{
+    LIGHT_NEIGHBOR_OFFSETS, LightQueue, LightSnapshot, MAX_LIGHT, combined_light, sky_light_below,
+};
+use crate::voxel::material_state::WorldMaterial;
+use crate::voxel::mesh::{
+    BlockMeshCache, build_chunk_layer_mesh_data, build_chunk_mesh_data, chunk_mesh_from_data,
+    remesh_cells, retint_sun_shading,
+};
+use crate::voxel::mesh_types::{ChunkNeighbors, NEIGHBOR_CHUNK_OFFSETS};
+use crate::voxel::sapling_state::SaplingGrowth;
+use crate::voxel::tnt_state::PrimedTnt;
+use crate::voxel::tree_defs::{TreeKind, TreeRegistry};
+use crate::voxel::water_state::WaterFlowQueue;
+use crate::voxel::world_state::{
+    ChunkBuildOutput, ChunkData, ChunkEntityRecord, ChunkLayerMesh, ChunkRemeshOutput, DirtyChunks,
+    TransientCollider, WorldBorder, WorldState,
+};
 
 /// Raymarch sampling distance in world units.
 const RAY_STEP: f32 = 0.1;
 /// Max interaction reach measured in block lengths.
-const RAY_MAX_DISTANCE_BLOCKS: f32 = 10.0;
+pub(crate) const RAY_MAX_DISTANCE_BLOCKS: f32 = 10.0;
+/// Gap kept before the attachment face when checking placement line of sight.
+const FACE_SAMPLE_EPSILON: f32 = 0.01 * BLOCK_SIZE;
+/// Penetration ignored against transient colliders so resting contact is not a hit.
+const COLLIDER_SKIN: f32 = 1e-4 * BLOCK_SIZE;
+/// Most edited cells of one chunk patched into its meshes in place; past this a full
+/// rebuild is cheaper. Each block edit marks the 27 cells around it.
+const MAX_INCREMENTAL_CELLS: usize = 8 * 27;
+/// Vertical slack when deciding whether the player stands on a moving collider.
+const RIDE_SNAP: f32 = 0.05 * BLOCK_SIZE;
+/// Brightest light at a cell's feet that still lets hostile mobs spawn there.
+const HOSTILE_SPAWN_MAX_LIGHT: u8 = 7;
+/// Build the opaque mesh and the separate class meshes with faces of a generated or
+/// restored chunk.
+fn build_chunk_output(
+    coord: IVec3,
+    chunk: Chunk,
+    neighbors: &ChunkNeighbors,
+    sun_shading: Option<Vec3>,
+) -> ChunkBuildOutput {
+    let mesh_data = build_chunk_mesh_data(&chunk, neighbors, sun_shading, None);
+    let layer_mesh_data = MeshLayer::SEPARATE
+        .into_iter()
+        .map(|layer| (layer, build_chunk_layer_mesh_data(&chunk, neighbors, layer)))
+        .filter(|(_, data)| !data.is_empty())
+        .collect();
+    ChunkBuildOutput::new(
+        coord,
+        chunk,
+        mesh_data,
+        layer_mesh_data,
+        neighbors.missing(),
+        sun_shading,
+    )
+}
+
+/// Rebuild the meshes of a loaded chunk from its blocks, including the separate class
+/// meshes in `layers`, the ones the chunk has.
+fn build_chunk_remesh_output(
+    chunk: &Chunk,
+    neighbors: &ChunkNeighbors,
+    sun_shading: Option<Vec3>,
+    light: Option<&LightSnapshot>,
+    layers: Vec<MeshLayer>,
+) -> ChunkRemeshOutput {
+    ChunkRemeshOutput {
+        mesh_data: build_chunk_mesh_data(chunk, neighbors, sun_shading, light),
+        layer_mesh_data: layers
+            .into_iter()
+            .map(|layer| (layer, build_chunk_layer_mesh_data(chunk, neighbors, layer)))
+            .collect(),
+        sun_shading,
+    }
+}
+
+/// Replace the meshes of a loaded chunk with rebuilt ones.
+fn apply_chunk_remesh_output(
+    chunk_data: &mut ChunkData,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    output: ChunkRemeshOutput,
+) {
+    if let Some(mesh) = meshes.get_mut(&chunk_data.mesh) {
+        (*mesh, chunk_data.quad_cells) = chunk_mesh_from_data(output.mesh_data);
+        chunk_data.sun_baked = output.sun_shading;
+    }
+    for (layer, data) in output.layer_mesh_data {
+        let Some(layer_mesh) = chunk_data.layer_meshes.get_mut(&layer) else {
+            continue;
+        };
+        if let Some(mesh) = meshes.get_mut(&layer_mesh.mesh) {
+            (*mesh, layer_mesh.quad_cells) = chunk_mesh_from_data(data);
+        }
+    }
+}
+
+/// Iterate `center` and the 26 cells around it.
+pub(crate) fn neighborhood(center: IVec3) -> impl Iterator<Item = IVec3> {
+    (-1..=1).flat_map(move |z| {
+        (-1..=1).flat_map(move |y| (-1..=1).map(move |x| center + IVec3::new(x, y, z)))
+    })
+}
+
+/// Return the separate class meshes a loaded chunk has.
+fn separate_layers(chunk_data: &ChunkData) -> Vec<MeshLayer> {
+    chunk_data.layer_meshes.keys().copied().collect()
+}
+
 impl WorldState {
-    /// Construct an empty runtime world state with a shared material handle.
-    pub fn new(material: Handle<StandardMaterial>) -> Self {
+    /// Construct an empty runtime world state.
+    pub fn new() -> Self {
         Self {
             chunks: HashMap::new(),
-            material,
+            block_meshes: BlockMeshCache::default(),
+            generator: generator_for_mode(0, WorldGenMode::Normal, TerrainConfig::default()),
             center: IVec3::new(i32::MIN, i32::MIN, i32::MIN),
             needed: HashSet::new(),
             pending: VecDeque::new(),
             in_flight: HashMap::new(),
+            remesh_dirty: DirtyChunks::default(),
+            remesh_tasks: HashMap::new(),
+            missing_layer_meshes: HashSet::new(),
+            edited_chunks: HashMap::new(),
+            block_entities: BlockEntities::default(),
+            chunk_entities: HashMap::new(),
+            sun_shading: None,
+            remesh_queue: VecDeque::new(),
+            sun_queue: VecDeque::new(),
+            water_queue: WaterFlowQueue::default(),
+            border_touched: HashSet::new(),
+            edited_cells: HashMap::new(),
+            sponge_queue: VecDeque::new(),
+            block_updates: BlockUpdateQueue::default(),
+            light_queue: LightQueue::default(),
+            sky_light: MAX_LIGHT,
+            sky_darkening: 0,
+            transient_colliders: Vec::new(),
+            view_distance: VIEW_DISTANCE,
+            effective_view_distance: VIEW_DISTANCE,
+            ring_growth_secs: 0.0,
+            border: WorldBorder::default(),
+            wireframe: false,
         }
     }
 
-    /// Spawn one chunk render entity and return its entity id.
+    /// Return the culling bounds of a chunk entity.
+    ///
+    /// The box spans the whole chunk volume rather than the current mesh, so it stays valid
+    /// when `rebuild_chunk_mesh` refills the mesh in place, including for chunks whose first
+    /// mesh was empty. `NoAutoAabb` keeps Bevy from replacing it with the mesh bounds.
+    fn chunk_bounds() -> impl Bundle {
+        (
+            bevy::camera::primitives::Aabb::from_min_max(
+                Vec3::ZERO,
+                Vec3::splat(CHUNK_SIZE as f32 * BLOCK_SIZE),
+            ),
+            bevy::camera::visibility::NoAutoAabb,
+        )
+    }
+
+    /// Spawn the entity drawing the opaque mesh of chunk `coord` and return its id.
     fn spawn_chunk_entity(
         &self,
         commands: &mut Commands,
         mesh: Handle<Mesh>,
         coord: IVec3,
     ) -> Entity {
-        commands
-            .spawn((
-                bevy::mesh::Mesh3d(mesh),
-                bevy::pbr::MeshMaterial3d(self.material.clone()),
-                Transform::from_translation(Chunk::world_translation(coord)),
-            ))
-            .id()
+        let mut entity = commands.spawn((
+            bevy::mesh::Mesh3d(mesh),
+            WorldMaterial::Opaque,
+            Transform::from_translation(Chunk::world_translation(coord)),
+            Self::chunk_bounds(),
+        ));
+        self.apply_wireframe(&mut entity);
+        entity.id()
+    }
+
+    /// Spawn the entity drawing the separate class `layer` mesh of chunk `coord` and return
+    /// its id.
+    ///
+    /// Blended water and glass cast no shadows; glowing blocks are solid and do.
+    fn spawn_layer_entity(
+        &self,
+        commands: &mut Commands,
+        mesh: Handle<Mesh>,
+        coord: IVec3,
+        layer: MeshLayer,
+    ) -> Entity {
+        let mut entity = commands.spawn((
+            bevy::mesh::Mesh3d(mesh),
+            WorldMaterial::for_layer(layer),
+            Transform::from_translation(Chunk::world_translation(coord)),
+            Self::chunk_bounds(),
+        ));
+        if layer != MeshLayer::Emissive {
+            entity.insert(bevy::light::NotShadowCaster);
+        }
+        self.apply_wireframe(&mut entity);
+        entity.id()
+    }
+
+    /// Draw chunk mesh entity `entity` as a wireframe while the wireframe debug view is on.
+    fn apply_wireframe(&self, entity: &mut EntityCommands) {
+        if self.wireframe {
+            entity.insert(Wireframe);
+        }
+    }
+
+    /// Give a loaded chunk a mesh and entity for separate class `layer` if it has none yet.
+    ///
+    /// Chunks only get a class mesh when they are built with blocks of that class, so the
+    /// first such block that reaches one needs it created here.
+    fn ensure_layer_mesh(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        coord: IVec3,
+        layer: MeshLayer,
+    ) {
+        let Some(chunk_data) = self.chunks.get(&coord) else {
+            return;
+        };
+        if layer == MeshLayer::Opaque || chunk_data.layer_meshes.contains_key(&layer) {
+            return;
+        }
+        let data =
+            build_chunk_layer_mesh_data(&chunk_data.chunk, &self.chunk_neighbors(coord), layer);
+        let (mesh, quad_cells) = chunk_mesh_from_data(data);
+        let mesh = meshes.add(mesh);
+        let entity = self.spawn_layer_entity(commands, mesh.clone(), coord, layer);
+        if let Some(chunk_data) = self.chunks.get_mut(&coord) {
+            chunk_data.layer_meshes.insert(
+                layer,
+                ChunkLayerMesh {
+                    mesh,
+                    entity,
+                    quad_cells,
+                },
+            );
+        }
+    }
+
+    /// Create the class meshes that loaded chunks gained blocks for since the last call.
+    pub(crate) fn create_missing_layer_meshes(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+    ) {
+        for (coord, layer) in std::mem::take(&mut self.missing_layer_meshes) {
+            self.ensure_layer_mesh(commands, meshes, coord, layer);
+        }
     }
 
     /// Convert a world block coordinate into `(chunk_coord, local_coord)`.
@@ -75,7 +302,8 @@ impl WorldState {
 
     /// Set block at world-space coordinate if containing chunk is loaded.
     ///
-    /// Returns containing chunk coord when write succeeds.
+    /// Queues the cell and its neighbors for water flow, and detaches the cell's block entity
+    /// when the block kind changes. Returns containing chunk coord when write succeeds.
     pub(crate) fn set_block_world_loaded(
         &mut self,
         world_pos: IVec3,
@@ -83,10 +311,370 @@ impl WorldState {
     ) -> Option<IVec3> {
         let (chunk_coord, local) = Self::world_to_chunk_local(world_pos);
         let chunk_data = self.chunks.get_mut(&chunk_coord)?;
+        let old = chunk_data.chunk.get_block(local);
+        if old.kind != block.kind {
+            self.block_entities.detach(world_pos);
+        }
         chunk_data.chunk.set_block(local, block);
+        chunk_data.edited = true;
+        let has_layer_mesh = block.mesh_layer() == MeshLayer::Opaque
+            || chunk_data.layer_meshes.contains_key(&block.mesh_layer());
+        if !has_layer_mesh {
+            self.missing_layer_meshes
+                .insert((chunk_coord, block.mesh_layer()));
+        }
+        self.mark_cell_edited(world_pos);
+        if old.kind != block.kind || old.culls_neighbors() != block.culls_neighbors() {
+            for offset in NEIGHBOR_CHUNK_OFFSETS {
+                let neighbor = chunk_coord + offset;
+                if !Chunk::in_bounds(local + offset) && self.chunks.contains_key(&neighbor) {
+                    self.border_touched.insert(neighbor);
+                }
+            }
+        }
+        self.water_queue.enqueue_with_neighbors(world_pos);
+        if old.blocks_light() != block.blocks_light()
+            || old.light_emission() != block.light_emission()
+        {
+            self.relight_cell(world_pos, block);
+        }
+        if old.blocks_light() != block.blocks_light() {
+            self.relight_sky_column(world_pos.x, world_pos.z);
+        }
+        Some(chunk_coord)
+    }
+
+    /// Return the metadata byte of the block at a world-space coordinate if its chunk is loaded.
+    pub(crate) fn get_state_world(&self, world_pos: IVec3) -> Option<u8> {
+        let (chunk_coord, local) = Self::world_to_chunk_local(world_pos);
+        self.chunks
+            .get(&chunk_coord)
+            .map(|chunk| chunk.chunk.get_state(local))
+    }
+
+    /// Set the metadata byte of the block at a world-space coordinate if its chunk is loaded,
+    /// keeping the block's kind and front.
+    ///
+    /// Queues the cell and its neighbors for water flow like a full block write. Returns
+    /// containing chunk coord when write succeeds.
+    pub(crate) fn set_state_world_loaded(&mut self, world_pos: IVec3, state: u8) -> Option<IVec3> {
+        let (chunk_coord, local) = Self::world_to_chunk_local(world_pos);
+        let chunk_data = self.chunks.get_mut(&chunk_coord)?;
+        chunk_data.chunk.set_state(local, state);
+        chunk_data.edited = true;
+        self.mark_cell_edited(world_pos);
+        self.water_queue.enqueue_with_neighbors(world_pos);
         Some(chunk_coord)
     }
 
+    /// Record that the faces of the cell at `world_pos` and of every cell around it may all
+    /// have changed, for the next patch of their chunk meshes.
+    ///
+    /// Diagonal neighbors are included because their face corners take ambient occlusion
+    /// from the cell.
+    fn mark_cell_edited(&mut self, world_pos: IVec3) {
+        for cell in neighborhood(world_pos) {
+            let (coord, cell_local) = Self::world_to_chunk_local(cell);
+            if self.chunks.contains_key(&coord) {
+                self.edited_cells
+                    .entry(coord)
+                    .or_default()
+                    .insert(cell_local);
+            }
+        }
+    }
+
+    /// Return the block light level at a world-space block coordinate when its chunk is loaded.
+    pub(crate) fn block_light(&self, pos: IVec3) -> Option<u8> {
+        let (chunk_coord, local) = Self::world_to_chunk_local(pos);
+        Some(self.chunks.get(&chunk_coord)?.light.get(local))
+    }
+
+    /// Return the light level gameplay rules see at a world-space block coordinate: the
+    /// brighter of its block light and its sky light dimmed by the time of day.
+    ///
+    /// Reads straight from the chunk's light arrays. Cells of unloaded chunks read as open
+    /// sky without block light.
+    pub(crate) fn light_at(&self, pos: IVec3) -> u8 {
+        let (chunk_coord, local) = Self::world_to_chunk_local(pos);
+        let (sky, block) = self
+            .chunks
+            .get(&chunk_coord)
+            .map_or((MAX_LIGHT, 0), |data| {
+                (data.sky.get(local), data.light.get(local))
+            });
+        combined_light(sky, block, self.sky_darkening)
+    }
+
+    /// Return whether a hostile mob may spawn standing in the cell at `feet`.
+    ///
+    /// It needs a loaded solid floor, two free cells for its body, and light at the feet no
+    /// brighter than `HOSTILE_SPAWN_MAX_LIGHT`.
+    #[allow(dead_code, reason = "read by hostile mob spawning")]
+    pub(crate) fn hostile_spawn_allowed(&self, feet: IVec3) -> bool {
+        let free = |pos: IVec3| self.get_block_world(pos).is_some_and(|b| !b.is_solid());
+        self.get_block_world(feet - IVec3::Y)
+            .is_some_and(|floor| floor.is_solid())
+            && free(feet)
+            && free(feet + IVec3::Y)
+            && self.light_at(feet) <= HOSTILE_SPAWN_MAX_LIGHT
+    }
+
+    /// Set the block light level at a loaded world-space block coordinate.
+    ///
+    /// Marks every chunk whose mesh shows the cell as touched: its own, and the neighbor
+    /// whose border faces look into it when the cell lies on the chunk border.
+    pub(crate) fn set_block_light(&mut self, pos: IVec3, level: u8) {
+        let (chunk_coord, local) = Self::world_to_chunk_local(pos);
+        let Some(chunk_data) = self.chunks.get_mut(&chunk_coord) else {
+            return;
+        };
+        chunk_data.light.set(local, level);
+        self.mark_light_touched(chunk_coord, local);
+    }
+
+    /// Mark the chunks whose meshes show the light of local cell `local` of `chunk_coord`.
+    fn mark_light_touched(&mut self, chunk_coord: IVec3, local: IVec3) {
+        self.light_queue.mark_touched(chunk_coord);
+        for offset in LIGHT_NEIGHBOR_OFFSETS {
+            if !Chunk::in_bounds(local + offset) {
+                self.light_queue.mark_touched(chunk_coord + offset);
+            }
+        }
+    }
+
+    /// Recompute the sky light of world column `x`, `z` through every loaded chunk layer.
+    ///
+    /// Walks down from the top streamed layer, so each cell is lit by its depth below the
+    /// highest light-blocking block above it; unloaded layers count as open sky. Chunks whose
+    /// levels changed are marked touched for a remesh, like block light changes.
+    fn relight_sky_column(&mut self, x: i32, z: i32) {
+        let column = IVec2::new(x, z);
+        let local_column = column.rem_euclid(IVec2::splat(CHUNK_SIZE));
+        let chunk_column = column.div_euclid(IVec2::splat(CHUNK_SIZE));
+        let mut roof = None;
+        for layer in (MIN_CHUNK_LAYER..MAX_CHUNK_LAYER).rev() {
+            let chunk_coord = IVec3::new(chunk_column.x, layer, chunk_column.y);
+            if let Some((highest, lowest)) =
+                self.light_sky_layer(chunk_coord, local_column, &mut roof)
+            {
+                self.mark_light_touched(chunk_coord, highest);
+                self.mark_light_touched(chunk_coord, lowest);
+            }
+        }
+    }
+
+    /// Light the cells of local column `local_column` of loaded chunk `chunk_coord` below
+    /// `roof`, lowering `roof` to the chunk's highest light-blocking cell when it is open.
+    ///
+    /// Returns the highest and lowest changed cell: they share the column with every other
+    /// changed cell, so together they reach every chunk whose mesh shows one.
+    fn light_sky_layer(
+        &mut self,
+        chunk_coord: IVec3,
+        local_column: IVec2,
+        roof: &mut Option<i32>,
+    ) -> Option<(IVec3, IVec3)> {
+        let chunk_data = self.chunks.get_mut(&chunk_coord)?;
+        let mut changed: Option<(IVec3, IVec3)> = None;
+        for local_y in (0..CHUNK_SIZE).rev() {
+            let local = IVec3::new(local_column.x, local_y, local_column.y);
+            let y = chunk_coord.y * CHUNK_SIZE + local_y;
+            if chunk_data.sky.set(local, sky_light_below(*roof, y)) {
+                changed = Some((changed.map_or(local, |(highest, _)| highest), local));
+            }
+            if roof.is_none() && chunk_data.chunk.get_block(local).blocks_light() {
+                *roof = Some(y);
+            }
+        }
+        changed
+    }
+
+    /// Return the highest light-blocking cell of local column `local_column` in the loaded
+    /// chunks above `coord`.
+    fn sky_roof_above(&self, coord: IVec3, local_column: IVec2) -> Option<i32> {
+        ((coord.y + 1)..MAX_CHUNK_LAYER).rev().find_map(|layer| {
+            let chunk_data = self.chunks.get(&coord.with_y(layer))?;
+            (0..CHUNK_SIZE).rev().find_map(|local_y| {
+                let local = IVec3::new(local_column.x, local_y, local_column.y);
+                chunk_data
+                    .chunk
+                    .get_block(local)
+                    .blocks_light()
+                    .then_some(layer * CHUNK_SIZE + local_y)
+            })
+        })
+    }
+
+    /// Light the columns through chunk `coord` after it loaded.
+    ///
+    /// Only columns the chunk newly roofs, where nothing loaded above blocks light but one
+    /// of its cells does, walk on into the chunks below. Changed chunks are queued for a
+    /// staged remesh rather than rebuilt at once, so streaming in a stack of chunks never
+    /// floods the remesh tasks; the neighbors of `coord` are already queued by its load.
+    fn relight_sky_chunk_columns(&mut self, coord: IVec3) {
+        let mut lit = false;
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let local_column = IVec2::new(x, z);
+                let mut roof = self.sky_roof_above(coord, local_column);
+                let roofed_above = roof.is_some();
+                lit |= self
+                    .light_sky_layer(coord, local_column, &mut roof)
+                    .is_some();
+                if roofed_above || roof.is_none() {
+                    continue;
+                }
+                for layer in (MIN_CHUNK_LAYER..coord.y).rev() {
+                    let chunk_coord = coord.with_y(layer);
+                    if let Some((highest, lowest)) =
+                        self.light_sky_layer(chunk_coord, local_column, &mut roof)
+                    {
+                        self.queue_light_remeshes(chunk_coord, highest);
+                        self.queue_light_remeshes(chunk_coord, lowest);
+                    }
+                }
+            }
+        }
+        if lit {
+            self.queue_remesh(coord);
+        }
+    }
+
+    /// Queue staged remeshes of the loaded chunks whose meshes show the light of local cell
+    /// `local` of `chunk_coord`, like `mark_light_touched` does for an immediate rebuild.
+    fn queue_light_remeshes(&mut self, chunk_coord: IVec3, local: IVec3) {
+        self.queue_remesh(chunk_coord);
+        for offset in LIGHT_NEIGHBOR_OFFSETS {
+            if !Chunk::in_bounds(local + offset)
+                && self.chunks.contains_key(&(chunk_coord + offset))
+            {
+                self.queue_remesh(chunk_coord + offset);
+            }
+        }
+    }
+
+    /// Queue light changes after the block at `pos` became `block`.
+    ///
+    /// The cell's old light is removed, its own emission is seeded, and the neighbors are
+    /// queued to spread back into it when it no longer blocks light.
+    fn relight_cell(&mut self, pos: IVec3, block: Block) {
+        let level = self.block_light(pos).unwrap_or(0);
+        if level > 0 {
+            self.set_block_light(pos, 0);
+            self.light_queue.enqueue_removal(pos, level);
+        }
+        let emission = block.light_emission();
+        if emission > 0 {
+            self.set_block_light(pos, emission);
+            self.light_queue.enqueue_add(pos);
+        }
+        if !block.blocks_light() {
+            for offset in LIGHT_NEIGHBOR_OFFSETS {
+                self.light_queue.enqueue_add(pos + offset);
+            }
+        }
+    }
+
+    /// Seed block light of a chunk that just loaded.
+    ///
+    /// Its emitters are lit and queued, and lit border cells of loaded neighbors are queued
+    /// to spread into it.
+    fn seed_chunk_light(&mut self, coord: IVec3) {
+        let Some(chunk_data) = self.chunks.get(&coord) else {
+            return;
+        };
+        let origin = coord * CHUNK_SIZE;
+        let mut emitters = Vec::new();
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    let local = IVec3::new(x, y, z);
+                    let emission = chunk_data.chunk.get_block(local).light_emission();
+                    if emission > 0 {
+                        emitters.push((origin + local, emission));
+                    }
+                }
+            }
+        }
+        for (pos, emission) in emitters {
+            self.set_block_light(pos, emission);
+            self.light_queue.enqueue_add(pos);
+        }
+        for offset in LIGHT_NEIGHBOR_OFFSETS {
+            let Some(neighbor) = self.chunks.get(&(coord + offset)) else {
+                continue;
+            };
+            if neighbor.light.is_dark() {
+                continue;
+            }
+            // Walk the neighbor's layer of cells touching this chunk.
+            let axis = offset.abs();
+            let layer = if offset.cmplt(IVec3::ZERO).any() {
+                CHUNK_SIZE - 1
+            } else {
+                0
+            };
+            for u in 0..CHUNK_SIZE {
+                for v in 0..CHUNK_SIZE {
+                    let local = match axis {
+                        IVec3::X => IVec3::new(layer, u, v),
+                        IVec3::Y => IVec3::new(u, layer, v),
+                        _ => IVec3::new(u, v, layer),
+                    };
+                    if neighbor.light.get(local) > 1 {
+                        self.light_queue
+                            .enqueue_add((coord + offset) * CHUNK_SIZE + local);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Copy the light levels a chunk's mesh shows, or `None` when it and its neighbors are
+    /// all dark and open to the sky.
+    pub(crate) fn light_snapshot(&self, coord: IVec3) -> Option<LightSnapshot> {
+        let chunk_data = self.chunks.get(&coord)?;
+        let neighbors: Vec<(IVec3, &ChunkData)> = LIGHT_NEIGHBOR_OFFSETS
+            .into_iter()
+            .filter_map(|offset| Some((offset, self.chunks.get(&(coord + offset))?)))
+            .filter(|(_, neighbor)| !neighbor.light.is_dark() || !neighbor.sky.is_open())
+            .collect();
+        if chunk_data.light.is_dark() && chunk_data.sky.is_open() && neighbors.is_empty() {
+            return None;
+        }
+        let mut snapshot = LightSnapshot::new(self.sky_light);
+        for z in -1..=CHUNK_SIZE {
+            for y in -1..=CHUNK_SIZE {
+                for x in -1..=CHUNK_SIZE {
+                    let local = IVec3::new(x, y, z);
+                    let (level, sky) = if Chunk::in_bounds(local) {
+                        (chunk_data.light.get(local), chunk_data.sky.get(local))
+                    } else {
+                        let offset = local.div_euclid(IVec3::splat(CHUNK_SIZE));
+                        let neighbor_local = local.rem_euclid(IVec3::splat(CHUNK_SIZE));
+                        neighbors
+                            .iter()
+                            .find(|(neighbor_offset, _)| *neighbor_offset == offset)
+                            .map_or((0, MAX_LIGHT), |(_, neighbor)| {
+                                (
+                                    neighbor.light.get(neighbor_local),
+                                    neighbor.sky.get(neighbor_local),
+                                )
+                            })
+                    };
+                    if level > 0 {
+                        snapshot.set(local, level);
+                    }
+                    if sky < self.sky_light {
+                        snapshot.set_sky(local, sky);
+                    }
+                }
+            }
+        }
+        Some(snapshot)
+    }
+
     /// Ensure containing chunk exists, then set block at world-space coordinate.
     ///
     /// Returns containing chunk coord when write succeeds.
@@ -104,7 +692,9 @@ impl WorldState {
 
     /// Settle one falling block into voxel world at landing coordinate.
     ///
-    /// Ensures target chunk exists, writes block, and returns touched chunk coord.
+    /// Ensures target chunk exists, writes block, and returns touched chunk coord. Returns
+    /// `None` without writing when a solid block already fills the landing cell, so two
+    /// blocks landing in one cell never overwrite each other.
     pub(crate) fn settle_falling_block(
         &mut self,
         commands: &mut Commands,
@@ -112,6 +702,9 @@ impl WorldState {
         landing_block: IVec3,
         block: Block,
     ) -> Option<IVec3> {
+        if self.is_solid_at_world_pos(landing_block) {
+            return None;
+        }
         self.set_block_world_ensured(commands, meshes, landing_block, block)
     }
 
@@ -121,35 +714,202 @@ impl WorldState {
             .is_some_and(|block| block.is_solid())
     }
 
-    /// Check whether a player-sized AABB intersects any solid block.
-    pub(crate) fn intersects_solid(&self, position: Vec3, half_size: Vec3) -> bool {
-        let min = position - half_size;
-        let max = position + half_size;
+    /// Return whether `block` at `world_pos` has the support it hangs off, if it needs one.
+    ///
+    /// Attached blocks rest on the block below or lean against one beside them, never hang
+    /// from a ceiling; saplings also need soil.
+    pub(crate) fn has_attachment_support(&self, world_pos: IVec3, block: Block) -> bool {
+        block.support_offset().is_none_or(|offset| {
+            offset.y <= 0
+                && self
+                    .get_block_world(world_pos + offset)
+                    .is_some_and(|support| block.rests_on(support))
+        })
+    }
 
+    /// Return the world-space tops of the solid block boxes the AABB `[min, max]` penetrates
+    /// deeper than `COLLIDER_SKIN`.
+    ///
+    /// Fences collide above their own cell, so the cells under the AABB are checked too.
+    fn overlapping_solid_tops(&self, min: Vec3, max: Vec3) -> Vec<f32> {
         let min_x = (min.x / BLOCK_SIZE).floor() as i32;
         let max_x = (max.x / BLOCK_SIZE).floor() as i32;
-        let min_y = (min.y / BLOCK_SIZE).floor() as i32;
+        let min_y = (min.y / BLOCK_SIZE).floor() as i32 - 1;
         let max_y = (max.y / BLOCK_SIZE).floor() as i32;
         let min_z = (min.z / BLOCK_SIZE).floor() as i32;
         let max_z = (max.z / BLOCK_SIZE).floor() as i32;
 
+        let penetrates = |box_min: Vec3, box_max: Vec3| {
+            (min + COLLIDER_SKIN).cmplt(box_max).all() && (max - COLLIDER_SKIN).cmpgt(box_min).all()
+        };
+        let mut tops = Vec::new();
         for z in min_z..=max_z {
             for y in min_y..=max_y {
                 for x in min_x..=max_x {
-                    if self.is_solid_at_world_pos(IVec3::new(x, y, z)) {
-                        return true;
+                    let cell = IVec3::new(x, y, z);
+                    let Some(block) = self.get_block_world(cell).filter(Block::is_solid) else {
+                        continue;
+                    };
+                    let origin = Block::world_translation(cell);
+                    if block.fills_cell() {
+                        let top = origin + Vec3::splat(BLOCK_SIZE);
+                        if penetrates(origin, top) {
+                            tops.push(top.y);
+                        }
+                        continue;
+                    }
+                    for local in block.collision_boxes() {
+                        let box_min = origin + local.min * BLOCK_SIZE;
+                        let box_max = origin + local.max * BLOCK_SIZE;
+                        if penetrates(box_min, box_max) {
+                            tops.push(box_max.y);
+                        }
                     }
                 }
             }
         }
-        false
+        tops
+    }
+
+    /// Check whether a player-sized AABB intersects any solid block.
+    ///
+    /// Blocks collide with their `collision_boxes`, so slabs and stairs only fill part of a cell.
+    pub(crate) fn intersects_solid(&self, position: Vec3, half_size: Vec3) -> bool {
+        !self
+            .overlapping_solid_tops(position - half_size, position + half_size)
+            .is_empty()
+    }
+
+    /// Return whether a player-sized AABB comes within `margin` of the collision boxes of a
+    /// `kind` block.
+    pub(crate) fn touches_kind(
+        &self,
+        position: Vec3,
+        half_size: Vec3,
+        margin: f32,
+        kind: BlockKind,
+    ) -> bool {
+        let min = position - half_size - Vec3::splat(margin);
+        let max = position + half_size + Vec3::splat(margin);
+        let min_cell = Block::world_coord_from_position(min);
+        let max_cell = Block::world_coord_from_position(max);
+        (min_cell.z..=max_cell.z).any(|z| {
+            (min_cell.y..=max_cell.y).any(|y| {
+                (min_cell.x..=max_cell.x).any(|x| {
+                    let cell = IVec3::new(x, y, z);
+                    let origin = Block::world_translation(cell);
+                    self.get_block_world(cell)
+                        .filter(|block| block.kind == kind)
+                        .is_some_and(|block| {
+                            block.collision_boxes().any(|local| {
+                                min.cmplt(origin + local.max * BLOCK_SIZE).all()
+                                    && max.cmpgt(origin + local.min * BLOCK_SIZE).all()
+                            })
+                        })
+                })
+            })
+        })
+    }
+
+    /// Return the highest top of the solid boxes a player-sized AABB intersects, if any.
+    ///
+    /// Used to land flush on the ground and to step up onto low obstacles such as slabs.
+    pub(crate) fn highest_solid_top(&self, position: Vec3, half_size: Vec3) -> Option<f32> {
+        self.overlapping_solid_tops(position - half_size, position + half_size)
+            .into_iter()
+            .max_by(f32::total_cmp)
+    }
+
+    /// Return the highest transient collider the AABB was standing on before it moved this frame.
+    ///
+    /// The collider top may have dropped by up to one frame of its own motion since the
+    /// player last touched it.
+    pub(crate) fn riding_collider(
+        &self,
+        position: Vec3,
+        half_size: Vec3,
+        dt: f32,
+    ) -> Option<TransientCollider> {
+        let feet = position.y - half_size.y;
+        let min = position - half_size;
+        let max = position + half_size;
+        self.transient_colliders
+            .iter()
+            .filter(|collider| {
+                min.x + COLLIDER_SKIN < collider.max.x
+                    && max.x - COLLIDER_SKIN > collider.min.x
+                    && min.z + COLLIDER_SKIN < collider.max.z
+                    && max.z - COLLIDER_SKIN > collider.min.z
+                    && collider.max.y <= feet + RIDE_SNAP
+                    && collider.max.y >= feet + collider.velocity_y.min(0.0) * dt - RIDE_SNAP
+            })
+            .max_by(|a, b| a.max.y.total_cmp(&b.max.y))
+            .copied()
+    }
+
+    /// Return `position` moved out of every transient collider a player-sized AABB there
+    /// penetrates, or `None` when it overlaps none.
+    ///
+    /// Each collider is left along its shallowest exit that does not end in a solid block,
+    /// so a block falling onto or into the player shoves them aside instead of trapping them.
+    pub(crate) fn push_out_of_transient_colliders(
+        &self,
+        position: Vec3,
+        half_size: Vec3,
+    ) -> Option<Vec3> {
+        let mut pushed = position;
+        for _ in 0..self.transient_colliders.len() {
+            let (min, max) = (pushed - half_size, pushed + half_size);
+            let Some(collider) = self
+                .transient_colliders
+                .iter()
+                .find(|collider| collider.overlaps(min, max, COLLIDER_SKIN))
+            else {
+                break;
+            };
+            let mut exits = [
+                Vec3::X * (collider.max.x - min.x),
+                Vec3::NEG_X * (max.x - collider.min.x),
+                Vec3::Y * (collider.max.y - min.y),
+                Vec3::NEG_Y * (max.y - collider.min.y),
+                Vec3::Z * (collider.max.z - min.z),
+                Vec3::NEG_Z * (max.z - collider.min.z),
+            ];
+            exits.sort_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+            let Some(exit) = exits
+                .into_iter()
+                .find(|exit| !self.intersects_solid(pushed + *exit, half_size))
+            else {
+                break;
+            };
+            pushed += exit;
+        }
+        (pushed != position).then_some(pushed)
+    }
+
+    /// Return whether world-space `point` lies inside a collision box of a solid block.
+    ///
+    /// Fences collide above their own cell, so the block below the point's cell is checked too.
+    fn point_in_solid(&self, point: Vec3) -> bool {
+        let cell = Block::world_coord_from_position(point);
+        [cell, cell - IVec3::Y].into_iter().any(|cell| {
+            let Some(block) = self.get_block_world(cell).filter(Block::is_solid) else {
+                return false;
+            };
+            let local = (point - Block::world_translation(cell)) / BLOCK_SIZE;
+            block
+                .collision_boxes()
+                .any(|part| local.cmpge(part.min).all() && local.cmple(part.max).all())
+        })
     }
 
     /// Check whether crouch edge-guard still has ground support.
+    ///
+    /// Probes just below the feet hit the collision boxes of the blocks there, so the open
+    /// side of a door or the empty half of a slab's cell gives no footing.
     pub(crate) fn has_ground_support(&self, position: Vec3, half_size: Vec3) -> bool {
         let probe_down = BLOCK_SIZE * 0.05;
         let foot_y = position.y - half_size.y - probe_down;
-        let block_y = (foot_y / BLOCK_SIZE).floor() as i32;
 
         // Use an inset footprint so crouch can hang slightly over an edge, similar to MC feel.
         let inset = BLOCK_SIZE * 0.2;
@@ -163,13 +923,9 @@ impl WorldState {
             Vec2::ZERO,
         ];
 
-        probes.iter().any(|p| {
-            let wx = position.x + p.x;
-            let wz = position.z + p.y;
-            let block_x = (wx / BLOCK_SIZE).floor() as i32;
-            let block_z = (wz / BLOCK_SIZE).floor() as i32;
-            self.is_solid_at_world_pos(IVec3::new(block_x, block_y, block_z))
-        })
+        probes
+            .iter()
+            .any(|p| self.point_in_solid(Vec3::new(position.x + p.x, foot_y, position.z + p.y)))
     }
 
     /// Build interaction ray from camera and run raymarch.
@@ -185,7 +941,9 @@ impl WorldState {
         Some(self.raymarch_hit_and_last_empty(origin, direction))
     }
 
-    /// Raymarch from camera and return `(first_solid_hit, last_empty_before_hit)`.
+    /// Raymarch from camera and return `(first_targetable_hit, last_empty_before_hit)`.
+    ///
+    /// Solid blocks and decorations stop the ray; water and air do not.
     pub(crate) fn raymarch_hit_and_last_empty(
         &self,
         origin: Vec3,
@@ -208,7 +966,7 @@ impl WorldState {
                 last_empty = Some(block_world);
                 continue;
             }
-            if chunk_data.chunk.get_block(local).is_solid() {
+            if chunk_data.chunk.get_block(local).is_targetable() {
                 hit = Some(block_world);
                 break;
             }
@@ -218,6 +976,29 @@ impl WorldState {
         (hit, last_empty)
     }
 
+    /// Return `true` when the face of `hit` toward `target` is visible from `eye`.
+    ///
+    /// The face must point toward the eye, and a ray from the eye to the face center must
+    /// not cross any other solid block. This rejects placements around corners.
+    pub(crate) fn attachment_face_visible(&self, eye: Vec3, hit: IVec3, target: IVec3) -> bool {
+        let normal = (target - hit).as_vec3();
+        let face_center = Block::world_translation(hit)
+            + Vec3::splat(0.5 * BLOCK_SIZE)
+            + normal * (0.5 * BLOCK_SIZE);
+        let to_face = face_center - eye;
+        if to_face.dot(normal) >= 0.0 {
+            return false;
+        }
+        // Stop just short of the face so the attachment block itself is never sampled.
+        let distance = to_face.length() - FACE_SAMPLE_EPSILON;
+        let direction = to_face.normalize_or_zero();
+        let steps = (distance.max(0.0) / RAY_STEP) as i32;
+        (0..=steps).all(|i| {
+            let position = eye + direction * (i as f32 * RAY_STEP).min(distance);
+            !self.is_solid_at_world_pos(Block::world_coord_from_position(position))
+        })
+    }
+
     /// Update `self.center` from camera position and return the new center.
     pub(crate) fn update_center_from_camera(
         &mut self,
@@ -247,19 +1028,65 @@ impl WorldState {
         )
     }
 
-    /// Build target chunk set inside the configured streaming window.
-    pub(crate) fn build_needed_chunk_set(center: IVec3) -> HashSet<IVec3> {
+    /// Return the horizontal ring of `coord` around `center`.
+    ///
+    /// The window of radius `r` spans `-r..r` chunks on X/Z, so a chunk is inside it
+    /// exactly when its ring is at most `r`.
+    pub(crate) fn ring_distance(center: IVec3, coord: IVec3) -> i32 {
+        let ring = |offset: i32| {
+            if offset >= 0 {
+                offset.saturating_add(1)
+            } else {
+                offset.saturating_neg()
+            }
+        };
+        ring(coord.x.saturating_sub(center.x)).max(ring(coord.z.saturating_sub(center.z)))
+    }
+
+    /// Radius the needed set is built with this frame.
+    ///
+    /// Growth follows the effective radius ring by ring; shrinking applies the target at once
+    /// and lets the budgeted unloads catch up.
+    pub(crate) fn streaming_radius(&self) -> i32 {
+        self.view_distance.min(self.effective_view_distance)
+    }
+
+    /// Build target chunk set inside a streaming window of `radius` chunks, clipped to the border.
+    pub(crate) fn build_needed_chunk_set(&self, center: IVec3, radius: i32) -> HashSet<IVec3> {
         let mut needed: HashSet<IVec3> = HashSet::new();
-        for dz in -VIEW_DISTANCE..VIEW_DISTANCE {
-            for dx in -VIEW_DISTANCE..VIEW_DISTANCE {
-                for dy in 0..VERTICAL_CHUNK_LAYERS {
-                    needed.insert(center + IVec3::new(dx, dy, dz));
+        for dz in -radius..radius {
+            for dx in -radius..radius {
+                let column = center + IVec3::new(dx, 0, dz);
+                if !self.border.overlaps_chunk(column) {
+                    continue;
+                }
+                for dy in MIN_CHUNK_LAYER..MAX_CHUNK_LAYER {
+                    needed.insert(column + IVec3::Y * dy);
                 }
             }
         }
         needed
     }
 
+    /// Add edited chunks outside the vertical window that lie inside the horizontal view range.
+    ///
+    /// Edited chunks (e.g. a tower above the top streaming layer) stream like any other
+    /// chunk: they unload by horizontal distance and reload from `edited_chunks` on return.
+    pub(crate) fn extend_needed_with_edited(&self, center: IVec3, needed: &mut HashSet<IVec3>) {
+        let radius = self.streaming_radius();
+        let in_range = |coord: &IVec3| Self::ring_distance(center, *coord) <= radius;
+        let loaded_edited = self
+            .chunks
+            .iter()
+            .filter(|(_, data)| data.edited)
+            .map(|(coord, _)| coord);
+        for coord in loaded_edited.chain(self.edited_chunks.keys()) {
+            if in_range(coord) {
+                needed.insert(*coord);
+            }
+        }
+    }
+
     /// Sync `needed` and drop pending/in-flight tasks that are no longer required.
     pub(crate) fn sync_needed_set(&mut self, needed: HashSet<IVec3>) {
         if needed == self.needed {
@@ -271,15 +1098,25 @@ impl WorldState {
         self.in_flight.retain(|coord, _| needed.contains(coord));
     }
 
-    /// Enqueue missing needed chunks into the build queue.
+    /// Enqueue missing needed chunks into the build queue, nearest rings first.
+    ///
+    /// Rings added while the view distance grows queue behind nearer chunks, including
+    /// ones the player walks into later.
     pub(crate) fn enqueue_needed_chunks(&mut self) {
         let needed = self.needed.clone();
+        let queued = self.pending.len();
         for coord in needed.iter().copied() {
             if self.is_chunk_scheduled_or_loaded(coord) {
                 continue;
             }
             self.pending.push_back(coord);
         }
+        if self.pending.len() > queued {
+            let center = self.center;
+            self.pending
+                .make_contiguous()
+                .sort_by_key(|coord| Self::ring_distance(center, *coord));
+        }
     }
 
     /// Return `true` if chunk is already loaded, pending, or currently building.
@@ -289,18 +1126,43 @@ impl WorldState {
             || self.in_flight.contains_key(&coord)
     }
 
-    /// Collect loaded chunks that are outside current needed set and should be unloaded.
-    pub(crate) fn collect_unneeded_loaded_chunks(&self) -> Vec<IVec3> {
-        self.chunks
+    /// Collect up to `budget` loaded chunks outside the needed set, farthest first.
+    pub(crate) fn collect_unneeded_loaded_chunks(&self, budget: usize) -> Vec<IVec3> {
+        let mut unneeded: Vec<IVec3> = self
+            .chunks
             .keys()
             .copied()
-            .filter(|coord| self.is_streaming_layer(*coord) && !self.needed.contains(coord))
-            .collect()
+            .filter(|coord| !self.needed.contains(coord))
+            .collect();
+        unneeded.sort_by_key(|coord| std::cmp::Reverse(Self::ring_distance(self.center, *coord)));
+        unneeded.truncate(budget);
+        unneeded
     }
 
-    /// Return `true` if chunk Y coordinate belongs to streaming vertical layers.
-    fn is_streaming_layer(&self, coord: IVec3) -> bool {
-        (0..VERTICAL_CHUNK_LAYERS).contains(&coord.y)
+    /// Move the effective view distance toward `view_distance` after `dt` seconds.
+    ///
+    /// It grows by one ring every `RING_GROW_SECS`, whether or not earlier rings finished
+    /// loading, so streaming around a moving player cannot stall it. It shrinks to the
+    /// farthest ring still loaded, following the budgeted unloads.
+    pub(crate) fn step_effective_view_distance(&mut self, dt: f32) {
+        if self.effective_view_distance < self.view_distance {
+            self.ring_growth_secs += dt;
+            if self.ring_growth_secs >= RING_GROW_SECS {
+                self.ring_growth_secs = 0.0;
+                self.effective_view_distance += 1;
+            }
+            return;
+        }
+        self.ring_growth_secs = 0.0;
+        let center = self.center;
+        let loaded_radius = self
+            .chunks
+            .keys()
+            .map(|coord| Self::ring_distance(center, *coord))
+            .max()
+            .unwrap_or(0);
+        self.effective_view_distance =
+            loaded_radius.clamp(self.view_distance, self.effective_view_distance);
     }
 
     /// Spawn bounded number of async chunk build tasks for queued coordinates.
@@ -308,10 +1170,17 @@ impl WorldState {
         let mut started = 0;
         while self.can_start_chunk_build(started) {
             let coord = self.pending.pop_front().unwrap();
+            // Keep the stored copy until the result is accepted, in case the task is dropped.
+            let stored = self
+                .edited_chunks
+                .get(&coord)
+                .map(|stored| stored.chunk.clone());
+            let neighbors = self.chunk_neighbors(coord);
+            let sun_shading = self.sun_shading;
+            let generator = self.generator.clone();
             let task = task_pool.spawn(async move {
-                let chunk = Chunk::new_streaming(coord);
-                let mesh_data = build_chunk_mesh_data(&chunk);
-                ChunkBuildOutput::new(coord, chunk, mesh_data)
+                let chunk = stored.unwrap_or_else(|| generator.generate(coord));
+                build_chunk_output(coord, chunk, &neighbors, sun_shading)
             });
             self.in_flight.insert(coord, task);
             started += 1;
@@ -342,32 +1211,36 @@ impl WorldState {
     }
 
     /// Spawn render entities and insert chunk data for finished build outputs.
+    ///
+    /// Returns coordinates of the chunks that were accepted and loaded.
     pub(crate) fn apply_finished_chunk_results(
         &mut self,
         commands: &mut Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
         finished: Vec<ChunkBuildOutput>,
-    ) {
+    ) -> Vec<IVec3> {
+        let mut loaded = Vec::new();
         for result in finished {
             if !self.should_accept_finished_chunk(result.coord) {
                 continue;
             }
-            self.insert_loaded_chunk(
-                commands,
-                meshes,
-                result.coord,
-                result.chunk,
-                result.mesh_data,
-            );
+            loaded.push(result.coord);
+            self.insert_loaded_chunk(commands, meshes, result);
         }
+        loaded
     }
 
-    /// Return `true` if finished chunk result is still needed by current window.
+    /// Return `true` if finished chunk result is still needed and not already loaded.
+    ///
+    /// A chunk can be loaded synchronously by `ensure_chunk` (e.g. placing a block) while
+    /// its async build is still running; that stale result must not replace the edited chunk.
     fn should_accept_finished_chunk(&self, coord: IVec3) -> bool {
-        self.needed.contains(&coord)
+        self.needed.contains(&coord) && !self.chunks.contains_key(&coord)
     }
 
     /// Break one block at world position and rebuild touched chunk mesh.
+    ///
+    /// Breaking either half of a door removes the other half too.
     pub(crate) fn break_block(
         &mut self,
         meshes: &mut ResMut<Assets<Mesh>>,
@@ -382,34 +1255,253 @@ impl WorldState {
         let Some(chunk_coord) = self.set_block_world_loaded(target_world, Block::air()) else {
             return false;
         };
-        self.rebuild_chunk_mesh(meshes, chunk_coord);
+        let mut touched = self.connect_fences(target_world);
+        touched.insert(chunk_coord);
+        touched.extend(self.clear_door_partner(target_world, target_block));
+        self.rebuild_touched_chunk_meshes(meshes, touched);
         true
     }
 
-    /// Place one block at world position (if not intersecting player) and rebuild mesh.
-    pub(crate) fn place_block(
-        &mut self,
-        commands: &mut Commands,
-        meshes: &mut ResMut<Assets<Mesh>>,
-        player_query: &Query<(&Transform, &Player), With<PlayerBody>>,
-        placement_forward: Vec3,
-        target_world: IVec3,
-        block: Block,
-    ) -> bool {
-        let mut block_to_place = block;
-        if let Ok((player_transform, player)) = player_query.single() {
-            if player.intersects_block(player_transform.translation, target_world) {
+    /// Clear the other half of the door whose half `block` stood at `pos`, if it is still
+    /// there.
+    ///
+    /// Returns the chunk holding the cleared half.
+    fn clear_door_partner(&mut self, pos: IVec3, block: Block) -> Option<IVec3> {
+        let offset = block.door_partner_offset()?;
+        let partner = pos + offset;
+        self.get_block_world(partner)
+            .filter(|other| other.door_partner_offset() == Some(-offset))?;
+        self.set_block_world_loaded(partner, Block::air())
+    }
+
+    /// Swing the door with a half at `pos` open or shut, flipping both halves together.
+    ///
+    /// Returns `false` when there is no door at `pos`.
+    pub(crate) fn toggle_door(&mut self, meshes: &mut ResMut<Assets<Mesh>>, pos: IVec3) -> bool {
+        let Some(block) = self.get_block_world(pos) else {
+            return false;
+        };
+        let Some(offset) = block.door_partner_offset() else {
+            return false;
+        };
+        let open = !block.is_open_door();
+        let partner = pos + offset;
+        let mut touched: HashSet<IVec3> = HashSet::new();
+        touched.extend(self.set_state_world_loaded(pos, block.with_door_open(open).state));
+        if let Some(other) = self
+            .get_block_world(partner)
+            .filter(|other| other.door_partner_offset() == Some(-offset))
+        {
+            touched.extend(self.set_state_world_loaded(partner, other.with_door_open(open).state));
+        }
+        self.rebuild_touched_chunk_meshes(meshes, touched);
+        true
+    }
+
+    /// Reconnect the arms of a fence at `pos` and of the fences beside it to their neighbors.
+    ///
+    /// Returns the chunks holding a fence whose arms changed, which may lie across a chunk
+    /// border from `pos`.
+    pub(crate) fn connect_fences(&mut self, pos: IVec3) -> HashSet<IVec3> {
+        let mut touched = HashSet::new();
+        let cells = std::iter::once(pos).chain(Facing::HORIZONTAL.map(|f| pos + f.as_normal()));
+        for cell in cells {
+            let Some(fence) = self
+                .get_block_world(cell)
+                .filter(|block| block.kind == BlockKind::Fence)
+            else {
+                continue;
+            };
+            let connected = fence.with_fence_arms(|facing| {
+                self.get_block_world(cell + facing.as_normal())
+                    .is_some_and(|block| block.connects_fences())
+            });
+            if connected != fence {
+                touched.extend(self.set_state_world_loaded(cell, connected.state));
+            }
+        }
+        touched
+    }
+
+    /// Write a batch of block edits into loaded chunks and rebuild each touched chunk once.
+    ///
+    /// Edits landing in unloaded chunks are skipped. Returns how many edits were written.
+    pub(crate) fn set_blocks_world_loaded<I>(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        edits: I,
+    ) -> usize
+    where
+        I: IntoIterator<Item = (IVec3, Block)>,
+    {
+        let mut touched: HashSet<IVec3> = HashSet::new();
+        let mut written = 0;
+        for (pos, block) in edits {
+            let Some(chunk_coord) = self.set_block_world_loaded(pos, block) else {
+                continue;
+            };
+            touched.insert(chunk_coord);
+            touched.extend(self.connect_fences(pos));
+            written += 1;
+        }
+        self.rebuild_touched_chunk_meshes(meshes, touched);
+        written
+    }
+
+    /// Grow the sapling at `sapling` into an oak with a trunk `trunk_height` blocks tall.
+    ///
+    /// Returns `false` and leaves the world untouched when any trunk cell above the sapling
+    /// is not air, or the tree would reach into an unloaded chunk.
+    pub(crate) fn grow_tree(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        sapling: IVec3,
+        trunk_height: i32,
+    ) -> bool {
+        let ground = sapling - IVec3::Y;
+        let template = TreeRegistry::global().template(TreeKind::Oak);
+        let blocks = template.blocks(ground, trunk_height);
+        let mut edits = Vec::with_capacity(blocks.len());
+        for (cell, block) in blocks {
+            let Some(existing) = self.get_block_world(cell) else {
+                return false;
+            };
+            if block == template.trunk && cell != sapling && !existing.is_air() {
+                return false;
+            }
+            if template.fits(block, existing) {
+                edits.push((cell, block));
+            }
+        }
+        self.set_blocks_world_loaded(meshes, edits);
+        true
+    }
+
+    /// Carve a sphere of `radius` blocks around `center` and return how many blocks it removed.
+    ///
+    /// Removes what the player could break (bedrock and water stay) along with the other half
+    /// of any door it catches, rebuilds each touched
+    /// chunk once, and queues every cell next to the crater for falling checks so unsupported
+    /// sand collapses into it and stranded torches pop off.
+    pub(crate) fn explode(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        falling_queue: &mut FallingPropagationQueue,
+        center: IVec3,
+        radius: f32,
+    ) -> usize {
+        let reach = radius.floor() as i32;
+        let mut touched: HashSet<IVec3> = HashSet::new();
+        let mut removed = 0;
+        for x in -reach..=reach {
+            for y in -reach..=reach {
+                for z in -reach..=reach {
+                    let offset = IVec3::new(x, y, z);
+                    if offset.as_vec3().length_squared() > radius * radius {
+                        continue;
+                    }
+                    let pos = center + offset;
+                    let Some(block) = self
+                        .get_block_world(pos)
+                        .filter(|block| block.is_interactable())
+                    else {
+                        continue;
+                    };
+                    touched.extend(self.set_block_world_loaded(pos, Block::air()));
+                    touched.extend(self.connect_fences(pos));
+                    touched.extend(self.clear_door_partner(pos, block));
+                    falling_queue.enqueue_with_neighbors(pos);
+                    if let Some(partner) = block.door_partner_offset() {
+                        falling_queue.enqueue_with_neighbors(pos + partner);
+                    }
+                    removed += 1;
+                }
+            }
+        }
+        self.rebuild_touched_chunk_meshes(meshes, touched);
+        removed
+    }
+
+    /// Place one block at world position (if not intersecting player) and rebuild mesh.
+    ///
+    /// Pillar-jump placements under an airborne player's feet are allowed by nudging the
+    /// player up by the overlap, as long as the lifted collider is clear. `face_normal` is the
+    /// normal of the face clicked to place against, orienting axis blocks such as logs and
+    /// torches. Attached blocks are refused without a solid support. Doors write their upper
+    /// half too and are refused unless both cells are air and clear of the player. Grass
+    /// covered by an opaque block is scheduled to die to dirt, placed saplings start their
+    /// growth timer, and blocks carrying data such as chests get a fresh block entity.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn place_block(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        player_query: &mut Query<(&mut Transform, &mut Player), With<PlayerBody>>,
+        placement_forward: Vec3,
+        face_normal: IVec3,
+        target_world: IVec3,
+        block: Block,
+    ) -> bool {
+        let mut block_to_place = block;
+        let door_upper = (block.kind == BlockKind::Door).then_some(target_world + IVec3::Y);
+        if let Ok((mut player_transform, player)) = player_query.single_mut() {
+            let player_pos = player_transform.translation;
+            if door_upper.is_some_and(|upper| player.intersects_block(player_pos, upper)) {
                 return false;
             }
-            // Use full 3D look direction so front can be any of 6 cardinal directions.
-            block_to_place = block.with_front_from_direction(-placement_forward);
+            if block.is_solid() && player.intersects_block(player_pos, target_world) {
+                let Some(lift) = player.pillar_lift(player_pos, target_world, placement_forward)
+                else {
+                    return false;
+                };
+                let lifted = player_pos + Vec3::Y * lift;
+                if self.intersects_solid(lifted, player.half_size) {
+                    return false;
+                }
+                player_transform.translation = lifted;
+            }
+            block_to_place = block.oriented_for_placement(placement_forward, face_normal);
+        }
+        if !self.has_attachment_support(target_world, block_to_place) {
+            return false;
+        }
+        if let Some(upper) = door_upper
+            && ![target_world, upper].into_iter().all(|cell| {
+                self.get_block_world(cell)
+                    .is_some_and(|block| block.is_air())
+            })
+        {
+            return false;
         }
         let Some(chunk_coord) =
             self.set_block_world_ensured(commands, meshes, target_world, block_to_place)
         else {
             return false;
         };
-        self.rebuild_chunk_mesh(meshes, chunk_coord);
+        let mut touched = HashSet::from([chunk_coord]);
+        if let Some(upper) = door_upper {
+            touched.extend(self.set_block_world_loaded(upper, block_to_place.door_upper_half()));
+        }
+        self.create_missing_layer_meshes(commands, meshes);
+        let below = target_world - IVec3::Y;
+        if block_to_place.smothers_grass()
+            && self
+                .get_block_world(below)
+                .is_some_and(|block| block.kind == BlockKind::DirtWithGrass)
+        {
+            self.block_updates.schedule(below, GRASS_COVER_DELAY_SECS);
+        }
+        if block_to_place.kind == BlockKind::Sapling {
+            SaplingGrowth::new(target_world).spawn(commands);
+        }
+        if block_to_place.kind == BlockKind::Sponge && !block_to_place.is_wet_sponge() {
+            self.sponge_queue.push_back(target_world);
+        }
+        if let Some(entity) = BlockEntity::for_kind(block_to_place.kind) {
+            self.block_entities.insert(target_world, entity);
+        }
+        touched.extend(self.connect_fences(target_world));
+        self.rebuild_touched_chunk_meshes(meshes, touched);
         true
     }
 
@@ -423,19 +1515,64 @@ impl WorldState {
         if self.chunks.contains_key(&coord) {
             return;
         }
-        let chunk = Chunk::new_streaming(coord);
-        let mesh = meshes.add(mesh_from_data(build_chunk_mesh_data(&chunk)));
-        let entity = self.spawn_chunk_entity(commands, mesh.clone(), coord);
-        self.chunks
-            .insert(coord, ChunkData::new(chunk, mesh, entity));
+        // The synchronous load supersedes any queued or running async build.
+        self.pending.retain(|pending| *pending != coord);
+        self.in_flight.remove(&coord);
+        let chunk = self
+            .edited_chunks
+            .get(&coord)
+            .map(|stored| stored.chunk.clone())
+            .unwrap_or_else(|| self.generator.generate(coord));
+        let output =
+            build_chunk_output(coord, chunk, &self.chunk_neighbors(coord), self.sun_shading);
+        self.insert_loaded_chunk(commands, meshes, output);
     }
 
-    /// Unload one chunk and despawn its render entity if present.
+    /// Unload one chunk and despawn the render entities of all its mesh classes.
+    ///
+    /// Edited chunks go to the edit store together with their block entities.
     pub(crate) fn unload_chunk(&mut self, commands: &mut Commands, coord: IVec3) {
         let Some(data) = self.chunks.remove(&coord) else {
             return;
         };
         commands.entity(data.entity).despawn();
+        for layer_mesh in data.layer_meshes.values() {
+            commands.entity(layer_mesh.entity).despawn();
+        }
+        self.remesh_dirty.forget(coord);
+        self.remesh_tasks.remove(&coord);
+        self.queue_neighbor_remeshes(coord);
+        let block_entities = self.block_entities.take_chunk(coord);
+        if data.edited {
+            self.edited_chunks.insert(
+                coord,
+                EditedChunk {
+                    chunk: data.chunk,
+                    block_entities,
+                },
+            );
+        }
+    }
+
+    /// Collect loaded chunks that still match generated terrain, i.e. were never edited.
+    pub(crate) fn unedited_loaded_chunks(&self) -> Vec<IVec3> {
+        self.chunks
+            .iter()
+            .filter(|(_, data)| !data.edited)
+            .map(|(coord, _)| *coord)
+            .collect()
+    }
+
+    /// Drop unedited loaded chunks and queued builds so they regenerate with new terrain.
+    ///
+    /// Edited chunks keep their voxels. Streaming rebuilds the dropped chunks over the
+    /// next frames, since they are still in the needed set.
+    pub(crate) fn regenerate_chunks(&mut self, commands: &mut Commands, unedited: &[IVec3]) {
+        for coord in unedited {
+            self.unload_chunk(commands, *coord);
+        }
+        self.pending.clear();
+        self.in_flight.clear();
     }
 
     /// Spawn render entity from mesh data and insert loaded chunk payload.
@@ -443,27 +1580,360 @@ impl WorldState {
         &mut self,
         commands: &mut Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
-        coord: IVec3,
-        chunk: Chunk,
-        mesh_data: MeshData,
+        output: ChunkBuildOutput,
     ) {
-        let mesh = meshes.add(mesh_from_data(mesh_data));
+        let ChunkBuildOutput {
+            coord,
+            chunk,
+            mesh_data,
+            layer_mesh_data,
+            unbordered,
+            sun_shading,
+        } = output;
+        let (mesh, quad_cells) = chunk_mesh_from_data(mesh_data);
+        let mesh = meshes.add(mesh);
         let entity = self.spawn_chunk_entity(commands, mesh.clone(), coord);
-        self.chunks
-            .insert(coord, ChunkData::new(chunk, mesh, entity));
+        let mut chunk_data = ChunkData::new(chunk, mesh, entity);
+        chunk_data.quad_cells = quad_cells;
+        chunk_data.sun_baked = sun_shading;
+        for (layer, data) in layer_mesh_data {
+            let (mesh, quad_cells) = chunk_mesh_from_data(data);
+            let mesh = meshes.add(mesh);
+            let entity = self.spawn_layer_entity(commands, mesh.clone(), coord, layer);
+            chunk_data.layer_meshes.insert(
+                layer,
+                ChunkLayerMesh {
+                    mesh,
+                    entity,
+                    quad_cells,
+                },
+            );
+        }
+        // A chunk restored from the edit store stays edited so it is stored again on unload.
+        if let Some(stored) = self.edited_chunks.remove(&coord) {
+            self.block_entities.restore(stored.block_entities);
+            chunk_data.edited = true;
+        }
+        self.chunks.insert(coord, chunk_data);
+        self.queue_stale_sun_shading(coord);
+        self.queue_neighbor_remeshes(coord);
+        // Neighbors that loaded while this chunk was building are missing from its borders.
+        if unbordered
+            .iter()
+            .any(|offset| self.chunks.contains_key(&(coord + *offset)))
+        {
+            self.queue_remesh(coord);
+        }
+        self.seed_chunk_light(coord);
+        self.relight_sky_chunk_columns(coord);
+        self.respawn_chunk_entities(commands, meshes, coord);
+    }
+
+    /// Return the chunk owning a dynamic entity: the one containing its block-sized center.
+    pub(crate) fn entity_owner_chunk(translation: Vec3) -> IVec3 {
+        let center = translation + Vec3::splat(0.5 * BLOCK_SIZE);
+        Self::world_to_chunk_local(Block::world_coord_from_position(center)).0
+    }
+
+    /// Store one dynamic entity record with its unloaded owner chunk.
+    pub(crate) fn park_chunk_entity(&mut self, owner: IVec3, record: ChunkEntityRecord) {
+        self.chunk_entities.entry(owner).or_default().push(record);
+    }
+
+    /// Respawn dynamic entities parked with a chunk that just loaded.
+    fn respawn_chunk_entities(
+        &mut self,
+        commands: &mut Commands,
+        meshes: &mut Assets<Mesh>,
+        coord: IVec3,
+    ) {
+        let Some(records) = self.chunk_entities.remove(&coord) else {
+            return;
+        };
+        for record in records {
+            match record {
+                ChunkEntityRecord::FallingBlock {
+                    translation,
+                    velocity_y,
+                    block,
+                } => FallingBlock {
+                    velocity_y,
+                    ..FallingBlock::new(block)
+                }
+                .spawn(commands, meshes, self, translation),
+                ChunkEntityRecord::ItemDrop {
+                    center,
+                    velocity_y,
+                    age,
+                    block,
+                    count,
+                } => ItemDrop {
+                    block,
+                    count,
+                    center,
+                    velocity_y,
+                    age,
+                    stranded_secs: 0.0,
+                }
+                .spawn(commands, meshes, self),
+                ChunkEntityRecord::PrimedTnt {
+                    cell,
+                    fuse_left,
+                    blast_radius,
+                } => PrimedTnt {
+                    cell,
+                    fuse_left,
+                    blast_radius,
+                }
+                .spawn(commands, meshes, self),
+            }
+        }
+    }
+
+    /// Copy the borders of the loaded face-neighbor chunks of `coord` for its mesh build.
+    pub(crate) fn chunk_neighbors(&self, coord: IVec3) -> ChunkNeighbors {
+        ChunkNeighbors::from_fn(|offset| {
+            self.chunks
+                .get(&(coord + offset))
+                .map(|chunk_data| &chunk_data.chunk)
+        })
+    }
+
+    /// Queue a staged remesh of loaded chunk `coord` unless one is already queued.
+    fn queue_remesh(&mut self, coord: IVec3) {
+        if !self.remesh_queue.contains(&coord) {
+            self.remesh_queue.push_back(coord);
+        }
+    }
+
+    /// Queue staged remeshes of the loaded face neighbors of `coord`, whose border faces
+    /// were culled against it before it loaded or unloaded.
+    fn queue_neighbor_remeshes(&mut self, coord: IVec3) {
+        for offset in NEIGHBOR_CHUNK_OFFSETS {
+            if self.chunks.contains_key(&(coord + offset)) {
+                self.queue_remesh(coord + offset);
+            }
+        }
     }
 
     /// Rebuild mesh for one loaded chunk if both chunk and mesh handles exist.
+    ///
+    /// Meshes are refilled in place; the entities keep their full-chunk `Aabb` from
+    /// `chunk_bounds`, so culling never sees bounds of an older mesh. Any off-thread
+    /// rebuild of the chunk is dropped, as this one already shows its latest blocks.
     pub(crate) fn rebuild_chunk_mesh(&mut self, meshes: &mut ResMut<Assets<Mesh>>, coord: IVec3) {
+        let light = self.light_snapshot(coord);
+        let neighbors = self.chunk_neighbors(coord);
         let Some(chunk_data) = self.chunks.get_mut(&coord) else {
             return;
         };
+        self.remesh_dirty.show_latest(coord);
+        self.remesh_tasks.remove(&coord);
+        let output = build_chunk_remesh_output(
+            &chunk_data.chunk,
+            &neighbors,
+            self.sun_shading,
+            light.as_ref(),
+            separate_layers(chunk_data),
+        );
+        apply_chunk_remesh_output(chunk_data, meshes, output);
+        chunk_data.light_sources = chunk_data.chunk.light_sources();
+    }
+
+    /// Queue a full rebuild of loaded chunk `coord` for the next `spawn_remesh_tasks`.
+    ///
+    /// The light source list is refreshed right away so torch lights do not wait for the mesh.
+    fn queue_async_remesh(&mut self, coord: IVec3) {
+        let Some(chunk_data) = self.chunks.get_mut(&coord) else {
+            return;
+        };
+        chunk_data.light_sources = chunk_data.chunk.light_sources();
+        self.remesh_dirty.mark(coord);
+    }
+
+    /// Start an off-thread rebuild for every queued chunk without one running.
+    ///
+    /// Chunks with a rebuild still running stay queued, so the rebuild that follows it
+    /// picks up edits made after its snapshot was taken.
+    pub(crate) fn spawn_remesh_tasks(&mut self, task_pool: &AsyncComputeTaskPool) {
+        let ready: Vec<IVec3> = self
+            .remesh_dirty
+            .iter()
+            .filter(|coord| !self.remesh_tasks.contains_key(*coord))
+            .copied()
+            .collect();
+        for coord in ready {
+            self.remesh_dirty.start(coord);
+            let light = self.light_snapshot(coord);
+            let neighbors = self.chunk_neighbors(coord);
+            let sun_shading = self.sun_shading;
+            let Some(chunk_data) = self.chunks.get(&coord) else {
+                continue;
+            };
+            let chunk = chunk_data.chunk.clone();
+            let layers = separate_layers(chunk_data);
+            let task = task_pool.spawn(async move {
+                build_chunk_remesh_output(&chunk, &neighbors, sun_shading, light.as_ref(), layers)
+            });
+            self.remesh_tasks.insert(coord, task);
+        }
+    }
+
+    /// Poll off-thread rebuilds and swap finished meshes into their chunks.
+    pub(crate) fn apply_finished_remeshes(&mut self, meshes: &mut ResMut<Assets<Mesh>>) {
+        let mut finished = Vec::new();
+        for (coord, task) in self.remesh_tasks.iter_mut() {
+            if let Some(output) = future::block_on(future::poll_once(task)) {
+                finished.push((*coord, output));
+            }
+        }
+        for (coord, output) in finished {
+            self.remesh_tasks.remove(&coord);
+            self.remesh_dirty.finish(coord);
+            if let Some(chunk_data) = self.chunks.get_mut(&coord) {
+                apply_chunk_remesh_output(chunk_data, meshes, output);
+                self.queue_stale_sun_shading(coord);
+            }
+        }
+    }
+
+    /// Patch the meshes of loaded chunk `coord` for edits to its local `cells`, replacing
+    /// only the faces of those cells.
+    ///
+    /// Queues a full off-thread rebuild when any layer mesh cannot be patched.
+    fn remesh_chunk_cells(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        coord: IVec3,
+        cells: &HashSet<IVec3>,
+    ) {
+        let light = self.light_snapshot(coord);
+        let neighbors = self.chunk_neighbors(coord);
+        let Some(chunk_data) = self.chunks.get_mut(&coord) else {
+            return;
+        };
+        let mut patched = true;
         if let Some(mesh) = meshes.get_mut(&chunk_data.mesh) {
-            *mesh = mesh_from_data(build_chunk_mesh_data(&chunk_data.chunk));
+            patched &= remesh_cells(
+                mesh,
+                &mut chunk_data.quad_cells,
+                &chunk_data.chunk,
+                &neighbors,
+                chunk_data.sun_baked,
+                light.as_ref(),
+                MeshLayer::Opaque,
+                cells,
+            );
+        }
+        for (layer, layer_mesh) in &mut chunk_data.layer_meshes {
+            if let Some(mesh) = meshes.get_mut(&layer_mesh.mesh) {
+                patched &= remesh_cells(
+                    mesh,
+                    &mut layer_mesh.quad_cells,
+                    &chunk_data.chunk,
+                    &neighbors,
+                    None,
+                    None,
+                    *layer,
+                    cells,
+                );
+            }
+        }
+        chunk_data.light_sources = chunk_data.chunk.light_sources();
+        if !patched {
+            self.queue_async_remesh(coord);
+        }
+    }
+
+    /// Switch baked sun shading.
+    ///
+    /// Turning cheap shadows on or off changes the ambient occlusion strength too, so every
+    /// loaded chunk is queued for a staged remesh. A sun that merely moved only queues the
+    /// loaded chunks for a re-tint of their baked colors. Chunks still building catch up
+    /// once their mesh arrives.
+    pub(crate) fn set_sun_shading(&mut self, sun_shading: Option<Vec3>) {
+        if self.sun_shading == sun_shading {
+            return;
+        }
+        let preset_changed = self.sun_shading.is_some() != sun_shading.is_some();
+        self.sun_shading = sun_shading;
+        let queue = if preset_changed {
+            &mut self.remesh_queue
+        } else {
+            &mut self.sun_queue
+        };
+        queue.clear();
+        queue.extend(self.chunks.keys().copied());
+    }
+
+    /// Queue loaded chunk `coord` to catch up when its mesh shows other sun shading than
+    /// `sun_shading`: a re-tint when only the direction differs, a staged remesh otherwise.
+    fn queue_stale_sun_shading(&mut self, coord: IVec3) {
+        let Some(baked) = self.chunks.get(&coord).map(|data| data.sun_baked) else {
+            return;
+        };
+        match (baked, self.sun_shading) {
+            (baked, current) if baked == current => {}
+            (Some(_), Some(_)) => {
+                if !self.sun_queue.contains(&coord) {
+                    self.sun_queue.push_back(coord);
+                }
+            }
+            _ => self.queue_remesh(coord),
+        }
+    }
+
+    /// Rebuild up to `budget` queued chunk meshes, dropping chunks that left the streaming
+    /// window.
+    pub(crate) fn process_remesh_queue(
+        &mut self,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        budget: usize,
+    ) {
+        for _ in 0..budget.min(self.remesh_queue.len()) {
+            let Some(coord) = self.remesh_queue.pop_front() else {
+                break;
+            };
+            if self.chunks.contains_key(&coord) {
+                self.rebuild_chunk_mesh(meshes, coord);
+            }
+        }
+    }
+
+    /// Re-tint up to `budget` queued chunk meshes to the current sun direction.
+    ///
+    /// Only the baked colors are rescaled, so this costs a pass over the vertices instead of
+    /// a remesh. Chunks whose mesh has no sun direction baked wait for their staged remesh.
+    pub(crate) fn process_sun_queue(&mut self, meshes: &mut ResMut<Assets<Mesh>>, budget: usize) {
+        let Some(to_sun) = self.sun_shading else {
+            self.sun_queue.clear();
+            return;
+        };
+        for _ in 0..budget.min(self.sun_queue.len()) {
+            let Some(coord) = self.sun_queue.pop_front() else {
+                break;
+            };
+            let Some(chunk_data) = self.chunks.get_mut(&coord) else {
+                continue;
+            };
+            let Some(baked) = chunk_data.sun_baked.filter(|baked| *baked != to_sun) else {
+                continue;
+            };
+            if let Some(mesh) = meshes.get_mut(&chunk_data.mesh) {
+                retint_sun_shading(mesh, baked, to_sun);
+                chunk_data.sun_baked = Some(to_sun);
+            }
         }
     }
 
     /// Rebuild meshes for a set of touched chunk coordinates.
+    ///
+    /// Neighbor chunks whose border faces look into a cell edited since the last batch are
+    /// rebuilt along with them. Chunks with few `edited_cells` only get those cells'
+    /// faces patched right away; the others, including chunks touched without an edit such
+    /// as by a light change, are queued for a full rebuild off the main thread. Chunks
+    /// already waiting on one are queued again rather than patched, since the running
+    /// rebuild would overwrite the patch with an older snapshot.
     pub(crate) fn rebuild_touched_chunk_meshes<I>(
         &mut self,
         meshes: &mut ResMut<Assets<Mesh>>,
@@ -471,22 +1941,40 @@ impl WorldState {
     ) where
         I: IntoIterator<Item = IVec3>,
     {
+        let mut touched: HashSet<IVec3> = touched.into_iter().collect();
+        touched.extend(self.border_touched.drain());
+        let mut edited_cells = std::mem::take(&mut self.edited_cells);
         for coord in touched {
-            self.rebuild_chunk_mesh(meshes, coord);
+            let awaiting_rebuild =
+                self.remesh_dirty.contains(&coord) || self.remesh_tasks.contains_key(&coord);
+            match edited_cells.remove(&coord) {
+                Some(cells) if cells.len() <= MAX_INCREMENTAL_CELLS && !awaiting_rebuild => {
+                    self.remesh_chunk_cells(meshes, coord, &cells);
+                }
+                _ => self.queue_async_remesh(coord),
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use bevy::ecs::system::RunSystemOnce;
     use bevy::prelude::*;
+    use bevy::tasks::TaskPool;
+    use std::sync::Arc;
+
+    use crate::terrain::TerrainNoise;
+    use crate::voxel::block_chunk::Facing;
+    use crate::voxel::generator::{CheckerboardGenerator, FlatGenerator};
+    use crate::voxel::light_state::{NIGHT_SKY_DARKENING, SKY_LIGHT_FALLOFF};
 
     use super::*;
 
     /// Verify landing write-back updates loaded chunk voxel and reports touched chunk.
     #[test]
     fn set_block_world_loaded_writes_into_loaded_chunk() {
-        let mut state = WorldState::new(Handle::<StandardMaterial>::default());
+        let mut state = WorldState::new();
         let chunk_coord = IVec3::new(0, 0, 0);
         state.chunks.insert(
             chunk_coord,
@@ -504,4 +1992,1383 @@ mod tests {
             matches!(state.get_block_world(landing_block), Some(block) if block == Block::dirt())
         );
     }
+
+    /// Verify block metadata survives world and chunk round-trips, across negative coordinates.
+    #[test]
+    fn block_state_round_trips_through_world() {
+        let mut state = WorldState::new();
+        let positions = [
+            IVec3::new(-1, -1, -1),
+            IVec3::new(-17, 5, -33),
+            IVec3::new(0, -16, 15),
+            IVec3::new(3, 2, 1),
+        ];
+        for pos in positions {
+            let (coord, _) = WorldState::world_to_chunk_local(pos);
+            state.chunks.entry(coord).or_insert_with(|| {
+                ChunkData::new(
+                    Chunk::new_empty(),
+                    Handle::<Mesh>::default(),
+                    Entity::PLACEHOLDER,
+                )
+            });
+        }
+
+        for (i, pos) in positions.into_iter().enumerate() {
+            let block = Block::sand().with_state(200 + i as u8);
+            assert!(state.set_block_world_loaded(pos, block).is_some());
+            assert_eq!(state.get_block_world(pos), Some(block));
+        }
+        for (i, pos) in positions.into_iter().enumerate() {
+            let (coord, local) = WorldState::world_to_chunk_local(pos);
+            let chunk = &mut state.chunks.get_mut(&coord).unwrap().chunk;
+            assert_eq!(chunk.get_state(local), 200 + i as u8);
+            chunk.set_state(local, 7);
+            assert_eq!(
+                state.get_block_world(pos),
+                Some(Block::sand().with_state(7))
+            );
+        }
+        assert_eq!(
+            state.get_block_world(IVec3::new(-2, -1, -1)),
+            Some(Block::air())
+        );
+    }
+
+    /// Verify generated logs and ores break through the normal `break_block` path.
+    #[test]
+    fn generated_features_break_like_any_block() {
+        for kind in [BlockKind::Log, BlockKind::CoalOre, BlockKind::IronOre] {
+            let (coord, chunk, local) = (-8..8)
+                .flat_map(|x| (0..2).flat_map(move |y| (-8..8).map(move |z| IVec3::new(x, y, z))))
+                .find_map(|coord| {
+                    let chunk = Chunk::new_terrain(coord, 0, &TerrainConfig::default());
+                    let local = (0..CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE)
+                        .map(|i| {
+                            IVec3::new(
+                                i % CHUNK_SIZE,
+                                i / (CHUNK_SIZE * CHUNK_SIZE),
+                                i / CHUNK_SIZE % CHUNK_SIZE,
+                            )
+                        })
+                        .find(|&cell| chunk.get_block(cell).kind == kind)?;
+                    Some((coord, chunk, local))
+                })
+                .unwrap_or_else(|| panic!("no generated {kind:?} near the origin"));
+            let mut ecs = World::new();
+            let mut state = WorldState::new();
+            state.chunks.insert(
+                coord,
+                ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+            );
+            ecs.insert_resource(state);
+            ecs.init_resource::<Assets<Mesh>>();
+
+            let target = coord * CHUNK_SIZE + local;
+            let broke = ecs
+                .run_system_once(
+                    move |mut world: ResMut<WorldState>, mut meshes: ResMut<Assets<Mesh>>| {
+                        world.break_block(&mut meshes, target)
+                    },
+                )
+                .unwrap();
+            assert!(broke, "{kind:?} refused to break");
+            assert_eq!(
+                ecs.resource::<WorldState>().get_block_world(target),
+                Some(Block::air())
+            );
+        }
+    }
+
+    /// Verify generated bedrock refuses to break through `break_block`.
+    #[test]
+    fn bedrock_is_unbreakable() {
+        let coord = IVec3::new(0, MIN_CHUNK_LAYER, 0);
+        let chunk = Chunk::new_terrain(coord, 0, &TerrainConfig::default());
+        assert_eq!(chunk.get_block(IVec3::new(3, 0, 3)), Block::bedrock());
+        let floor = IVec3::new(3, TerrainNoise::BEDROCK_Y, 3);
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.chunks.insert(
+            coord,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let broke = ecs
+            .run_system_once(
+                move |mut world: ResMut<WorldState>, mut meshes: ResMut<Assets<Mesh>>| {
+                    world.break_block(&mut meshes, floor)
+                },
+            )
+            .unwrap();
+        assert!(!broke);
+        let world = ecs.resource::<WorldState>();
+        assert_eq!(world.get_block_world(floor), Some(Block::bedrock()));
+        assert!(world.is_solid_at_world_pos(floor));
+    }
+
+    /// Verify an explosion carves a sphere, spares bedrock, and queues the sand above it.
+    #[test]
+    fn explosion_carves_sphere_and_queues_crater_rim() {
+        let mut chunk = Chunk::new_empty();
+        for x in 0..CHUNK_SIZE {
+            for y in 0..6 {
+                for z in 0..CHUNK_SIZE {
+                    chunk.set_block(IVec3::new(x, y, z), Block::stone());
+                }
+            }
+            for z in 0..CHUNK_SIZE {
+                chunk.set_block(IVec3::new(x, 6, z), Block::sand());
+            }
+        }
+        let center = IVec3::new(8, 3, 8);
+        chunk.set_block(center + IVec3::X, Block::bedrock());
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let (removed, queued) = ecs
+            .run_system_once(
+                move |mut world: ResMut<WorldState>, mut meshes: ResMut<Assets<Mesh>>| {
+                    let mut queue = FallingPropagationQueue::default();
+                    let removed = world.explode(&mut meshes, &mut queue, center, 2.0);
+                    let queued: HashSet<IVec3> = std::iter::from_fn(|| queue.pop()).collect();
+                    (removed, queued)
+                },
+            )
+            .unwrap();
+        // 33 cells lie within two blocks of the center; the bedrock one stays.
+        assert_eq!(removed, 32);
+        let world = ecs.resource::<WorldState>();
+        assert_eq!(world.get_block_world(center), Some(Block::air()));
+        assert_eq!(
+            world.get_block_world(center + IVec3::Y * 2),
+            Some(Block::air())
+        );
+        assert_eq!(
+            world.get_block_world(center + IVec3::X),
+            Some(Block::bedrock())
+        );
+        assert_eq!(
+            world.get_block_world(center + IVec3::new(2, 1, 0)),
+            Some(Block::stone())
+        );
+        assert!(queued.contains(&IVec3::new(8, 6, 8)));
+        assert!(world.chunks[&IVec3::ZERO].edited);
+    }
+
+    /// Verify async streaming fills chunks from the world's generator, across chunk borders.
+    #[test]
+    fn streamed_chunks_come_from_world_generator() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let checkerboard = CheckerboardGenerator { height: 3 };
+        let mut state = WorldState::new();
+        state.generator = Arc::new(checkerboard);
+        let mut ecs = World::new();
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let coords = [
+            IVec3::new(0, 0, 0),
+            IVec3::new(-1, 0, 0),
+            IVec3::new(0, 0, -1),
+            IVec3::new(-1, 0, -1),
+        ];
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                state.sync_needed_set(coords.into_iter().collect());
+                state.enqueue_needed_chunks();
+                let mut finished = Vec::new();
+                while !state.pending.is_empty() || !state.in_flight.is_empty() {
+                    state.spawn_chunk_build_tasks(AsyncComputeTaskPool::get());
+                    finished.extend(state.collect_finished_chunk_tasks());
+                }
+                state.apply_finished_chunk_results(&mut commands, &mut meshes, finished);
+            },
+        )
+        .unwrap();
+
+        let state = ecs.resource::<WorldState>();
+        assert_eq!(state.chunks.len(), coords.len());
+        for z in -CHUNK_SIZE..CHUNK_SIZE {
+            for x in -CHUNK_SIZE..CHUNK_SIZE {
+                for y in 0..5 {
+                    let pos = IVec3::new(x, y, z);
+                    assert_eq!(state.get_block_world(pos), Some(checkerboard.block_at(pos)));
+                }
+            }
+        }
+    }
+
+    /// Verify culling border faces against neighbor chunks drops over half the triangles of
+    /// a generated 8×8 region, and that edits on a border mark the neighbor for a remesh.
+    #[test]
+    fn neighbor_culling_trims_region_triangles() {
+        let mut state = WorldState::new();
+        for z in 0..8 {
+            for y in MIN_CHUNK_LAYER..MAX_CHUNK_LAYER {
+                for x in 0..8 {
+                    let coord = IVec3::new(x, y, z);
+                    let chunk = state.generator.generate(coord);
+                    state.chunks.insert(
+                        coord,
+                        ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+                    );
+                }
+            }
+        }
+        let (mut isolated, mut bordered) = (0, 0);
+        for (coord, chunk_data) in &state.chunks {
+            let chunk = &chunk_data.chunk;
+            isolated += build_chunk_mesh_data(chunk, &ChunkNeighbors::default(), None, None)
+                .indices
+                .len()
+                / 3;
+            bordered += build_chunk_mesh_data(chunk, &state.chunk_neighbors(*coord), None, None)
+                .indices
+                .len()
+                / 3;
+        }
+        assert!(bordered * 2 < isolated);
+
+        state.set_block_world_loaded(IVec3::new(CHUNK_SIZE - 1, 5, 5), Block::glass());
+        assert_eq!(state.border_touched, HashSet::from([IVec3::X]));
+        state.set_block_world_loaded(IVec3::new(CHUNK_SIZE - 2, 5, 5), Block::glass());
+        assert_eq!(state.border_touched, HashSet::from([IVec3::X]));
+    }
+
+    /// Verify AABB collision follows generated pillars and gaps of a known pattern.
+    #[test]
+    fn collision_follows_generated_pattern() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(CheckerboardGenerator { height: 2 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+        ecs.run_system_once(
+            |mut commands: Commands,
+             mut meshes: ResMut<Assets<Mesh>>,
+             mut state: ResMut<WorldState>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::new(-1, 0, 0));
+            },
+        )
+        .unwrap();
+
+        let state = ecs.resource::<WorldState>();
+        let half = Vec3::splat(0.4 * BLOCK_SIZE);
+        let center = |cell: IVec3| (cell.as_vec3() + Vec3::splat(0.5)) * BLOCK_SIZE;
+        assert!(state.intersects_solid(center(IVec3::new(-2, 1, 4)), half));
+        assert!(!state.intersects_solid(center(IVec3::new(-1, 1, 4)), half));
+        assert!(!state.intersects_solid(center(IVec3::new(-2, 2, 4)), half));
+        let standing = |cell: IVec3| center(cell).with_y(2.0 * BLOCK_SIZE + half.y);
+        assert!(state.has_ground_support(standing(IVec3::new(-2, 2, 4)), half));
+        assert!(!state.has_ground_support(standing(IVec3::new(-1, 2, 4)), half));
+    }
+
+    /// Verify a flat generator world loads its slab through `ensure_chunk`.
+    #[test]
+    fn flat_world_loads_slab() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 5 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+        ecs.run_system_once(
+            |mut commands: Commands,
+             mut meshes: ResMut<Assets<Mesh>>,
+             mut state: ResMut<WorldState>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+            },
+        )
+        .unwrap();
+        let state = ecs.resource::<WorldState>();
+        let surface = IVec3::new(6, 5, 9);
+        assert_eq!(
+            state.get_block_world(surface),
+            Some(Block::dirt_with_grass())
+        );
+        assert_eq!(
+            state.get_block_world(surface.with_y(0)),
+            Some(Block::bedrock())
+        );
+        assert_eq!(
+            state.get_block_world(surface + IVec3::Y),
+            Some(Block::air())
+        );
+    }
+
+    /// Verify the first glass block placed into a chunk gets a glass mesh and entity drawn
+    /// with the transparent material, and unloading despawns every class entity.
+    #[test]
+    fn placed_glass_gets_its_own_chunk_mesh() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let placed = IVec3::new(2, 4, 2);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>,
+                  mut players: Query<(&mut Transform, &mut Player), With<PlayerBody>>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                assert!(state.chunks[&IVec3::ZERO].layer_meshes.is_empty());
+                assert!(state.place_block(
+                    &mut commands,
+                    &mut meshes,
+                    &mut players,
+                    Vec3::NEG_Y,
+                    IVec3::Y,
+                    placed,
+                    Block::glass(),
+                ));
+            },
+        )
+        .unwrap();
+
+        assert_eq!(ecs.query::<&bevy::mesh::Mesh3d>().iter(&ecs).count(), 2);
+        let state = ecs.resource::<WorldState>();
+        assert_eq!(state.get_block_world(placed), Some(Block::glass()));
+        let glass = &state.chunks[&IVec3::ZERO].layer_meshes[&MeshLayer::Glass];
+        assert_eq!(
+            ecs.get::<WorldMaterial>(glass.entity),
+            Some(&WorldMaterial::Transparent)
+        );
+        let meshes = ecs.resource::<Assets<Mesh>>();
+        // Every face but the bottom one resting on the grass.
+        assert_eq!(meshes.get(&glass.mesh).unwrap().count_vertices(), 5 * 4);
+
+        ecs.run_system_once(
+            |mut commands: Commands,
+             mut meshes: ResMut<Assets<Mesh>>,
+             mut state: ResMut<WorldState>| {
+                state.set_blocks_world_loaded(
+                    &mut meshes,
+                    [(IVec3::new(5, 4, 5), Block::glowstone())],
+                );
+                state.create_missing_layer_meshes(&mut commands, &mut meshes);
+            },
+        )
+        .unwrap();
+        assert_eq!(ecs.query::<&bevy::mesh::Mesh3d>().iter(&ecs).count(), 3);
+        let emissive =
+            &ecs.resource::<WorldState>().chunks[&IVec3::ZERO].layer_meshes[&MeshLayer::Emissive];
+        assert_eq!(
+            ecs.get::<WorldMaterial>(emissive.entity),
+            Some(&WorldMaterial::Emissive)
+        );
+
+        ecs.run_system_once(|mut commands: Commands, mut state: ResMut<WorldState>| {
+            state.unload_chunk(&mut commands, IVec3::ZERO);
+        })
+        .unwrap();
+        assert_eq!(ecs.query::<&bevy::mesh::Mesh3d>().iter(&ecs).count(), 0);
+    }
+
+    /// Verify water set into a chunk built without any gets a water mesh once the remesh
+    /// system creates missing layer meshes, even though the edit had no `Commands`.
+    #[test]
+    fn water_reaching_a_dry_chunk_gets_its_own_mesh() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let flooded = IVec3::new(2, 3, 2);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                assert!(state.chunks[&IVec3::ZERO].layer_meshes.is_empty());
+                state.set_blocks_world_loaded(&mut meshes, [(flooded, Block::water())]);
+                assert!(
+                    state
+                        .missing_layer_meshes
+                        .contains(&(IVec3::ZERO, MeshLayer::Water))
+                );
+                state.create_missing_layer_meshes(&mut commands, &mut meshes);
+                assert!(state.missing_layer_meshes.is_empty());
+            },
+        )
+        .unwrap();
+
+        assert_eq!(ecs.query::<&bevy::mesh::Mesh3d>().iter(&ecs).count(), 2);
+        let state = ecs.resource::<WorldState>();
+        let chunk_data = &state.chunks[&IVec3::ZERO];
+        let water = &chunk_data.layer_meshes[&MeshLayer::Water];
+        assert!(!water.quad_cells.is_empty());
+        assert_eq!(
+            ecs.get::<WorldMaterial>(water.entity),
+            Some(&WorldMaterial::Fluid)
+        );
+        let meshes = ecs.resource::<Assets<Mesh>>();
+        // Only the surface shows; the sides face the grass the water is sunk into.
+        assert_eq!(meshes.get(&water.mesh).unwrap().count_vertices(), 4);
+    }
+
+    /// Verify a chunk streamed in empty renders the blocks later set into it: its mesh is
+    /// refilled and its entity keeps culling bounds covering the whole chunk.
+    #[test]
+    fn chunk_loaded_empty_renders_later_edits() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let coord = IVec3::Y;
+        let placed = coord * CHUNK_SIZE + IVec3::new(CHUNK_SIZE - 1, 5, CHUNK_SIZE - 1);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                state.ensure_chunk(&mut commands, &mut meshes, coord);
+                let mesh = state.chunks[&coord].mesh.clone();
+                assert_eq!(meshes.get(&mesh).unwrap().count_vertices(), 0);
+                let touched = state.set_block_world_loaded(placed, Block::stone());
+                state.rebuild_touched_chunk_meshes(&mut meshes, touched);
+            },
+        )
+        .unwrap();
+
+        let state = ecs.resource::<WorldState>();
+        let chunk_data = &state.chunks[&coord];
+        let meshes = ecs.resource::<Assets<Mesh>>();
+        assert_eq!(
+            meshes.get(&chunk_data.mesh).unwrap().count_vertices(),
+            6 * 4
+        );
+        let entity = ecs.entity(chunk_data.entity);
+        assert!(entity.contains::<bevy::camera::visibility::NoAutoAabb>());
+        let aabb = entity.get::<bevy::camera::primitives::Aabb>().unwrap();
+        let local = (placed - coord * CHUNK_SIZE).as_vec3() * BLOCK_SIZE;
+        assert!(
+            (aabb.min().cmple(local.into()) & aabb.max().cmpge((local + BLOCK_SIZE).into())).all()
+        );
+        assert_eq!(
+            Vec3::from(aabb.max() - aabb.min()),
+            Vec3::splat(CHUNK_SIZE as f32 * BLOCK_SIZE)
+        );
+    }
+
+    /// Verify an edit too large to patch is rebuilt off the main thread, and an edit made
+    /// while that rebuild runs still shows once the chunk settles.
+    #[test]
+    fn large_edits_remesh_off_thread_without_losing_later_edits() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let late = IVec3::new(2, 4, 2);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                let before = state.chunks[&IVec3::ZERO].quad_cells.clone();
+                let mut falling = FallingPropagationQueue::default();
+                assert!(state.explode(&mut meshes, &mut falling, IVec3::new(8, 3, 8), 3.0) > 0);
+                assert!(state.remesh_dirty.contains(&IVec3::ZERO));
+                assert_eq!(state.chunks[&IVec3::ZERO].quad_cells, before);
+
+                state.spawn_remesh_tasks(AsyncComputeTaskPool::get());
+                assert!(state.remesh_tasks.contains_key(&IVec3::ZERO));
+                state.set_blocks_world_loaded(&mut meshes, [(late, Block::stone())]);
+                assert!(state.remesh_dirty.contains(&IVec3::ZERO));
+
+                while !state.remesh_tasks.is_empty() || state.remesh_dirty.iter().next().is_some() {
+                    state.apply_finished_remeshes(&mut meshes);
+                    state.spawn_remesh_tasks(AsyncComputeTaskPool::get());
+                }
+            },
+        )
+        .unwrap();
+
+        let state = ecs.resource::<WorldState>();
+        let chunk_data = &state.chunks[&IVec3::ZERO];
+        let expected = build_chunk_mesh_data(
+            &chunk_data.chunk,
+            &state.chunk_neighbors(IVec3::ZERO),
+            None,
+            None,
+        );
+        assert_eq!(chunk_data.quad_cells, expected.quad_cells);
+        let meshes = ecs.resource::<Assets<Mesh>>();
+        assert_eq!(
+            meshes.get(&chunk_data.mesh).unwrap().count_vertices(),
+            expected.positions.len()
+        );
+    }
+
+    /// Verify a fence placed beside another across a chunk border connects both and remeshes
+    /// the neighbor chunk, and that fences collide too tall to jump over.
+    #[test]
+    fn fences_connect_across_chunk_border_and_stand_tall() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let (near, far) = (IVec3::new(15, 4, 2), IVec3::new(16, 4, 2));
+        let place = move |cell: IVec3| {
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>,
+                  mut players: Query<(&mut Transform, &mut Player), With<PlayerBody>>| {
+                for coord in [IVec3::ZERO, IVec3::X] {
+                    state.ensure_chunk(&mut commands, &mut meshes, coord);
+                }
+                assert!(state.place_block(
+                    &mut commands,
+                    &mut meshes,
+                    &mut players,
+                    Vec3::NEG_Y,
+                    IVec3::Y,
+                    cell,
+                    Block::fence(),
+                ));
+            }
+        };
+        let far_vertices = |ecs: &World| {
+            let handle = &ecs.resource::<WorldState>().chunks[&IVec3::X].mesh;
+            ecs.resource::<Assets<Mesh>>()
+                .get(handle)
+                .unwrap()
+                .count_vertices()
+        };
+        ecs.run_system_once(place(far)).unwrap();
+        let lone_post = far_vertices(&ecs);
+        ecs.run_system_once(place(near)).unwrap();
+        assert!(far_vertices(&ecs) > lone_post);
+
+        let state = ecs.resource::<WorldState>();
+        let near_block = state.get_block_world(near).unwrap();
+        let far_block = state.get_block_world(far).unwrap();
+        assert_eq!(near_block.fence_arms().collect::<Vec<_>>(), [Facing::PosX]);
+        assert_eq!(far_block.fence_arms().collect::<Vec<_>>(), [Facing::NegX]);
+
+        let half_size = Vec3::new(0.3, 0.9, 0.3) * BLOCK_SIZE;
+        let above_post =
+            (far.as_vec3() + Vec3::new(0.5, 1.0, 0.5)) * BLOCK_SIZE + Vec3::Y * half_size.y;
+        assert_eq!(
+            state.highest_solid_top(above_post, half_size),
+            Some((far.y as f32 + 1.5) * BLOCK_SIZE)
+        );
+        assert!(!state.intersects_solid(above_post + Vec3::Y * 0.5 * BLOCK_SIZE, half_size));
+    }
+
+    /// Verify crouch support follows collision boxes: a fence top holds the player above its
+    /// cell, while the empty upper half of a bottom slab gives no footing.
+    #[test]
+    fn ground_support_follows_collision_boxes() {
+        let mut state = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        let (fence, slab) = (IVec3::new(2, 1, 2), IVec3::new(6, 1, 2));
+        chunk.set_block(fence, Block::fence());
+        chunk.set_block(slab, Block::slab());
+        state.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let half_size = Vec3::new(0.3, 0.9, 0.3) * BLOCK_SIZE;
+        let standing = |cell: IVec3, height: f32| {
+            (cell.as_vec3() + Vec3::new(0.5, height, 0.5)) * BLOCK_SIZE + Vec3::Y * half_size.y
+        };
+
+        assert!(state.has_ground_support(standing(fence, 1.5), half_size));
+        assert!(!state.has_ground_support(standing(fence, 2.0), half_size));
+        assert!(state.has_ground_support(standing(slab, 0.5), half_size));
+        assert!(!state.has_ground_support(standing(slab, 1.0), half_size));
+    }
+
+    /// Verify stairs collide with their lower half and the upper quarter behind their front,
+    /// for every horizontal facing.
+    #[test]
+    fn stairs_collide_with_lower_half_and_upper_back() {
+        let cell = IVec3::new(3, 1, 3);
+        let center = (cell.as_vec3() + Vec3::splat(0.5)) * BLOCK_SIZE;
+        let probe = Vec3::splat(0.2 * BLOCK_SIZE);
+        for front in [Facing::PosX, Facing::NegX, Facing::PosZ, Facing::NegZ] {
+            let mut state = WorldState::new();
+            let mut chunk = Chunk::new_empty();
+            chunk.set_block(
+                cell,
+                Block::stairs().with_front_from_direction(front.as_normal().as_vec3()),
+            );
+            state.chunks.insert(
+                IVec3::ZERO,
+                ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+            );
+
+            let toward_front = front.as_normal().as_vec3() * 0.25 * BLOCK_SIZE;
+            let upper = center + Vec3::Y * 0.25 * BLOCK_SIZE;
+            let lower = center - Vec3::Y * 0.25 * BLOCK_SIZE;
+            assert!(
+                state.intersects_solid(upper - toward_front, probe),
+                "{front:?}"
+            );
+            assert!(
+                !state.intersects_solid(upper + toward_front, probe),
+                "{front:?}"
+            );
+            assert!(
+                state.intersects_solid(lower + toward_front, probe),
+                "{front:?}"
+            );
+            assert!(
+                state.intersects_solid(lower - toward_front, probe),
+                "{front:?}"
+            );
+        }
+    }
+
+    /// Verify falling-block colliders never count as solid, and a player inside one is pushed
+    /// out along its shallowest exit that stays clear of blocks.
+    #[test]
+    fn transient_colliders_push_out_instead_of_blocking() {
+        let mut state = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(IVec3::new(2, 0, 2), Block::stone());
+        chunk.set_block(IVec3::new(3, 1, 2), Block::stone());
+        state.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        state.transient_colliders.push(TransientCollider {
+            min: Vec3::new(2.0, 1.0, 2.0),
+            max: Vec3::new(3.0, 2.0, 3.0),
+            velocity_y: -4.0,
+        });
+        let half = Vec3::splat(0.3 * BLOCK_SIZE);
+
+        let sunk = Vec3::new(2.5, 2.2, 2.5);
+        assert!(!state.intersects_solid(sunk, half));
+        assert_eq!(state.highest_solid_top(sunk, half), None);
+        assert_eq!(
+            state.push_out_of_transient_colliders(sunk, half),
+            Some(Vec3::new(2.5, 2.0 + half.y, 2.5))
+        );
+
+        // The shallowest exit, toward +X, ends in stone, so the player goes out over the top.
+        let side = Vec3::new(2.8, 1.5, 2.5);
+        let pushed = state.push_out_of_transient_colliders(side, half).unwrap();
+        assert!((pushed - Vec3::new(2.8, 2.0 + half.y, 2.5)).length() < 1e-5);
+        assert_eq!(
+            state.push_out_of_transient_colliders(Vec3::new(4.5, 1.5, 2.5), half),
+            None
+        );
+    }
+
+    /// Verify torches only attach to a solid block below or beside them, never to a ceiling.
+    #[test]
+    fn torch_placement_needs_solid_support() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let grounded = IVec3::new(2, 4, 2);
+        let floating = IVec3::new(2, 6, 2);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>,
+                  mut players: Query<(&mut Transform, &mut Player), With<PlayerBody>>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                for (cell, placed) in [(grounded, true), (floating, false)] {
+                    assert_eq!(
+                        state.place_block(
+                            &mut commands,
+                            &mut meshes,
+                            &mut players,
+                            Vec3::NEG_Y,
+                            IVec3::Y,
+                            cell,
+                            Block::torch(),
+                        ),
+                        placed
+                    );
+                }
+            },
+        )
+        .unwrap();
+
+        let state = ecs.resource::<WorldState>();
+        assert_eq!(state.get_block_world(grounded), Some(Block::torch()));
+        assert_eq!(state.get_block_world(floating), Some(Block::air()));
+        assert_eq!(
+            state.chunks[&IVec3::ZERO].light_sources,
+            vec![(grounded, Block::torch())]
+        );
+        let hanging = Block::torch().oriented_for_placement(Vec3::Y, IVec3::NEG_Y);
+        assert!(!state.has_attachment_support(IVec3::new(2, 2, 2), hanging));
+        let wall = Block::torch().oriented_for_placement(Vec3::NEG_X, IVec3::X);
+        assert!(!state.has_attachment_support(grounded, wall));
+    }
+
+    /// Verify a door needs air for both halves, swings its collision aside when opened from
+    /// either half, and breaks as a whole.
+    #[test]
+    fn door_places_both_halves_toggles_and_breaks_whole() {
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let (door, blocked) = (IVec3::new(2, 4, 2), IVec3::new(6, 4, 2));
+        let probe = move |state: &WorldState, offset: Vec3| {
+            state.intersects_solid(
+                Block::world_translation(door) + offset * BLOCK_SIZE,
+                Vec3::splat(0.05 * BLOCK_SIZE),
+            )
+        };
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>,
+                  mut players: Query<(&mut Transform, &mut Player), With<PlayerBody>>| {
+                state.ensure_chunk(&mut commands, &mut meshes, IVec3::ZERO);
+                state.set_block_world_loaded(blocked + IVec3::Y, Block::stone());
+                for (cell, placed) in [(door, true), (blocked, false)] {
+                    assert_eq!(
+                        state.place_block(
+                            &mut commands,
+                            &mut meshes,
+                            &mut players,
+                            Vec3::NEG_Z,
+                            IVec3::Y,
+                            cell,
+                            Block::door(),
+                        ),
+                        placed
+                    );
+                }
+                assert_eq!(state.get_block_world(blocked), Some(Block::air()));
+                assert_eq!(
+                    state.get_block_world(door + IVec3::Y),
+                    Some(Block::door().door_upper_half())
+                );
+
+                let (panel, hinge_side) = (Vec3::new(0.5, 0.5, 0.1), Vec3::new(0.1, 0.5, 0.5));
+                assert!(probe(&state, panel) && !probe(&state, hinge_side));
+                assert!(state.toggle_door(&mut meshes, door + IVec3::Y));
+                assert!(state.get_block_world(door).unwrap().is_open_door());
+                assert!(!probe(&state, panel) && probe(&state, hinge_side));
+                assert!(state.toggle_door(&mut meshes, door));
+                assert!(
+                    !state
+                        .get_block_world(door + IVec3::Y)
+                        .unwrap()
+                        .is_open_door()
+                );
+
+                assert!(state.break_block(&mut meshes, door + IVec3::Y));
+                assert_eq!(state.get_block_world(door), Some(Block::air()));
+            },
+        )
+        .unwrap();
+    }
+
+    /// Verify saplings root only in soil, grow into a tree, and wait under an obstruction.
+    #[test]
+    fn sapling_grows_into_tree_unless_obstructed() {
+        let mut chunk = Chunk::new_empty();
+        let (open, covered) = (IVec3::new(5, 3, 10), IVec3::new(12, 3, 4));
+        for sapling in [open, covered] {
+            chunk.set_block(sapling - IVec3::Y, Block::dirt_with_grass());
+            chunk.set_block(sapling, Block::sapling());
+        }
+        chunk.set_block(covered + IVec3::Y * 3, Block::stone());
+        chunk.set_block(IVec3::new(4, 2, 4), Block::stone());
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let grown = ecs
+            .run_system_once(
+                move |mut world: ResMut<WorldState>, mut meshes: ResMut<Assets<Mesh>>| {
+                    (
+                        world.grow_tree(&mut meshes, open, 4),
+                        world.grow_tree(&mut meshes, covered, 4),
+                    )
+                },
+            )
+            .unwrap();
+        assert_eq!(grown, (true, false));
+        let state = ecs.resource::<WorldState>();
+        assert_eq!(state.get_block_world(open - IVec3::Y), Some(Block::dirt()));
+        for y in 0..4 {
+            assert_eq!(
+                state.get_block_world(open + IVec3::Y * y),
+                Some(Block::log())
+            );
+        }
+        assert_eq!(
+            state.get_block_world(open + IVec3::new(1, 3, 0)),
+            Some(Block::leaves())
+        );
+        assert_eq!(state.get_block_world(covered), Some(Block::sapling()));
+        assert_eq!(
+            state.get_block_world(covered + IVec3::Y),
+            Some(Block::air())
+        );
+
+        assert!(state.has_attachment_support(covered, Block::sapling()));
+        assert!(!state.has_attachment_support(IVec3::new(4, 3, 4), Block::sapling()));
+        assert!(state.has_attachment_support(IVec3::new(4, 3, 4), Block::tall_grass()));
+    }
+
+    /// Verify an edited chunk above the vertical window unloads by distance and reloads intact.
+    #[test]
+    fn edited_chunk_above_window_unloads_and_reloads_intact() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut ecs = World::new();
+        ecs.insert_resource(WorldState::new());
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let tower_block = IVec3::new(3, MAX_CHUNK_LAYER * CHUNK_SIZE + 2, 3);
+        let (tower_chunk, _) = WorldState::world_to_chunk_local(tower_block);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                state.set_block_world_ensured(
+                    &mut commands,
+                    &mut meshes,
+                    tower_block,
+                    Block::dirt(),
+                );
+            },
+        )
+        .unwrap();
+
+        // Walk away: the tower chunk leaves the needed set and is stored on unload.
+        ecs.run_system_once(|mut commands: Commands, mut state: ResMut<WorldState>| {
+            let far = IVec3::new(10 * VIEW_DISTANCE, 0, 0);
+            let mut needed = state.build_needed_chunk_set(far, VIEW_DISTANCE);
+            state.extend_needed_with_edited(far, &mut needed);
+            state.sync_needed_set(needed);
+            for coord in state.collect_unneeded_loaded_chunks(usize::MAX) {
+                state.unload_chunk(&mut commands, coord);
+            }
+        })
+        .unwrap();
+        let state = ecs.resource::<WorldState>();
+        assert!(!state.chunks.contains_key(&tower_chunk));
+        assert!(state.edited_chunks.contains_key(&tower_chunk));
+
+        // Return: the stored chunk re-enters the needed set and streams back in.
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                let mut needed = state.build_needed_chunk_set(IVec3::ZERO, VIEW_DISTANCE);
+                state.extend_needed_with_edited(IVec3::ZERO, &mut needed);
+                assert!(needed.contains(&tower_chunk));
+                state.sync_needed_set(needed);
+                state.pending.push_back(tower_chunk);
+                state.spawn_chunk_build_tasks(AsyncComputeTaskPool::get());
+                let mut finished = Vec::new();
+                while !state.in_flight.is_empty() {
+                    finished.extend(state.collect_finished_chunk_tasks());
+                }
+                state.apply_finished_chunk_results(&mut commands, &mut meshes, finished);
+            },
+        )
+        .unwrap();
+        let state = ecs.resource::<WorldState>();
+        assert_eq!(state.get_block_world(tower_block), Some(Block::dirt()));
+        assert!(state.chunks[&tower_chunk].edited);
+        assert!(state.edited_chunks.is_empty());
+    }
+
+    /// Verify chest contents are stored with their unloaded chunk, come back on reload, and
+    /// are detached for spilling once the chest is broken.
+    #[test]
+    fn chest_contents_survive_unload_and_detach_when_broken() {
+        let mut ecs = World::new();
+        ecs.insert_resource(WorldState::new());
+        ecs.init_resource::<Assets<Mesh>>();
+        let chest = IVec3::new(3, 2 * CHUNK_SIZE + 2, 3);
+        let (chest_chunk, _) = WorldState::world_to_chunk_local(chest);
+
+        let broken = ecs
+            .run_system_once(
+                move |mut commands: Commands,
+                      mut meshes: ResMut<Assets<Mesh>>,
+                      mut state: ResMut<WorldState>| {
+                    state.set_block_world_ensured(
+                        &mut commands,
+                        &mut meshes,
+                        chest,
+                        Block::chest(),
+                    );
+                    state
+                        .block_entities
+                        .insert(chest, BlockEntity::for_kind(BlockKind::Chest).unwrap());
+                    let container = state.block_entities.container_mut(chest).unwrap();
+                    container.insert(Block::of_kind(BlockKind::Log), 5);
+
+                    state.unload_chunk(&mut commands, chest_chunk);
+                    assert!(state.block_entities.container(chest).is_none());
+                    assert_eq!(state.edited_chunks[&chest_chunk].block_entities.len(), 1);
+                    state.ensure_chunk(&mut commands, &mut meshes, chest_chunk);
+                    let container = state.block_entities.container(chest).unwrap();
+                    assert_eq!(
+                        container.slots()[0],
+                        Some((Block::of_kind(BlockKind::Log), 5))
+                    );
+
+                    assert!(state.break_block(&mut meshes, chest));
+                    state.block_entities.take_detached()
+                },
+            )
+            .unwrap();
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].0, chest);
+        assert_eq!(
+            broken[0].1.spilled_stacks(),
+            vec![(Block::of_kind(BlockKind::Log), 5)]
+        );
+    }
+
+    /// Verify the needed set stops at the world border but keeps chunks straddling it.
+    #[test]
+    fn needed_set_is_clipped_to_border() {
+        let mut state = WorldState::new();
+        state.border = WorldBorder {
+            half_extent: 2 * CHUNK_SIZE + 4,
+        };
+        let needed = state.build_needed_chunk_set(IVec3::ZERO, 4);
+        let columns: HashSet<IVec2> = needed.iter().map(|coord| coord.xz()).collect();
+        assert!(columns.contains(&IVec2::new(2, 0)));
+        assert!(columns.contains(&IVec2::new(-3, 0)));
+        assert!(columns.iter().all(|column| column.x <= 2 && column.x >= -3));
+        assert!(columns.iter().all(|column| column.y <= 2 && column.y >= -3));
+        assert_eq!(
+            needed.len(),
+            columns.len() * (MAX_CHUNK_LAYER - MIN_CHUNK_LAYER) as usize
+        );
+    }
+
+    /// Verify shrinking unloads farthest rings first within budget and growth adds rings on a
+    /// time budget behind nearer chunks.
+    #[test]
+    fn view_distance_changes_stream_gradually() {
+        let mut state = WorldState::new();
+        state.center = IVec3::ZERO;
+        state.view_distance = 4;
+        state.effective_view_distance = 4;
+        for coord in state.build_needed_chunk_set(IVec3::ZERO, 4) {
+            state.chunks.insert(
+                coord,
+                ChunkData::new(
+                    Chunk::new_empty(),
+                    Handle::<Mesh>::default(),
+                    Entity::PLACEHOLDER,
+                ),
+            );
+        }
+
+        state.view_distance = 2;
+        let needed = state.build_needed_chunk_set(IVec3::ZERO, state.streaming_radius());
+        state.sync_needed_set(needed);
+        let mut previous_effective = state.effective_view_distance;
+        loop {
+            let batch = state.collect_unneeded_loaded_chunks(40);
+            if batch.is_empty() {
+                break;
+            }
+            assert!(batch.len() <= 40);
+            let nearest_unloaded = batch
+                .iter()
+                .map(|coord| WorldState::ring_distance(IVec3::ZERO, *coord))
+                .min()
+                .unwrap();
+            for coord in &batch {
+                state.chunks.remove(coord);
+            }
+            let farthest_kept = state
+                .chunks
+                .keys()
+                .filter(|coord| !state.needed.contains(coord))
+                .map(|coord| WorldState::ring_distance(IVec3::ZERO, *coord))
+                .max()
+                .unwrap_or(0);
+            assert!(farthest_kept <= nearest_unloaded);
+            state.step_effective_view_distance(RING_GROW_SECS);
+            assert!(state.effective_view_distance <= previous_effective);
+            assert!(state.effective_view_distance >= 2);
+            previous_effective = state.effective_view_distance;
+        }
+        assert_eq!(state.effective_view_distance, 2);
+        assert_eq!(state.chunks.len(), state.needed.len());
+
+        // Growth adds a ring per budget interval even while earlier chunks still build,
+        // and queues the new ring behind nearer chunks.
+        state.view_distance = 4;
+        state.chunks.remove(&IVec3::X);
+        state.pending.push_back(IVec3::X);
+        state.step_effective_view_distance(RING_GROW_SECS * 0.5);
+        assert_eq!(state.effective_view_distance, 2);
+        state.step_effective_view_distance(RING_GROW_SECS * 0.5);
+        assert_eq!(state.effective_view_distance, 3);
+        let needed = state.build_needed_chunk_set(IVec3::ZERO, state.streaming_radius());
+        state.sync_needed_set(needed);
+        state.enqueue_needed_chunks();
+        let rings: Vec<i32> = state
+            .pending
+            .iter()
+            .map(|coord| WorldState::ring_distance(IVec3::ZERO, *coord))
+            .collect();
+        assert_eq!(rings.first(), Some(&2));
+        assert!(rings.is_sorted());
+        assert!(rings.contains(&3));
+        state.step_effective_view_distance(RING_GROW_SECS);
+        assert_eq!(state.effective_view_distance, 4);
+        assert_eq!(state.streaming_radius(), 4);
+    }
+
+    /// Verify placing into a chunk whose async build is in flight keeps one chunk and the edit.
+    #[test]
+    fn place_into_building_chunk_keeps_single_chunk_and_block() {
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        let mut ecs = World::new();
+        ecs.insert_resource(WorldState::new());
+        ecs.init_resource::<Assets<Mesh>>();
+
+        let coord = IVec3::new(0, 2, 0);
+        let placed = IVec3::new(5, 2 * CHUNK_SIZE + 7, 5);
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                let needed = state.build_needed_chunk_set(IVec3::ZERO, VIEW_DISTANCE);
+                state.sync_needed_set(needed);
+                state.pending.push_back(coord);
+                state.spawn_chunk_build_tasks(AsyncComputeTaskPool::get());
+                assert!(state.in_flight.contains_key(&coord));
+
+                state.set_block_world_ensured(&mut commands, &mut meshes, placed, Block::sand());
+                assert!(!state.should_accept_finished_chunk(coord));
+
+                let mut finished = Vec::new();
+                while !state.in_flight.is_empty() {
+                    finished.extend(state.collect_finished_chunk_tasks());
+                }
+                state.apply_finished_chunk_results(&mut commands, &mut meshes, finished);
+            },
+        )
+        .unwrap();
+
+        let chunk_entities = ecs.query::<&bevy::mesh::Mesh3d>().iter(&ecs).count();
+        assert_eq!(chunk_entities, 1);
+        let state = ecs.resource::<WorldState>();
+        assert_eq!(state.get_block_world(placed), Some(Block::sand()));
+    }
+
+    /// Verify repeated jump-place-land cycles build a pillar under an airborne player.
+    #[test]
+    fn pillar_jumping_builds_five_block_column() {
+        use crate::player::{PlayerRecovered, Velocity, physics_system};
+        use crate::scene::SpawnPoint;
+        use crate::{STAND_EYE_HEIGHT, STAND_HALF_SIZE};
+
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(
+                Chunk::new_empty(),
+                Handle::<Mesh>::default(),
+                Entity::PLACEHOLDER,
+            ),
+        );
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                state.set_block_world_loaded(IVec3::new(x, 0, z), Block::dirt());
+            }
+        }
+        ecs.insert_resource(state);
+        ecs.insert_resource(SpawnPoint {
+            ground: IVec3::ZERO,
+        });
+        ecs.init_resource::<Assets<Mesh>>();
+        ecs.insert_resource(ButtonInput::<KeyCode>::default());
+        ecs.init_resource::<Messages<PlayerRecovered>>();
+        ecs.insert_resource(Time::<()>::default());
+        let player = ecs
+            .spawn((
+                PlayerBody,
+                Velocity(Vec3::ZERO),
+                Transform::from_xyz(4.5, 1.0 + STAND_HALF_SIZE.y, 4.5),
+                Player::new_standing(10.4, STAND_HALF_SIZE, STAND_EYE_HEIGHT),
+            ))
+            .id();
+
+        let mut placed = 0;
+        for _ in 0..600 {
+            if placed == 5 {
+                break;
+            }
+            ecs.resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(1.0 / 60.0));
+            ecs.run_system_once(physics_system).unwrap();
+            ecs.run_system_once(
+                |mut query: Query<(&mut Velocity, &mut Player), With<PlayerBody>>| {
+                    let (mut velocity, mut player) = query.single_mut().unwrap();
+                    player.try_start_jump(&mut velocity.0);
+                },
+            )
+            .unwrap();
+            let placed_now = ecs
+                .run_system_once(
+                    |mut commands: Commands,
+                     mut meshes: ResMut<Assets<Mesh>>,
+                     mut state: ResMut<WorldState>,
+                     mut query: Query<(&mut Transform, &mut Player), With<PlayerBody>>| {
+                        let (transform, player) = query.single().unwrap();
+                        let eye = transform.translation + Vec3::Y * player.eye_offset();
+                        let (Some(hit), Some(target)) =
+                            state.raymarch_hit_and_last_empty(eye, Vec3::NEG_Y)
+                        else {
+                            return false;
+                        };
+                        hit == target - IVec3::Y
+                            && state.place_block(
+                                &mut commands,
+                                &mut meshes,
+                                &mut query,
+                                Vec3::NEG_Y,
+                                IVec3::Y,
+                                target,
+                                Block::sand(),
+                            )
+                    },
+                )
+                .unwrap();
+            if placed_now {
+                placed += 1;
+            }
+        }
+
+        assert_eq!(placed, 5);
+        let state = ecs.resource::<WorldState>();
+        for y in 1..=5 {
+            let block = state.get_block_world(IVec3::new(4, y, 4)).unwrap();
+            assert_eq!(block.kind, BlockKind::Sand);
+        }
+        let feet = ecs.get::<Transform>(player).unwrap().translation.y - STAND_HALF_SIZE.y;
+        assert!(feet >= 6.0 - 1e-4);
+    }
+
+    /// Verify cells under a roof darken with depth, and that breaking a roof block lights
+    /// its column again and remeshes both chunks it runs through.
+    #[test]
+    fn sky_light_follows_column_roofs() {
+        let mut state = WorldState::new();
+        let mut roof = Chunk::new_empty();
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                roof.set_block(IVec3::new(x, 2, z), Block::stone());
+            }
+        }
+        for (coord, chunk) in [(IVec3::ZERO, Chunk::new_empty()), (IVec3::Y, roof)] {
+            state.chunks.insert(
+                coord,
+                ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+            );
+        }
+        state.relight_sky_chunk_columns(IVec3::Y);
+        let sky = |state: &WorldState, pos: IVec3| {
+            let (coord, local) = WorldState::world_to_chunk_local(pos);
+            state.chunks[&coord].sky.get(local)
+        };
+        assert_eq!(sky(&state, IVec3::new(5, 19, 5)), MAX_LIGHT);
+        assert_eq!(
+            sky(&state, IVec3::new(5, 17, 5)),
+            MAX_LIGHT - SKY_LIGHT_FALLOFF as u8
+        );
+        assert_eq!(sky(&state, IVec3::new(5, 10, 5)), 0);
+        assert!(!state.light_queue.has_touched());
+        assert!(
+            state.remesh_queue.contains(&IVec3::ZERO) && state.remesh_queue.contains(&IVec3::Y)
+        );
+
+        state.set_block_world_loaded(IVec3::new(5, 18, 5), Block::air());
+        assert_eq!(sky(&state, IVec3::new(5, 10, 5)), MAX_LIGHT);
+        assert_eq!(sky(&state, IVec3::new(6, 10, 5)), 0);
+        let touched = state.light_queue.take_touched();
+        assert!(touched.contains(&IVec3::ZERO) && touched.contains(&IVec3::Y));
+        let light = state.light_snapshot(IVec3::ZERO).unwrap();
+        assert_eq!(light.level(IVec3::new(5, 10, 5)), MAX_LIGHT);
+        assert_eq!(light.level(IVec3::new(6, 10, 5)), 0);
+    }
+
+    /// Verify a loading chunk only walks into the chunks below through columns it newly
+    /// roofs, and queues staged remeshes instead of touching chunks for an immediate one.
+    #[test]
+    fn chunk_load_relights_only_newly_roofed_columns() {
+        let mut state = WorldState::new();
+        let mut roof = Chunk::new_empty();
+        roof.set_block(IVec3::new(3, 4, 3), Block::stone());
+        let mut floor = Chunk::new_empty();
+        floor.set_block(IVec3::new(3, 0, 3), Block::stone());
+        floor.set_block(IVec3::new(9, 0, 9), Block::stone());
+        for (coord, chunk) in [
+            (IVec3::ZERO, Chunk::new_empty()),
+            (IVec3::Y * 2, roof),
+            (IVec3::Y, floor),
+        ] {
+            state.chunks.insert(
+                coord,
+                ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+            );
+            state.remesh_queue.clear();
+            state.relight_sky_chunk_columns(coord);
+        }
+        let sky = |state: &WorldState, pos: IVec3| {
+            let (coord, local) = WorldState::world_to_chunk_local(pos);
+            state.chunks[&coord].sky.get(local)
+        };
+        // Roofed from above before the middle chunk loaded: its floor changes nothing below.
+        assert_eq!(sky(&state, IVec3::new(3, 8, 3)), 0);
+        // Newly roofed by the middle chunk's floor.
+        assert_eq!(
+            sky(&state, IVec3::new(9, 15, 9)),
+            MAX_LIGHT - SKY_LIGHT_FALLOFF as u8
+        );
+        assert_eq!(sky(&state, IVec3::new(8, 15, 9)), MAX_LIGHT);
+        assert!(!state.light_queue.has_touched());
+        assert!(state.remesh_queue.contains(&IVec3::Y));
+        assert!(state.remesh_queue.contains(&IVec3::ZERO));
+
+        state.remesh_queue.clear();
+        state.chunks.insert(
+            IVec3::Y * 3,
+            ChunkData::new(
+                Chunk::new_empty(),
+                Handle::<Mesh>::default(),
+                Entity::PLACEHOLDER,
+            ),
+        );
+        state.relight_sky_chunk_columns(IVec3::Y * 3);
+        assert!(state.remesh_queue.is_empty());
+    }
+
+    /// Verify gameplay light dims open sky at midnight, keeps a torch's glow, and reads
+    /// unloaded cells as open sky.
+    #[test]
+    fn light_at_combines_time_of_day_with_torches() {
+        let mut state = WorldState::new();
+        let mut chunk = Chunk::new_empty();
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                chunk.set_block(IVec3::new(x, 0, z), Block::stone());
+            }
+        }
+        state.chunks.insert(
+            IVec3::ZERO,
+            ChunkData::new(chunk, Handle::<Mesh>::default(), Entity::PLACEHOLDER),
+        );
+        let torch = IVec3::new(2, 1, 2);
+        let open = IVec3::new(12, 1, 12);
+        state.set_block_world_loaded(torch, Block::torch());
+        let torch_level = Block::torch().light_emission();
+
+        assert_eq!(state.light_at(open), MAX_LIGHT);
+        assert_eq!(state.light_at(torch), MAX_LIGHT);
+        assert!(!state.hostile_spawn_allowed(open));
+
+        state.sky_darkening = NIGHT_SKY_DARKENING;
+        assert_eq!(state.light_at(open), MAX_LIGHT - NIGHT_SKY_DARKENING);
+        assert_eq!(state.light_at(torch), torch_level);
+        assert!(state.hostile_spawn_allowed(open));
+        assert!(!state.hostile_spawn_allowed(torch));
+        assert_eq!(
+            state.light_at(IVec3::new(100, 1, 100)),
+            MAX_LIGHT - NIGHT_SKY_DARKENING
+        );
+    }
+
+    /// Verify a moving sun re-tints loaded chunk meshes on the budgeted sun queue without
+    /// remeshing them, while toggling the preset queues full remeshes.
+    #[test]
+    fn sun_moves_retint_without_remeshing() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::sync::Arc;
+
+        use crate::voxel::generator::FlatGenerator;
+
+        let mut ecs = World::new();
+        let mut state = WorldState::new();
+        state.generator = Arc::new(FlatGenerator { height: 3 });
+        state.sun_shading = Some(Vec3::Y);
+        ecs.insert_resource(state);
+        ecs.init_resource::<Assets<Mesh>>();
+        let coords = [IVec3::ZERO, IVec3::X];
+        ecs.run_system_once(
+            move |mut commands: Commands,
+                  mut meshes: ResMut<Assets<Mesh>>,
+                  mut state: ResMut<WorldState>| {
+                for coord in coords {
+                    state.ensure_chunk(&mut commands, &mut meshes, coord);
+                }
+            },
+        )
+        .unwrap();
+
+        let to_sun = Vec3::new(0.6, 0.8, 0.0);
+        let mut state = ecs.resource_mut::<WorldState>();
+        assert!(
+            coords
+                .iter()
+                .all(|coord| state.chunks[coord].sun_baked == Some(Vec3::Y))
+        );
+        let remeshes_before = state.remesh_queue.clone();
+        state.set_sun_shading(Some(to_sun));
+        assert_eq!(state.remesh_queue, remeshes_before);
+        assert_eq!(state.sun_queue.len(), coords.len());
+
+        ecs.run_system_once(
+            |mut meshes: ResMut<Assets<Mesh>>, mut state: ResMut<WorldState>| {
+                state.process_sun_queue(&mut meshes, 1);
+            },
+        )
+        .unwrap();
+        let mut state = ecs.resource_mut::<WorldState>();
+        assert_eq!(state.sun_queue.len(), 1);
+        let retinted = coords
+            .iter()
+            .filter(|coord| state.chunks[*coord].sun_baked == Some(to_sun))
+            .count();
+        assert_eq!(retinted, 1);
+
+        state.set_sun_shading(None);
+        assert_eq!(state.remesh_queue.len(), coords.len());
+    }
 }
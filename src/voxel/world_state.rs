@@ -2,26 +2,67 @@ use bevy::prelude::*;
 use bevy::tasks::Task;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::voxel::block_chunk::Chunk;
+use crate::voxel::block_chunk::{Block, Chunk};
+use crate::voxel::block_defs::MeshLayer;
+use crate::voxel::block_entity_state::{BlockEntities, EditedChunk};
+use crate::voxel::block_update_state::BlockUpdateQueue;
+use crate::voxel::generator::SharedChunkGenerator;
+use crate::voxel::light_state::{ChunkLight, ChunkSkyLight, LightQueue};
+use crate::voxel::mesh::BlockMeshCache;
 use crate::voxel::mesh_types::MeshData;
+use crate::voxel::water_state::WaterFlowQueue;
+use crate::{BLOCK_SIZE, CHUNK_SIZE};
+
+/// Mesh of one of a chunk's `MeshLayer::SEPARATE` classes and the entity drawing it.
+pub struct ChunkLayerMesh {
+    /// GPU mesh handle of the class's faces.
+    pub mesh: Handle<Mesh>,
+    /// Spawned world entity that renders `mesh` with the class's material.
+    pub entity: Entity,
+    /// Owning cell of every quad of `mesh`, in mesh order, for incremental remeshes.
+    pub quad_cells: Vec<u16>,
+}
 
 /// Runtime wrapper that binds chunk voxel data to mesh/entity handles.
 pub struct ChunkData {
     /// Voxel payload for this loaded chunk.
     pub chunk: Chunk,
-    /// GPU mesh handle corresponding to the current chunk mesh.
+    /// GPU mesh handle of the opaque chunk mesh.
     pub mesh: Handle<Mesh>,
-    /// Spawned world entity that renders this chunk.
+    /// Owning cell of every quad of `mesh`, in mesh order, for incremental remeshes.
+    pub quad_cells: Vec<u16>,
+    /// Spawned world entity that renders the opaque chunk mesh.
     pub entity: Entity,
+    /// Meshes of the water, glass, and emissive classes, each present once the chunk has
+    /// held blocks of that class.
+    pub layer_meshes: HashMap<MeshLayer, ChunkLayerMesh>,
+    /// Local cells and blocks of the chunk's torches and glowstone, refreshed with its mesh.
+    pub light_sources: Vec<(IVec3, Block)>,
+    /// Whether voxels diverge from freshly generated terrain (kept across unloads).
+    pub edited: bool,
+    /// Block light level of every cell, rebuilt from emitters whenever the chunk loads.
+    pub light: ChunkLight,
+    /// Sky light level of every cell from its depth below its column's roof, rebuilt
+    /// whenever a chunk of its column loads or a light-blocking block changes.
+    pub sky: ChunkSkyLight,
+    /// Sun direction baked into the face shading of `mesh`, `None` for the fixed shades.
+    pub sun_baked: Option<Vec3>,
 }
 
 impl ChunkData {
     /// Build runtime chunk data from voxel payload, mesh handle, and entity id.
     pub fn new(chunk: Chunk, mesh: Handle<Mesh>, entity: Entity) -> Self {
         Self {
+            light_sources: chunk.light_sources(),
             chunk,
             mesh,
+            quad_cells: Vec::new(),
             entity,
+            layer_meshes: HashMap::new(),
+            edited: false,
+            light: ChunkLight::default(),
+            sky: ChunkSkyLight::default(),
+            sun_baked: None,
         }
     }
 }
@@ -31,8 +72,10 @@ impl ChunkData {
 pub struct WorldState {
     /// Loaded chunks currently present in the world.
     pub chunks: HashMap<IVec3, ChunkData>,
-    /// Shared block material handle used by chunk meshes.
-    pub material: Handle<StandardMaterial>,
+    /// Shared single-block meshes for the preview and falling blocks.
+    pub block_meshes: BlockMeshCache,
+    /// Chunk generator shared with async chunk generation tasks.
+    pub generator: SharedChunkGenerator,
     /// Chunk-space center around the camera/player for streaming.
     pub center: IVec3,
     /// Desired chunk set for the current streaming window.
@@ -41,6 +84,254 @@ pub struct WorldState {
     pub pending: VecDeque<IVec3>,
     /// Async chunk build tasks currently running.
     pub in_flight: HashMap<IVec3, Task<ChunkBuildOutput>>,
+    /// Loaded chunks waiting for a full mesh rebuild off the main thread, with the
+    /// generation of each chunk's latest edit and of the mesh it shows.
+    pub remesh_dirty: DirtyChunks,
+    /// Running off-thread mesh rebuilds of loaded chunks, at most one per chunk.
+    pub remesh_tasks: HashMap<IVec3, Task<ChunkRemeshOutput>>,
+    /// Loaded chunks that gained blocks of a separate mesh class they have no mesh for yet,
+    /// created by the next `create_missing_layer_meshes`.
+    pub missing_layer_meshes: HashSet<(IVec3, MeshLayer)>,
+    /// Voxel payloads and block entities of edited chunks that were unloaded, restored when
+    /// they stream back in.
+    pub edited_chunks: HashMap<IVec3, EditedChunk>,
+    /// Data attached to blocks of loaded chunks, such as chest contents.
+    pub block_entities: BlockEntities,
+    /// Dynamic entities owned by unloaded chunks, respawned when the chunk loads again.
+    pub chunk_entities: HashMap<IVec3, Vec<ChunkEntityRecord>>,
+    /// Direction towards the sun baked into chunk meshes in cheap-shadow mode (`None` when off).
+    pub sun_shading: Option<Vec3>,
+    /// Chunks waiting for a staged remesh after the mesh shading changed.
+    pub remesh_queue: VecDeque<IVec3>,
+    /// Chunks whose opaque mesh may show an older sun direction than `sun_shading`, re-tinted
+    /// a few per frame without a remesh.
+    pub sun_queue: VecDeque<IVec3>,
+    /// Cells whose water flow needs re-evaluation, queued by every loaded block write.
+    pub water_queue: WaterFlowQueue,
+    /// Loaded chunks whose border faces look into a cell edited since the last mesh batch,
+    /// rebuilt by the next `rebuild_touched_chunk_meshes`.
+    pub border_touched: HashSet<IVec3>,
+    /// Local cells per loaded chunk whose faces may have changed with a block edited since
+    /// the last mesh batch, so the next `rebuild_touched_chunk_meshes` can patch just those.
+    pub edited_cells: HashMap<IVec3, HashSet<IVec3>>,
+    /// Dry sponges placed since the last sponge pass, waiting to soak up the water around them.
+    pub sponge_queue: VecDeque<IVec3>,
+    /// Delayed block updates, such as covered grass dying to dirt.
+    pub block_updates: BlockUpdateQueue,
+    /// Block light changes still spreading through loaded chunks.
+    pub light_queue: LightQueue,
+    /// Sky light level of cells open to the sky, the most any cell's column sky light reaches.
+    pub sky_light: u8,
+    /// Sky light levels the time of day currently takes off gameplay light (`0` at noon).
+    pub sky_darkening: u8,
+    /// Moving entity boxes the player collides with this frame (rebuilt every frame).
+    pub transient_colliders: Vec<TransientCollider>,
+    /// Target horizontal streaming radius in chunks.
+    pub view_distance: i32,
+    /// Radius actually streamed right now, trailing `view_distance` while rings load or unload.
+    pub effective_view_distance: i32,
+    /// Seconds the effective view distance has waited for its next ring while growing.
+    pub ring_growth_secs: f32,
+    /// Horizontal limit for streaming, player movement, and block placement.
+    pub border: WorldBorder,
+    /// Draw chunk meshes as wireframes, a debug view for meshing bugs.
+    pub wireframe: bool,
+}
+
+/// Square horizontal world limit centered on the origin, in blocks.
+///
+/// Keeps streaming and the player away from coordinates where `f32` positions lose precision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorldBorder {
+    /// Distance from the origin to each border plane along X and Z, in blocks.
+    pub half_extent: i32,
+}
+
+impl Default for WorldBorder {
+    fn default() -> Self {
+        Self {
+            half_extent: Self::DEFAULT_HALF_EXTENT,
+        }
+    }
+}
+
+impl WorldBorder {
+    /// Border distance used when `--world-border` is not given.
+    pub const DEFAULT_HALF_EXTENT: i32 = 2048;
+
+    /// Read `--world-border <blocks>` from the command line, defaulting to ±2048 blocks.
+    pub fn from_args() -> Self {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg != "--world-border" {
+                continue;
+            }
+            match args.next().map(|value| value.parse()) {
+                Some(Ok(half_extent)) if half_extent > 0 => return Self { half_extent },
+                _ => warn!(
+                    "Ignoring invalid --world-border value, using ±{}",
+                    Self::DEFAULT_HALF_EXTENT
+                ),
+            }
+        }
+        Self::default()
+    }
+
+    /// Return whether block `pos` lies inside the border.
+    pub fn contains_block(&self, pos: IVec3) -> bool {
+        (-self.half_extent..self.half_extent).contains(&pos.x)
+            && (-self.half_extent..self.half_extent).contains(&pos.z)
+    }
+
+    /// Return whether any block column of chunk `coord` lies inside the border.
+    pub fn overlaps_chunk(&self, coord: IVec3) -> bool {
+        let min = coord.xz() * CHUNK_SIZE;
+        let max = min + CHUNK_SIZE;
+        min.cmplt(IVec2::splat(self.half_extent)).all()
+            && max.cmpgt(IVec2::splat(-self.half_extent)).all()
+    }
+
+    /// Return whether an AABB at `position` with `half_size` stays inside the border planes.
+    pub fn contains_aabb(&self, position: Vec3, half_size: Vec3) -> bool {
+        let limit = self.half_extent as f32 * BLOCK_SIZE;
+        let (min, max) = ((position - half_size).xz(), (position + half_size).xz());
+        min.cmpge(Vec2::splat(-limit)).all() && max.cmple(Vec2::splat(limit)).all()
+    }
+}
+
+/// Chunks whose full mesh rebuild is queued or running, tracked by edit generation.
+///
+/// Every queued rebuild takes a fresh generation, so callers holding one can tell when a
+/// mesh showing at least that edit has been swapped in.
+#[derive(Default)]
+pub struct DirtyChunks {
+    /// Generation handed to the last queued rebuild.
+    generation: u64,
+    /// Chunks waiting for a rebuild task, with the generation of their latest edit.
+    queued: HashMap<IVec3, u64>,
+    /// Chunks with a rebuild task running, with the generation its snapshot covers.
+    building: HashMap<IVec3, u64>,
+    /// Loaded chunks with the generation their current mesh covers.
+    shown: HashMap<IVec3, u64>,
+}
+
+impl DirtyChunks {
+    /// Queue a rebuild of `coord` and return the generation of this edit.
+    pub(crate) fn mark(&mut self, coord: IVec3) -> u64 {
+        self.generation += 1;
+        self.queued.insert(coord, self.generation);
+        self.generation
+    }
+
+    /// Return whether `coord` waits for a rebuild task.
+    pub(crate) fn contains(&self, coord: &IVec3) -> bool {
+        self.queued.contains_key(coord)
+    }
+
+    /// Iterate the chunks waiting for a rebuild task.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &IVec3> {
+        self.queued.keys()
+    }
+
+    /// Move `coord` from the queue to the running rebuilds, as its task snapshots it.
+    pub(crate) fn start(&mut self, coord: IVec3) {
+        if let Some(generation) = self.queued.remove(&coord) {
+            self.building.insert(coord, generation);
+        }
+    }
+
+    /// Record that the running rebuild of `coord` was swapped in.
+    pub(crate) fn finish(&mut self, coord: IVec3) {
+        if let Some(generation) = self.building.remove(&coord) {
+            self.shown.insert(coord, generation);
+        }
+    }
+
+    /// Record a synchronous rebuild of `coord`, which shows every edit made so far.
+    pub(crate) fn show_latest(&mut self, coord: IVec3) {
+        self.queued.remove(&coord);
+        self.building.remove(&coord);
+        self.shown.insert(coord, self.generation);
+    }
+
+    /// Drop all tracking of `coord`, as the chunk unloads.
+    pub(crate) fn forget(&mut self, coord: IVec3) {
+        self.queued.remove(&coord);
+        self.building.remove(&coord);
+        self.shown.remove(&coord);
+    }
+
+    /// Return the generation of the latest edit of `coord` its mesh does not show yet.
+    pub(crate) fn unshown(&self, coord: IVec3) -> Option<u64> {
+        self.queued
+            .get(&coord)
+            .or_else(|| self.building.get(&coord))
+            .copied()
+    }
+
+    /// Return whether the mesh of `coord` shows the edit of `generation`.
+    pub(crate) fn is_shown(&self, coord: IVec3, generation: u64) -> bool {
+        self.shown
+            .get(&coord)
+            .is_some_and(|shown| *shown >= generation)
+    }
+}
+
+/// World-space box of a moving entity registered for player collision for one frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransientCollider {
+    /// Minimum corner in world space.
+    pub min: Vec3,
+    /// Maximum corner in world space.
+    pub max: Vec3,
+    /// Vertical velocity of the entity in world units per second.
+    pub velocity_y: f32,
+}
+
+impl TransientCollider {
+    /// Return whether the box `[min, max]` penetrates this collider deeper than `skin`.
+    pub(crate) fn overlaps(&self, min: Vec3, max: Vec3, skin: f32) -> bool {
+        (min + skin).cmplt(self.max).all() && (max - skin).cmpgt(self.min).all()
+    }
+}
+
+/// Dynamic entity state parked with an unloaded chunk.
+///
+/// Ownership goes to the chunk containing the entity center, so border-straddling
+/// entities are stored exactly once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChunkEntityRecord {
+    /// Falling block entity mid-flight.
+    FallingBlock {
+        /// Entity translation (block min corner) in world space.
+        translation: Vec3,
+        /// Vertical velocity in world units per second.
+        velocity_y: f32,
+        /// Block state carried by the entity.
+        block: Block,
+    },
+    /// Dropped item waiting to be picked up.
+    ItemDrop {
+        /// World-space center of the item's cube.
+        center: Vec3,
+        /// Vertical velocity in world units per second.
+        velocity_y: f32,
+        /// Seconds since the item was dropped, driving its spin.
+        age: f32,
+        /// Block state the item shows and adds to the inventory by kind.
+        block: Block,
+        /// Number of items the pickup is worth.
+        count: u32,
+    },
+    /// Primed TNT whose fuse keeps its remaining time while parked.
+    PrimedTnt {
+        /// World cell the TNT was primed in and explodes from.
+        cell: IVec3,
+        /// Seconds of fuse left before the explosion.
+        fuse_left: f32,
+        /// Radius of the carved sphere, in blocks.
+        blast_radius: f32,
+    },
 }
 
 /// Result payload returned by async chunk-build tasks.
@@ -49,17 +340,43 @@ pub struct ChunkBuildOutput {
     pub(crate) coord: IVec3,
     /// Generated chunk voxel data.
     pub(crate) chunk: Chunk,
-    /// Generated mesh payload for this chunk.
+    /// Generated opaque mesh payload for this chunk.
     pub(crate) mesh_data: MeshData,
+    /// Generated mesh payload of every `MeshLayer::SEPARATE` class with faces in the chunk.
+    pub(crate) layer_mesh_data: Vec<(MeshLayer, MeshData)>,
+    /// Offsets of the neighbor chunks that were not loaded when the meshes were built.
+    pub(crate) unbordered: Vec<IVec3>,
+    /// Sun direction baked into `mesh_data`.
+    pub(crate) sun_shading: Option<Vec3>,
 }
 
 impl ChunkBuildOutput {
     /// Build async chunk-build result payload.
-    pub(crate) fn new(coord: IVec3, chunk: Chunk, mesh_data: MeshData) -> Self {
+    pub(crate) fn new(
+        coord: IVec3,
+        chunk: Chunk,
+        mesh_data: MeshData,
+        layer_mesh_data: Vec<(MeshLayer, MeshData)>,
+        unbordered: Vec<IVec3>,
+        sun_shading: Option<Vec3>,
+    ) -> Self {
         Self {
             coord,
             chunk,
             mesh_data,
+            layer_mesh_data,
+            unbordered,
+            sun_shading,
         }
     }
 }
+
+/// Meshes of one loaded chunk rebuilt from a snapshot of its blocks.
+pub struct ChunkRemeshOutput {
+    /// Rebuilt opaque mesh payload.
+    pub(crate) mesh_data: MeshData,
+    /// Rebuilt payload of every separate class mesh the chunk has.
+    pub(crate) layer_mesh_data: Vec<(MeshLayer, MeshData)>,
+    /// Sun direction baked into `mesh_data`.
+    pub(crate) sun_shading: Option<Vec3>,
+}